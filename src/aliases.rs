@@ -0,0 +1,95 @@
+// src/aliases.rs
+// User-defined command aliases from `[aliases]` in config.toml, expanded before clap sees the arguments.
+use anyhow::{anyhow, Result};
+use clap::CommandFactory;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+/// Whether `name` is a built-in vg command; built-ins always win over an alias of the same name.
+pub fn is_builtin(name: &str) -> bool {
+    name == "help" || crate::Cli::command().find_subcommand(name).is_some()
+}
+
+/// Why an alias can't be used, if it can't.
+pub fn problem(name: &str, expansion: &str) -> Option<String> {
+    if name.is_empty() || name.starts_with('-') || name.chars().any(char::is_whitespace) {
+        return Some(format!("aliases.{} is not a valid alias name", name));
+    }
+    if is_builtin(name) {
+        return Some(format!("aliases.{} shadows the built-in vg {} command", name, name));
+    }
+    match shlex::split(expansion) {
+        Some(words) if !words.is_empty() => None,
+        Some(_) => Some(format!("aliases.{} is empty", name)),
+        None => Some(format!("aliases.{} has unbalanced quotes", name)),
+    }
+}
+
+/// Index of the command word: the first argument that isn't a global flag or a global option's value.
+fn command_position(args: &[OsString]) -> Option<usize> {
+    let cli = crate::Cli::command();
+    let takes_value = |flag: &str| {
+        cli.get_arguments().any(|a| {
+            a.get_action().takes_values()
+                && (flag.strip_prefix("--").is_some_and(|l| a.get_long() == Some(l))
+                    || flag.strip_prefix('-').and_then(|s| s.parse::<char>().ok()).is_some_and(|c| a.get_short() == Some(c)))
+        })
+    };
+    let mut i = 1;
+    while i < args.len() {
+        let arg = args[i].to_string_lossy();
+        if !arg.starts_with('-') {
+            return Some(i);
+        }
+        // `--host web1`: skip the value too (`--host=web1` is one argument)
+        i += if takes_value(&arg) { 2 } else { 1 };
+    }
+    None
+}
+
+/// Replace an alias in the command position with its expansion; the remaining arguments are kept
+/// after it, so `vg u --dry-run` with `u = "update --yes"` runs `vg update --yes --dry-run`.
+/// Aliases may refer to other aliases.
+pub fn expand(mut args: Vec<OsString>, aliases: &BTreeMap<String, String>) -> Result<Vec<OsString>> {
+    if aliases.is_empty() {
+        return Ok(args);
+    }
+    let mut seen: Vec<String> = Vec::new();
+    loop {
+        // Global flags like --debug or --host <HOST> may come before the command
+        let Some(pos) = command_position(&args) else { return Ok(args) };
+        let name = args[pos].to_string_lossy().to_string();
+        if is_builtin(&name) {
+            return Ok(args);
+        }
+        let Some(expansion) = aliases.get(&name) else { return Ok(args) };
+        if seen.contains(&name) {
+            seen.push(name);
+            return Err(anyhow!("Alias loop: {}", seen.join(" → ")));
+        }
+        let words = shlex::split(expansion)
+            .filter(|w| !w.is_empty())
+            .ok_or_else(|| anyhow!("aliases.{} could not be parsed — check its quoting with vg config edit", name))?;
+        tracing::debug!(alias = %name, expansion = %expansion, "expanding alias");
+        args.splice(pos..=pos, words.into_iter().map(OsString::from));
+        seen.push(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(line: &str, aliases: &[(&str, &str)]) -> Vec<String> {
+        let aliases = aliases.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        let args = line.split_whitespace().map(OsString::from).collect();
+        expand(args, &aliases).unwrap().iter().map(|a| a.to_string_lossy().to_string()).collect()
+    }
+
+    #[test]
+    fn host_value_is_not_taken_for_the_command() {
+        let aliases = [("myalias", "health --json"), ("web1", "update --yes")];
+        assert_eq!(run("vg --host web1 myalias", &aliases), ["vg", "--host", "web1", "health", "--json"]);
+        assert_eq!(run("vg --host=web1 myalias -v", &aliases), ["vg", "--host=web1", "health", "--json", "-v"]);
+    }
+}
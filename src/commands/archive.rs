@@ -0,0 +1,227 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Zip,
+    /// Any tar flavour — GNU/BSD tar picks the compressor from the file name
+    Tar,
+    SevenZ,
+}
+
+impl Format {
+    fn from_path(path: &Path) -> Option<Format> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".zip") {
+            Some(Format::Zip)
+        } else if name.ends_with(".7z") {
+            Some(Format::SevenZ)
+        } else if [".tar", ".tar.gz", ".tgz", ".tar.zst", ".tzst", ".tar.xz", ".txz", ".tar.bz2", ".tbz2"]
+            .iter().any(|ext| name.ends_with(ext))
+        {
+            Some(Format::Tar)
+        } else {
+            None
+        }
+    }
+
+    /// Binary needed for this format, checked up front so the error names the package.
+    /// Zip is the one format with separate tools for writing and reading.
+    fn tool(&self, create: bool) -> Result<String> {
+        let bin = match self {
+            Format::Zip if create => "zip",
+            Format::Zip => "unzip",
+            Format::Tar => "tar",
+            // Or 7zz / 7za, see the tool registry
+            Format::SevenZ => "7z",
+        };
+        crate::tools::require(bin).map(str::to_string)
+    }
+}
+
+fn detect(path: &Path) -> Result<Format> {
+    Format::from_path(path).ok_or_else(|| {
+        anyhow!("Unknown archive type: {} (supported: .zip, .7z, .tar, .tar.gz, .tar.zst, .tar.xz, .tar.bz2)", path.display())
+    })
+}
+
+/// Default extraction folder: the archive name without its extension(s), next to the archive.
+fn default_dest(archive: &Path) -> PathBuf {
+    let name = archive.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let stem = [".tar.gz", ".tar.zst", ".tar.xz", ".tar.bz2", ".tgz", ".tzst", ".txz", ".tbz2", ".tar", ".zip", ".7z"]
+        .iter()
+        .find_map(|ext| {
+            let cut = name.len().checked_sub(ext.len())?;
+            let suffix = name.get(cut..)?;
+            suffix.eq_ignore_ascii_case(ext).then(|| name[..cut].to_string())
+        })
+        .unwrap_or(name);
+    archive.with_file_name(stem)
+}
+
+/// Files and folders under `inputs` — archivers report both, one line each.
+fn count_entries(inputs: &[PathBuf]) -> usize {
+    inputs.iter()
+        .map(|p| crate::walk::walk(p, &crate::walk::Options::default(), |_| Some(())).items.len())
+        .sum()
+}
+
+/// Run `cmd` (which prints one line per processed file) and draw a progress bar out of `total`.
+fn run_with_progress(mut cmd: Command, total: usize, label: &str) -> Result<()> {
+    let running = crate::process::Running::start();
+    let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()
+        .context("Failed to start archiver")?;
+    let stdout = child.stdout.take().context("Failed to read archiver output")?;
+    // Drain stderr on the side so a chatty tool can't block on a full pipe
+    let mut stderr = child.stderr.take().context("Failed to read archiver errors")?;
+    let err_reader = std::thread::spawn(move || {
+        let mut s = String::new();
+        let _ = std::io::Read::read_to_string(&mut stderr, &mut s);
+        s
+    });
+
+    let pb = crate::progress::bar(total as u64, label);
+    for line in BufReader::new(stdout).lines().map_while(|l| l.ok()) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // The entry count is an estimate; some tools print a few extra lines
+        if pb.position() < total as u64 {
+            pb.inc(1);
+        }
+    }
+    let status = child.wait()?;
+    let errors = err_reader.join().unwrap_or_default();
+    pb.finish_and_clear();
+    running.check()?;
+    if !status.success() {
+        return Err(anyhow!("{} failed: {}", label, errors.trim()));
+    }
+    Ok(())
+}
+
+/// Entry names inside `archive`.
+fn entries(archive: &Path, format: Format) -> Result<Vec<String>> {
+    let tool = format.tool(false)?;
+    let out = match format {
+        Format::Zip => Command::new(&tool).arg("-Z1").arg(archive).output(),
+        Format::Tar => Command::new(&tool).arg("-tf").arg(archive).output(),
+        Format::SevenZ => Command::new(&tool).args(["l", "-slt", "-ba"]).arg(archive).output(),
+    }.context("Failed to list archive")?;
+    if !out.status.success() {
+        return Err(anyhow!("Could not read {}: {}", archive.display(), String::from_utf8_lossy(&out.stderr).trim()));
+    }
+    let text = String::from_utf8_lossy(&out.stdout);
+    Ok(match format {
+        // -slt prints "Path = name" blocks
+        Format::SevenZ => text.lines().filter_map(|l| l.strip_prefix("Path = ")).map(str::to_string).collect(),
+        _ => text.lines().filter(|l| !l.is_empty()).map(str::to_string).collect(),
+    })
+}
+
+/// `vg archive create <output> <inputs...>`
+pub fn create(output: &Path, inputs: &[PathBuf]) -> Result<()> {
+    ui::print_header("ARCHIVE CREATE");
+    let format = detect(output)?;
+    let tool = format.tool(true)?;
+    if output.exists() {
+        return Err(anyhow!("{} already exists", output.display()));
+    }
+    for input in inputs {
+        if !input.exists() {
+            return Err(anyhow!("{} does not exist", input.display()));
+        }
+    }
+
+    let total = count_entries(inputs);
+    ui::info_line("Archive", &output.display().to_string());
+    ui::info_line("Entries", &total.to_string());
+    println!();
+
+    let mut cmd = Command::new(&tool);
+    match format {
+        Format::Zip => { cmd.arg("-r").arg(output); }
+        // -a: compress according to the suffix; -v lists each file on stdout
+        Format::Tar => { cmd.arg("-cavf").arg(output); }
+        Format::SevenZ => { cmd.args(["a", "-bb1", "-bso1"]).arg(output); }
+    }
+    cmd.args(inputs);
+    // A half-written archive is worse than none
+    let _partial = crate::process::on_interrupt(|| { let _ = std::fs::remove_file(output); });
+    run_with_progress(cmd, total, "Compressing")?;
+
+    let size = std::fs::metadata(output).map(|m| m.len()).unwrap_or(0);
+    ui::success(&format!("Created {} ({})", output.display(), super::search::fmt_bytes(size)));
+    Ok(())
+}
+
+/// `vg archive extract <archive> [dest]`
+pub fn extract(archive: &Path, dest: Option<PathBuf>, force: bool) -> Result<()> {
+    ui::print_header("ARCHIVE EXTRACT");
+    let format = detect(archive)?;
+    let tool = format.tool(false)?;
+
+    let dest = dest.unwrap_or_else(|| default_dest(archive));
+    let names = entries(archive, format)?;
+    let total = names.len();
+    // Existing files are never replaced without --force, whichever tool unpacks the archive
+    let existing: Vec<&String> = names.iter()
+        .filter(|n| std::fs::symlink_metadata(dest.join(n)).is_ok_and(|m| !m.is_dir()))
+        .collect();
+    if !existing.is_empty() && !force {
+        return Err(anyhow!(
+            "{} file(s) already exist in {} (e.g. {}) — pass --force to overwrite them",
+            existing.len(), dest.display(), existing[0],
+        ));
+    }
+    std::fs::create_dir_all(&dest).with_context(|| format!("Failed to create {}", dest.display()))?;
+    ui::info_line("Archive", &archive.display().to_string());
+    ui::info_line("Destination", &dest.display().to_string());
+    ui::info_line("Entries", &total.to_string());
+    println!();
+
+    let mut cmd = match format {
+        Format::Zip => {
+            let mut c = Command::new(&tool);
+            c.arg(if force { "-o" } else { "-n" }).arg(archive).arg("-d").arg(&dest);
+            c
+        }
+        Format::Tar => {
+            let mut c = Command::new(&tool);
+            // tar replaces files by default; -k (GNU and bsdtar) refuses, in case a name slipped past the check above
+            if !force {
+                c.arg("-k");
+            }
+            c.arg("-xvf").arg(archive).arg("-C").arg(&dest);
+            c
+        }
+        Format::SevenZ => {
+            let mut c = Command::new(&tool);
+            c.args(["x", "-y", if force { "-aoa" } else { "-aos" }, "-bb1", "-bso1"]).arg(format!("-o{}", dest.display())).arg(archive);
+            c
+        }
+    };
+    cmd.stdin(Stdio::null());
+    run_with_progress(cmd, total, "Extracting")?;
+
+    ui::success(&format!("Extracted to {}", dest.display()));
+    Ok(())
+}
+
+/// `vg archive list <archive>`
+pub fn list(archive: &Path) -> Result<()> {
+    ui::print_header("ARCHIVE CONTENTS");
+    let format = detect(archive)?;
+    let names = entries(archive, format)?;
+    ui::info_line("Archive", &archive.display().to_string());
+    ui::info_line("Entries", &names.len().to_string());
+    println!();
+    for name in &names {
+        println!("  {}", name.truecolor(224, 242, 254));
+    }
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use super::search::{self, SearchParams};
+use crate::config::ConfigManager;
+use crate::ui;
+use anyhow::Result;
+use comfy_table::{Attribute, Cell, Color, Table};
+use globset::Glob;
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Name stems of the synthetic tree; every query below hits a known share of it.
+const WORDS: &[&str] = &["report", "invoice", "notes", "photo", "config", "main", "readme", "budget", "draft", "backup"];
+const EXTS: &[&str] = &["md", "txt", "rs", "pdf", "jpg", "toml"];
+const FILES_PER_DIR: usize = 200;
+/// (label, query) run against the synthetic tree
+const SYNTHETIC_QUERIES: &[(&str, &str)] = &[
+    ("common word", "report"),
+    ("exact name", "budget_0007"),
+    ("glob", "*.md"),
+    ("fuzzy fallback", "invce"),
+];
+const CURRENT_QUERIES: &[&str] = &["readme", "config", "*.md"];
+
+pub struct SearchBenchParams {
+    /// Synthetic tree sizes (files)
+    pub sizes: Vec<usize>,
+    /// Measure the real index instead of synthetic trees
+    pub current: bool,
+    /// Queries for --current
+    pub queries: Vec<String>,
+    /// Warm repetitions per query; the median is reported
+    pub runs: usize,
+}
+
+fn fmt_duration(d: Duration) -> String {
+    let us = d.as_secs_f64() * 1_000_000.0;
+    if us < 1000.0 {
+        format!("{:.0} µs", us)
+    } else if us < 1_000_000.0 {
+        format!("{:.1} ms", us / 1000.0)
+    } else {
+        format!("{:.2} s", us / 1_000_000.0)
+    }
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples.get(samples.len() / 2).copied().unwrap_or_default()
+}
+
+fn params(query: &str) -> SearchParams {
+    SearchParams {
+        query: query.to_string(),
+        ext: None,
+        path_filter: None,
+        mime: None,
+        limit: Some(10),
+        verbose: false,
+        all_scopes: true,
+        format: None,
+    }
+}
+
+/// `files` small files spread over directories of FILES_PER_DIR.
+fn generate(root: &Path, files: usize) -> Result<()> {
+    for i in 0..files {
+        let dir = root.join(format!("d{:04}", i / FILES_PER_DIR));
+        if i.is_multiple_of(FILES_PER_DIR) {
+            std::fs::create_dir_all(&dir)?;
+        }
+        let word = WORDS[i % WORDS.len()];
+        let ext = EXTS[(i / WORDS.len()) % EXTS.len()];
+        let content = format!("{} {} lorem ipsum dolor sit amet\n", word, i);
+        std::fs::write(dir.join(format!("{}_{:04}.{}", word, i, ext)), content)?;
+    }
+    Ok(())
+}
+
+/// What search replaces: walk the tree and match every file name.
+fn linear_scan(root: &Path, query: &str) -> usize {
+    let glob = search::is_glob_pattern(query).then(|| Glob::new(query).ok().map(|g| g.compile_matcher())).flatten();
+    let needle = query.to_lowercase();
+    crate::walk::walk(root, &crate::walk::Options::default(), |e| {
+        let name = e.file_name().to_string_lossy();
+        let hit = match &glob {
+            Some(g) => g.is_match(name.as_ref()),
+            None => name.to_lowercase().contains(&needle),
+        };
+        (hit && e.file_type().is_some_and(|t| t.is_file())).then_some(())
+    }).items.len()
+}
+
+/// Cold (first query on a new connection) and warm (median of `runs`) latency, plus the result count.
+fn time_query(db: &Path, query: &str, runs: usize) -> Result<(Duration, Duration, usize)> {
+    let conn = Connection::open(db)?;
+    let p = params(query);
+    let started = Instant::now();
+    let found = search::count_matches(&p, &conn)?;
+    let cold = started.elapsed();
+    let warm = median((0..runs.max(1)).map(|_| {
+        let started = Instant::now();
+        let _ = search::count_matches(&p, &conn);
+        started.elapsed()
+    }).collect());
+    Ok((cold, warm, found))
+}
+
+fn header(table: &mut Table, baseline: &str) {
+    table.set_header(vec![
+        Cell::new("Query").add_attribute(Attribute::Bold),
+        Cell::new("Results").add_attribute(Attribute::Bold),
+        Cell::new("Cold").add_attribute(Attribute::Bold),
+        Cell::new("Warm (median)").add_attribute(Attribute::Bold),
+        Cell::new(baseline).add_attribute(Attribute::Bold),
+        Cell::new("Speedup").add_attribute(Attribute::Bold),
+    ]);
+}
+
+fn row(table: &mut Table, label: &str, found: usize, cold: Duration, warm: Duration, linear: Duration) {
+    let speedup = linear.as_secs_f64() / warm.as_secs_f64().max(1e-9);
+    table.add_row(vec![
+        Cell::new(label),
+        Cell::new(found),
+        Cell::new(fmt_duration(cold)),
+        Cell::new(fmt_duration(warm)).fg(Color::Green),
+        Cell::new(fmt_duration(linear)),
+        Cell::new(if speedup < 10.0 { format!("{:.1}×", speedup) } else { format!("{:.0}×", speedup) }).fg(if speedup >= 1.0 { Color::Green } else { Color::Red }),
+    ]);
+}
+
+fn synthetic(size: usize, runs: usize, config: &ConfigManager) -> Result<()> {
+    ui::section(&format!("{} files", size));
+    let tmp = tempfile::Builder::new().prefix("vg-bench-").tempdir()?;
+    let tree: PathBuf = tmp.path().join("tree");
+    let started = Instant::now();
+    generate(&tree, size)?;
+    ui::info_line("Generate", &fmt_duration(started.elapsed()));
+
+    let db = tmp.path().join("bench.db");
+    let conn = search::create_db_at(&db)?;
+    let started = Instant::now();
+    let indexed = search::index_into(&conn, &tree, config)?;
+    let build = started.elapsed();
+    drop(conn);
+    ui::info_line("Index build", &format!(
+        "{} files in {} ({:.0} files/s)",
+        indexed, fmt_duration(build), indexed as f64 / build.as_secs_f64().max(1e-9),
+    ));
+    if let Ok(meta) = std::fs::metadata(&db) {
+        ui::info_line("Index size", &search::fmt_bytes(meta.len()));
+    }
+    println!();
+
+    let mut table = Table::new();
+    header(&mut table, "Linear scan");
+    for (label, query) in SYNTHETIC_QUERIES {
+        let (cold, warm, found) = time_query(&db, query, runs)?;
+        let linear = median((0..3).map(|_| {
+            let started = Instant::now();
+            linear_scan(&tree, query);
+            started.elapsed()
+        }).collect());
+        row(&mut table, label, found, cold, warm, linear);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+/// Latency on the real index, against a full-table LIKE scan of the same rows.
+fn current(queries: &[String], runs: usize) -> Result<()> {
+    let db = search::get_db_path();
+    if !db.exists() {
+        return Err(anyhow::Error::from(crate::error::Error::IndexMissing).context("--current benchmarks the existing index; drop it for synthetic trees"));
+    }
+    let conn = search::open_db()?;
+    let files: i64 = conn.query_row("SELECT COUNT(*) FROM files", [], |r| r.get(0))?;
+    ui::info_line("Index", &format!("{} files · {}", files, db.display()));
+    println!();
+
+    let mut table = Table::new();
+    header(&mut table, "LIKE scan");
+    for query in queries {
+        let (cold, warm, found) = time_query(&db, query, runs)?;
+        let like = format!("%{}%", query.replace(['*', '?'], ""));
+        let linear = median((0..3).map(|_| {
+            let started = Instant::now();
+            let _: Result<i64, _> = conn.query_row("SELECT COUNT(*) FROM files WHERE name LIKE ?1", [&like], |r| r.get(0));
+            started.elapsed()
+        }).collect());
+        row(&mut table, query, found, cold, warm, linear);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+/// `vg benchmark search` — index build time and query latency versus a naive scan.
+pub fn search(params: SearchBenchParams, config: &ConfigManager) -> Result<()> {
+    ui::print_header("BENCHMARK · SEARCH");
+    if params.current {
+        let queries = if params.queries.is_empty() {
+            CURRENT_QUERIES.iter().map(|q| q.to_string()).collect()
+        } else {
+            params.queries
+        };
+        current(&queries, params.runs)?;
+    } else {
+        for size in params.sizes {
+            synthetic(size, params.runs, config)?;
+        }
+    }
+    println!();
+    ui::skip("Cold = first query on a new connection; warm = median of repeated runs.");
+    Ok(())
+}
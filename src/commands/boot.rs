@@ -0,0 +1,175 @@
+use crate::ui;
+use crate::package_managers::run_cmd;
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use inquire::MultiSelect;
+use std::process::Command;
+use which::which;
+
+/// Units slower than this are highlighted.
+const SLOW_SECS: f64 = 5.0;
+
+/// Units that commonly slow boot and are safe to disable on most desktops, with the reason.
+const OPTIONAL_UNITS: &[(&str, &str)] = &[
+    ("NetworkManager-wait-online.service", "only needed when services must wait for the network at boot"),
+    ("systemd-networkd-wait-online.service", "only needed when services must wait for the network at boot"),
+    ("plymouth-quit-wait.service", "keeps the splash screen up until the display manager starts"),
+    ("ModemManager.service", "only needed for mobile broadband modems"),
+    ("lvm2-monitor.service", "only needed with LVM snapshots or mirrors"),
+    ("man-db.service", "rebuilds the man page cache; can run on demand instead"),
+    ("apt-daily.service", "background apt refresh; vg update does this on demand"),
+    ("apt-daily-upgrade.service", "background unattended upgrades"),
+    ("snapd.seeded.service", "only needed when snaps are in use"),
+    ("cups.service", "only needed for printing"),
+];
+
+fn analyze(args: &[&str]) -> Result<String> {
+    let out = Command::new("systemd-analyze").args(args).output()?;
+    if !out.status.success() {
+        return Err(anyhow!("systemd-analyze {} failed: {}", args.join(" "), String::from_utf8_lossy(&out.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).to_string())
+}
+
+/// Parse a systemd duration such as "1min 2.345s", "345ms" or "1h 2min" into seconds.
+fn parse_duration(text: &str) -> Option<f64> {
+    let mut total = 0.0;
+    for token in text.split_whitespace() {
+        let split = token.find(|c: char| c.is_ascii_alphabetic())?;
+        let (num, unit) = token.split_at(split);
+        let value: f64 = num.parse().ok()?;
+        total += value * match unit {
+            "h" => 3600.0,
+            "min" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            "us" | "µs" => 0.000_001,
+            _ => return None,
+        };
+    }
+    Some(total)
+}
+
+pub(crate) fn fmt_secs(secs: f64) -> String {
+    if secs >= 60.0 {
+        format!("{}m {:.1}s", (secs / 60.0) as u64, secs % 60.0)
+    } else if secs >= 1.0 {
+        format!("{:.2}s", secs)
+    } else {
+        format!("{:.0}ms", secs * 1000.0)
+    }
+}
+
+/// Per-unit startup times from `systemd-analyze blame`, slowest first.
+/// `user` reads the user manager instead of the system one.
+pub(crate) fn blame(user: bool) -> Result<Vec<(f64, String)>> {
+    let args: &[&str] = if user { &["--user", "blame", "--no-pager"] } else { &["blame", "--no-pager"] };
+    Ok(analyze(args)?
+        .lines()
+        .filter_map(|line| {
+            let (dur, unit) = line.trim().rsplit_once(' ')?;
+            Some((parse_duration(dur)?, unit.to_string()))
+        })
+        .collect())
+}
+
+/// `vg boot` — boot time breakdown, slowest units and optional cleanup.
+pub fn run(top: usize) -> Result<()> {
+    ui::print_header("BOOT ANALYSIS");
+
+    if !cfg!(target_os = "linux") || which("systemd-analyze").is_err() {
+        return run_fallback();
+    }
+
+    // ── Phases ───────────────────────────────────────────────────
+    ui::section("Boot time");
+    let time = analyze(&["time"])?;
+    let first = time.lines().next().unwrap_or("");
+    // "Startup finished in 3.1s (firmware) + 2.5s (loader) + ... = 11.4s"
+    if let Some(rest) = first.split_once(" in ").map(|(_, r)| r) {
+        let (phases, total) = rest.split_once(" = ").unwrap_or((rest, ""));
+        for phase in phases.split(" + ") {
+            if let Some((dur, label)) = phase.trim().rsplit_once(" (") {
+                ui::info_line(label.trim_end_matches(')'), dur.trim());
+            }
+        }
+        if !total.is_empty() {
+            ui::info_line("Total", total.trim());
+        }
+    } else {
+        ui::skip(first.trim());
+    }
+
+    // ── Slowest units ────────────────────────────────────────────
+    ui::section(&format!("Slowest {} units", top));
+    let blame = blame(false)?;
+    for (secs, unit) in blame.iter().take(top) {
+        let dur = format!("{:>9}", fmt_secs(*secs));
+        if *secs >= SLOW_SECS {
+            println!("  {}  {}", dur.truecolor(248, 113, 113).bold(), unit.truecolor(224, 242, 254));
+        } else {
+            println!("  {}  {}", dur.truecolor(96, 165, 250), unit.truecolor(224, 242, 254));
+        }
+    }
+
+    ui::section("Critical chain");
+    let chain = analyze(&["critical-chain", "--no-pager"]).unwrap_or_default();
+    for line in chain.lines().filter(|l| !l.trim().is_empty()).skip_while(|l| !l.contains('@')).take(15) {
+        println!("  {}", line.truecolor(100, 116, 139));
+    }
+
+    // ── Optional units ───────────────────────────────────────────
+    let candidates: Vec<String> = OPTIONAL_UNITS.iter()
+        .filter_map(|(unit, why)| {
+            let (secs, _) = blame.iter().find(|(_, u)| u == unit)?;
+            Some(format!("{} ({}) — {}", unit, fmt_secs(*secs), why))
+        })
+        .collect();
+
+    ui::section("Optional units");
+    if candidates.is_empty() {
+        ui::success("No commonly unnecessary units found in this boot.");
+        return Ok(());
+    }
+    for c in &candidates {
+        ui::skip(c);
+    }
+    if !crate::ui::interactive() {
+        return Ok(());
+    }
+    println!();
+    let chosen = MultiSelect::new("Disable any of these?", candidates)
+        .with_help_message("space toggle · enter confirm · nothing selected = keep all")
+        .prompt()?;
+    for choice in chosen {
+        let unit = choice.split_whitespace().next().unwrap_or_default();
+        match run_cmd(&["systemctl", "disable", unit], true) {
+            Ok(_) => ui::success(&format!("Disabled {} — re-enable with: sudo systemctl enable {}", unit, unit)),
+            Err(e) => ui::fail(&e.to_string()),
+        }
+    }
+    Ok(())
+}
+
+/// Non-systemd platforms: report what can be read without systemd-analyze.
+fn run_fallback() -> Result<()> {
+    ui::section("Boot time");
+    if cfg!(target_os = "macos") {
+        // "{ sec = 1712345678, usec = 0 } Mon Apr  1 09:00:00 2024"
+        let out = Command::new("sysctl").args(["-n", "kern.boottime"]).output()?;
+        let text = String::from_utf8_lossy(&out.stdout);
+        if let Some((_, date)) = text.split_once('}') {
+            ui::info_line("Booted", date.trim());
+        }
+    } else if cfg!(target_os = "windows") {
+        let out = Command::new("powershell")
+            .args(["-NoProfile", "-Command", "(Get-CimInstance Win32_OperatingSystem).LastBootUpTime"])
+            .output()?;
+        ui::info_line("Booted", String::from_utf8_lossy(&out.stdout).trim());
+    }
+    let uptime = sysinfo::System::uptime();
+    ui::info_line("Uptime", &format!("{}d {}h {}m", uptime / 86400, (uptime % 86400) / 3600, uptime % 3600 / 60));
+    println!();
+    ui::skip("Per-unit boot timing needs systemd-analyze (Linux with systemd).");
+    Ok(())
+}
@@ -0,0 +1,385 @@
+use crate::config::ConfigManager;
+use crate::package_managers::get_available_managers;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use inquire::Confirm;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Marks the rc-file line and the shell init file written by vg bootstrap.
+const MARKER: &str = "# added by vg bootstrap";
+
+const EXAMPLE: &str = r#"# vg bootstrap profile — run with: vg bootstrap <name>
+description = "My laptop"
+
+# Installed with the first package manager that has them
+packages = ["git", "ripgrep", "neovim"]
+
+# Environment variables, exported from every new shell
+[env]
+EDITOR = "nvim"
+
+# Cloned (or pulled) into `dir`, then each link target in your home points at a file in the repo
+[dotfiles]
+repo = "https://github.com/you/dotfiles.git"
+dir = "~/.dotfiles"
+[dotfiles.links]
+".zshrc" = "zshrc"
+".config/nvim" = "nvim"
+
+[services]
+# Build the search index now and keep it fresh in the background
+index = true
+# Show vg greet when a new shell starts
+greet = true
+"#;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Profile {
+    description: String,
+    packages: Vec<String>,
+    env: BTreeMap<String, String>,
+    dotfiles: Option<Dotfiles>,
+    services: Services,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Dotfiles {
+    repo: Option<String>,
+    dir: String,
+    /// Path under home → path inside the dotfiles dir
+    links: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
+struct Services {
+    index: bool,
+    greet: bool,
+}
+
+impl Default for Services {
+    fn default() -> Self {
+        Self { index: true, greet: false }
+    }
+}
+
+fn profiles_dir(config: &ConfigManager) -> PathBuf {
+    config.config_path().parent().map(Path::to_path_buf).unwrap_or_default().join("bootstrap")
+}
+
+/// A profile name from the profiles dir, or a path to a .toml file.
+fn profile_path(config: &ConfigManager, name: &str) -> PathBuf {
+    if name.ends_with(".toml") {
+        PathBuf::from(name)
+    } else {
+        profiles_dir(config).join(format!("{}.toml", name))
+    }
+}
+
+fn expand_home(p: &str) -> PathBuf {
+    match p.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        None => PathBuf::from(p),
+    }
+}
+
+/// Add `line` to an rc file unless it's already there.
+fn ensure_line(rc: &Path, line: &str) -> Result<bool> {
+    let content = std::fs::read_to_string(rc).unwrap_or_default();
+    if content.lines().any(|l| l.trim_start().starts_with(line)) {
+        return Ok(false);
+    }
+    if let Some(parent) = rc.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let sep = if content.is_empty() || content.ends_with('\n') { "" } else { "\n" };
+    std::fs::write(rc, format!("{}{}{}  {}\n", content, sep, line, MARKER))
+        .with_context(|| format!("Failed to update {}", rc.display()))?;
+    Ok(true)
+}
+
+/// bash and zsh rc files that exist, plus .bashrc as a fallback.
+fn posix_rc_files() -> Vec<PathBuf> {
+    let home = dirs::home_dir().unwrap_or_default();
+    let mut rcs: Vec<PathBuf> = [".bashrc", ".zshrc"].iter().map(|f| home.join(f)).filter(|p| p.exists()).collect();
+    if rcs.is_empty() {
+        rcs.push(home.join(".bashrc"));
+    }
+    rcs
+}
+
+fn fish_conf() -> Option<PathBuf> {
+    let dir = dirs::config_dir()?.join("fish");
+    dir.exists().then(|| dir.join("conf.d").join("vg.fish"))
+}
+
+fn install_packages(packages: &[String], yes: bool) -> Result<()> {
+    let managers = get_available_managers();
+    if managers.is_empty() {
+        return Err(anyhow!("No package managers available"));
+    }
+    let mut failed = Vec::new();
+    for pkg in packages {
+        // The first manager that installs it wins; later ones are fallbacks (e.g. flatpak)
+        let installed = managers.iter().find_map(|m| m.install(pkg, yes).ok().map(|_| m.display_name().to_string()));
+        match installed {
+            Some(via) => ui::success(&format!("{} ({})", pkg, via)),
+            None => {
+                ui::fail(pkg);
+                failed.push(pkg.as_str());
+            }
+        }
+    }
+    if failed.is_empty() { Ok(()) } else { Err(anyhow!("could not install {}", failed.join(", "))) }
+}
+
+fn apply_dotfiles(d: &Dotfiles) -> Result<()> {
+    let dir = expand_home(if d.dir.is_empty() { "~/.dotfiles" } else { &d.dir });
+    match &d.repo {
+        Some(repo) if !dir.exists() => {
+            let status = Command::new("git").args(["clone", repo]).arg(&dir).status().context("Failed to run git")?;
+            if !status.success() {
+                return Err(anyhow!("git clone {} failed", repo));
+            }
+            ui::success(&format!("Cloned {}", repo));
+        }
+        Some(_) if dir.join(".git").exists() => {
+            let status = Command::new("git").arg("-C").arg(&dir).args(["pull", "--ff-only"]).status()?;
+            if status.success() {
+                ui::success(&format!("Updated {}", dir.display()));
+            } else {
+                ui::skip(&format!("{} has local changes — left as is", dir.display()));
+            }
+        }
+        _ if !dir.exists() => return Err(anyhow!("{} does not exist and no repo is set", dir.display())),
+        _ => {}
+    }
+
+    let home = dirs::home_dir().ok_or_else(|| anyhow!("No home directory"))?;
+    for (target, source) in &d.links {
+        let link = home.join(target);
+        let source = dir.join(source);
+        if !source.exists() {
+            ui::fail(&format!("{} is missing from the dotfiles", source.display()));
+            continue;
+        }
+        if std::fs::read_link(&link).is_ok_and(|t| t == source) {
+            ui::skip(&format!("~/{} already linked", target));
+            continue;
+        }
+        if let Ok(meta) = std::fs::symlink_metadata(&link) {
+            // Never delete what was there: keep it next to the link
+            let backup = link.with_file_name(format!("{}.vg-backup", link.file_name().unwrap_or_default().to_string_lossy()));
+            if meta.file_type().is_symlink() {
+                std::fs::remove_file(&link)?;
+            } else {
+                std::fs::rename(&link, &backup)?;
+                ui::skip(&format!("Moved the old ~/{} to {}", target, backup.display()));
+            }
+        }
+        if let Some(parent) = link.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        symlink(&source, &link)?;
+        ui::success(&format!("~/{} → {}", target, source.display()));
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink(source: &Path, link: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(source, link).with_context(|| format!("Failed to link {}", link.display()))
+}
+
+#[cfg(windows)]
+fn symlink(source: &Path, link: &Path) -> Result<()> {
+    let res = if source.is_dir() {
+        std::os::windows::fs::symlink_dir(source, link)
+    } else {
+        std::os::windows::fs::symlink_file(source, link)
+    };
+    res.with_context(|| format!("Failed to link {} (symlinks need Developer Mode on Windows)", link.display()))
+}
+
+/// Environment and greeting go into files vg owns; rc files only get one line sourcing them,
+/// so rerunning with a changed profile replaces values instead of piling up lines.
+fn write_shell_init(config: &ConfigManager, env: &BTreeMap<String, String>, greet: bool) -> Result<()> {
+    if cfg!(windows) {
+        for (k, v) in env {
+            crate::package_managers::run_cmd(&["setx", k, v], false)?;
+            ui::success(&format!("{}={}", k, v));
+        }
+        if greet {
+            ui::skip("The greeting on new shells is not supported on Windows yet");
+        }
+        return Ok(());
+    }
+
+    let quote = |v: &str| format!("'{}'", v.replace('\'', "'\\''"));
+    let mut sh = format!("{}\n", MARKER);
+    let mut fish = format!("{}\n", MARKER);
+    for (k, v) in env {
+        sh.push_str(&format!("export {}={}\n", k, quote(v)));
+        fish.push_str(&format!("set -gx {} {}\n", k, quote(v)));
+        ui::success(&format!("{}={}", k, v));
+    }
+    if greet {
+        sh.push_str("[ -t 1 ] && command -v vg >/dev/null && vg greet\n");
+        fish.push_str("status is-interactive; and type -q vg; and vg greet\n");
+        ui::success("vg greet runs in new shells");
+    }
+
+    let init = config.config_path().parent().map(Path::to_path_buf).unwrap_or_default().join("shell.sh");
+    std::fs::write(&init, sh).with_context(|| format!("Failed to write {}", init.display()))?;
+    for rc in posix_rc_files() {
+        ensure_line(&rc, &format!("[ -f '{}' ] && . '{}'", init.display(), init.display()))?;
+    }
+    if let Some(conf) = fish_conf() {
+        if let Some(parent) = conf.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&conf, fish)?;
+    }
+    ui::skip("Takes effect in new shells");
+    Ok(())
+}
+
+fn enable_index(config: &mut ConfigManager) -> Result<()> {
+    let paths: Vec<PathBuf> = config.config.search.default_paths.iter().map(PathBuf::from).collect();
+    super::search::build_index(paths, config)?;
+    ConfigManager::touch_auto_index_stamp();
+    if !config.config.auto_index.enabled {
+        config.config.auto_index.enabled = true;
+        config.save()?;
+    }
+    ui::success(&format!("Re-indexing every {} minutes in the background", config.config.auto_index.interval_minutes));
+    Ok(())
+}
+
+/// `vg bootstrap` without a profile — what's available and where profiles live.
+pub fn list(config: &ConfigManager) -> Result<()> {
+    ui::print_header("BOOTSTRAP");
+    let dir = profiles_dir(config);
+    let mut profiles: Vec<(String, String)> = std::fs::read_dir(&dir).into_iter().flatten().flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "toml"))
+        .map(|p| {
+            let desc = std::fs::read_to_string(&p).ok()
+                .and_then(|c| toml::from_str::<Profile>(&c).ok())
+                .map(|prof| prof.description)
+                .unwrap_or_default();
+            (p.file_stem().unwrap_or_default().to_string_lossy().to_string(), desc)
+        })
+        .collect();
+    profiles.sort();
+    ui::info_line("Profiles", &dir.display().to_string());
+    println!();
+    if profiles.is_empty() {
+        ui::skip("No profiles yet — create one with: vg bootstrap <name> --init");
+        return Ok(());
+    }
+    for (name, desc) in profiles {
+        println!("  {:<16} {}", name.truecolor(96, 165, 250), desc.truecolor(100, 116, 139));
+    }
+    println!();
+    ui::skip("Run one with: vg bootstrap <name>");
+    Ok(())
+}
+
+/// `vg bootstrap <profile>` — packages, dotfiles, environment and services in one go.
+pub fn run(config: &mut ConfigManager, name: &str, init: bool, dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header(&format!("BOOTSTRAP  {}", name));
+    let path = profile_path(config, name);
+
+    if init {
+        if path.exists() {
+            return Err(anyhow!("{} already exists", path.display()));
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, EXAMPLE)?;
+        ui::success(&format!("Created {}", path.display()));
+        ui::skip("Edit it, then run: vg bootstrap <name> --dry-run");
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("No profile at {} — create it with: vg bootstrap {} --init", path.display(), name))?;
+    let profile: Profile = toml::from_str(&content).with_context(|| format!("Invalid profile {}", path.display()))?;
+
+    // (step, detail) for everything the profile asks for
+    let mut plan: Vec<(&str, String)> = Vec::new();
+    if !profile.packages.is_empty() {
+        plan.push(("Packages", profile.packages.join(", ")));
+    }
+    if let Some(d) = &profile.dotfiles {
+        plan.push(("Dotfiles", format!("{} ({} links)", d.repo.as_deref().unwrap_or(&d.dir), d.links.len())));
+    }
+    if !profile.env.is_empty() || profile.services.greet {
+        let mut parts: Vec<String> = profile.env.keys().cloned().collect();
+        if profile.services.greet {
+            parts.push("vg greet in new shells".into());
+        }
+        plan.push(("Shell", parts.join(", ")));
+    }
+    if profile.services.index {
+        plan.push(("Search index", config.config.search.default_paths.join(", ")));
+    }
+
+    if !profile.description.is_empty() {
+        ui::info_line("Profile", &profile.description);
+    }
+    ui::section("Plan");
+    for (i, (step, detail)) in plan.iter().enumerate() {
+        println!("  {} {:<14} {}", format!("{}.", i + 1).truecolor(71, 85, 105), step.truecolor(96, 165, 250), detail.truecolor(224, 242, 254));
+    }
+    println!();
+    if plan.is_empty() {
+        ui::skip("The profile is empty — nothing to do");
+        return Ok(());
+    }
+    if dry_run {
+        ui::skip("Dry run — nothing changed");
+        return Ok(());
+    }
+    if !yes {
+        if !crate::ui::interactive() {
+            return Err(crate::error::Error::not_a_terminal("pass --yes to run the plan").into());
+        }
+        if !Confirm::new("Run these steps?").with_default(true).prompt()? {
+            ui::skip("Cancelled");
+            return Ok(());
+        }
+    }
+
+    // Each step runs even if an earlier one failed; the summary lists what needs another look
+    let mut failed: Vec<String> = Vec::new();
+    for (step, _) in &plan {
+        ui::section(step);
+        let res = match *step {
+            "Packages" => install_packages(&profile.packages, yes),
+            "Dotfiles" => profile.dotfiles.as_ref().map(apply_dotfiles).unwrap_or(Ok(())),
+            "Shell" => write_shell_init(config, &profile.env, profile.services.greet),
+            _ => enable_index(config),
+        };
+        if let Err(e) = res {
+            ui::fail(&format!("{}: {}", step, e));
+            failed.push(step.to_string());
+        }
+    }
+
+    println!();
+    if failed.is_empty() {
+        ui::success("Bootstrap complete — open a new shell to pick up the changes");
+        Ok(())
+    } else {
+        Err(anyhow!("{} of {} steps failed: {} — fix them and rerun, finished steps are skipped quickly", failed.len(), plan.len(), failed.join(", ")))
+    }
+}
@@ -0,0 +1,242 @@
+use crate::package_managers::{self, run_cmd};
+use crate::ui;
+use anyhow::Result;
+use colored::Colorize;
+use inquire::MultiSelect;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use which::which;
+
+/// Temp files untouched for this long are considered leftovers.
+const TEMP_MAX_AGE: Duration = Duration::from_secs(7 * 86400);
+
+enum Action {
+    PackageCache(String),
+    Command(Vec<&'static str>, bool),
+    ClearDir(PathBuf),
+    OldTempFiles(PathBuf),
+}
+
+struct Item {
+    label: String,
+    /// Estimated bytes freed; None when the tool can't tell us up front
+    size: Option<u64>,
+    /// Pre-selected in the picker
+    default: bool,
+    action: Action,
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let Ok(meta) = std::fs::symlink_metadata(path) else { return 0 };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    std::fs::read_dir(path)
+        .map(|rd| rd.flatten().map(|e| dir_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+/// Newest mtime anywhere below `path` (a directory's own mtime misses changes deeper down),
+/// or None when the tree holds a socket — tmux, ssh-agent, X and IDEs keep theirs in /tmp.
+fn newest_mtime(path: &Path) -> Option<SystemTime> {
+    let meta = std::fs::symlink_metadata(path).ok()?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        if meta.file_type().is_socket() {
+            return None;
+        }
+    }
+    let mut newest = meta.modified().ok()?;
+    if meta.is_dir() {
+        // An unreadable directory can't be judged, so it counts as in use
+        for entry in std::fs::read_dir(path).ok()?.flatten() {
+            newest = newest.max(newest_mtime(&entry.path())?);
+        }
+    }
+    Some(newest)
+}
+
+fn is_old(path: &Path) -> bool {
+    newest_mtime(path)
+        .and_then(|t| SystemTime::now().duration_since(t).ok())
+        .is_some_and(|age| age > TEMP_MAX_AGE)
+}
+
+/// Entries in the temp dir that belong to us, hold no sockets and had nothing inside touched in a week.
+fn old_temp_entries(dir: &Path) -> Vec<PathBuf> {
+    #[cfg(unix)]
+    let mine = |p: &Path| {
+        use std::os::unix::fs::MetadataExt;
+        std::fs::symlink_metadata(p).map(|m| m.uid() == unsafe { libc::getuid() }).unwrap_or(false)
+    };
+    #[cfg(not(unix))]
+    let mine = |_: &Path| true;
+    std::fs::read_dir(dir)
+        .map(|rd| rd.flatten().map(|e| e.path()).filter(|p| mine(p) && is_old(p)).collect())
+        .unwrap_or_default()
+}
+
+/// "Archived and active journals take up 1.2G in the file system."
+fn journal_usage() -> Option<u64> {
+    let out = crate::logging::output(Command::new("journalctl").arg("--disk-usage")).ok()?;
+    let text = String::from_utf8_lossy(&out.stdout);
+    let token = text.split_whitespace().find(|t| t.ends_with(['B', 'K', 'M', 'G', 'T']) && t.starts_with(|c: char| c.is_ascii_digit()))?;
+    let (num, unit) = token.split_at(token.len() - 1);
+    let num: f64 = num.trim_end_matches('B').parse().ok()?;
+    let mult = match unit { "K" => 1u64 << 10, "M" => 1 << 20, "G" => 1 << 30, "T" => 1 << 40, _ => 1 };
+    Some((num * mult as f64) as u64)
+}
+
+fn collect() -> Vec<Item> {
+    let mut items = Vec::new();
+
+    for pm in package_managers::get_available_managers() {
+        let dirs = pm.cache_dirs();
+        let size: u64 = dirs.iter().map(|d| dir_size(d)).sum();
+        if size > 0 {
+            items.push(Item {
+                label: format!("{} package cache", pm.display_name()),
+                size: Some(size),
+                default: true,
+                action: Action::PackageCache(pm.id().to_string()),
+            });
+        }
+    }
+
+    // No dnf backend in package_managers yet, so it's handled here directly
+    if which("dnf").is_ok() {
+        items.push(Item {
+            label: "DNF package cache".into(),
+            size: Some(dir_size(Path::new("/var/cache/dnf"))),
+            default: true,
+            action: Action::Command(vec!["dnf", "clean", "packages"], true),
+        });
+    }
+
+    if which("journalctl").is_ok() {
+        if let Some(size) = journal_usage().filter(|s| *s > 0) {
+            items.push(Item {
+                label: "systemd journal (keep 2 weeks)".into(),
+                size: Some(size),
+                default: true,
+                action: Action::Command(vec!["journalctl", "--vacuum-time=2weeks"], true),
+            });
+        }
+    }
+
+    let thumbs = if cfg!(target_os = "macos") {
+        None
+    } else {
+        dirs::cache_dir().map(|c| c.join("thumbnails"))
+    };
+    if let Some(thumbs) = thumbs {
+        let size = dir_size(&thumbs);
+        if size > 0 {
+            items.push(Item { label: "Thumbnail cache".into(), size: Some(size), default: true, action: Action::ClearDir(thumbs) });
+        }
+    }
+
+    let tmp = std::env::temp_dir();
+    let old: u64 = old_temp_entries(&tmp).iter().map(|p| dir_size(p)).sum();
+    if old > 0 {
+        items.push(Item {
+            label: format!("Your files in {} older than 7 days", tmp.display()),
+            size: Some(old),
+            default: true,
+            action: Action::OldTempFiles(tmp),
+        });
+    }
+
+    // Docker prune deletes stopped containers and unused images — opt-in only
+    for engine in ["docker", "podman"] {
+        if which(engine).is_ok() {
+            items.push(Item {
+                label: format!("{} prune (stopped containers, dangling images, build cache)", engine),
+                size: None,
+                default: false,
+                action: Action::Command(vec![engine, "system", "prune", "-f"], false),
+            });
+        }
+    }
+    items
+}
+
+fn perform(action: &Action) -> Result<()> {
+    match action {
+        Action::PackageCache(id) => {
+            let pm = package_managers::get_all_managers().into_iter().find(|pm| pm.id() == id)
+                .ok_or_else(|| anyhow::anyhow!("Unknown package manager {}", id))?;
+            pm.clean_cache()
+        }
+        Action::Command(args, sudo) => run_cmd(args, *sudo),
+        Action::ClearDir(dir) => {
+            for entry in std::fs::read_dir(dir)?.flatten() {
+                let p = entry.path();
+                if p.is_dir() { std::fs::remove_dir_all(&p)?; } else { std::fs::remove_file(&p)?; }
+            }
+            Ok(())
+        }
+        Action::OldTempFiles(dir) => {
+            for p in old_temp_entries(dir) {
+                // Files still in use or owned by a sandbox are skipped, not fatal
+                let _ = if p.is_dir() { std::fs::remove_dir_all(&p) } else { std::fs::remove_file(&p) };
+            }
+            Ok(())
+        }
+    }
+}
+
+fn fmt_size(size: Option<u64>) -> String {
+    size.map(super::search::fmt_bytes).unwrap_or_else(|| "?".into())
+}
+
+/// `vg clean` — free space from caches, logs and temp files.
+pub fn run(dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header("CLEAN");
+    let items = collect();
+    if items.is_empty() {
+        ui::success("Nothing to clean");
+        return Ok(());
+    }
+
+    for item in &items {
+        println!("  {:>10}  {}", fmt_size(item.size).truecolor(96, 165, 250), item.label.truecolor(224, 242, 254));
+    }
+    let total: u64 = items.iter().filter_map(|i| i.size).sum();
+    println!();
+    ui::info_line("Reclaimable", &format!("about {}", super::search::fmt_bytes(total)));
+    println!();
+    if dry_run {
+        ui::skip("Dry run — nothing deleted");
+        return Ok(());
+    }
+
+    let labels: Vec<String> = items.iter().map(|i| format!("{} ({})", i.label, fmt_size(i.size))).collect();
+    let chosen: Vec<usize> = if yes {
+        items.iter().enumerate().filter(|(_, i)| i.default).map(|(idx, _)| idx).collect()
+    } else if crate::ui::interactive() {
+        let defaults: Vec<usize> = items.iter().enumerate().filter(|(_, i)| i.default).map(|(idx, _)| idx).collect();
+        let picked = MultiSelect::new("Clean which?", labels.clone()).with_default(&defaults).prompt()?;
+        picked.iter().filter_map(|p| labels.iter().position(|l| l == p)).collect()
+    } else {
+        ui::skip("Not a terminal — pass --yes to clean the default selection");
+        return Ok(());
+    };
+
+    let mut freed = 0;
+    for idx in chosen {
+        let item = &items[idx];
+        match perform(&item.action) {
+            Ok(()) => {
+                freed += item.size.unwrap_or(0);
+                ui::success(&item.label);
+            }
+            Err(e) => ui::fail(&format!("{}: {}", item.label, e)),
+        }
+    }
+    println!();
+    ui::success(&format!("Freed about {}", super::search::fmt_bytes(freed)));
+    Ok(())
+}
@@ -1,31 +1,54 @@
 // src/commands/config_cmd.rs
 use crate::ui;
-use crate::config::ConfigManager;
-use anyhow::Result;
-use inquire::{Select, Text, Confirm};
+use crate::config::{Config, ConfigManager};
+use anyhow::{Context, Result};
+use inquire::Confirm;
 use colored::Colorize;
 
 pub fn run(action: Option<String>, key: Option<String>, value: Option<String>, config: &mut ConfigManager) -> Result<()> {
     match action.as_deref() {
-        // No action or "edit" → launch TUI; "list" → plain text output for scripting
-        None | Some("edit") => super::config_tui::run(config)?,
+        // No action → launch TUI; "list" → plain text output for scripting
+        None | Some("tui") => super::config_tui::run(config)?,
         Some("list") => list(config),
+        Some("path") => println!("{}", config.config_path().display()),
+        Some("edit") => open_in_editor(config)?,
         Some("get") => {
             if let Some(k) = key {
-                get_key(&k, config);
+                get_key(&k, config)?;
             } else {
-                ui::fail("Usage: vg config get <key>");
+                anyhow::bail!("Usage: vg config get <key>");
             }
         }
         Some("set") => {
             if let (Some(k), Some(v)) = (key, value) {
                 set_key(&k, &v, config)?;
             } else {
-                ui::fail("Usage: vg config set <key> <value>");
+                anyhow::bail!("Usage: vg config set <key> <value>");
             }
         }
-        Some("edit") => interactive_edit(config)?,
-        Some(unknown) => ui::fail(&format!("Unknown config action: {}", unknown)),
+        Some("set-secret") => match key {
+            Some(k) => set_secret(&k, value, config)?,
+            None => anyhow::bail!("Usage: vg config set-secret <name> [value]"),
+        },
+        Some("get-secret") => match key {
+            Some(k) => match config.get_secret(&k) {
+                Some(v) => println!("{} = {}", k.truecolor(96, 165, 250), mask(&v).truecolor(224, 242, 254)),
+                None => anyhow::bail!("No secret named {}", k),
+            },
+            None => anyhow::bail!("Usage: vg config get-secret <name>"),
+        },
+        Some("unset-secret") => match key {
+            Some(k) => {
+                if config.delete_secret(&k)? {
+                    ui::success(&format!("Removed secret {}", k));
+                } else {
+                    ui::skip(&format!("No stored secret named {}", k));
+                    crate::exit::set(crate::exit::NEGATIVE);
+                }
+            }
+            None => anyhow::bail!("Usage: vg config unset-secret <name>"),
+        },
+        Some(unknown) => anyhow::bail!("Unknown config action: {}", unknown),
     }
     Ok(())
 }
@@ -40,6 +63,7 @@ fn list(config: &ConfigManager) {
     ui::info_line("search.system_exclude_paths",&config.config.search.system_exclude_paths.join(", "));
     ui::info_line("search.max_depth",           &config.config.search.max_depth.to_string());
     ui::info_line("search.exclude_hidden",      &config.config.search.exclude_hidden.to_string());
+    ui::info_line("search.follow_links",        &config.config.search.follow_links.to_string());
 
     ui::section("Search — Results");
     ui::info_line("search.max_results",         &config.config.search.max_results.to_string());
@@ -47,6 +71,73 @@ fn list(config: &ConfigManager) {
 
     ui::section("System");
     ui::info_line("system.auto_confirm_update", &config.config.system.auto_confirm_update.to_string());
+    ui::info_line("system.update_mirrors", &config.config.system.update_mirrors.to_string());
+    ui::info_line("system.language",       &config.config.system.language);
+
+    ui::section("Greet");
+    ui::info_line("greet.show_uptime",          &config.config.greet.show_uptime.to_string());
+    ui::info_line("greet.show_updates",         &config.config.greet.show_updates.to_string());
+    ui::info_line("greet.show_disks",           &config.config.greet.show_disks.to_string());
+    ui::info_line("greet.show_tip",             &config.config.greet.show_tip.to_string());
+    ui::info_line("greet.show_weather",         &config.config.greet.show_weather.to_string());
+    ui::info_line("greet.disk_warn_percent",    &config.config.greet.disk_warn_percent.to_string());
+
+    ui::section("Weather");
+    ui::info_line("weather.location",           &config.config.weather.location);
+    ui::info_line("weather.units",              &config.config.weather.units);
+
+    ui::section("Hooks");
+    ui::info_line("hooks.forbidden",            &config.config.hooks.forbidden.join(", "));
+    ui::info_line("hooks.extra",                &config.config.hooks.extra.join(", "));
+    for (lang, cmds) in &config.config.hooks.commands {
+        ui::info_line(&format!("hooks.commands.{}", lang), &cmds.join(", "));
+    }
+    for (key, script) in &config.config.hooks.scripts {
+        ui::info_line(&format!("hooks.scripts.{}", key), script);
+    }
+
+    if !config.config.aliases.is_empty() {
+        ui::section("Aliases");
+        for (name, expansion) in &config.config.aliases {
+            ui::info_line(&format!("aliases.{}", name), expansion);
+        }
+    }
+
+    ui::section("Update Check");
+    ui::info_line("update_check.enabled",       &config.config.update_check.enabled.to_string());
+    ui::info_line("update_check.interval_hours",&config.config.update_check.interval_hours.to_string());
+
+    ui::section("Toolchain");
+    ui::info_line("toolchain.node",             &config.config.toolchain.node);
+    ui::info_line("toolchain.python",           &config.config.toolchain.python);
+
+    ui::section("Sync");
+    ui::info_line("sync.backend",               &config.config.sync.backend);
+    ui::info_line("sync.remote",                &config.config.sync.remote);
+
+    ui::section("Timeouts");
+    ui::info_line("timeouts.query",             &config.config.timeouts.query.to_string());
+    ui::info_line("timeouts.task",              &config.config.timeouts.task.to_string());
+
+    ui::section("Dash");
+    ui::info_line("dash.widgets",               &config.config.dash.widgets.join(", "));
+    ui::info_line("dash.refresh_secs",          &config.config.dash.refresh_secs.to_string());
+
+    ui::section("Notify");
+    ui::info_line("notify.commands",            &config.config.notify.commands.join(", "));
+    ui::info_line("notify.min_seconds",         &config.config.notify.min_seconds.to_string());
+    ui::info_line("notify.desktop",             &config.config.notify.desktop.to_string());
+    ui::info_line("notify.bell",                &config.config.notify.bell.to_string());
+    ui::info_line("notify.ntfy",                &config.config.notify.ntfy);
+    ui::info_line("notify.reports",             &config.config.notify.reports.join(", "));
+    ui::info_line("notify.webhook",             &config.config.notify.webhook);
+    ui::info_line("notify.template",            &config.config.notify.template);
+
+    ui::section("Fleet");
+    ui::info_line("fleet.hosts",                &config.config.fleet.hosts.join(", "));
+
+    ui::section("Self Update");
+    ui::info_line("self_update.channel",        &config.config.self_update.channel);
 
     ui::section("Analytics");
     ui::info_line("analytics.enabled",          &config.config.analytics.enabled.to_string());
@@ -58,137 +149,210 @@ fn list(config: &ConfigManager) {
     println!("  {} {}", "Tip:".truecolor(71, 85, 105), "vg config set search.full_system_index true  →  index entire filesystem".truecolor(100, 116, 139));
 }
 
-fn get_key(key: &str, config: &ConfigManager) {
-    let value: Option<String> = match key {
-        "search.default_paths"        => Some(config.config.search.default_paths.join(", ")),
-        "search.full_system_index"    => Some(config.config.search.full_system_index.to_string()),
-        "search.system_index_roots"   => Some(config.config.search.system_index_roots.join(", ")),
-        "search.system_exclude_paths" => Some(config.config.search.system_exclude_paths.join(", ")),
-        "search.max_results"          => Some(config.config.search.max_results.to_string()),
-        "search.max_depth"            => Some(config.config.search.max_depth.to_string()),
-        "search.exclude_hidden"       => Some(config.config.search.exclude_hidden.to_string()),
-        "search.fuzzy_threshold"      => Some(config.config.search.fuzzy_threshold.to_string()),
-        "system.auto_confirm_update"  => Some(config.config.system.auto_confirm_update.to_string()),
-        "analytics.enabled"           => Some(config.config.analytics.enabled.to_string()),
-        "analytics.track_commands"    => Some(config.config.analytics.track_commands.to_string()),
-        "analytics.client_id"         => Some(config.config.analytics.client_id.clone()),
-        _ => None,
+/// Show only the first and last characters so a secret can be recognised but not copied.
+fn mask(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 8 {
+        return "•".repeat(chars.len());
+    }
+    format!("{}{}{}",
+        chars[..3].iter().collect::<String>(),
+        "•".repeat(chars.len() - 6),
+        chars[chars.len() - 3..].iter().collect::<String>())
+}
+
+fn set_secret(name: &str, value: Option<String>, config: &ConfigManager) -> Result<()> {
+    // Prompt when no value is given so the key doesn't end up in shell history
+    let value = match value {
+        Some(v) => v,
+        None => {
+            ui::ensure_interactive("pass the value after the name")?;
+            inquire::Password::new(&format!("{}:", name))
+                .without_confirmation()
+                .prompt()?
+        }
     };
+    if value.is_empty() {
+        anyhow::bail!("Secret is empty — nothing stored");
+    }
+    let store = config.set_secret(name, &value)?;
+    ui::success(&format!("Stored {} in the {}", name, store.label()));
+    Ok(())
+}
+
+/// Keys that `vg config set` refuses to touch.
+const READ_ONLY_KEYS: &[&str] = &["analytics.client_id"];
+
+/// Look up a dot-path (e.g. `search.max_results`) in a serialized config table.
+fn lookup<'a>(root: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    key.split('.').try_fold(root, |v, part| v.get(part))
+}
+
+fn lookup_mut<'a>(root: &'a mut toml::Value, key: &str) -> Option<&'a mut toml::Value> {
+    key.split('.').try_fold(root, |v, part| v.get_mut(part))
+}
+
+fn display_value(value: &toml::Value) -> String {
     match value {
-        Some(v) => println!("{} = {}", key.truecolor(96, 165, 250), v.truecolor(224, 242, 254)),
-        None => ui::fail(&format!("Unknown config key: {}", key)),
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Array(items) => items.iter().map(display_value).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
     }
 }
 
-fn set_key(key: &str, value: &str, config: &mut ConfigManager) -> Result<()> {
-    match key {
-        "search.full_system_index"    => config.config.search.full_system_index    = value.parse()?,
-        "search.max_results"          => config.config.search.max_results          = value.parse()?,
-        "search.max_depth"            => config.config.search.max_depth            = value.parse()?,
-        "search.exclude_hidden"       => config.config.search.exclude_hidden       = value.parse()?,
-        "search.fuzzy_threshold"      => config.config.search.fuzzy_threshold      = value.parse()?,
-        "system.auto_confirm_update"  => config.config.system.auto_confirm_update  = value.parse()?,
-        "analytics.enabled"           => config.config.analytics.enabled           = value.parse()?,
-        "analytics.track_commands"    => config.config.analytics.track_commands    = value.parse()?,
+/// Parse `raw` into the same TOML type as `current`, so `set` can't change a key's type.
+fn coerce(raw: &str, current: &toml::Value) -> Result<toml::Value> {
+    Ok(match current {
+        toml::Value::Boolean(_) => toml::Value::Boolean(raw.trim().parse()
+            .map_err(|_| anyhow::anyhow!("expected true or false, got '{}'", raw))?),
+        toml::Value::Integer(_) => toml::Value::Integer(raw.trim().parse()
+            .map_err(|_| anyhow::anyhow!("expected a whole number, got '{}'", raw))?),
+        toml::Value::Float(_) => toml::Value::Float(raw.trim().parse()
+            .map_err(|_| anyhow::anyhow!("expected a number, got '{}'", raw))?),
         // Vec fields: comma-separated
-        "search.default_paths" => {
-            config.config.search.default_paths = value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
-        }
-        "search.system_index_roots" => {
-            config.config.search.system_index_roots = value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
-        }
-        "search.system_exclude_paths" => {
-            config.config.search.system_exclude_paths = value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
-        }
-        _ => {
-            ui::fail(&format!("Unknown or read-only config key: {}", key));
-            return Ok(());
-        }
+        toml::Value::Array(_) => toml::Value::Array(
+            raw.split(',').map(|s| s.trim()).filter(|s| !s.is_empty())
+                .map(|s| toml::Value::String(s.to_string())).collect()
+        ),
+        toml::Value::Table(_) => anyhow::bail!("is a section — set one of its keys instead"),
+        _ => toml::Value::String(raw.to_string()),
+    })
+}
+
+/// Checks the type system can't express.
+fn validate(config: &Config) -> Result<()> {
+    if !matches!(config.self_update.channel.as_str(), "stable" | "nightly") {
+        anyhow::bail!("self_update.channel must be 'stable' or 'nightly'");
+    }
+    if config.greet.disk_warn_percent > 100 {
+        anyhow::bail!("greet.disk_warn_percent must be between 0 and 100");
+    }
+    if !matches!(config.weather.units.as_str(), "metric" | "imperial") {
+        anyhow::bail!("weather.units must be 'metric' or 'imperial'");
     }
+    if !matches!(config.secrets.backend.as_str(), "auto" | "keyring" | "file") {
+        anyhow::bail!("secrets.backend must be 'auto', 'keyring' or 'file'");
+    }
+    if config.update_check.interval_hours == 0 {
+        anyhow::bail!("update_check.interval_hours must be at least 1");
+    }
+    if !matches!(config.sync.backend.as_str(), "" | "git" | "rclone") {
+        anyhow::bail!("sync.backend must be 'git', 'rclone' or empty");
+    }
+    if !matches!(config.toolchain.node.as_str(), "auto" | "fnm" | "volta" | "package") {
+        anyhow::bail!("toolchain.node must be 'auto', 'fnm', 'volta' or 'package'");
+    }
+    if !matches!(config.toolchain.python.as_str(), "auto" | "pyenv" | "uv" | "package") {
+        anyhow::bail!("toolchain.python must be 'auto', 'pyenv', 'uv' or 'package'");
+    }
+    if let Some(lang) = config.hooks.commands.keys().find(|k| !matches!(k.as_str(), "rust" | "node" | "python" | "go")) {
+        anyhow::bail!("hooks.commands.{} is not a known language (rust, node, python, go)", lang);
+    }
+    if let Some(key) = config.hooks.scripts.keys().find(|k| !k.starts_with("pre_") && !k.starts_with("post_")) {
+        anyhow::bail!("hooks.scripts.{} is not a known hook — command scripts are named pre_<command> or post_<command>", key);
+    }
+    if let Some(problem) = config.aliases.iter().find_map(|(name, expansion)| crate::aliases::problem(name, expansion)) {
+        anyhow::bail!("{}", problem);
+    }
+    if !crate::config::LANGUAGES.iter().any(|(code, _)| *code == config.system.language) {
+        let codes: Vec<&str> = crate::config::LANGUAGES.iter().map(|(code, _)| *code).collect();
+        anyhow::bail!("system.language must be one of: {}", codes.join(", "));
+    }
+    if let Some(w) = config.dash.widgets.iter().find(|w| !super::dash::WIDGETS.contains(&w.as_str())) {
+        anyhow::bail!("dash.widgets: '{}' is not a widget ({})", w, super::dash::WIDGETS.join(", "));
+    }
+    if !config.notify.ntfy.is_empty() && !config.notify.ntfy.starts_with("https://") && !config.notify.ntfy.starts_with("http://") {
+        anyhow::bail!("notify.ntfy must be an http(s) topic URL, e.g. https://ntfy.sh/<topic>");
+    }
+    if !config.notify.webhook.is_empty() && !config.notify.webhook.starts_with("https://") && !config.notify.webhook.starts_with("http://") {
+        anyhow::bail!("notify.webhook must be an http(s) URL");
+    }
+    if let Some(c) = config.notify.reports.iter().find(|c| !crate::notify::REPORTS.contains(&c.as_str())) {
+        anyhow::bail!("notify.reports: '{}' doesn't report results ({})", c, crate::notify::REPORTS.join(", "));
+    }
+    if let Some(problem) = config.fleet.hosts.iter().find_map(|h| super::remote::host_problem(h)) {
+        anyhow::bail!("fleet.hosts: {}", problem);
+    }
+    if let Some(p) = config.hooks.forbidden.iter().find(|p| !super::hooks::valid_pattern(p)) {
+        anyhow::bail!("hooks.forbidden pattern '{}' may only contain letters, digits and . _ - * ? / [ ]", p);
+    }
+    Ok(())
+}
+
+fn get_key(key: &str, config: &ConfigManager) -> Result<()> {
+    let root = toml::Value::try_from(&config.config)?;
+    match lookup(&root, key) {
+        Some(toml::Value::Table(_)) => anyhow::bail!("{} is a section — try vg config list", key),
+        Some(v) => println!("{} = {}", key.truecolor(96, 165, 250), display_value(v).truecolor(224, 242, 254)),
+        None => anyhow::bail!("Unknown config key: {}", key),
+    }
+    Ok(())
+}
+
+fn set_key(key: &str, value: &str, config: &mut ConfigManager) -> Result<()> {
+    config.ensure_writable()?;
+    if READ_ONLY_KEYS.contains(&key) {
+        anyhow::bail!("{} is read-only", key);
+    }
+    let mut root = toml::Value::try_from(&config.config)?;
+    let Some(slot) = lookup_mut(&mut root, key) else {
+        anyhow::bail!("Unknown config key: {}", key);
+    };
+    *slot = coerce(value, slot).map_err(|e| anyhow::anyhow!("{}: {}", key, e))?;
+    // Round-trip through the typed struct so only values Config accepts get saved
+    let updated: Config = root.try_into().map_err(|e| anyhow::anyhow!("Invalid value for {}: {}", key, e))?;
+    validate(&updated)?;
+    config.config = updated;
     config.save()?;
     ui::success(&format!("Set {} = {}", key, value));
     Ok(())
 }
 
-fn interactive_edit(config: &mut ConfigManager) -> Result<()> {
-    ui::print_header("EDIT SETTINGS");
-
-    let options = vec![
-        "search.full_system_index",
-        "search.default_paths",
-        "search.system_index_roots",
-        "search.system_exclude_paths",
-        "search.max_results",
-        "search.max_depth",
-        "search.exclude_hidden",
-        "search.fuzzy_threshold",
-        "system.auto_confirm_update",
-        "analytics.enabled",
-        "analytics.track_commands",
-        "[ Save & Exit ]",
-    ];
+/// Open the config file in $VISUAL/$EDITOR and re-validate it after the editor exits.
+fn open_in_editor(config: &mut ConfigManager) -> Result<()> {
+    ui::ensure_interactive("change keys with vg config set <key> <value>")?;
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| if cfg!(windows) { "notepad".into() } else { "nano".into() });
+
+    // A file that didn't parse is opened as it is; saving would replace it with defaults
+    if config.load_error.is_none() {
+        config.save()?;
+    }
+    let path = config.config_path().to_path_buf();
+    let backup = std::fs::read_to_string(&path)?;
 
     loop {
-        let choice = Select::new("Select setting to edit:", options.clone()).prompt()?;
-        if choice == "[ Save & Exit ]" {
-            config.save()?;
-            ui::success("Settings saved.");
-            break;
+        // $EDITOR may carry arguments, e.g. "code --wait"
+        let parts = shlex::split(&editor).unwrap_or_else(|| vec![editor.clone()]);
+        let Some((prog, args)) = parts.split_first() else {
+            anyhow::bail!("$EDITOR is empty");
+        };
+        let status = std::process::Command::new(prog).args(args).arg(&path).status()
+            .with_context(|| format!("Failed to launch editor '{}'", editor))?;
+        if !status.success() {
+            ui::fail(&format!("{} exited with {}", editor, status));
         }
 
-        match choice {
-            "search.full_system_index" => {
-                let val = Confirm::new("Enable full system index? (indexes entire filesystem)")
-                    .with_default(config.config.search.full_system_index)
-                    .prompt()?;
-                config.config.search.full_system_index = val;
-            }
-            "search.default_paths" => {
-                let current = config.config.search.default_paths.join(", ");
-                let val = Text::new("default_paths (comma-separated):").with_default(&current).prompt()?;
-                config.config.search.default_paths = val.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
-            }
-            "search.system_index_roots" => {
-                let current = config.config.search.system_index_roots.join(", ");
-                let val = Text::new("system_index_roots (comma-separated):").with_default(&current).prompt()?;
-                config.config.search.system_index_roots = val.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+        let content = std::fs::read_to_string(&path)?;
+        let parsed = toml::from_str::<Config>(&content)
+            .map_err(anyhow::Error::from)
+            .and_then(|c| validate(&c).map(|_| c));
+        match parsed {
+            Ok(c) => {
+                config.config = c;
+                config.load_error = None;
+                ui::success("Config saved.");
+                return Ok(());
             }
-            "search.system_exclude_paths" => {
-                let current = config.config.search.system_exclude_paths.join(", ");
-                let val = Text::new("system_exclude_paths (comma-separated):").with_default(&current).prompt()?;
-                config.config.search.system_exclude_paths = val.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+            Err(e) => {
+                ui::fail(&format!("Config is invalid: {}", e));
+                if !Confirm::new("Re-open the editor to fix it?").with_default(true).prompt()? {
+                    std::fs::write(&path, &backup)?;
+                    ui::skip("Restored the previous config.");
+                    return Ok(());
+                }
             }
-            "search.max_results" => {
-                let val = Text::new("max_results:").with_default(&config.config.search.max_results.to_string()).prompt()?;
-                if let Ok(n) = val.parse() { config.config.search.max_results = n; }
-            }
-            "search.max_depth" => {
-                let val = Text::new("max_depth:").with_default(&config.config.search.max_depth.to_string()).prompt()?;
-                if let Ok(n) = val.parse() { config.config.search.max_depth = n; }
-            }
-            "search.exclude_hidden" => {
-                let val = Confirm::new("exclude_hidden?").with_default(config.config.search.exclude_hidden).prompt()?;
-                config.config.search.exclude_hidden = val;
-            }
-            "search.fuzzy_threshold" => {
-                let val = Text::new("fuzzy_threshold:").with_default(&config.config.search.fuzzy_threshold.to_string()).prompt()?;
-                if let Ok(n) = val.parse() { config.config.search.fuzzy_threshold = n; }
-            }
-            "system.auto_confirm_update" => {
-                let val = Confirm::new("auto_confirm_update?").with_default(config.config.system.auto_confirm_update).prompt()?;
-                config.config.system.auto_confirm_update = val;
-            }
-            "analytics.enabled" => {
-                let val = Confirm::new("Enable analytics ping?").with_default(config.config.analytics.enabled).prompt()?;
-                config.config.analytics.enabled = val;
-            }
-            "analytics.track_commands" => {
-                let val = Confirm::new("Track command usage?").with_default(config.config.analytics.track_commands).prompt()?;
-                config.config.analytics.track_commands = val;
-            }
-            _ => {}
         }
     }
-
-    Ok(())
 }
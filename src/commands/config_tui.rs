@@ -72,6 +72,12 @@ fn rows() -> Vec<Row> {
             description: "Skip hidden files and directories (names starting with .) when indexing user paths.",
             kind: FieldKind::Bool,
         }),
+        Row::Field(FieldDef {
+            key: "search.follow_links",
+            label: "follow_links",
+            description: "Follow symlinks when indexing user paths. Loops are detected; results show link -> target.",
+            kind: FieldKind::Bool,
+        }),
         Row::Section(SectionDef { title: "Search — Results" }),
         Row::Field(FieldDef {
             key: "search.max_results",
@@ -92,6 +98,209 @@ fn rows() -> Vec<Row> {
             description: "Automatically confirm package manager updates without prompting.",
             kind: FieldKind::Bool,
         }),
+        Row::Field(FieldDef {
+            key: "system.update_mirrors",
+            label: "update_mirrors",
+            description: "Refresh distro mirrors (reflector, apt ranking, dnf fastestmirror) before vg update.",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "system.language",
+            label: "language",
+            description: "Language for greetings: en or de.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Greet" }),
+        Row::Field(FieldDef {
+            key: "greet.show_uptime",
+            label: "show_uptime",
+            description: "Show system uptime in vg greet.",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "greet.show_updates",
+            label: "show_updates",
+            description: "Show the number of pending package updates in vg greet (queries every package manager, up to a few seconds).",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "greet.show_disks",
+            label: "show_disks",
+            description: "Warn in vg greet about disks that are nearly full.",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "greet.show_tip",
+            label: "show_tip",
+            description: "Show a random vg tip at the end of vg greet.",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "greet.show_weather",
+            label: "show_weather",
+            description: "Show current weather for weather.location in vg greet (needs network).",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "greet.disk_warn_percent",
+            label: "disk_warn_percent",
+            description: "Disk usage percentage (0–100) at which vg greet reports a disk.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Weather" }),
+        Row::Field(FieldDef {
+            key: "weather.location",
+            label: "location",
+            description: "City used by vg weather and the greeting when no city is given (e.g. Berlin).",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "weather.units",
+            label: "units",
+            description: "metric (°C, km/h) or imperial (°F, mph).",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Hooks" }),
+        Row::Field(FieldDef {
+            key: "hooks.forbidden",
+            label: "forbidden",
+            description: "Comma-separated file globs the pre-commit hook refuses to commit (e.g. .env, *.pem). Reinstall with vg hooks install.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "hooks.extra",
+            label: "extra",
+            description: "Comma-separated commands the pre-commit hook runs in every project, after the language checks.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Update Check" }),
+        Row::Field(FieldDef {
+            key: "update_check.enabled",
+            label: "enabled",
+            description: "Count pending package updates and new vg releases in the background, so vg greet shows them without waiting.",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "update_check.interval_hours",
+            label: "interval_hours",
+            description: "Hours between background update checks (default: 24).",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Toolchain" }),
+        Row::Field(FieldDef {
+            key: "toolchain.node",
+            label: "node",
+            description: "Installer for vg toolchain update node: auto (fnm, then volta), fnm, volta, or package for the system package manager.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "toolchain.python",
+            label: "python",
+            description: "Installer for vg toolchain update python: auto (pyenv, then uv), pyenv, uv, or package for the system package manager.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Sync" }),
+        Row::Field(FieldDef {
+            key: "sync.backend",
+            label: "backend",
+            description: "Where vg sync keeps snippets and saved requests: git, rclone, or empty to disable.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "sync.remote",
+            label: "remote",
+            description: "Git URL for the git backend, or an rclone remote path (e.g. webdav:genesis).",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Timeouts" }),
+        Row::Field(FieldDef {
+            key: "timeouts.query",
+            label: "query",
+            description: "Seconds before a hung background query (update lists, searches, probes) is stopped. 0 = no limit.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "timeouts.task",
+            label: "task",
+            description: "Seconds before a hung non-interactive package operation (upgrades behind a spinner) is stopped. 0 = no limit.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Dash" }),
+        Row::Field(FieldDef {
+            key: "dash.widgets",
+            label: "widgets",
+            description: "Comma-separated tiles for vg dash, two per row in this order: system, updates, reminders, track, search.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "dash.refresh_secs",
+            label: "refresh_secs",
+            description: "Seconds between vg dash refreshes (default: 2).",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Notify" }),
+        Row::Field(FieldDef {
+            key: "notify.commands",
+            label: "commands",
+            description: "Comma-separated commands that notify when they finish, e.g. update, index. Reminders always notify.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.min_seconds",
+            label: "min_seconds",
+            description: "Commands finishing sooner than this don't notify (default: 60).",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.desktop",
+            label: "desktop",
+            description: "Show a desktop notification (notify-send, or Notification Center on macOS).",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.bell",
+            label: "bell",
+            description: "Ring the terminal bell, which most terminals show on a background tab.",
+            kind: FieldKind::Bool,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.ntfy",
+            label: "ntfy",
+            description: "ntfy topic URL to push notifications to as well, e.g. https://ntfy.sh/<topic>. Empty = off.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.reports",
+            label: "reports",
+            description: "Comma-separated commands that send their results to ntfy and the webhook every run: health, update, data.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.webhook",
+            label: "webhook",
+            description: "Slack or Discord incoming-webhook URL, or any URL taking a JSON POST. Empty = off.",
+            kind: FieldKind::Text,
+        }),
+        Row::Field(FieldDef {
+            key: "notify.template",
+            label: "template",
+            description: "JSON body for the webhook using {title}, {body}, {host}, {command} and {status}. Empty = suited to the URL.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Fleet" }),
+        Row::Field(FieldDef {
+            key: "fleet.hosts",
+            label: "hosts",
+            description: "Comma-separated machines vg fleet status checks over SSH: user@server or aliases from ~/.ssh/config.",
+            kind: FieldKind::Text,
+        }),
+        Row::Section(SectionDef { title: "Self Update" }),
+        Row::Field(FieldDef {
+            key: "self_update.channel",
+            label: "channel",
+            description: "Release channel for vg self-update and vg update: stable, or nightly to include prereleases.",
+            kind: FieldKind::Text,
+        }),
         Row::Section(SectionDef { title: "Analytics" }),
         Row::Field(FieldDef {
             key: "analytics.enabled",
@@ -124,9 +333,42 @@ fn get_value(key: &str, config: &ConfigManager) -> String {
         "search.system_exclude_paths" => config.config.search.system_exclude_paths.join(", "),
         "search.max_depth"            => config.config.search.max_depth.to_string(),
         "search.exclude_hidden"       => config.config.search.exclude_hidden.to_string(),
+        "search.follow_links"         => config.config.search.follow_links.to_string(),
         "search.max_results"          => config.config.search.max_results.to_string(),
         "search.fuzzy_threshold"      => config.config.search.fuzzy_threshold.to_string(),
         "system.auto_confirm_update"  => config.config.system.auto_confirm_update.to_string(),
+        "system.update_mirrors"       => config.config.system.update_mirrors.to_string(),
+        "system.language"             => config.config.system.language.clone(),
+        "greet.show_uptime"           => config.config.greet.show_uptime.to_string(),
+        "greet.show_updates"          => config.config.greet.show_updates.to_string(),
+        "greet.show_disks"            => config.config.greet.show_disks.to_string(),
+        "greet.show_tip"              => config.config.greet.show_tip.to_string(),
+        "greet.show_weather"          => config.config.greet.show_weather.to_string(),
+        "greet.disk_warn_percent"     => config.config.greet.disk_warn_percent.to_string(),
+        "weather.location"            => config.config.weather.location.clone(),
+        "weather.units"               => config.config.weather.units.clone(),
+        "hooks.forbidden"             => config.config.hooks.forbidden.join(", "),
+        "hooks.extra"                 => config.config.hooks.extra.join(", "),
+        "update_check.enabled"        => config.config.update_check.enabled.to_string(),
+        "update_check.interval_hours" => config.config.update_check.interval_hours.to_string(),
+        "toolchain.node"              => config.config.toolchain.node.clone(),
+        "toolchain.python"            => config.config.toolchain.python.clone(),
+        "sync.backend"                => config.config.sync.backend.clone(),
+        "sync.remote"                 => config.config.sync.remote.clone(),
+        "timeouts.query"              => config.config.timeouts.query.to_string(),
+        "timeouts.task"               => config.config.timeouts.task.to_string(),
+        "dash.widgets"                => config.config.dash.widgets.join(", "),
+        "dash.refresh_secs"           => config.config.dash.refresh_secs.to_string(),
+        "notify.commands"             => config.config.notify.commands.join(", "),
+        "notify.min_seconds"          => config.config.notify.min_seconds.to_string(),
+        "notify.desktop"              => config.config.notify.desktop.to_string(),
+        "notify.bell"                 => config.config.notify.bell.to_string(),
+        "notify.ntfy"                 => config.config.notify.ntfy.clone(),
+        "notify.reports"              => config.config.notify.reports.join(", "),
+        "notify.webhook"              => config.config.notify.webhook.clone(),
+        "notify.template"             => config.config.notify.template.clone(),
+        "fleet.hosts"                 => config.config.fleet.hosts.join(", "),
+        "self_update.channel"         => config.config.self_update.channel.clone(),
         "analytics.enabled"           => config.config.analytics.enabled.to_string(),
         "analytics.track_commands"    => config.config.analytics.track_commands.to_string(),
         "analytics.client_id"         => format!("{}…", &config.config.analytics.client_id.chars().take(8).collect::<String>()),
@@ -138,9 +380,19 @@ fn toggle_bool(key: &str, config: &mut ConfigManager) {
     match key {
         "search.full_system_index"    => config.config.search.full_system_index    = !config.config.search.full_system_index,
         "search.exclude_hidden"       => config.config.search.exclude_hidden       = !config.config.search.exclude_hidden,
+        "search.follow_links"         => config.config.search.follow_links         = !config.config.search.follow_links,
         "system.auto_confirm_update"  => config.config.system.auto_confirm_update  = !config.config.system.auto_confirm_update,
+        "system.update_mirrors"       => config.config.system.update_mirrors       = !config.config.system.update_mirrors,
+        "greet.show_uptime"           => config.config.greet.show_uptime           = !config.config.greet.show_uptime,
+        "greet.show_updates"          => config.config.greet.show_updates          = !config.config.greet.show_updates,
+        "greet.show_disks"            => config.config.greet.show_disks            = !config.config.greet.show_disks,
+        "greet.show_tip"              => config.config.greet.show_tip              = !config.config.greet.show_tip,
+        "greet.show_weather"          => config.config.greet.show_weather          = !config.config.greet.show_weather,
+        "update_check.enabled"        => config.config.update_check.enabled        = !config.config.update_check.enabled,
         "analytics.enabled"           => config.config.analytics.enabled           = !config.config.analytics.enabled,
         "analytics.track_commands"    => config.config.analytics.track_commands    = !config.config.analytics.track_commands,
+        "notify.desktop"              => config.config.notify.desktop              = !config.config.notify.desktop,
+        "notify.bell"                 => config.config.notify.bell                 = !config.config.notify.bell,
         _ => {}
     }
 }
@@ -150,12 +402,35 @@ fn apply_text(key: &str, value: &str, config: &mut ConfigManager) {
         value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
     };
     match key {
+        "system.language" if crate::config::LANGUAGES.iter().any(|(code, _)| *code == value) => config.config.system.language = value.to_string(),
         "search.default_paths"        => config.config.search.default_paths        = vec_val(),
         "search.system_index_roots"   => config.config.search.system_index_roots   = vec_val(),
         "search.system_exclude_paths" => config.config.search.system_exclude_paths = vec_val(),
         "search.max_depth"            => { if let Ok(n) = value.parse() { config.config.search.max_depth = n; } }
         "search.max_results"          => { if let Ok(n) = value.parse() { config.config.search.max_results = n; } }
         "search.fuzzy_threshold"      => { if let Ok(n) = value.parse() { config.config.search.fuzzy_threshold = n; } }
+        "greet.disk_warn_percent"     => { if let Ok(n) = value.parse::<u8>() { if n <= 100 { config.config.greet.disk_warn_percent = n; } } }
+        "weather.location"            => config.config.weather.location = value.trim().to_string(),
+        "weather.units"               => { if matches!(value, "metric" | "imperial") { config.config.weather.units = value.to_string(); } }
+        "hooks.forbidden"             => { let v = vec_val(); if v.iter().all(|p| super::hooks::valid_pattern(p)) { config.config.hooks.forbidden = v; } }
+        "hooks.extra"                 => config.config.hooks.extra = vec_val(),
+        "update_check.interval_hours" => { if let Ok(n) = value.parse::<u64>() { if n > 0 { config.config.update_check.interval_hours = n; } } }
+        "toolchain.node"              => { if matches!(value, "auto" | "fnm" | "volta" | "package") { config.config.toolchain.node = value.to_string(); } }
+        "toolchain.python"            => { if matches!(value, "auto" | "pyenv" | "uv" | "package") { config.config.toolchain.python = value.to_string(); } }
+        "sync.backend"                => { if matches!(value, "" | "git" | "rclone") { config.config.sync.backend = value.to_string(); } }
+        "sync.remote"                 => config.config.sync.remote = value.trim().to_string(),
+        "timeouts.query"              => { if let Ok(n) = value.parse() { config.config.timeouts.query = n; } }
+        "timeouts.task"               => { if let Ok(n) = value.parse() { config.config.timeouts.task = n; } }
+        "dash.widgets"                => { let v = vec_val(); if v.iter().all(|w| super::dash::WIDGETS.contains(&w.as_str())) { config.config.dash.widgets = v; } }
+        "dash.refresh_secs"           => { if let Ok(n) = value.parse::<u64>() { if n > 0 { config.config.dash.refresh_secs = n; } } }
+        "notify.commands"             => config.config.notify.commands             = vec_val(),
+        "notify.min_seconds"          => { if let Ok(n) = value.parse::<u64>() { config.config.notify.min_seconds = n; } }
+        "notify.ntfy" if value.is_empty() || value.starts_with("http://") || value.starts_with("https://") => config.config.notify.ntfy = value.to_string(),
+        "notify.reports"              => { let v = vec_val(); if v.iter().all(|c| crate::notify::REPORTS.contains(&c.as_str())) { config.config.notify.reports = v; } }
+        "notify.webhook" if value.is_empty() || value.starts_with("http://") || value.starts_with("https://") => config.config.notify.webhook = value.to_string(),
+        "notify.template"             => config.config.notify.template             = value.to_string(),
+        "fleet.hosts"                 => config.config.fleet.hosts                 = vec_val(),
+        "self_update.channel"         => { if matches!(value, "stable" | "nightly") { config.config.self_update.channel = value.to_string(); } }
         _ => {}
     }
 }
@@ -451,11 +726,12 @@ impl Drop for TermGuard {
 // ── Public entry point ─────────────────────────────────────────────────────────
 
 pub fn run(config: &mut ConfigManager) -> Result<()> {
-    if !io::stdout().is_terminal() {
+    if !io::stdout().is_terminal() || !crate::ui::interactive() {
         // Non-interactive: fall back to plain list
         println!("Config file: {}", config.config_path().display());
         return Ok(());
     }
+    config.ensure_writable()?;
 
     let _guard = TermGuard::new()?;
     let backend = CrosstermBackend::new(io::stdout());
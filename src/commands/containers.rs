@@ -0,0 +1,214 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color, Table};
+use inquire::{MultiSelect, Select};
+use std::process::Command;
+
+/// docker if present, otherwise podman — their CLIs are compatible for everything used here.
+fn engine() -> Result<&'static str> {
+    crate::tools::require("docker")
+}
+
+/// Run the engine with a Go template and split its tab-separated output.
+fn query(engine: &str, args: &[&str]) -> Result<Vec<Vec<String>>> {
+    let out = crate::logging::output(Command::new(engine).args(args))
+        .with_context(|| format!("Failed to run {}", engine))?;
+    if !out.status.success() {
+        let err = String::from_utf8_lossy(&out.stderr);
+        if err.contains("Cannot connect") {
+            return Err(anyhow!("The {} daemon is not running — start it with: sudo systemctl start {}", engine, engine));
+        }
+        if err.contains("permission denied") {
+            return Err(anyhow!("{} needs permission — add yourself to the '{}' group or run rootless", engine, engine));
+        }
+        return Err(anyhow!("{} {} failed: {}", engine, args.join(" "), err.trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.split('\t').map(|c| c.trim().to_string()).collect())
+        .collect())
+}
+
+fn col(row: &[String], i: usize) -> &str {
+    row.get(i).map(String::as_str).unwrap_or("")
+}
+
+fn bold(title: &str) -> Cell {
+    Cell::new(title).add_attribute(Attribute::Bold)
+}
+
+/// `vg containers` — containers, images and volumes.
+pub fn list() -> Result<()> {
+    let engine = engine()?;
+    ui::print_header("CONTAINERS");
+    ui::info_line("Engine", engine);
+
+    ui::section("Containers");
+    let containers = query(engine, &["ps", "-a", "--size", "--format", "{{.Names}}\t{{.Image}}\t{{.State}}\t{{.Status}}\t{{.Size}}"])?;
+    if containers.is_empty() {
+        ui::skip("No containers");
+    } else {
+        let mut table = Table::new();
+        table.set_header(vec![bold("Name"), bold("Image"), bold("Status"), bold("Size")]);
+        for c in &containers {
+            let color = if col(c, 2) == "running" { Color::Green } else { Color::DarkGrey };
+            table.add_row(vec![
+                Cell::new(col(c, 0)).fg(Color::Blue),
+                Cell::new(col(c, 1)),
+                Cell::new(col(c, 3)).fg(color),
+                Cell::new(col(c, 4)),
+            ]);
+        }
+        println!("{}", table);
+    }
+
+    ui::section("Images");
+    let images = query(engine, &["images", "--format", "{{.Repository}}\t{{.Tag}}\t{{.ID}}\t{{.Size}}\t{{.CreatedSince}}"])?;
+    if images.is_empty() {
+        ui::skip("No images");
+    } else {
+        let mut table = Table::new();
+        table.set_header(vec![bold("Repository"), bold("Tag"), bold("ID"), bold("Size"), bold("Created")]);
+        for i in &images {
+            let dangling = col(i, 0) == "<none>";
+            table.add_row(vec![
+                Cell::new(col(i, 0)).fg(if dangling { Color::DarkGrey } else { Color::Blue }),
+                Cell::new(col(i, 1)),
+                Cell::new(col(i, 2).chars().take(12).collect::<String>()).fg(Color::DarkGrey),
+                Cell::new(col(i, 3)),
+                Cell::new(col(i, 4)).fg(Color::DarkGrey),
+            ]);
+        }
+        println!("{}", table);
+    }
+
+    ui::section("Volumes");
+    let volumes = query(engine, &["volume", "ls", "--format", "{{.Name}}\t{{.Driver}}"])?;
+    if volumes.is_empty() {
+        ui::skip("No volumes");
+    } else {
+        for v in &volumes {
+            println!("  {}  {}", col(v, 0).truecolor(96, 165, 250), col(v, 1).truecolor(71, 85, 105));
+        }
+    }
+
+    ui::section("Disk usage");
+    for row in query(engine, &["system", "df", "--format", "{{.Type}}\t{{.Size}}\t{{.Reclaimable}}"]).unwrap_or_default() {
+        ui::info_line(col(&row, 0), &format!("{}  (reclaimable {})", col(&row, 1), col(&row, 2)));
+    }
+    println!();
+    ui::skip("Actions: vg containers logs|exec|stop <name>  ·  vg containers prune");
+    Ok(())
+}
+
+/// Container name from a partial query, or a picker when none is given.
+fn resolve(engine: &str, name: Option<String>, running_only: bool) -> Result<String> {
+    let mut args = vec!["ps", "--format", "{{.Names}}"];
+    if !running_only {
+        args.insert(1, "-a");
+    }
+    let names: Vec<String> = query(engine, &args)?.into_iter().map(|r| col(&r, 0).to_string()).collect();
+    if names.is_empty() {
+        return Err(anyhow!("No {}containers", if running_only { "running " } else { "" }));
+    }
+    match name {
+        Some(n) if names.contains(&n) => Ok(n),
+        Some(n) => {
+            let (idx, _) = super::search::fuzzy_rank(&n, &names).into_iter().next()
+                .ok_or_else(|| anyhow!("No container matches '{}'", n))?;
+            Ok(names[idx].clone())
+        }
+        None if crate::ui::interactive() => Ok(Select::new("Container:", names).prompt()?),
+        None => Err(anyhow!("Pass a container name")),
+    }
+}
+
+pub fn logs(name: Option<String>, follow: bool, tail: usize) -> Result<()> {
+    let engine = engine()?;
+    let name = resolve(engine, name, false)?;
+    let tail = tail.to_string();
+    let mut args = vec!["logs", "--tail", &tail];
+    if follow {
+        args.push("-f");
+    }
+    args.push(&name);
+    crate::logging::status(Command::new(engine).args(&args))?;
+    Ok(())
+}
+
+pub fn exec(name: Option<String>, command: Vec<String>) -> Result<()> {
+    let engine = engine()?;
+    let name = resolve(engine, name, true)?;
+    let command = if command.is_empty() {
+        // Most images have sh; bash only when it exists
+        let has_bash = crate::logging::output(Command::new(engine).args(["exec", &name, "which", "bash"]))
+            .map(|o| o.status.success()).unwrap_or(false);
+        vec![if has_bash { "bash" } else { "sh" }.to_string()]
+    } else {
+        command
+    };
+    let mut args = vec!["exec".to_string(), "-it".to_string(), name];
+    args.extend(command);
+    crate::logging::status(Command::new(engine).args(&args))?;
+    Ok(())
+}
+
+pub fn stop(name: Option<String>) -> Result<()> {
+    let engine = engine()?;
+    let name = resolve(engine, name, true)?;
+    let out = crate::logging::output(Command::new(engine).args(["stop", &name]))?;
+    if !out.status.success() {
+        return Err(anyhow!("Could not stop {}: {}", name, String::from_utf8_lossy(&out.stderr).trim()));
+    }
+    ui::success(&format!("Stopped {}", name));
+    Ok(())
+}
+
+/// `vg containers prune` — pick what to remove, with reclaimable sizes from `system df`.
+pub fn prune(yes: bool) -> Result<()> {
+    let engine = engine()?;
+    ui::print_header("CONTAINER PRUNE");
+    let df = query(engine, &["system", "df", "--format", "{{.Type}}\t{{.Reclaimable}}"]).unwrap_or_default();
+    let reclaim = |kind: &str| df.iter()
+        .find(|r| col(r, 0).to_lowercase().starts_with(kind))
+        .map(|r| col(r, 1).to_string())
+        .unwrap_or_else(|| "?".into());
+
+    // (label, args, selected by default)
+    let mut steps: Vec<(String, Vec<&str>, bool)> = vec![
+        (format!("Stopped containers ({})", reclaim("containers")), vec!["container", "prune", "-f"], true),
+        ("Dangling images".to_string(), vec!["image", "prune", "-f"], true),
+        (format!("All unused images ({})", reclaim("images")), vec!["image", "prune", "-a", "-f"], false),
+        (format!("Unused volumes — deletes data ({})", reclaim("local volumes")), vec!["volume", "prune", "-f"], false),
+    ];
+    if engine == "docker" {
+        steps.push((format!("Build cache ({})", reclaim("build cache")), vec!["builder", "prune", "-f"], true));
+    }
+
+    let labels: Vec<String> = steps.iter().map(|(l, _, _)| l.clone()).collect();
+    let defaults: Vec<usize> = steps.iter().enumerate().filter(|(_, s)| s.2).map(|(i, _)| i).collect();
+    let chosen: Vec<usize> = if yes {
+        defaults
+    } else if crate::ui::interactive() {
+        MultiSelect::new("Prune which?", labels.clone()).with_default(&defaults).prompt()?
+            .iter().filter_map(|c| labels.iter().position(|l| l == c)).collect()
+    } else {
+        return Err(crate::error::Error::not_a_terminal("pass --yes to prune the default selection").into());
+    };
+
+    for idx in chosen {
+        let (label, args, _) = &steps[idx];
+        let out = crate::logging::task_output(Command::new(engine).args(args))?;
+        if out.status.success() {
+            let summary = String::from_utf8_lossy(&out.stdout).lines()
+                .find(|l| l.contains("reclaimed"))
+                .map(|l| format!(" — {}", l.trim()))
+                .unwrap_or_default();
+            ui::success(&format!("{}{}", label, summary));
+        } else {
+            ui::fail(&format!("{}: {}", label, String::from_utf8_lossy(&out.stderr).trim()));
+        }
+    }
+    Ok(())
+}
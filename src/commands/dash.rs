@@ -0,0 +1,352 @@
+use crate::config::ConfigManager;
+use anyhow::Result;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame, Terminal,
+};
+use std::io::{self, IsTerminal};
+use std::time::{Duration, Instant};
+use sysinfo::{Disks, System};
+
+/// Widgets `dash.widgets` may list, in the default order.
+pub const WIDGETS: &[&str] = &["system", "updates", "reminders", "track", "search"];
+
+struct TermGuard;
+
+impl TermGuard {
+    fn new() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen)?;
+        Ok(TermGuard)
+    }
+}
+
+impl Drop for TermGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+    }
+}
+
+struct Dash {
+    sys: System,
+    disks: Disks,
+    updates: Option<super::update_check::Cached>,
+    reboot: Option<String>,
+    reminders: Vec<(String, String, String)>,
+    tracked: super::track::Today,
+    query: String,
+    typing: bool,
+    /// (name, path) of the best matches for `query`
+    results: Vec<(String, String)>,
+    conn: Option<rusqlite::Connection>,
+    refreshed: Instant,
+}
+
+impl Dash {
+    fn new() -> Self {
+        let db = super::search::get_db_path();
+        let conn = if db.exists() { super::search::open_db().ok() } else { None };
+        let mut dash = Dash {
+            sys: System::new(),
+            disks: Disks::new(),
+            updates: None,
+            reboot: None,
+            reminders: Vec::new(),
+            tracked: super::track::Today { running: None, labels: Vec::new() },
+            query: String::new(),
+            typing: false,
+            results: Vec::new(),
+            conn,
+            refreshed: Instant::now(),
+        };
+        dash.refresh();
+        dash
+    }
+
+    /// Re-read everything; the stores are small and sysinfo only refreshes what is shown.
+    fn refresh(&mut self) {
+        self.sys.refresh_cpu_usage();
+        self.sys.refresh_memory();
+        self.disks = Disks::new_with_refreshed_list();
+        self.updates = super::update_check::load();
+        self.reboot = super::update::reboot_required();
+        self.reminders = super::remind::due_today();
+        self.tracked = super::track::today();
+        self.refreshed = Instant::now();
+    }
+
+    fn search(&mut self) {
+        self.results.clear();
+        let Some(conn) = &self.conn else { return };
+        if self.query.trim().is_empty() {
+            return;
+        }
+        let params = super::search::SearchParams {
+            query: self.query.clone(),
+            ext: None,
+            path_filter: None,
+            mime: None,
+            limit: Some(8),
+            verbose: false,
+            all_scopes: false,
+            format: None,
+        };
+        if let Ok(found) = super::search::query_json(&params, conn) {
+            self.results = found.iter()
+                .map(|r| (r["name"].as_str().unwrap_or_default().to_string(), r["path"].as_str().unwrap_or_default().to_string()))
+                .collect();
+        }
+    }
+}
+
+fn dim() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+fn tile(title: &str) -> Block<'_> {
+    Block::default()
+        .title(format!(" {} ", title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+}
+
+fn bar(pct: f64, width: usize) -> Span<'static> {
+    let filled = ((pct / 100.0) * width as f64).round().clamp(0.0, width as f64) as usize;
+    let color = match pct {
+        p if p >= 90.0 => Color::Red,
+        p if p >= 75.0 => Color::Yellow,
+        _ => Color::Blue,
+    };
+    Span::styled(format!("{}{}", "█".repeat(filled), "░".repeat(width - filled)), Style::default().fg(color))
+}
+
+fn row(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{:<10}", label), Style::default().fg(Color::Blue)),
+        Span::raw(value),
+    ])
+}
+
+fn system_lines(dash: &Dash) -> Vec<Line<'static>> {
+    let cpu = dash.sys.global_cpu_usage() as f64;
+    let (used, total) = (dash.sys.used_memory(), dash.sys.total_memory());
+    let mem = if total > 0 { used as f64 / total as f64 * 100.0 } else { 0.0 };
+    let load = System::load_average();
+    let uptime = System::uptime();
+    let mut lines = vec![
+        Line::from(vec![Span::styled(format!("{:<10}", "CPU"), Style::default().fg(Color::Blue)), bar(cpu, 20), Span::raw(format!(" {:>5.1}%", cpu))]),
+        Line::from(vec![
+            Span::styled(format!("{:<10}", "Memory"), Style::default().fg(Color::Blue)),
+            bar(mem, 20),
+            Span::raw(format!(" {} / {}", super::search::fmt_bytes(used), super::search::fmt_bytes(total))),
+        ]),
+        row("Load", format!("{:.2}  {:.2}  {:.2}", load.one, load.five, load.fifteen)),
+        row("Uptime", format!("{}d {}h {}m", uptime / 86_400, uptime % 86_400 / 3600, uptime % 3600 / 60)),
+    ];
+    for disk in dash.disks.iter().filter(|d| super::health::Mount::from(*d).counted()).take(3) {
+        let total = disk.total_space();
+        let pct = (total - disk.available_space()) as f64 / total as f64 * 100.0;
+        let mount = disk.mount_point().to_string_lossy().to_string();
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<10}", mount.chars().take(9).collect::<String>()), Style::default().fg(Color::Blue)),
+            bar(pct, 20),
+            Span::raw(format!(" {:>5.1}% of {}", pct, super::search::fmt_bytes(total))),
+        ]));
+    }
+    lines
+}
+
+fn update_lines(dash: &Dash) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    match &dash.updates {
+        Some(cached) => {
+            lines.push(Line::from(super::update_check::summary(cached)));
+            for (manager, count) in cached.packages.iter().filter(|(_, n)| **n > 0) {
+                lines.push(row(manager, count.to_string()));
+            }
+            if let Some(latest) = &cached.vg_latest {
+                lines.push(row("vg", format!("{} available — vg self-update", latest)));
+            }
+        }
+        None => lines.push(Line::styled("Not checked yet — run vg updates", dim())),
+    }
+    if let Some(reason) = &dash.reboot {
+        lines.push(Line::styled(format!("Reboot required — {}", reason), Style::default().fg(Color::Red)));
+    }
+    lines
+}
+
+fn reminder_lines(dash: &Dash) -> Vec<Line<'static>> {
+    if dash.reminders.is_empty() {
+        return vec![Line::styled("Nothing due today", dim())];
+    }
+    dash.reminders.iter()
+        .map(|(at, message, until)| Line::from(vec![
+            Span::styled(format!("{}  ", at), Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)),
+            Span::raw(message.clone()),
+            Span::styled(format!("  ({})", until), dim()),
+        ]))
+        .collect()
+}
+
+fn track_lines(dash: &Dash) -> Vec<Line<'static>> {
+    let mut lines = vec![match &dash.tracked.running {
+        Some((label, elapsed)) => Line::from(vec![
+            Span::styled("● ", Style::default().fg(Color::Green)),
+            Span::raw(format!("{} for {}", label, elapsed)),
+        ]),
+        None => Line::styled("Nothing running — vg track start <label>", dim()),
+    }];
+    lines.extend(dash.tracked.labels.iter().map(|(label, total)| row(label, total.clone())));
+    lines
+}
+
+fn render_search(f: &mut Frame, dash: &Dash, area: Rect) {
+    let block = tile("search");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+
+    let prompt = if dash.typing || !dash.query.is_empty() {
+        Line::from(vec![Span::styled("> ", Style::default().fg(Color::Blue)), Span::raw(dash.query.clone())])
+    } else {
+        Line::styled("Press / to search the index", dim())
+    };
+    f.render_widget(Paragraph::new(prompt), parts[0]);
+    if dash.typing {
+        f.set_cursor_position((parts[0].x + 2 + dash.query.chars().count() as u16, parts[0].y));
+    }
+
+    let lines: Vec<Line> = if dash.conn.is_none() {
+        vec![Line::styled("No index yet — run vg index", dim())]
+    } else {
+        dash.results.iter()
+            .map(|(name, path)| Line::from(vec![Span::raw(format!("{}  ", name)), Span::styled(path.clone(), dim())]))
+            .collect()
+    };
+    f.render_widget(Paragraph::new(lines), parts[1]);
+}
+
+fn render(f: &mut Frame, dash: &Dash, widgets: &[String]) {
+    let outer = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1), Constraint::Length(1)])
+        .split(f.area());
+
+    let header = Line::from(vec![
+        Span::styled(" vg dash", Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("  {}  {}", System::host_name().unwrap_or_default(), chrono::Local::now().format("%a %d %b %H:%M")), dim()),
+    ]);
+    f.render_widget(Paragraph::new(header), outer[0]);
+
+    // Two tiles per row, in the configured order
+    let rows = widgets.len().div_ceil(2).max(1);
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(outer[1]);
+    for (i, chunk) in widgets.chunks(2).enumerate() {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, chunk.len() as u32); chunk.len()])
+            .split(row_areas[i]);
+        for (name, area) in chunk.iter().zip(cols.iter()) {
+            let lines = match name.as_str() {
+                "system" => system_lines(dash),
+                "updates" => update_lines(dash),
+                "reminders" => reminder_lines(dash),
+                "track" => track_lines(dash),
+                "search" => {
+                    render_search(f, dash, *area);
+                    continue;
+                }
+                _ => continue,
+            };
+            f.render_widget(Paragraph::new(lines).block(tile(name)), *area);
+        }
+    }
+
+    let keys = if dash.typing {
+        " type to search · Enter open in vg search · Esc done"
+    } else {
+        " / search · r refresh · q quit"
+    };
+    f.render_widget(Paragraph::new(Line::styled(keys, dim())), outer[2]);
+}
+
+/// `vg dash` — a home screen tiling system load, pending updates, today's reminders and
+/// tracked time, and a quick search box. Tiles and their order come from `dash.widgets`.
+pub fn run(config: &ConfigManager) -> Result<()> {
+    if !io::stdout().is_terminal() || !crate::ui::interactive() {
+        return Err(crate::error::Error::not_a_terminal("use vg health, vg updates or vg track status").into());
+    }
+    let dash_config = &config.config.dash;
+    let widgets: Vec<String> = dash_config.widgets.iter().filter(|w| WIDGETS.contains(&w.as_str())).cloned().collect();
+    let every = Duration::from_secs(dash_config.refresh_secs.max(1));
+
+    let mut dash = Dash::new();
+    let mut open_search = false;
+    {
+        let _guard = TermGuard::new()?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+        loop {
+            terminal.draw(|f| render(f, &dash, &widgets))?;
+            if !event::poll(Duration::from_millis(250))? {
+                if dash.refreshed.elapsed() >= every {
+                    dash.refresh();
+                }
+                continue;
+            }
+            let Event::Key(key) = event::read()? else { continue };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                break;
+            }
+            if dash.typing {
+                match key.code {
+                    KeyCode::Esc => dash.typing = false,
+                    KeyCode::Enter if !dash.query.trim().is_empty() => {
+                        open_search = true;
+                        break;
+                    }
+                    KeyCode::Backspace => {
+                        dash.query.pop();
+                        dash.search();
+                    }
+                    KeyCode::Char(c) => {
+                        dash.query.push(c);
+                        dash.search();
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('/') if widgets.iter().any(|w| w == "search") => dash.typing = true,
+                KeyCode::Char('r') => dash.refresh(),
+                _ => {}
+            }
+        }
+    }
+
+    if open_search {
+        return super::search_tui::run_interactive_with_query(config, &dash.query);
+    }
+    Ok(())
+}
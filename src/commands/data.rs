@@ -0,0 +1,272 @@
+use crate::config::ConfigManager;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use inquire::Confirm;
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+const MANIFEST: &str = "manifest.json";
+const INDEX: &str = "search.db";
+
+/// Data dir entries that are machine-specific or rebuilt on their own.
+/// The undo journal refers to paths on this machine; logs, stamps and the sync mirror are throwaway.
+const SKIP_DATA: &[&str] = &[
+    "logs", "history", "backups", "sync", "last_auto_index", "last_update_check", "update_check.json",
+    "onboarding_pending", "search.db", "search.db-wal", "search.db-shm",
+];
+
+/// Plaintext secret store; only exported on request.
+const SECRETS: &str = "secrets.toml";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    vg_version: String,
+    created: chrono::DateTime<chrono::Utc>,
+    host: String,
+    index: bool,
+    secrets: bool,
+}
+
+fn config_dir(config: &ConfigManager) -> PathBuf {
+    config.config_path().parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+fn default_archive() -> PathBuf {
+    PathBuf::from(format!("genesis-backup-{}.tar.gz", chrono::Local::now().format("%Y-%m-%d")))
+}
+
+/// Files under `dir`, relative to it, skipping `skip` at the top level.
+fn files_under(dir: &Path, skip: &[&str]) -> Vec<PathBuf> {
+    if !dir.is_dir() {
+        return Vec::new();
+    }
+    let options = crate::walk::Options {
+        skip_paths: skip.iter().map(|s| dir.join(s)).collect(),
+        ..Default::default()
+    };
+    let mut files: Vec<PathBuf> = crate::walk::files(dir, &options).into_iter()
+        .filter_map(|p| p.strip_prefix(dir).ok().map(Path::to_path_buf))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Files to export and their names in the archive. Both directories skip throwaway state and,
+/// unless `secrets`, the plaintext secret store; on macOS config and data share one directory.
+fn sources(config_dir: &Path, data_dir: &Path, secrets: bool) -> Vec<(PathBuf, PathBuf)> {
+    let mut skip = SKIP_DATA.to_vec();
+    if !secrets {
+        skip.push(SECRETS);
+    }
+    let mut dirs = vec![(config_dir, "config")];
+    if data_dir != config_dir {
+        dirs.push((data_dir, "data"));
+    }
+    dirs.into_iter()
+        .flat_map(|(dir, prefix)| {
+            let skip = &skip;
+            files_under(dir, skip).into_iter().map(move |rel| (dir.join(&rel), Path::new(prefix).join(rel)))
+        })
+        .collect()
+}
+
+/// Write the archive; returns (files, bytes before compression).
+fn write_archive(out: &Path, config: &ConfigManager, index: bool, secrets: bool) -> Result<(usize, u64)> {
+    let file = std::fs::File::create(out).with_context(|| format!("Cannot write {}", out.display()))?;
+    let mut tar = tar::Builder::new(GzEncoder::new(file, flate2::Compression::default()));
+    let mut count = 0;
+    let mut bytes = 0;
+
+    let manifest = Manifest {
+        vg_version: env!("CARGO_PKG_VERSION").to_string(),
+        created: chrono::Utc::now(),
+        host: sysinfo::System::host_name().unwrap_or_default(),
+        index,
+        secrets,
+    };
+    let json = serde_json::to_vec_pretty(&manifest)?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(manifest.created.timestamp() as u64);
+    tar.append_data(&mut header, MANIFEST, json.as_slice())?;
+
+    for (path, name) in sources(&config_dir(config), &ConfigManager::data_dir(), secrets) {
+        bytes += std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        tar.append_path_with_name(&path, name)?;
+        count += 1;
+    }
+
+    let db = super::search::get_db_path();
+    if index && db.exists() {
+        // A consistent copy even while a background index job is writing
+        let tmp = tempfile::Builder::new().prefix("vg-export-").tempdir()?;
+        let snapshot = tmp.path().join(INDEX);
+        super::search::open_db()?.execute("VACUUM INTO ?1", [snapshot.to_string_lossy()])?;
+        bytes += std::fs::metadata(&snapshot).map(|m| m.len()).unwrap_or(0);
+        tar.append_path_with_name(&snapshot, Path::new("data").join(INDEX))?;
+        count += 1;
+    }
+
+    tar.into_inner()?.finish()?;
+    Ok((count, bytes))
+}
+
+/// `vg data export [file]` — config, snippets, history and other state (plus the search index) in one archive.
+pub fn export(out: Option<PathBuf>, no_index: bool, secrets: bool, config: &ConfigManager) -> Result<()> {
+    ui::print_header("DATA EXPORT");
+    let out = out.unwrap_or_else(default_archive);
+    if out.exists() {
+        return Err(anyhow!("{} already exists — pass another file name", out.display()));
+    }
+    let (count, bytes) = write_archive(&out, config, !no_index, secrets).inspect_err(|_| {
+        let _ = std::fs::remove_file(&out);
+    })?;
+
+    ui::info_line("Files", &count.to_string());
+    ui::info_line("Index", if no_index { "excluded" } else { "included" });
+    ui::info_line("Secrets", if secrets { "included (plaintext store only)" } else { "excluded — pass --secrets to include" });
+    let size = std::fs::metadata(&out).map(|m| m.len()).unwrap_or(0);
+    let wrote = format!(
+        "Wrote {} ({}, {} uncompressed)",
+        out.display(), super::search::fmt_bytes(size), super::search::fmt_bytes(bytes),
+    );
+    ui::success(&wrote);
+    crate::notify::note(wrote);
+    if secrets {
+        ui::skip("The archive holds your secrets in plaintext — keep it somewhere safe");
+    }
+    Ok(())
+}
+
+/// Where an archive entry lands, or None for entries that don't belong to us.
+fn destination(entry: &Path, config_dir: &Path, data_dir: &Path) -> Option<PathBuf> {
+    // Only plain relative names; `..` or absolute paths would escape the target directories
+    if !entry.components().all(|c| matches!(c, Component::Normal(_))) {
+        return None;
+    }
+    let mut parts = entry.components();
+    let base = match parts.next()?.as_os_str().to_str()? {
+        "config" => config_dir,
+        "data" => data_dir,
+        _ => return None,
+    };
+    let rest = parts.as_path();
+    (!rest.as_os_str().is_empty()).then(|| base.join(rest))
+}
+
+fn open(archive: &Path) -> Result<tar::Archive<GzDecoder<std::fs::File>>> {
+    let file = std::fs::File::open(archive).with_context(|| format!("Cannot open {}", archive.display()))?;
+    Ok(tar::Archive::new(GzDecoder::new(file)))
+}
+
+/// `vg data import <file>` — restore an export over the current state. Files not in the archive are kept.
+pub fn import(archive: PathBuf, no_index: bool, dry_run: bool, yes: bool, config: &mut ConfigManager) -> Result<()> {
+    ui::print_header("DATA IMPORT");
+    let config_dir = config_dir(config);
+    let data_dir = ConfigManager::data_dir();
+    let is_index = |rel: &Path| rel == Path::new("data").join(INDEX);
+
+    // First pass: read the manifest and list what would be written
+    let mut manifest: Option<Manifest> = None;
+    let mut planned: Vec<(PathBuf, u64)> = Vec::new();
+    for entry in open(&archive)?.entries()? {
+        let mut entry = entry?;
+        let rel = entry.path()?.to_path_buf();
+        if rel == Path::new(MANIFEST) {
+            manifest = Some(serde_json::from_reader(&mut entry).context("Invalid manifest")?);
+        } else if entry.header().entry_type().is_file()
+            && !(no_index && is_index(&rel))
+            && destination(&rel, &config_dir, &data_dir).is_some()
+        {
+            planned.push((rel, entry.size()));
+        }
+    }
+    let manifest = manifest.ok_or_else(|| anyhow!("{} is not a vg data export", archive.display()))?;
+
+    ui::info_line("Exported", &format!(
+        "{} from {} (vg {})",
+        manifest.created.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"), manifest.host, manifest.vg_version,
+    ));
+    let count = |prefix: &str| planned.iter().filter(|(p, _)| p.starts_with(prefix)).count();
+    ui::info_line("Config", &format!("{} files → {}", count("config"), config_dir.display()));
+    ui::info_line("Data", &format!("{} files → {}", count("data"), data_dir.display()));
+    ui::info_line("Index", if planned.iter().any(|(p, _)| is_index(p)) { "included" } else { "not restored" });
+    println!();
+
+    if planned.is_empty() {
+        ui::skip("Nothing to restore");
+        return Ok(());
+    }
+    if dry_run {
+        for (rel, size) in &planned {
+            ui::info_line(&super::search::fmt_bytes(*size), &rel.display().to_string());
+        }
+        println!();
+        ui::skip("Dry run: nothing written");
+        return Ok(());
+    }
+    if !yes {
+        if !crate::ui::interactive() {
+            return Err(crate::error::Error::not_a_terminal("pass --yes to apply or --dry-run to preview").into());
+        }
+        if !Confirm::new("Overwrite the current state with this export?").with_default(false).prompt()? {
+            ui::skip("Cancelled");
+            return Ok(());
+        }
+    }
+
+    // Keep what's there now, so an import can be rolled back with another import
+    let backups = data_dir.join("backups");
+    std::fs::create_dir_all(&backups)?;
+    let backup = backups.join(format!("pre-import-{}.tar.gz", chrono::Local::now().format("%Y%m%d-%H%M%S")));
+    write_archive(&backup, config, false, true)?;
+
+    let mut written = 0;
+    for entry in open(&archive)?.entries()? {
+        let mut entry = entry?;
+        let rel = entry.path()?.to_path_buf();
+        if !entry.header().entry_type().is_file() || (no_index && is_index(&rel)) {
+            continue;
+        }
+        let Some(dest) = destination(&rel, &config_dir, &data_dir) else { continue };
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if is_index(&rel) {
+            // A WAL left from the old index would be replayed into the new one
+            let _ = std::fs::remove_file(dest.with_extension("db-wal"));
+            let _ = std::fs::remove_file(dest.with_extension("db-shm"));
+        }
+        let tmp = dest.with_file_name(format!(".vg-import-{}", std::process::id()));
+        entry.unpack(&tmp).with_context(|| format!("Cannot write {}", dest.display()))?;
+        std::fs::rename(&tmp, &dest).with_context(|| format!("Cannot write {}", dest.display()))?;
+        written += 1;
+    }
+
+    // The restored config stands in for first-run setup
+    config.finish_onboarding();
+    ui::success(&format!("Restored {} files", written));
+    ui::skip(&format!("Previous state saved to {} — vg data import it to go back", backup.display()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_config_and_data_dir_is_archived_once() {
+        let dir = tempfile::tempdir().unwrap();
+        for f in ["config.toml", "snippets.json", "secrets.toml", "search.db", "search.db-wal", "logs/vg.log"] {
+            let path = dir.path().join(f);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "x").unwrap();
+        }
+        let names = |secrets| -> Vec<PathBuf> { sources(dir.path(), dir.path(), secrets).into_iter().map(|(_, n)| n).collect() };
+        assert_eq!(names(false), [Path::new("config/config.toml"), Path::new("config/snippets.json")]);
+        assert!(names(true).contains(&PathBuf::from("config/secrets.toml")));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+use std::process::Command;
+use which::which;
+
+/// lsblk columns we ask for; older util-linux prints booleans as "0"/"1", newer as true/false.
+const LSBLK_COLUMNS: &str = "NAME,SIZE,TYPE,FSTYPE,LABEL,MOUNTPOINT,FSUSE%,TRAN,RM,MODEL";
+
+fn lsblk() -> Result<Vec<Value>> {
+    let out = Command::new("lsblk")
+        .args(["-J", "-o", LSBLK_COLUMNS])
+        .output()
+        .context("Failed to run lsblk. Is util-linux installed?")?;
+    let json: Value = serde_json::from_slice(&out.stdout).context("Failed to parse lsblk JSON output")?;
+    Ok(json["blockdevices"].as_array().cloned().unwrap_or_default())
+}
+
+fn text(dev: &Value, key: &str) -> String {
+    match &dev[key] {
+        Value::String(s) => s.trim().to_string(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn is_removable(dev: &Value) -> bool {
+    matches!(&dev["rm"], Value::Bool(true)) || dev["rm"].as_str() == Some("1") || text(dev, "tran") == "usb"
+}
+
+fn children(dev: &Value) -> Vec<Value> {
+    dev["children"].as_array().cloned().unwrap_or_default()
+}
+
+/// `vg disks` — block devices, partitions and mounts.
+pub fn list() -> Result<()> {
+    ui::print_header("DISKS");
+    if cfg!(target_os = "macos") {
+        Command::new("diskutil").arg("list").status().context("Failed to run diskutil")?;
+        return Ok(());
+    }
+    if !cfg!(target_os = "linux") {
+        ui::skip("Block device listing is available on Linux and macOS.");
+        return Ok(());
+    }
+
+    let devices: Vec<Value> = lsblk()?.into_iter()
+        // Loop devices are snap/flatpak images, not hardware
+        .filter(|d| text(d, "type") != "loop")
+        .collect();
+    if devices.is_empty() {
+        ui::skip("No block devices found");
+        return Ok(());
+    }
+
+    for dev in &devices {
+        let name = text(dev, "name");
+        let removable = is_removable(dev);
+        let model = text(dev, "model");
+        let tran = text(dev, "tran");
+        ui::section(&format!("/dev/{}", name));
+        ui::info_line("Size", &text(dev, "size"));
+        if !model.is_empty() {
+            ui::info_line("Model", &model);
+        }
+        let kind = match (removable, tran.as_str()) {
+            (_, "usb") => "USB, removable".to_string(),
+            (true, "") => "removable".to_string(),
+            (true, t) => format!("{}, removable", t),
+            (false, "") => text(dev, "type"),
+            (false, t) => t.to_string(),
+        };
+        ui::info_line("Type", &kind);
+
+        let parts = children(dev);
+        let rows = if parts.is_empty() { vec![dev.clone()] } else { parts };
+        for part in &rows {
+            print_partition(part);
+        }
+        if removable {
+            println!("  {} {}", "Eject:".truecolor(71, 85, 105), format!("vg disks eject {}", name).truecolor(100, 116, 139));
+        }
+    }
+    println!();
+    Ok(())
+}
+
+fn print_partition(part: &Value) {
+    let name = text(part, "name");
+    let fstype = text(part, "fstype");
+    let label = text(part, "label");
+    let mount = text(part, "mountpoint");
+    let usage = text(part, "fsuse%");
+    let detail = [
+        if fstype.is_empty() { "—".to_string() } else { fstype },
+        label,
+    ].into_iter().filter(|s| !s.is_empty()).collect::<Vec<_>>().join("  ");
+    let mount = if mount.is_empty() {
+        "not mounted".truecolor(71, 85, 105).to_string()
+    } else if usage.is_empty() {
+        mount.truecolor(224, 242, 254).to_string()
+    } else {
+        format!("{}  {}", mount.truecolor(224, 242, 254), usage.truecolor(96, 165, 250))
+    };
+    println!(
+        "    {:<12} {:>8}  {:<24} {}",
+        name.truecolor(96, 165, 250),
+        text(part, "size"),
+        detail,
+        mount,
+    );
+    // LVM and LUKS nest one level deeper
+    for child in children(part) {
+        print_partition(&child);
+    }
+}
+
+/// Every mounted node under `dev`, deepest first so nested mounts go before their parents.
+fn mounted(dev: &Value, out: &mut Vec<String>) {
+    for child in children(dev) {
+        mounted(&child, out);
+    }
+    if !text(dev, "mountpoint").is_empty() {
+        out.push(text(dev, "name"));
+    }
+}
+
+/// `vg disks eject <device>` — unmount every partition, then power the drive off.
+pub fn eject(device: &str) -> Result<()> {
+    ui::print_header("EJECT");
+    let name = device.trim_start_matches("/dev/");
+
+    if cfg!(target_os = "macos") {
+        let status = Command::new("diskutil").args(["eject", &format!("/dev/{}", name)]).status()?;
+        if !status.success() {
+            return Err(anyhow!("diskutil could not eject /dev/{}", name));
+        }
+        ui::success(&format!("/dev/{} can be removed safely", name));
+        return Ok(());
+    }
+
+    let dev = lsblk()?.into_iter()
+        .find(|d| text(d, "name") == name)
+        .ok_or_else(|| anyhow!("/dev/{} is not a whole disk — pass the drive (e.g. sdb), not a partition", name))?;
+    if !is_removable(&dev) {
+        return Err(anyhow!("/dev/{} is not a removable drive — refusing to eject it", name));
+    }
+
+    let mut mounts = Vec::new();
+    mounted(&dev, &mut mounts);
+    let udisks = which("udisksctl").is_ok();
+    for part in &mounts {
+        let path = format!("/dev/{}", part);
+        let status = if udisks {
+            Command::new("udisksctl").args(["unmount", "-b", &path]).output()?.status
+        } else {
+            Command::new("umount").arg(&path).output()?.status
+        };
+        if !status.success() {
+            return Err(anyhow!("Could not unmount {} — close any files open on it and try again", path));
+        }
+        ui::success(&format!("Unmounted {}", path));
+    }
+
+    // Flush write caches before cutting power
+    let _ = Command::new("sync").status();
+    let path = format!("/dev/{}", name);
+    let powered_off = if udisks {
+        Command::new("udisksctl").args(["power-off", "-b", &path]).output().map(|o| o.status.success()).unwrap_or(false)
+    } else {
+        Command::new("eject").arg(&path).output().map(|o| o.status.success()).unwrap_or(false)
+    };
+    if powered_off {
+        ui::success(&format!("{} can be removed safely", path));
+    } else {
+        ui::success(&format!("{} is unmounted — it is safe to remove once activity stops", path));
+    }
+    Ok(())
+}
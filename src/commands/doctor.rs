@@ -0,0 +1,245 @@
+use crate::ui;
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use which::which;
+
+/// Files bigger than this are flagged when they are untracked or modified in git.
+const LARGE_FILE_BYTES: u64 = 5 * 1024 * 1024; // 5 MB
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Rust,
+    Node,
+    Python,
+    Go,
+}
+
+impl Language {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Rust => "Rust",
+            Language::Node => "Node.js",
+            Language::Python => "Python",
+            Language::Go => "Go",
+        }
+    }
+
+    /// Key used for this language in config sections such as `hooks.commands`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Node => "node",
+            Language::Python => "python",
+            Language::Go => "go",
+        }
+    }
+
+    /// Format and lint checks the pre-commit hook runs when `hooks.commands` doesn't override them.
+    pub fn hook_commands(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => &["cargo fmt --check", "cargo clippy --quiet -- -D warnings"],
+            Language::Node => &["npm run lint --if-present"],
+            Language::Python => &["ruff format --check .", "ruff check ."],
+            Language::Go => &["test -z \"$(gofmt -l .)\"", "go vet ./..."],
+        }
+    }
+
+    /// Toolchain binaries this language needs, with the flag that prints their version.
+    fn tools(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Rust => &[("cargo", "--version"), ("rustc", "--version")],
+            Language::Node => &[("node", "--version"), ("npm", "--version")],
+            Language::Python => &[("python3", "--version"), ("pip3", "--version")],
+            Language::Go => &[("go", "version")],
+        }
+    }
+
+    /// Entries every project of this language should have in .gitignore.
+    pub fn gitignore_entries(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => &["target"],
+            Language::Node => &["node_modules"],
+            Language::Python => &["__pycache__", ".venv"],
+            Language::Go => &[],
+        }
+    }
+}
+
+/// Detect which languages a project directory uses from its manifest files.
+pub fn detect_languages(dir: &Path) -> Vec<Language> {
+    let mut langs = Vec::new();
+    if dir.join("Cargo.toml").exists() {
+        langs.push(Language::Rust);
+    }
+    if dir.join("package.json").exists() {
+        langs.push(Language::Node);
+    }
+    if ["pyproject.toml", "requirements.txt", "setup.py"].iter().any(|f| dir.join(f).exists()) {
+        langs.push(Language::Python);
+    }
+    if dir.join("go.mod").exists() {
+        langs.push(Language::Go);
+    }
+    langs
+}
+
+/// Walk up from `start` to the nearest directory containing a known manifest or `.git`.
+pub fn find_project_root(start: &Path) -> Option<PathBuf> {
+    start.ancestors()
+        .find(|d| d.join(".git").exists() || !detect_languages(d).is_empty())
+        .map(Path::to_path_buf)
+}
+
+pub fn run() -> Result<()> {
+    ui::print_header("PROJECT DOCTOR");
+
+    let cwd = std::env::current_dir()?;
+    let Some(root) = find_project_root(&cwd) else {
+        ui::fail("No project found in the current directory or its parents.");
+        ui::skip("Run vg doctor inside a directory with Cargo.toml, package.json, pyproject.toml or go.mod");
+        return Ok(());
+    };
+
+    let langs = detect_languages(&root);
+    let mut issues = 0usize;
+
+    ui::section("Project");
+    ui::info_line("Root", &root.display().to_string());
+    let names: Vec<&str> = langs.iter().map(|l| l.name()).collect();
+    ui::info_line("Languages", &if names.is_empty() { "unknown".to_string() } else { names.join(", ") });
+
+    ui::section("Toolchain");
+    for lang in &langs {
+        for (tool, flag) in lang.tools() {
+            if which(tool).is_err() {
+                ui::fail(&format!("{} not found", tool));
+                ui::skip(&format!("Fix: vg install {}", tool));
+                issues += 1;
+                continue;
+            }
+            let version = crate::logging::output(Command::new(tool).arg(flag)).ok()
+                .map(|o| String::from_utf8_lossy(&o.stdout).lines().next().unwrap_or("").trim().to_string())
+                .unwrap_or_default();
+            ui::success(&format!("{}  {}", tool, version));
+        }
+    }
+    if langs.is_empty() {
+        ui::skip("No language manifest detected — skipping toolchain checks");
+    }
+
+    ui::section("Lockfiles");
+    issues += check_lockfiles(&root, &langs);
+
+    let is_git = root.join(".git").exists();
+
+    ui::section("Git");
+    if is_git {
+        issues += check_gitignore(&root, &langs);
+        issues += check_large_files(&root);
+        if !super::hooks::installed(&root) {
+            ui::skip("No vg pre-commit hook (format, lint and secret-file checks)");
+            ui::skip("Fix: vg hooks install");
+        }
+    } else {
+        ui::skip("Not a git repository");
+        ui::skip("Fix: git init");
+    }
+
+    println!();
+    if issues == 0 {
+        ui::success("No problems found.");
+    } else {
+        ui::fail(&format!("{} issue{} found — see fixes above.", issues, if issues == 1 { "" } else { "s" }));
+        crate::exit::set(crate::exit::NEGATIVE);
+    }
+    Ok(())
+}
+
+fn modified(path: &Path) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn check_lockfiles(root: &Path, langs: &[Language]) -> usize {
+    let mut issues = 0;
+    for lang in langs {
+        // (manifest, accepted lockfiles, command that refreshes the lockfile)
+        let (manifest, locks, fix): (&str, &[&str], &str) = match lang {
+            Language::Rust => ("Cargo.toml", &["Cargo.lock"], "cargo generate-lockfile"),
+            Language::Node => ("package.json", &["package-lock.json", "yarn.lock", "pnpm-lock.yaml"], "npm install"),
+            Language::Python => ("pyproject.toml", &["poetry.lock", "uv.lock", "pdm.lock"], "poetry lock"),
+            Language::Go => ("go.mod", &["go.sum"], "go mod tidy"),
+        };
+        let manifest_path = root.join(manifest);
+        if !manifest_path.exists() {
+            continue;
+        }
+        let Some(lock) = locks.iter().map(|l| root.join(l)).find(|p| p.exists()) else {
+            // Library crates and plain requirements.txt projects legitimately skip lockfiles
+            ui::skip(&format!("{}: no lockfile", manifest));
+            continue;
+        };
+        let lock_name = lock.file_name().unwrap_or_default().to_string_lossy().to_string();
+        match (modified(&manifest_path), modified(&lock)) {
+            (Some(m), Some(l)) if m > l => {
+                ui::fail(&format!("{} is older than {}", lock_name, manifest));
+                ui::skip(&format!("Fix: {}", fix));
+                issues += 1;
+            }
+            _ => ui::success(&format!("{} is up to date", lock_name)),
+        }
+    }
+    if langs.is_empty() {
+        ui::skip("Nothing to check");
+    }
+    issues
+}
+
+fn check_gitignore(root: &Path, langs: &[Language]) -> usize {
+    let content = std::fs::read_to_string(root.join(".gitignore")).unwrap_or_default();
+    let entries: Vec<&str> = content.lines()
+        .map(|l| l.trim().trim_start_matches('/').trim_end_matches('/'))
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect();
+
+    let missing: Vec<&str> = langs.iter()
+        .flat_map(|l| l.gitignore_entries().iter().copied())
+        .filter(|e| !entries.contains(e))
+        .collect();
+
+    if missing.is_empty() {
+        ui::success(".gitignore covers build and dependency dirs");
+        return 0;
+    }
+    for entry in &missing {
+        ui::fail(&format!(".gitignore is missing '{}'", entry));
+    }
+    ui::skip(&format!("Fix: printf '{}\\n' >> .gitignore", missing.join("\\n")));
+    missing.len()
+}
+
+fn check_large_files(root: &Path) -> usize {
+    let Ok(out) = crate::logging::output(Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=all"])
+        .current_dir(root)) else {
+        ui::skip("git not available");
+        return 0;
+    };
+
+    let mut issues = 0;
+    for line in String::from_utf8_lossy(&out.stdout).lines() {
+        // Porcelain format: "XY path" (renames: "XY old -> new")
+        let Some(rel) = line.get(3..) else { continue };
+        let rel = rel.rsplit(" -> ").next().unwrap_or(rel).trim_matches('"');
+        let Ok(meta) = std::fs::metadata(root.join(rel)) else { continue };
+        if meta.is_file() && meta.len() > LARGE_FILE_BYTES {
+            ui::fail(&format!("Large uncommitted file: {} ({})", rel, super::search::fmt_bytes(meta.len())));
+            ui::skip(&format!("Fix: add it to .gitignore or track it with git lfs track '{}'", rel));
+            issues += 1;
+        }
+    }
+    if issues == 0 {
+        ui::success("No large uncommitted files");
+    }
+    issues
+}
@@ -0,0 +1,54 @@
+use crate::package_managers::PmUpdate;
+use anyhow::{anyhow, Context, Result};
+use std::process::Command;
+
+/// Firmware updates known to fwupd: (device, installed version, newest release).
+/// Uses the cached metadata; `apply` refreshes it first.
+pub fn pending() -> Result<Vec<PmUpdate>> {
+    let bin = crate::tools::require("fwupdmgr")?;
+    let out = crate::logging::output(Command::new(bin).args(["get-updates", "--json"]))
+        .context("Failed to run fwupdmgr")?;
+    // Exit code 2 means "nothing to do" and may come without JSON
+    if out.status.code() == Some(2) {
+        return Ok(Vec::new());
+    }
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout)
+        .map_err(|_| anyhow!("fwupdmgr get-updates failed: {}", String::from_utf8_lossy(&out.stderr).trim()))?;
+    Ok(json["Devices"].as_array().into_iter().flatten()
+        .filter_map(|device| {
+            // Releases are sorted newest first
+            let latest = device["Releases"].as_array()?.first()?["Version"].as_str()?;
+            Some((
+                device["Name"].as_str().unwrap_or("?").to_string(),
+                device["Version"].as_str().unwrap_or("?").to_string(),
+                latest.to_string(),
+            ))
+        })
+        .collect())
+}
+
+/// Refresh fwupd's metadata and list what can be updated.
+pub fn refresh() -> Result<Vec<PmUpdate>> {
+    let bin = crate::tools::require("fwupdmgr")?;
+    let out = crate::logging::output(Command::new(bin).arg("refresh")).context("Failed to run fwupdmgr")?;
+    // 2: metadata is already current
+    if !out.status.success() && out.status.code() != Some(2) {
+        tracing::debug!(stderr = %String::from_utf8_lossy(&out.stderr), "fwupdmgr refresh failed, using cached metadata");
+    }
+    pending()
+}
+
+/// Install all pending firmware updates. fwupd asks about restarts itself unless `yes`.
+pub fn apply(yes: bool) -> Result<()> {
+    let bin = crate::tools::require("fwupdmgr")?;
+    let mut cmd = Command::new(bin);
+    cmd.arg("update");
+    if yes {
+        cmd.args(["--assume-yes", "--no-reboot-check"]);
+    }
+    let status = crate::logging::status(&mut cmd).context("Failed to run fwupdmgr")?;
+    if !status.success() {
+        return Err(anyhow!("fwupdmgr update exited with {}", status.code().unwrap_or(-1)));
+    }
+    Ok(())
+}
@@ -0,0 +1,112 @@
+// src/commands/fleet.rs
+// `vg fleet status`: health, updates and disks of every machine in `fleet.hosts`, side by side.
+// Each host runs `vg health --json` over SSH (see `remote`), all at once, so the table takes
+// as long as the slowest machine rather than the sum of them.
+
+use super::health::Snapshot;
+use crate::ui;
+use anyhow::{anyhow, Result};
+use comfy_table::{Attribute, Cell, CellAlignment, Color, Table};
+
+fn gather(host: &str) -> Result<Snapshot> {
+    let out = super::remote::output(host, &["health".to_string(), "--json".to_string()])?;
+    // Skip anything the remote vg printed before the command ran (config notes, first-run hints)
+    let json = out.find('{').map_or("", |i| &out[i..]);
+    serde_json::from_str(json).map_err(|_| anyhow!("no health snapshot — vg there may predate `vg health --json`"))
+}
+
+fn status_color(status: &str) -> Color {
+    match status {
+        "ok" => Color::Green,
+        "warning" => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+pub fn status(hosts: &[String]) -> Result<()> {
+    if hosts.is_empty() {
+        return Err(anyhow!("No hosts in fleet.hosts — add them with: vg config set fleet.hosts web1,user@db"));
+    }
+    // The config file may have been edited by hand; never hand ssh an option as a host
+    if let Some(problem) = hosts.iter().find_map(|h| super::remote::host_problem(h)) {
+        return Err(anyhow!("fleet.hosts: {} — fix it with vg config edit", problem));
+    }
+    ui::print_header("FLEET");
+    let results: Vec<(&String, Result<Snapshot>)> = std::thread::scope(|s| {
+        hosts.iter()
+            .map(|h| (h, s.spawn(move || gather(h))))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(h, t)| (h, t.join().unwrap_or_else(|_| Err(anyhow!("check panicked")))))
+            .collect()
+    });
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Host").add_attribute(Attribute::Bold),
+        Cell::new("Status").add_attribute(Attribute::Bold),
+        Cell::new("Uptime").add_attribute(Attribute::Bold),
+        Cell::new("Load").add_attribute(Attribute::Bold),
+        Cell::new("Mem").add_attribute(Attribute::Bold),
+        Cell::new("Fullest disk").add_attribute(Attribute::Bold),
+        Cell::new("Updates").add_attribute(Attribute::Bold),
+        Cell::new("Problems").add_attribute(Attribute::Bold),
+    ]);
+    let mut unreachable = 0;
+    for (host, result) in &results {
+        match result {
+            Ok(snap) => {
+                let disk = snap.disks.first();
+                let mut problems = Vec::new();
+                if !snap.failed_units.is_empty() {
+                    problems.push(format!("failed: {}", snap.failed_units.join(", ")));
+                }
+                if snap.reboot_required.is_some() {
+                    problems.push("reboot required".to_string());
+                }
+                match snap.status.as_str() {
+                    "critical" => crate::exit::set(crate::exit::CRITICAL),
+                    "warning" => crate::exit::set(crate::exit::NEGATIVE),
+                    _ => {}
+                }
+                table.add_row(vec![
+                    Cell::new(host).fg(Color::Blue),
+                    Cell::new(&snap.status).fg(status_color(&snap.status)),
+                    Cell::new(super::greet::fmt_uptime(snap.uptime)),
+                    Cell::new(format!("{:.2}", snap.load)).set_alignment(CellAlignment::Right),
+                    Cell::new(format!("{:.0}%", snap.memory_percent)).set_alignment(CellAlignment::Right),
+                    match disk {
+                        Some((mount, pct)) => Cell::new(format!("{} {:.0}%", mount, pct))
+                            .fg(if *pct > super::health::DISK_WARNING { Color::Red } else { Color::Reset }),
+                        None => Cell::new("—").fg(Color::DarkGrey),
+                    },
+                    match snap.updates {
+                        Some(0) => Cell::new("0").fg(Color::Green),
+                        Some(n) => Cell::new(n).fg(Color::Yellow),
+                        None => Cell::new("?").fg(Color::DarkGrey),
+                    }.set_alignment(CellAlignment::Right),
+                    Cell::new(problems.join("; ")).fg(Color::Red),
+                ]);
+            }
+            Err(e) => {
+                unreachable += 1;
+                let mut row = vec![
+                    Cell::new(host).fg(Color::Blue),
+                    Cell::new("error").fg(Color::Red).add_attribute(Attribute::Bold),
+                ];
+                row.extend((0..5).map(|_| Cell::new("—").fg(Color::DarkGrey)));
+                row.push(Cell::new(format!("{:#}", e)).fg(Color::Red));
+                table.add_row(row);
+            }
+        }
+    }
+    println!("{}", table);
+    println!();
+    if unreachable > 0 {
+        crate::exit::set(crate::exit::ERROR);
+        ui::fail(&format!("{} of {} hosts could not be checked", unreachable, results.len()));
+    } else {
+        ui::success(&format!("{} hosts checked", results.len()));
+    }
+    Ok(())
+}
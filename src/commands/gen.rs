@@ -0,0 +1,215 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use colored::Colorize;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, RngCore};
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use which::which;
+
+const LOWER: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPER: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!@#$%^&*()-_=+[]{};:,.?/";
+/// Characters that are easy to mix up when read aloud or typed from paper.
+const AMBIGUOUS: &str = "Il1O0o";
+
+/// Built-in passphrase words, used unless --wordlist points at a diceware-style file.
+const WORDS: &[&str] = &[
+    "acid", "acorn", "actor", "adobe", "agent", "alarm", "album", "alert", "alien", "alley",
+    "alpha", "amber", "angle", "ankle", "apple", "apron", "arena", "armor", "arrow", "atlas",
+    "attic", "audio", "autumn", "avocado", "bacon", "badge", "bagel", "baker", "balsa", "bamboo",
+    "banjo", "barn", "basil", "basin", "beach", "beacon", "beard", "beetle", "bench", "berry",
+    "bison", "blade", "blanket", "blaze", "blossom", "board", "bonus", "booth", "boulder",
+    "bracket", "brave", "bread", "brick", "bridge", "bronze", "brook", "broom", "bubble", "bucket",
+    "buffalo", "bugle", "bundle", "butter", "cabin", "cactus", "camel", "candle", "canoe", "canyon",
+    "carbon", "cargo", "carpet", "castle", "cedar", "cello", "chalk", "charm", "cherry", "chess",
+    "chimney", "cider", "cinema", "circus", "citrus", "clamp", "clover", "cobalt", "cocoa", "comet",
+    "compass", "copper", "coral", "cotton", "cougar", "crane", "crater", "crayon", "cricket",
+    "crystal", "cupcake", "curtain", "cycle", "dagger", "daisy", "dancer", "delta", "denim",
+    "desert", "diamond", "dingo", "dolphin", "domino", "donkey", "dragon", "drift", "drum", "eagle",
+    "easel", "echo", "eclipse", "elbow", "ember", "emerald", "engine", "falcon", "fender", "ferry",
+    "fiddle", "fjord", "flame", "flannel", "flint", "flute", "forest", "fossil", "fountain", "fox",
+    "frost", "galaxy", "garden", "garlic", "gazebo", "gecko", "geyser", "ginger", "glacier",
+    "globe", "goblin", "gopher", "granite", "grape", "gravel", "guitar", "hammer", "harbor",
+    "harvest", "hazel", "helmet", "heron", "hickory", "honey", "hornet", "husky", "igloo", "indigo",
+    "iris", "island", "ivory", "jacket", "jaguar", "jasmine", "jelly", "jigsaw", "jungle", "kayak",
+    "kernel", "kettle", "kiwi", "koala", "ladder", "lagoon", "lantern", "laser", "lemon", "lentil",
+    "lilac", "linen", "lizard", "lobster", "locket", "lotus", "lunar", "magnet", "mango", "maple",
+    "marble", "meadow", "melon", "meteor", "mint", "mitten", "monsoon", "mosaic", "muffin", "mural",
+    "nectar", "nickel", "noodle", "nutmeg", "oasis", "obsidian", "ocean", "olive", "onion", "orbit",
+    "orchid", "otter", "oyster", "paddle", "palace", "panda", "papaya", "parrot", "pebble",
+    "pepper", "pickle", "pigeon", "pilot", "pistol", "planet", "plaza", "plum", "polar", "pony",
+    "poppy", "prairie", "prism", "pulsar", "puzzle", "quartz", "quill", "quiver", "rabbit", "radar",
+    "radish", "raven", "reef", "ribbon", "river", "robin", "rocket", "saddle", "saffron", "salmon",
+    "sandal", "satin", "scarf", "shadow", "shrimp", "silver", "sketch", "sloth", "sparrow",
+    "spider", "spruce", "squid", "stable", "summit", "sunset", "swan", "tango", "temple", "thistle",
+    "thunder", "tiger", "timber", "toast", "tomato", "topaz", "torch", "tractor", "trumpet",
+    "tulip", "tundra", "turtle", "umbrella", "valley", "velvet", "violin", "walnut", "walrus",
+    "willow", "winter", "wizard", "yacht", "zebra", "zephyr",
+];
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum UuidVersion {
+    #[value(name = "4")]
+    V4,
+    #[value(name = "7")]
+    V7,
+}
+
+/// Print each generated value, optionally copying all of them to the clipboard.
+/// Piped output is bare values, one per line, so `vg gen ... | cmd` just works.
+fn emit(values: &[String], copy: bool, note: Option<String>) -> Result<()> {
+    if !std::io::stdout().is_terminal() {
+        for v in values {
+            println!("{}", v);
+        }
+        if copy {
+            copy_to_clipboard(&values.join("\n"))?;
+        }
+        return Ok(());
+    }
+    ui::print_header("GENERATE");
+    for v in values {
+        println!("  {}", v.truecolor(224, 242, 254));
+    }
+    if let Some(note) = note {
+        println!();
+        ui::skip(&note);
+    }
+    if copy {
+        copy_to_clipboard(&values.join("\n"))?;
+        ui::success("Copied to clipboard");
+    }
+    Ok(())
+}
+
+/// Copy `text` with whichever clipboard tool the platform has.
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    let candidates: &[(&str, &[&str])] = &[
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+        ("pbcopy", &[]),
+        ("clip.exe", &[]),
+    ];
+    let (tool, args) = candidates.iter()
+        .find(|(tool, _)| which(tool).is_ok())
+        .ok_or_else(|| anyhow!("No clipboard tool found — install wl-clipboard, xclip or xsel"))?;
+    let mut child = Command::new(tool)
+        .args(*args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {}", tool))?;
+    child.stdin.take().context("Failed to open clipboard stdin")?.write_all(text.as_bytes())?;
+    child.wait()?;
+    Ok(())
+}
+
+/// `vg gen uuid` — random (v4) or time-ordered (v7) UUIDs.
+pub fn uuid(version: UuidVersion, count: usize, copy: bool) -> Result<()> {
+    let values: Vec<String> = (0..count)
+        .map(|_| match version {
+            UuidVersion::V4 => uuid::Uuid::new_v4().to_string(),
+            UuidVersion::V7 => uuid::Uuid::now_v7().to_string(),
+        })
+        .collect();
+    emit(&values, copy, None)
+}
+
+/// `vg gen password` — random passwords with at least one character from each enabled class.
+pub fn password(length: usize, no_upper: bool, no_digits: bool, no_symbols: bool, no_ambiguous: bool, count: usize, copy: bool) -> Result<()> {
+    let mut classes: Vec<Vec<char>> = vec![LOWER.chars().collect()];
+    if !no_upper { classes.push(UPPER.chars().collect()); }
+    if !no_digits { classes.push(DIGITS.chars().collect()); }
+    if !no_symbols { classes.push(SYMBOLS.chars().collect()); }
+    if no_ambiguous {
+        for class in classes.iter_mut() {
+            class.retain(|c| !AMBIGUOUS.contains(*c));
+        }
+    }
+    if length < classes.len() {
+        return Err(anyhow!("Length must be at least {} to include every character class", classes.len()));
+    }
+    let pool: Vec<char> = classes.concat();
+
+    let mut rng = rand::rng();
+    let values: Vec<String> = (0..count)
+        .map(|_| {
+            let mut chars: Vec<char> = classes.iter()
+                .map(|class| *class.choose(&mut rng).expect("character class is never empty"))
+                .collect();
+            while chars.len() < length {
+                chars.push(pool[rng.random_range(0..pool.len())]);
+            }
+            chars.shuffle(&mut rng);
+            chars.into_iter().collect()
+        })
+        .collect();
+
+    let bits = length as f64 * (pool.len() as f64).log2();
+    emit(&values, copy, Some(format!("~{:.0} bits of entropy ({} characters from a pool of {})", bits, length, pool.len())))
+}
+
+/// `vg gen passphrase` — diceware-style word sequences.
+pub fn passphrase(words: usize, separator: &str, capitalize: bool, wordlist: Option<&Path>, count: usize, copy: bool) -> Result<()> {
+    let owned: Vec<String>;
+    let list: Vec<&str> = match wordlist {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read wordlist {}", path.display()))?;
+            // Accept plain lists and diceware files ("11111\tword")
+            owned = content.lines()
+                .filter_map(|l| l.split_whitespace().last())
+                .map(str::to_string)
+                .collect();
+            owned.iter().map(String::as_str).collect()
+        }
+        None => WORDS.to_vec(),
+    };
+    if list.len() < 2 {
+        return Err(anyhow!("Wordlist needs at least two words"));
+    }
+
+    let mut rng = rand::rng();
+    let values: Vec<String> = (0..count)
+        .map(|_| {
+            (0..words)
+                .map(|_| {
+                    let w = *list.choose(&mut rng).expect("wordlist is never empty");
+                    if capitalize {
+                        let mut c = w.chars();
+                        c.next().map(|f| f.to_uppercase().chain(c).collect()).unwrap_or_default()
+                    } else {
+                        w.to_string()
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(separator)
+        })
+        .collect();
+
+    let bits = words as f64 * (list.len() as f64).log2();
+    emit(&values, copy, Some(format!("~{:.0} bits of entropy ({} words from a list of {})", bits, words, list.len())))
+}
+
+/// `vg gen token` — random bytes as hex or URL-safe base64.
+pub fn token(bytes: usize, base64: bool, count: usize, copy: bool) -> Result<()> {
+    let mut rng = rand::rng();
+    let values: Vec<String> = (0..count)
+        .map(|_| {
+            let mut buf = vec![0u8; bytes];
+            rng.fill_bytes(&mut buf);
+            if base64 {
+                base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&buf)
+            } else {
+                hex::encode(&buf)
+            }
+        })
+        .collect();
+    emit(&values, copy, None)
+}
@@ -0,0 +1,252 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use inquire::Confirm;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Commit message used by `vg git wip`; `unwip` only undoes commits with exactly this subject.
+const WIP_MESSAGE: &str = "WIP: saved by vg git wip";
+
+// The git CLI rather than git2: it honours the user's config, hooks, credential helpers and
+// safe.directory rules as they expect, and libgit2 would add a C build dependency for a few calls.
+
+/// Run git with `args` and return trimmed stdout. Fails with git's stderr on a non-zero exit.
+fn git(args: &[&str]) -> Result<String> {
+    let out = Command::new("git").args(args).output().context("Failed to run git — is it installed?")?;
+    if !out.status.success() {
+        let err = String::from_utf8_lossy(&out.stderr).trim().to_string();
+        return Err(anyhow!("git {} failed: {}", args.join(" "), err));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim_end().to_string())
+}
+
+fn ensure_repo() -> Result<()> {
+    git(&["rev-parse", "--is-inside-work-tree"])
+        .map(|_| ())
+        .map_err(|_| anyhow!("Not inside a git repository"))
+}
+
+fn confirm(prompt: &str, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    ui::ensure_interactive("pass --yes")?;
+    Ok(Confirm::new(prompt).with_default(false).prompt()?)
+}
+
+fn ref_exists(name: &str) -> bool {
+    git(&["rev-parse", "--verify", "--quiet", name]).is_ok()
+}
+
+/// Best guess at the repository's main branch (origin/HEAD, else main, else master) and the ref
+/// to compare against: the local branch, or `origin/<base>` when only that exists here.
+fn default_branch() -> (String, String) {
+    let from_origin = git(&["symbolic-ref", "--short", "refs/remotes/origin/HEAD"]).ok()
+        .and_then(|head| head.strip_prefix("origin/").map(str::to_string));
+    let name = from_origin
+        .or_else(|| ["main", "master"].into_iter()
+            .find(|c| ref_exists(&format!("refs/heads/{}", c)) || ref_exists(&format!("refs/remotes/origin/{}", c)))
+            .map(str::to_string))
+        .unwrap_or_else(|| "main".to_string());
+    let reference = if !ref_exists(&format!("refs/heads/{}", name)) && ref_exists(&format!("refs/remotes/origin/{}", name)) {
+        format!("origin/{}", name)
+    } else {
+        name.clone()
+    };
+    (name, reference)
+}
+
+/// `vg git cleanup` — delete local branches already merged into the default branch.
+pub fn cleanup(dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header("GIT CLEANUP");
+    ensure_repo()?;
+
+    let (base, reference) = default_branch();
+    let current = git(&["branch", "--show-current"]).unwrap_or_default();
+    ui::info_line("Base branch", &reference);
+
+    let merged: Vec<String> = git(&["branch", "--merged", &reference, "--format=%(refname:short)"])?
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|b| !b.is_empty() && *b != base && *b != current)
+        .collect();
+
+    ui::section("Merged branches");
+    if merged.is_empty() {
+        ui::success("No merged branches to delete.");
+    } else {
+        for b in &merged {
+            ui::skip(b);
+        }
+        println!();
+        if dry_run {
+            ui::skip(&format!("Dry run — {} branch(es) would be deleted.", merged.len()));
+        } else if confirm(&format!("Delete {} merged branch(es)?", merged.len()), yes)? {
+            // `-d` only knows about HEAD and upstreams; these were just checked against the remote base
+            let delete = if reference == base { "-d" } else { "-D" };
+            for b in &merged {
+                match git(&["branch", delete, b]) {
+                    Ok(_) => ui::success(&format!("Deleted {}", b)),
+                    Err(e) => ui::fail(&e.to_string()),
+                }
+            }
+        } else {
+            ui::skip("Aborted.");
+        }
+    }
+
+    // Drop remote-tracking refs whose upstream branch is gone
+    if git(&["remote"]).map(|r| r.lines().any(|l| l == "origin")).unwrap_or(false) {
+        ui::section("Remote-tracking refs");
+        let stale = git(&["remote", "prune", "--dry-run", "origin"]).unwrap_or_default();
+        let count = stale.lines().filter(|l| l.contains("[would prune]")).count();
+        if count == 0 {
+            ui::success("No stale refs to prune.");
+        } else if dry_run {
+            ui::skip(&format!("Dry run — {} stale ref(s) would be pruned.", count));
+        } else if confirm(&format!("Prune {} stale ref(s) from origin?", count), yes)? {
+            git(&["remote", "prune", "origin"])?;
+            ui::success("Pruned stale refs from origin");
+        } else {
+            ui::skip("Aborted.");
+        }
+    }
+    Ok(())
+}
+
+/// `vg git wip` — stage everything and commit it as a WIP checkpoint.
+pub fn wip(dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header("GIT WIP");
+    ensure_repo()?;
+
+    let status = git(&["status", "--porcelain"])?;
+    if status.is_empty() {
+        ui::success("Working tree clean — nothing to save.");
+        return Ok(());
+    }
+    ui::section("Changes");
+    for line in status.lines() {
+        ui::skip(line);
+    }
+    println!();
+    if dry_run {
+        ui::skip(&format!("Dry run — would commit {} change(s) as '{}'.", status.lines().count(), WIP_MESSAGE));
+        return Ok(());
+    }
+    if !confirm(&format!("Commit {} change(s) as a WIP checkpoint?", status.lines().count()), yes)? {
+        ui::skip("Aborted.");
+        return Ok(());
+    }
+    git(&["add", "-A"])?;
+    git(&["commit", "--no-verify", "-m", WIP_MESSAGE])?;
+    ui::success("Saved work in progress. Restore with: vg git unwip");
+    Ok(())
+}
+
+/// `vg git unwip` — undo the last commit if it is a WIP checkpoint, keeping its changes unstaged.
+pub fn unwip(dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header("GIT UNWIP");
+    ensure_repo()?;
+
+    let subject = git(&["log", "-1", "--format=%s"])?;
+    if subject != WIP_MESSAGE {
+        return Err(anyhow!("Last commit is not a vg git wip checkpoint: {}", subject));
+    }
+    if dry_run {
+        ui::skip("Dry run — would reset the WIP commit and keep its changes in the working tree.");
+        return Ok(());
+    }
+    if !confirm("Undo the WIP checkpoint and restore its changes?", yes)? {
+        ui::skip("Aborted.");
+        return Ok(());
+    }
+    git(&["reset", "HEAD~1"])?;
+    ui::success("Restored work in progress to the working tree.");
+    Ok(())
+}
+
+/// `vg git undo` — soft-reset the last commit, keeping its changes staged.
+pub fn undo(dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header("GIT UNDO");
+    ensure_repo()?;
+
+    let last = git(&["log", "-1", "--format=%h %s"])?;
+    ui::info_line("Last commit", &last);
+
+    if !ref_exists("HEAD~1") {
+        return Err(anyhow!("This is the first commit — nothing to reset to"));
+    }
+    // Undoing a pushed commit rewrites shared history, so call it out
+    if let Ok(upstream) = git(&["rev-parse", "--abbrev-ref", "@{upstream}"]) {
+        let pushed = git(&["branch", "-r", "--contains", "HEAD"]).unwrap_or_default();
+        if pushed.lines().any(|l| l.trim() == upstream) {
+            ui::fail(&format!("This commit is already on {} — undoing it will require a force push.", upstream));
+        }
+    }
+    println!();
+    if dry_run {
+        ui::skip("Dry run — would soft-reset HEAD~1 and keep the changes staged.");
+        return Ok(());
+    }
+    if !confirm("Undo the last commit?", yes)? {
+        ui::skip("Aborted.");
+        return Ok(());
+    }
+    git(&["reset", "--soft", "HEAD~1"])?;
+    ui::success("Commit undone — its changes are still staged.");
+    Ok(())
+}
+
+/// `vg git big-files` — list the largest blobs anywhere in history.
+pub fn big_files(limit: usize) -> Result<()> {
+    ui::print_header("GIT BIG FILES");
+    ensure_repo()?;
+
+    let objects = git(&["rev-list", "--objects", "--all"])?;
+
+    let mut child = Command::new("git")
+        .args(["cat-file", "--batch-check=%(objecttype) %(objectname) %(objectsize) %(rest)"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Failed to run git cat-file")?;
+    // Feed stdin from a thread so a full stdout pipe can't deadlock us on big repos
+    let mut stdin = child.stdin.take().context("Failed to open git cat-file stdin")?;
+    let writer = std::thread::spawn(move || {
+        let _ = stdin.write_all(objects.as_bytes());
+        let _ = stdin.write_all(b"\n");
+    });
+    let out = child.wait_with_output()?;
+    let _ = writer.join();
+
+    let mut blobs: Vec<(u64, String, String)> = String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(4, ' ');
+            if parts.next()? != "blob" { return None; }
+            let hash = parts.next()?.to_string();
+            let size = parts.next()?.parse().ok()?;
+            let path = parts.next().unwrap_or("").to_string();
+            Some((size, hash, path))
+        })
+        .collect();
+    blobs.sort_by_key(|b| std::cmp::Reverse(b.0));
+
+    ui::section(&format!("Largest {} blobs", limit));
+    if blobs.is_empty() {
+        ui::skip("No blobs found.");
+        return Ok(());
+    }
+    for (size, hash, path) in blobs.iter().take(limit) {
+        println!(
+            "  {:>10}  {}  {}",
+            super::search::fmt_bytes(*size).truecolor(96, 165, 250),
+            hash[..hash.len().min(10)].truecolor(71, 85, 105),
+            path.truecolor(224, 242, 254),
+        );
+    }
+    println!();
+    ui::skip("Remove from history with git filter-repo --path <file> --invert-paths");
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::ui;
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+use which::which;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Gpu {
+    pub model: String,
+    pub driver: Option<String>,
+    pub vram_total_mb: Option<u64>,
+    pub vram_used_mb: Option<u64>,
+    pub utilization_percent: Option<u8>,
+}
+
+fn output(prog: &str, args: &[&str]) -> Option<String> {
+    let out = Command::new(prog).args(args).output().ok()?;
+    out.status.success().then(|| String::from_utf8_lossy(&out.stdout).to_string())
+}
+
+fn read_trim(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// All GPUs the platform tools can see. Empty if none are detectable.
+pub fn detect() -> Vec<Gpu> {
+    let mut gpus = nvidia();
+    if cfg!(target_os = "linux") {
+        // nvidia-smi already covered NVIDIA cards with better numbers
+        let have_nvidia = !gpus.is_empty();
+        gpus.extend(sysfs().into_iter().filter(|(vendor, _)| !(have_nvidia && vendor == "0x10de")).map(|(_, g)| g));
+    } else if cfg!(target_os = "macos") {
+        gpus.extend(macos());
+    } else if cfg!(target_os = "windows") && gpus.is_empty() {
+        gpus.extend(windows());
+    }
+    gpus
+}
+
+fn nvidia() -> Vec<Gpu> {
+    if which("nvidia-smi").is_err() {
+        return Vec::new();
+    }
+    let Some(text) = output("nvidia-smi", &[
+        "--query-gpu=name,driver_version,memory.total,memory.used,utilization.gpu",
+        "--format=csv,noheader,nounits",
+    ]) else { return Vec::new() };
+    text.lines()
+        .filter_map(|line| {
+            let cols: Vec<&str> = line.split(',').map(str::trim).collect();
+            Some(Gpu {
+                model: cols.first()?.to_string(),
+                driver: cols.get(1).map(|d| format!("nvidia {}", d)),
+                vram_total_mb: cols.get(2).and_then(|v| v.parse().ok()),
+                vram_used_mb: cols.get(3).and_then(|v| v.parse().ok()),
+                utilization_percent: cols.get(4).and_then(|v| v.parse().ok()),
+            })
+        })
+        .collect()
+}
+
+/// Linux DRM cards as (PCI vendor id, GPU). amdgpu exposes VRAM and load in sysfs.
+fn sysfs() -> Vec<(String, Gpu)> {
+    let mut gpus = Vec::new();
+    let Ok(entries) = std::fs::read_dir("/sys/class/drm") else { return gpus };
+    let mut cards: Vec<_> = entries.filter_map(|e| e.ok())
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.strip_prefix("card").is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(|e| e.path().join("device"))
+        .collect();
+    cards.sort();
+
+    for dev in cards {
+        let vendor = read_trim(&dev.join("vendor")).unwrap_or_default();
+        let driver = std::fs::read_link(dev.join("driver")).ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()));
+        let slot = std::fs::canonicalize(&dev).ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()));
+        let model = slot.as_deref().and_then(lspci_model).unwrap_or_else(|| match vendor.as_str() {
+            "0x1002" => "AMD GPU".into(),
+            "0x8086" => "Intel GPU".into(),
+            "0x10de" => "NVIDIA GPU".into(),
+            _ => "GPU".into(),
+        });
+        let bytes_to_mb = |p: &Path| read_trim(p).and_then(|v| v.parse::<u64>().ok()).map(|b| b / 1024 / 1024);
+        gpus.push((vendor, Gpu {
+            model,
+            driver,
+            vram_total_mb: bytes_to_mb(&dev.join("mem_info_vram_total")),
+            vram_used_mb: bytes_to_mb(&dev.join("mem_info_vram_used")),
+            utilization_percent: read_trim(&dev.join("gpu_busy_percent")).and_then(|v| v.parse().ok()),
+        }));
+    }
+    gpus
+}
+
+/// "Vendor Device" for a PCI slot, from `lspci -mm` ("slot" "class" "vendor" "device" ...).
+fn lspci_model(slot: &str) -> Option<String> {
+    let text = output("lspci", &["-mm", "-s", slot])?;
+    let fields: Vec<&str> = text.lines().next()?.split('"').filter(|f| !f.trim().is_empty()).collect();
+    Some(format!("{} {}", fields.get(2)?, fields.get(3)?))
+}
+
+fn macos() -> Vec<Gpu> {
+    let Some(text) = output("system_profiler", &["SPDisplaysDataType", "-json"]) else { return Vec::new() };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) else { return Vec::new() };
+    // Apple Silicon reports one shared-memory GPU; the accelerator's load is in ioreg
+    let utilization = output("ioreg", &["-r", "-d", "1", "-w", "0", "-c", "IOAccelerator"])
+        .and_then(|t| {
+            let (_, rest) = t.split_once("\"Device Utilization %\"=")?;
+            rest.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+        });
+    json["SPDisplaysDataType"].as_array().cloned().unwrap_or_default().iter()
+        .map(|d| {
+            let vram = d["spdisplays_vram"].as_str().or(d["spdisplays_vram_shared"].as_str())
+                .and_then(|v| {
+                    let mut parts = v.split_whitespace();
+                    let n: u64 = parts.next()?.parse().ok()?;
+                    Some(if parts.next() == Some("GB") { n * 1024 } else { n })
+                });
+            Gpu {
+                model: d["sppci_model"].as_str().unwrap_or("GPU").to_string(),
+                driver: d["spdisplays_mtlgpufamilysupport"].as_str().map(|m| m.replace("spdisplays_", "")),
+                vram_total_mb: vram,
+                vram_used_mb: None,
+                utilization_percent: utilization,
+            }
+        })
+        .collect()
+}
+
+fn windows() -> Vec<Gpu> {
+    let script = "Get-CimInstance Win32_VideoController | ForEach-Object { \"$($_.Name)|$($_.DriverVersion)|$($_.AdapterRAM)\" }";
+    let Some(text) = output("powershell", &["-NoProfile", "-Command", script]) else { return Vec::new() };
+    text.lines()
+        .filter_map(|l| {
+            let mut cols = l.trim().splitn(3, '|');
+            Some(Gpu {
+                model: cols.next().filter(|m| !m.is_empty())?.to_string(),
+                driver: cols.next().map(str::to_string),
+                vram_total_mb: cols.next().and_then(|v| v.parse::<u64>().ok()).map(|b| b / 1024 / 1024),
+                vram_used_mb: None,
+                utilization_percent: None,
+            })
+        })
+        .collect()
+}
+
+/// Print each GPU as info lines under the current section.
+pub fn print(gpus: &[Gpu]) {
+    if gpus.is_empty() {
+        ui::skip("No GPU detected");
+    }
+    for (i, g) in gpus.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        ui::info_line("Model", &g.model);
+        if let Some(d) = &g.driver {
+            ui::info_line("Driver", d);
+        }
+        match (g.vram_used_mb, g.vram_total_mb) {
+            (Some(used), Some(total)) => ui::info_line("VRAM", &format!("{} / {} MB", used, total)),
+            (None, Some(total)) => ui::info_line("VRAM", &format!("{} MB", total)),
+            _ => {}
+        }
+        if let Some(u) = g.utilization_percent {
+            ui::info_line("Load", &format!("{}%", u));
+        }
+    }
+}
+
+/// `vg gpu`
+pub fn run() -> Result<()> {
+    ui::print_header("GPU");
+    ui::section("Graphics");
+    print(&detect());
+    println!();
+    Ok(())
+}
@@ -1,20 +1,45 @@
 use crate::ui;
+use crate::config::ConfigManager;
+use crate::package_managers::get_available_managers;
 use colored::Colorize;
 use chrono::{Local, Timelike};
+use std::sync::mpsc;
+use std::time::Duration;
+use sysinfo::System;
 
-pub fn run() {
+/// How long greet waits for package managers before giving up on the update count.
+const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+const TIPS: &[&str] = &[
+    "vg search <query> finds files by name in milliseconds once the index is built.",
+    "vg config opens an interactive editor for every setting.",
+    "vg config set greet.show_updates false skips the update check in this greeting.",
+    "vg doctor checks the project in the current directory for missing tools and stale lockfiles.",
+    "vg git wip saves everything as a checkpoint commit; vg git unwip restores it.",
+    "vg git cleanup deletes local branches that are already merged.",
+    "vg update upgrades every package manager on the system in one go.",
+    "vg health shows memory, disk usage and failed services at a glance.",
+    "vg self-update --channel nightly follows prerelease builds.",
+    "vg updates lists pending updates per package manager.",
+    "vg weather <city> shows a 3-day forecast; set weather.location to skip the city.",
+    "vg index --background rebuilds the search index without blocking the terminal.",
+];
+
+pub fn run(config: &ConfigManager) {
+    let cfg = &config.config.greet;
     ui::print_header("WELCOME");
 
     let now = Local::now();
     let hour = now.hour();
     let user = whoami::username();
 
-    let greeting = if hour < 12 {
-        "Good Morning"
-    } else if hour < 18 {
-        "Good Afternoon"
-    } else {
-        "Good Evening"
+    let greeting = match (config.config.system.language.as_str(), hour) {
+        ("de", 0..=11) => "Guten Morgen",
+        ("de", 12..=17) => "Guten Tag",
+        ("de", _) => "Guten Abend",
+        (_, 0..=11) => "Good Morning",
+        (_, 12..=17) => "Good Afternoon",
+        _ => "Good Evening",
     };
 
     println!("  {}, {}!",
@@ -22,7 +47,94 @@ pub fn run() {
         user.truecolor(224, 242, 254).bold()
     );
     println!("  {}", now.format("%A, %B %-d · %H:%M").to_string().truecolor(71, 85, 105));
+
+    if cfg.show_uptime || cfg.show_updates || cfg.show_disks || cfg.show_weather {
+        ui::section("Today");
+    }
+    if cfg.show_uptime {
+        ui::info_line("Uptime", &fmt_uptime(System::uptime()));
+    }
+    if cfg.show_weather {
+        match super::weather::summary_line(config) {
+            Some(line) => ui::info_line("Weather", &line),
+            None if config.config.weather.location.is_empty() => ui::info_line("Weather", "set weather.location to enable"),
+            None => ui::info_line("Weather", "unavailable"),
+        }
+    }
+    if cfg.show_updates && config.config.update_check.enabled {
+        // The background check keeps this current; greet never waits for package managers
+        match super::update_check::load() {
+            Some(cached) => {
+                ui::info_line("Updates", &super::update_check::summary(&cached));
+                if let Some(v) = &cached.vg_latest {
+                    ui::info_line("vg", &format!("{} available — run vg self-update", v));
+                }
+            }
+            None => ui::info_line("Updates", "checking in the background…"),
+        }
+    } else if cfg.show_updates {
+        match pending_updates() {
+            Some(0) => ui::info_line("Updates", "system is up to date"),
+            Some(n) => ui::info_line("Updates", &format!("{} pending — run vg update", n)),
+            None => ui::info_line("Updates", "check timed out"),
+        }
+    }
+    if cfg.show_disks {
+        let full = full_disks(cfg.disk_warn_percent);
+        if full.is_empty() {
+            ui::info_line("Disks", &format!("all below {}%", cfg.disk_warn_percent));
+        }
+        for (mount, pct) in full {
+            ui::fail(&format!("{} is {:.0}% full", mount, pct));
+        }
+    }
+
+    if cfg.show_tip {
+        // Sub-second clock noise is random enough for picking a tip
+        let idx = now.timestamp_subsec_nanos() as usize % TIPS.len();
+        println!();
+        println!("  {} {}", "Tip:".truecolor(71, 85, 105), TIPS[idx].truecolor(100, 116, 139));
+    }
+
     println!();
     ui::divider();
     println!();
 }
+
+pub(crate) fn fmt_uptime(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3600;
+    let mins = (secs % 3600) / 60;
+    match (days, hours) {
+        (0, 0) => format!("{}m", mins),
+        (0, _) => format!("{}h {}m", hours, mins),
+        _ => format!("{}d {}h {}m", days, hours, mins),
+    }
+}
+
+/// Total pending updates across all package managers, or None if they didn't answer in time.
+fn pending_updates() -> Option<usize> {
+    let (tx, rx) = mpsc::channel();
+    // Detached so a hanging package manager can't hold up the shell prompt
+    std::thread::spawn(move || {
+        let managers = get_available_managers();
+        let total: usize = std::thread::scope(|s| {
+            managers.iter()
+                .map(|m| s.spawn(|| m.list_updates().len()))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|h| h.join().unwrap_or(0))
+                .sum()
+        });
+        let _ = tx.send(total);
+    });
+    rx.recv_timeout(UPDATE_CHECK_TIMEOUT).ok()
+}
+
+/// Mount points whose usage is at or above `threshold` percent.
+fn full_disks(threshold: u8) -> Vec<(String, f64)> {
+    super::health::mounts().into_iter()
+        .map(|m| { let pct = m.percent(); (m.mount_point, pct) })
+        .filter(|(_, pct)| *pct >= threshold as f64)
+        .collect()
+}
@@ -0,0 +1,176 @@
+use crate::ui;
+use crate::config::ConfigManager;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use rayon::prelude::*;
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Files above this are skipped — they're logs or data dumps, not source.
+const MAX_FILE_BYTES: u64 = 16 * 1024 * 1024;
+/// Long lines (minified JS, lockfiles) are cut around the first match.
+const MAX_LINE_CHARS: usize = 240;
+
+pub struct GrepParams {
+    pub pattern: String,
+    pub path: Option<PathBuf>,
+    pub ignore_case: bool,
+    pub fixed: bool,
+    pub word: bool,
+    pub hidden: bool,
+    pub files_only: bool,
+    pub max_count: Option<usize>,
+    pub json: bool,
+}
+
+#[derive(Serialize)]
+struct Match {
+    path: String,
+    line: usize,
+    column: usize,
+    text: String,
+}
+
+fn build_regex(p: &GrepParams) -> Result<Regex> {
+    let mut pattern = if p.fixed { regex::escape(&p.pattern) } else { p.pattern.clone() };
+    if p.word {
+        pattern = format!(r"\b(?:{})\b", pattern);
+    }
+    RegexBuilder::new(&pattern)
+        .case_insensitive(p.ignore_case)
+        .build()
+        .with_context(|| format!("Invalid pattern '{}'", p.pattern))
+}
+
+/// Files under `root`, honouring .gitignore and the search ignore settings from config.
+pub(crate) fn files(root: &Path, hidden: bool, config: &ConfigManager) -> Vec<PathBuf> {
+    let ignore_patterns = &config.config.search.ignore_patterns;
+    let options = crate::walk::Options {
+        ignore_files: true,
+        skip_hidden: !hidden && config.config.search.exclude_hidden,
+        ..Default::default()
+    };
+    let mut files = crate::walk::walk(root, &options, |e| {
+        if !e.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            return None;
+        }
+        let s = e.path().to_string_lossy();
+        if ignore_patterns.iter().any(|p| s.contains(p.as_str())) {
+            return None;
+        }
+        e.metadata().is_ok_and(|m| m.len() <= MAX_FILE_BYTES).then(|| e.path().to_path_buf())
+    }).items;
+    // Matches are printed in walk order
+    files.sort();
+    files
+}
+
+fn search_file(path: &Path, re: &Regex, max_count: Option<usize>) -> Vec<Match> {
+    let Ok(bytes) = std::fs::read(path) else { return Vec::new() };
+    // Same heuristic as git and ripgrep: a NUL near the start means binary
+    if bytes.iter().take(8192).any(|&b| b == 0) {
+        return Vec::new();
+    }
+    let content = String::from_utf8_lossy(&bytes);
+    let display = path.strip_prefix("./").unwrap_or(path).to_string_lossy().to_string();
+    let mut out = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        if let Some(m) = re.find(line) {
+            out.push(Match {
+                path: display.clone(),
+                line: i + 1,
+                column: line[..m.start()].chars().count() + 1,
+                text: line.to_string(),
+            });
+            if max_count.is_some_and(|n| out.len() >= n) {
+                break;
+            }
+        }
+    }
+    out
+}
+
+/// Highlight every match in `line`, trimming very long lines to a window around the first one.
+fn highlight(line: &str, re: &Regex) -> String {
+    let mut line = line.trim_end();
+    let mut prefix = "";
+    let mut suffix = "";
+    if line.chars().count() > MAX_LINE_CHARS {
+        let first = re.find(line).map(|m| m.start()).unwrap_or(0);
+        let start = line.char_indices().map(|(i, _)| i).take_while(|&i| i <= first).last().unwrap_or(0);
+        let start = line[..start].char_indices().rev().nth(MAX_LINE_CHARS / 4).map(|(i, _)| i).unwrap_or(0);
+        let end = line[start..].char_indices().nth(MAX_LINE_CHARS).map(|(i, _)| start + i).unwrap_or(line.len());
+        if start > 0 { prefix = "…"; }
+        if end < line.len() { suffix = "…"; }
+        line = &line[start..end];
+    }
+    let mut out = String::from(prefix);
+    let mut last = 0;
+    for m in re.find_iter(line) {
+        out.push_str(&line[last..m.start()].truecolor(224, 242, 254).to_string());
+        out.push_str(&m.as_str().truecolor(251, 191, 36).bold().to_string());
+        last = m.end();
+    }
+    out.push_str(&line[last..].truecolor(224, 242, 254).to_string());
+    out.push_str(suffix);
+    out
+}
+
+pub fn run(params: GrepParams, config: &ConfigManager) -> Result<()> {
+    let re = build_regex(&params)?;
+    let root = params.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let start = std::time::Instant::now();
+
+    let mut results: Vec<(PathBuf, Vec<Match>)> = if root.is_file() {
+        vec![(root.clone(), search_file(&root, &re, params.max_count))]
+    } else {
+        files(&root, params.hidden, config)
+            .into_par_iter()
+            .map(|p| {
+                let m = search_file(&p, &re, params.max_count);
+                (p, m)
+            })
+            .collect()
+    };
+    results.retain(|(_, m)| !m.is_empty());
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    if results.is_empty() {
+        crate::exit::set(crate::exit::NEGATIVE);
+    }
+
+    if params.json {
+        let all: Vec<&Match> = results.iter().flat_map(|(_, m)| m).collect();
+        println!("{}", serde_json::to_string_pretty(&all)?);
+        return Ok(());
+    }
+
+    if params.files_only {
+        for (_, matches) in &results {
+            println!("{}", matches[0].path.truecolor(96, 165, 250));
+        }
+        return Ok(());
+    }
+
+    if results.is_empty() {
+        ui::skip(&format!("No matches for '{}' in {}", params.pattern, root.display()));
+        return Ok(());
+    }
+
+    let total: usize = results.iter().map(|(_, m)| m.len()).sum();
+    for (_, matches) in &results {
+        println!();
+        println!("{}", matches[0].path.truecolor(96, 165, 250).bold());
+        for m in matches {
+            println!("{:>6}{} {}", m.line.to_string().truecolor(100, 116, 139), ":".truecolor(71, 85, 105), highlight(&m.text, &re));
+        }
+    }
+    println!();
+    ui::skip(&format!(
+        "{} matches in {} files · {:.0} ms",
+        total,
+        results.len(),
+        start.elapsed().as_secs_f64() * 1000.0,
+    ));
+    Ok(())
+}
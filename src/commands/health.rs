@@ -1,10 +1,124 @@
 use crate::ui;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use sysinfo::System;
 use std::process::Command;
 use which::which;
 
-pub fn run() -> Result<()> {
+/// Disk usage (percent) that makes the exit code a warning, and critical.
+pub(crate) const DISK_WARNING: f64 = 90.0;
+const DISK_CRITICAL: f64 = 98.0;
+
+/// A machine's state in numbers: `vg health --json`, and what `vg fleet status` compares.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub hostname: String,
+    pub version: String,
+    /// Seconds since boot
+    pub uptime: u64,
+    /// One-minute load average
+    pub load: f64,
+    pub memory_percent: f64,
+    /// Mount point and percent used, fullest first
+    pub disks: Vec<(String, f64)>,
+    pub failed_units: Vec<String>,
+    /// Pending package updates from the last background check; None before the first one
+    pub updates: Option<usize>,
+    pub reboot_required: Option<String>,
+    /// ok, warning or critical — the same verdict as the exit code
+    pub status: String,
+}
+
+/// Filesystems that are full by design or live in memory; their usage says nothing about disk space.
+const SKIPPED_FILESYSTEMS: &[&str] = &["squashfs", "overlay", "tmpfs", "devtmpfs", "iso9660"];
+
+/// A mounted filesystem, as far as disk-space checks care.
+pub(crate) struct Mount {
+    pub mount_point: String,
+    file_system: String,
+    read_only: bool,
+    pub total: u64,
+    pub available: u64,
+}
+
+impl From<&sysinfo::Disk> for Mount {
+    fn from(d: &sysinfo::Disk) -> Self {
+        Mount {
+            mount_point: d.mount_point().to_string_lossy().into_owned(),
+            file_system: d.file_system().to_string_lossy().into_owned(),
+            read_only: d.is_read_only(),
+            total: d.total_space(),
+            available: d.available_space(),
+        }
+    }
+}
+
+impl Mount {
+    /// Read-only mounts (snaps, loop-mounted images) always look 100% full, so they're left out.
+    pub fn counted(&self) -> bool {
+        self.total > 0 && !self.read_only && !SKIPPED_FILESYSTEMS.contains(&self.file_system.as_str())
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.total.saturating_sub(self.available) as f64 / self.total as f64 * 100.0
+    }
+}
+
+/// Mounts whose usage counts towards DISK_WARNING and DISK_CRITICAL.
+pub(crate) fn mounts() -> Vec<Mount> {
+    sysinfo::Disks::new_with_refreshed_list().iter().map(Mount::from).filter(Mount::counted).collect()
+}
+
+/// The verdict for a machine, from everything that isn't a plain reading.
+fn assess(mounts: &[Mount], failed_units: Vec<String>, updates: Option<usize>, reboot_required: Option<String>) -> Snapshot {
+    let mut disks: Vec<(String, f64)> = mounts.iter()
+        .filter(|m| m.counted())
+        .map(|m| (m.mount_point.clone(), m.percent()))
+        .collect();
+    disks.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let critical = !failed_units.is_empty() || disks.iter().any(|(_, pct)| *pct > DISK_CRITICAL);
+    let warning = updates.unwrap_or(0) > 0 || reboot_required.is_some() || disks.iter().any(|(_, pct)| *pct > DISK_WARNING);
+    let status = if critical { "critical" } else if warning { "warning" } else { "ok" };
+    Snapshot {
+        hostname: String::new(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        uptime: 0,
+        load: 0.0,
+        memory_percent: 0.0,
+        disks,
+        failed_units,
+        updates,
+        reboot_required,
+        status: status.to_string(),
+    }
+}
+
+/// Collect a snapshot and set the exit code from it. Cheap: no package manager is queried.
+pub fn snapshot() -> Snapshot {
+    let mut sys = System::new();
+    sys.refresh_memory();
+    let failed_units = if cfg!(target_os = "linux") && which("systemctl").is_ok() { super::service::failed_units() } else { Vec::new() };
+    let updates = super::update_check::load().map(|c| c.total());
+    let snapshot = assess(&mounts(), failed_units, updates, super::update::reboot_required());
+    match snapshot.status.as_str() {
+        "critical" => crate::exit::set(crate::exit::CRITICAL),
+        "warning" => crate::exit::set(crate::exit::NEGATIVE),
+        _ => {}
+    }
+    Snapshot {
+        hostname: System::host_name().unwrap_or_default(),
+        uptime: System::uptime(),
+        load: System::load_average().one,
+        memory_percent: sys.used_memory() as f64 / sys.total_memory().max(1) as f64 * 100.0,
+        ..snapshot
+    }
+}
+
+pub fn run(json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(&snapshot())?);
+        return Ok(());
+    }
     ui::print_header("SYSTEM HEALTH");
 
     let mut sys = System::new_all();
@@ -35,14 +149,20 @@ pub fn run() -> Result<()> {
 
     // Storage
     ui::section("Storage");
-    let disks = sysinfo::Disks::new_with_refreshed_list();
-    for disk in &disks {
-        let total = disk.total_space();
-        let avail = disk.available_space();
-        let used = total - avail;
-        let pct = (used as f64 / total as f64) * 100.0;
+    for disk in mounts() {
+        let total = disk.total;
+        let used = total.saturating_sub(disk.available);
+        let pct = disk.percent();
+        if pct > DISK_CRITICAL {
+            crate::exit::set(crate::exit::CRITICAL);
+        } else if pct > DISK_WARNING {
+            crate::exit::set(crate::exit::NEGATIVE);
+        }
+        if pct > DISK_WARNING {
+            crate::notify::note(format!("{} is {:.0}% full", disk.mount_point, pct));
+        }
         ui::info_line(
-            &disk.mount_point().to_string_lossy(),
+            &disk.mount_point,
             &format!("{} / {}  {:.1}%", fmt_bytes(used), fmt_bytes(total), pct)
         );
     }
@@ -50,41 +170,78 @@ pub fn run() -> Result<()> {
     // Integrity
     ui::section("Integrity");
 
-    if cfg!(target_os = "linux") {
-        if let Ok(output) = Command::new("systemctl").args(["--failed", "--no-legend"]).output() {
-            let out = String::from_utf8_lossy(&output.stdout);
-            let count = out.lines().filter(|l| !l.trim().is_empty()).count();
-            if count == 0 {
-                ui::success("No failed systemd units");
-            } else {
-                ui::fail(&format!("{} failed systemd unit(s)", count));
-            }
+    if cfg!(target_os = "linux") && which("systemctl").is_ok() {
+        let failed = super::service::failed_units();
+        if failed.is_empty() {
+            ui::success("No failed systemd units");
+        } else {
+            ui::fail(&format!("{} failed systemd unit(s): {}", failed.len(), failed.join(", ")));
+            crate::exit::set(crate::exit::CRITICAL);
+            crate::notify::note(format!("{} failed systemd unit(s): {}", failed.len(), failed.join(", ")));
+            ui::skip("Inspect with: vg service list --failed");
         }
     }
 
     // Pending updates
     if which("checkupdates").is_ok() {
-        if let Ok(output) = Command::new("checkupdates").output() {
+        if let Ok(output) = crate::logging::output(&mut Command::new("checkupdates")) {
             let count = String::from_utf8_lossy(&output.stdout).lines().count();
             if count == 0 {
                 ui::success("System is up to date");
             } else {
                 ui::info_line("Updates", &format!("{} pending", count));
+                crate::exit::set(crate::exit::NEGATIVE);
+                crate::notify::note(format!("{} updates pending", count));
             }
         }
     } else if which("apt").is_ok() {
-        if let Ok(output) = Command::new("apt").args(["list", "--upgradable"]).output() {
+        if let Ok(output) = crate::logging::output(Command::new("apt").args(["list", "--upgradable"])) {
             let out = String::from_utf8_lossy(&output.stdout);
             let count = out.lines().filter(|l| !l.starts_with("Listing")).count();
             ui::info_line("Updates", &format!("{} pending", count));
+            if count > 0 {
+                crate::exit::set(crate::exit::NEGATIVE);
+                crate::notify::note(format!("{} updates pending", count));
+            }
+        }
+    } else if cfg!(windows) {
+        let managers: Vec<_> = crate::package_managers::get_available_managers().into_iter()
+            .filter(|m| matches!(m.id(), "winget" | "choco"))
+            .collect();
+        if !managers.is_empty() {
+            let count: usize = managers.iter().map(|m| m.list_updates().len()).sum();
+            if count == 0 {
+                ui::success("System is up to date");
+            } else {
+                let names: Vec<&str> = managers.iter().map(|m| m.id()).collect();
+                ui::info_line("Updates", &format!("{} pending ({})", count, names.join(", ")));
+                crate::exit::set(crate::exit::NEGATIVE);
+                crate::notify::note(format!("{} updates pending", count));
+            }
+        }
+    }
+
+    if let Some(reason) = super::update::reboot_required() {
+        ui::fail(&format!("Reboot required — {}", reason));
+        crate::exit::set(crate::exit::NEGATIVE);
+        crate::notify::note(format!("Reboot required — {}", reason));
+    }
+    if crate::tools::find("fwupdmgr").and_then(|t| t.installed()).is_some() {
+        match super::firmware::pending() {
+            Ok(devices) if devices.is_empty() => ui::success("Firmware is up to date"),
+            Ok(devices) => {
+                ui::info_line("Firmware", &format!("{} update{} pending", devices.len(), if devices.len() == 1 { "" } else { "s" }));
+                ui::skip("Install with: vg update --firmware");
+                crate::exit::set(crate::exit::NEGATIVE);
+                crate::notify::note(format!("{} firmware update(s) pending", devices.len()));
+            }
+            Err(e) => ui::skip(&format!("Firmware: {:#}", e)),
         }
     }
 
     // Volantic service
     if cfg!(target_os = "linux") {
-        let status = Command::new("systemctl")
-            .args(["--user", "is-active", "genesis-greet.service"])
-            .output();
+        let status = crate::logging::output(Command::new("systemctl").args(["--user", "is-active", "genesis-greet.service"]));
         match status {
             Ok(o) => {
                 let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
@@ -117,3 +274,32 @@ fn fmt_bytes(bytes: u64) -> String {
     let pre = "KMGTPE".chars().nth((exp - 1) as usize).unwrap_or('?');
     format!("{:.1} {}B", (bytes as f64) / div.powi(exp), pre)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mount(mount_point: &str, file_system: &str, read_only: bool, percent_used: u64) -> Mount {
+        Mount { mount_point: mount_point.to_string(), file_system: file_system.to_string(), read_only, total: 100, available: 100 - percent_used }
+    }
+
+    #[test]
+    fn full_snap_and_memory_mounts_are_ignored() {
+        let mounts = [
+            mount("/", "ext4", false, 40),
+            mount("/snap/core22/1380", "squashfs", true, 100),
+            mount("/media/cdrom", "iso9660", false, 100),
+            mount("/run", "tmpfs", false, 100),
+            mount("/mnt/image", "ext4", true, 100),
+        ];
+        let snapshot = assess(&mounts, Vec::new(), Some(0), None);
+        assert_eq!(snapshot.disks, vec![("/".to_string(), 40.0)]);
+        assert_eq!(snapshot.status, "ok");
+    }
+
+    #[test]
+    fn full_writable_disk_is_critical() {
+        let snapshot = assess(&[mount("/home", "btrfs", false, 99)], Vec::new(), Some(0), None);
+        assert_eq!(snapshot.status, "critical");
+    }
+}
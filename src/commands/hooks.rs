@@ -0,0 +1,257 @@
+use super::doctor::{detect_languages, Language};
+use crate::config::HooksConfig;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// First line after the shebang; marks a hook as ours so it can be replaced and removed safely.
+const MARKER: &str = "# Installed by vg hooks install";
+/// An existing hook that isn't ours is moved here instead of being overwritten.
+const BACKUP: &str = "pre-commit.vg-backup";
+
+/// Forbidden-file globs go into a shell `case`, so only glob-safe characters are allowed.
+pub fn valid_pattern(p: &str) -> bool {
+    !p.is_empty() && p.chars().all(|c| c.is_ascii_alphanumeric() || "._-*?/[]".contains(c))
+}
+
+fn git(root: &Path, args: &[&str]) -> Result<String> {
+    let out = Command::new("git").args(args).current_dir(root).output().context("Failed to run git")?;
+    if !out.status.success() {
+        return Err(anyhow!("{}", String::from_utf8_lossy(&out.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn repo_root() -> Result<PathBuf> {
+    let cwd = std::env::current_dir()?;
+    git(&cwd, &["rev-parse", "--show-toplevel"])
+        .map(PathBuf::from)
+        .map_err(|_| anyhow!("Not inside a git repository — run git init first"))
+}
+
+/// Honours core.hooksPath and worktrees.
+fn hook_path(root: &Path) -> Result<PathBuf> {
+    let dir = PathBuf::from(git(root, &["rev-parse", "--git-path", "hooks"])?);
+    Ok(if dir.is_absolute() { dir } else { root.join(dir) }.join("pre-commit"))
+}
+
+fn is_ours(path: &Path) -> bool {
+    std::fs::read_to_string(path).map(|s| s.contains(MARKER)).unwrap_or(false)
+}
+
+/// Whether the repository at `root` has our pre-commit hook.
+pub fn installed(root: &Path) -> bool {
+    hook_path(root).map(|p| is_ours(&p)).unwrap_or(false)
+}
+
+/// The checks for each detected language, from `hooks.commands` or the built-in defaults.
+fn checks(langs: &[Language], config: &HooksConfig) -> Vec<(String, Vec<String>)> {
+    let mut out: Vec<(String, Vec<String>)> = langs.iter()
+        .map(|l| {
+            let cmds = config.commands.get(l.key()).cloned()
+                .unwrap_or_else(|| l.hook_commands().iter().map(|c| c.to_string()).collect());
+            (l.name().to_string(), cmds)
+        })
+        .collect();
+    if !config.extra.is_empty() {
+        out.push(("Extra".to_string(), config.extra.clone()));
+    }
+    out.retain(|(_, cmds)| !cmds.is_empty());
+    out
+}
+
+fn render(langs: &[Language], config: &HooksConfig) -> String {
+    let names: Vec<&str> = langs.iter().map(|l| l.name()).collect();
+    let mut s = format!(
+        "#!/bin/sh\n{}\n# Languages: {}\n# Regenerate with: vg hooks install --force   Skip once with: git commit --no-verify\nset -e\ncd \"$(git rev-parse --show-toplevel)\"\n",
+        MARKER,
+        if names.is_empty() { "none detected".to_string() } else { names.join(", ") },
+    );
+
+    let (full, base): (Vec<&String>, Vec<&String>) = config.forbidden.iter().partition(|p| p.contains('/'));
+    if !config.forbidden.is_empty() {
+        s.push_str("\n# Forbidden files (hooks.forbidden)\ngit diff --cached --name-only --diff-filter=ACR | while IFS= read -r f; do\n");
+        for (subject, patterns) in [("$f", &full), ("${f##*/}", &base)] {
+            if patterns.is_empty() {
+                continue;
+            }
+            let joined: Vec<&str> = patterns.iter().map(|p| p.as_str()).collect();
+            s.push_str(&format!(
+                "  case \"{}\" in\n    {}) echo \"vg: refusing to commit $f (matches hooks.forbidden)\" >&2; exit 1 ;;\n  esac\n",
+                subject,
+                joined.join("|"),
+            ));
+        }
+        s.push_str("done\n");
+    }
+
+    for (name, cmds) in checks(langs, config) {
+        s.push_str(&format!("\n# {}\n", name));
+        for cmd in cmds {
+            // A missing tool shouldn't block every commit; the check is skipped with a note
+            let tool = cmd.split_whitespace().next().unwrap_or_default();
+            s.push_str(&format!(
+                "if command -v {tool} >/dev/null 2>&1; then\n  echo \"vg: {escaped}\"\n  {cmd}\nelse\n  echo \"vg: {tool} not found, skipping\" >&2\nfi\n",
+                tool = tool,
+                escaped = cmd.replace('\\', "\\\\").replace('"', "\\\"").replace('$', "\\$").replace('`', "\\`"),
+                cmd = cmd,
+            ));
+        }
+    }
+    s
+}
+
+/// `vg hooks install` — write a pre-commit hook for the current repository.
+pub fn install(config: &HooksConfig, force: bool) -> Result<()> {
+    ui::print_header("HOOKS");
+    if let Some(p) = config.forbidden.iter().find(|p| !valid_pattern(p)) {
+        return Err(anyhow!("hooks.forbidden pattern '{}' may only contain letters, digits and . _ - * ? / [ ]", p));
+    }
+    let root = repo_root()?;
+    let path = hook_path(&root)?;
+    let langs = detect_languages(&root);
+
+    if path.exists() && !is_ours(&path) {
+        if !force {
+            return Err(anyhow!(
+                "{} already exists and wasn't written by vg — pass --force to replace it (the old hook is kept as {})",
+                path.display(), BACKUP,
+            ));
+        }
+        std::fs::rename(&path, path.with_file_name(BACKUP))
+            .with_context(|| format!("Failed to back up {}", path.display()))?;
+        ui::skip(&format!("Existing hook moved to {}", BACKUP));
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, render(&langs, config)).with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    ui::success(&format!("Installed {}", path.display()));
+    print_checks(&langs, config);
+    Ok(())
+}
+
+/// `vg hooks uninstall` — remove our hook and restore whatever it replaced.
+pub fn uninstall() -> Result<()> {
+    ui::print_header("HOOKS");
+    let root = repo_root()?;
+    let path = hook_path(&root)?;
+    if !is_ours(&path) {
+        ui::skip("No vg pre-commit hook installed");
+        return Ok(());
+    }
+    std::fs::remove_file(&path)?;
+    ui::success(&format!("Removed {}", path.display()));
+    let backup = path.with_file_name(BACKUP);
+    if backup.exists() {
+        std::fs::rename(&backup, &path)?;
+        ui::success("Restored the previous pre-commit hook");
+    }
+    Ok(())
+}
+
+/// `vg hooks` — what is installed and what it would run.
+pub fn status(config: &HooksConfig) -> Result<()> {
+    ui::print_header("HOOKS");
+    let root = repo_root()?;
+    let path = hook_path(&root)?;
+    let langs = detect_languages(&root);
+    ui::info_line("Repository", &root.display().to_string());
+    if is_ours(&path) {
+        ui::success("vg pre-commit hook installed");
+    } else if path.exists() {
+        ui::skip("A pre-commit hook exists but wasn't written by vg");
+    } else {
+        ui::skip("No pre-commit hook — vg hooks install");
+    }
+    print_checks(&langs, config);
+    if !config.scripts.is_empty() {
+        ui::section("Command scripts");
+        for (key, script) in &config.scripts {
+            println!("  {:<20} {}", key.truecolor(96, 165, 250), script.truecolor(224, 242, 254));
+        }
+        println!();
+    }
+    Ok(())
+}
+
+fn print_checks(langs: &[Language], config: &HooksConfig) {
+    ui::section("Checks");
+    let checks = checks(langs, config);
+    if checks.is_empty() {
+        ui::skip("No language detected — only the forbidden-file guard runs");
+    }
+    for (name, cmds) in checks {
+        for cmd in cmds {
+            println!("  {:<10} {}", name.truecolor(96, 165, 250), cmd.truecolor(224, 242, 254));
+        }
+    }
+    if !config.forbidden.is_empty() {
+        println!("  {:<10} {}", "Guard".truecolor(96, 165, 250), config.forbidden.join(", ").truecolor(224, 242, 254));
+    }
+    println!();
+    ui::skip("Customise with hooks.commands.<language>, hooks.extra and hooks.forbidden in vg config");
+}
+
+/// Paths changed by journalled operations (replace, rename-batch, tidy) since `since`.
+fn touched_paths(since: DateTime<Utc>) -> Vec<String> {
+    crate::history::load().unwrap_or_default().into_iter()
+        .filter(|op| op.timestamp >= since)
+        .flat_map(|op| op.changes)
+        .map(|c| match c {
+            crate::history::Change::Edit { path, .. } => path.display().to_string(),
+            crate::history::Change::Rename { to, .. } => to.display().to_string(),
+            crate::history::Change::RemoveFile { path, .. }
+            | crate::history::Change::RemoveDir { path }
+            | crate::history::Change::RemoveLink { path, .. } => path.display().to_string(),
+        })
+        .collect()
+}
+
+/// Run the user's `[hooks.scripts] pre_<command>` / `post_<command>` script, if there is one.
+/// Context goes in VG_* environment variables. A failing pre script stops the command;
+/// a failing post script only warns, since the command already ran.
+pub fn run_script(config: &HooksConfig, stage: &str, command: &str, result: Option<&Result<()>>, since: DateTime<Utc>) -> Result<()> {
+    let key = format!("{}_{}", stage, command.replace('-', "_"));
+    let Some(script) = config.scripts.get(&key) else { return Ok(()) };
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut cmd = if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.args(["/C", script]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", script]);
+        c
+    };
+    cmd.env("VG_HOOK", stage)
+        .env("VG_COMMAND", command)
+        .env("VG_ARGS", shlex::try_join(args.iter().map(String::as_str)).unwrap_or_else(|_| args.join(" ")));
+    if let Some(result) = result {
+        cmd.env("VG_EXIT_STATUS", if result.is_ok() { "0" } else { "1" })
+            .env("VG_ERROR", result.as_ref().err().map(|e| format!("{:#}", e)).unwrap_or_default())
+            .env("VG_PATHS", touched_paths(since).join("\n"));
+    }
+
+    let status = crate::logging::status(&mut cmd).with_context(|| format!("Failed to run hooks.{}", key))?;
+    if status.success() {
+        return Ok(());
+    }
+    let code = status.code().map(|c| c.to_string()).unwrap_or_else(|| "a signal".into());
+    if result.is_none() {
+        return Err(anyhow!("hooks.{} exited with {} — vg {} was not run", key, code, command));
+    }
+    ui::fail(&format!("hooks.{} exited with {}", key, code));
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use crate::ui;
+use crate::config::ConfigManager;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::net::ToSocketAddrs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Methods recognised as such when `vg http` gets a single argument, so `vg http GET` isn't a GET to "GET".
+pub const METHODS: &[&str] = &["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD"];
+
+/// Everything needed to replay a request later with `vg http @name`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Body is JSON (sets Content-Type unless a header overrides it)
+    #[serde(default)]
+    pub json: bool,
+}
+
+fn store_path() -> PathBuf {
+    ConfigManager::data_dir().join("http_requests.json")
+}
+
+fn load() -> Result<BTreeMap<String, Request>> {
+    let path = store_path();
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn save(requests: &BTreeMap<String, Request>) -> Result<()> {
+    let path = store_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // Saved requests may carry auth headers
+    crate::secrets::write_private(&path, serde_json::to_string_pretty(requests)?.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Look up a saved request; flags given on the command line are layered on top.
+pub fn saved(name: &str) -> Result<Request> {
+    load()?.remove(name).ok_or_else(|| anyhow!("No saved request '{}'. See: vg http", name))
+}
+
+/// `vg http` with no arguments — list saved requests.
+pub fn list() -> Result<()> {
+    ui::print_header("HTTP");
+    let requests = load()?;
+    if requests.is_empty() {
+        ui::skip("No saved requests. Save one with: vg http GET <url> --save <name>");
+        return Ok(());
+    }
+    for (name, req) in &requests {
+        println!(
+            "  {:<16} {:<7} {}",
+            format!("@{}", name).truecolor(96, 165, 250).bold(),
+            req.method.truecolor(251, 191, 36),
+            req.url.truecolor(224, 242, 254),
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Accepts `name:value` and `name: value`.
+fn parse_header(raw: &str) -> Result<(String, String)> {
+    let (name, value) = raw.split_once(':')
+        .ok_or_else(|| anyhow!("Header '{}' must look like name:value", raw))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+pub fn run(req: Request, save_as: Option<String>, include_headers: bool, timeout_secs: u64) -> Result<()> {
+    let method = reqwest::Method::from_bytes(req.method.to_uppercase().as_bytes())
+        .map_err(|_| anyhow!("Invalid HTTP method '{}'", req.method))?;
+    let url = if req.url.contains("://") {
+        req.url.clone()
+    } else if req.url.starts_with("localhost") || req.url.starts_with("127.") || req.url.starts_with("[::1]") {
+        // Local dev servers rarely speak TLS
+        format!("http://{}", req.url)
+    } else {
+        format!("https://{}", req.url)
+    };
+    let parsed = reqwest::Url::parse(&url).with_context(|| format!("Invalid URL '{}'", url))?;
+
+    // reqwest doesn't expose per-phase timings, so resolve once up front to time DNS on its own
+    let dns_start = Instant::now();
+    if let Some(host) = parsed.host_str() {
+        let port = parsed.port_or_known_default().unwrap_or(80);
+        let _ = (host, port).to_socket_addrs();
+    }
+    let dns = dns_start.elapsed();
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(timeout_secs))
+        .user_agent(concat!("vg/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+    let mut builder = client.request(method.clone(), parsed);
+    let mut has_content_type = false;
+    for raw in &req.headers {
+        let (name, value) = parse_header(raw)?;
+        has_content_type |= name.eq_ignore_ascii_case("content-type");
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = &req.body {
+        if req.json {
+            serde_json::from_str::<serde_json::Value>(body).context("--json body is not valid JSON")?;
+            if !has_content_type {
+                builder = builder.header("Content-Type", "application/json");
+            }
+        }
+        builder = builder.body(body.clone());
+    }
+
+    let start = Instant::now();
+    let resp = builder.send().with_context(|| format!("{} {} failed", method, url))?;
+    // Only a request that could be sent is worth replaying
+    if let Some(name) = &save_as {
+        let mut requests = load()?;
+        requests.insert(name.clone(), req.clone());
+        save(&requests)?;
+    }
+    let to_headers = start.elapsed();
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    let bytes = resp.bytes().context("Failed to read response body")?;
+    let download = start.elapsed() - to_headers;
+
+    let content_type = headers.get("content-type").and_then(|v| v.to_str().ok()).unwrap_or("").to_string();
+    let text = String::from_utf8_lossy(&bytes);
+
+    // Piped: just the body, so `vg http ... | jq` works
+    if !std::io::stdout().is_terminal() {
+        print!("{}", text);
+        return if status.is_success() { Ok(()) } else { Err(anyhow!("HTTP {}", status)) };
+    }
+
+    let status_color = match status.as_u16() {
+        200..=299 => status.to_string().truecolor(74, 222, 128),
+        300..=399 => status.to_string().truecolor(251, 191, 36),
+        _ => status.to_string().truecolor(248, 113, 113),
+    };
+    println!();
+    println!("  {} {}  {}", method.as_str().truecolor(96, 165, 250).bold(), url.truecolor(224, 242, 254), status_color.bold());
+
+    if include_headers {
+        println!();
+        for (name, value) in &headers {
+            println!("  {} {}", format!("{}:", name).truecolor(96, 165, 250), value.to_str().unwrap_or("<binary>").truecolor(100, 116, 139));
+        }
+    }
+
+    println!();
+    if content_type.contains("json") {
+        match serde_json::from_slice::<serde_json::Value>(&bytes) {
+            Ok(value) => println!("{}", highlight_json(&serde_json::to_string_pretty(&value)?)),
+            Err(_) => println!("{}", text),
+        }
+    } else if bytes.is_empty() {
+        ui::skip("(empty body)");
+    } else if std::str::from_utf8(&bytes).is_err() {
+        ui::skip(&format!("(binary body, {} bytes — pipe to a file to save it)", bytes.len()));
+    } else {
+        println!("{}", text);
+    }
+
+    println!();
+    let ms = |d: Duration| format!("{:.0} ms", d.as_secs_f64() * 1000.0);
+    println!(
+        "  {} DNS {}  ·  wait {}  ·  download {}  ·  total {}  ·  {} bytes",
+        "⏱".truecolor(71, 85, 105),
+        ms(dns).truecolor(224, 242, 254),
+        ms(to_headers).truecolor(224, 242, 254),
+        ms(download).truecolor(224, 242, 254),
+        ms(dns + to_headers + download).truecolor(96, 165, 250).bold(),
+        bytes.len(),
+    );
+    if let Some(name) = save_as {
+        ui::success(&format!("Saved as @{}", name));
+    }
+    println!();
+    if status.is_success() { Ok(()) } else { Err(anyhow!("HTTP {}", status)) }
+}
+
+/// Colour pretty-printed JSON: keys blue, strings green, numbers yellow, literals red.
+fn highlight_json(pretty: &str) -> String {
+    let chars: Vec<char> = pretty.chars().collect();
+    let mut out = String::with_capacity(pretty.len() * 2);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            let token: String = chars[start..i.min(chars.len())].iter().collect();
+            let is_key = chars[i.min(chars.len())..].iter().find(|c| !c.is_whitespace()) == Some(&':');
+            if is_key {
+                out.push_str(&token.truecolor(96, 165, 250).to_string());
+            } else {
+                out.push_str(&token.truecolor(74, 222, 128).to_string());
+            }
+        } else if c == '-' || c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || "+-.eE".contains(chars[i])) {
+                i += 1;
+            }
+            let token: String = chars[start..i].iter().collect();
+            out.push_str(&token.truecolor(251, 191, 36).to_string());
+        } else if c.is_ascii_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_alphabetic() {
+                i += 1;
+            }
+            let token: String = chars[start..i].iter().collect();
+            out.push_str(&token.truecolor(248, 113, 113).to_string());
+        } else {
+            if "{}[],:".contains(c) {
+                out.push_str(&c.to_string().truecolor(71, 85, 105).to_string());
+            } else {
+                out.push(c);
+            }
+            i += 1;
+        }
+    }
+    out
+}
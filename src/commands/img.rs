@@ -0,0 +1,325 @@
+use crate::history::Recorder;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use comfy_table::{Attribute, Cell, Color, Table};
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use which::which;
+
+/// Rows shown in the result table; the total always covers every file.
+const MAX_ROWS: usize = 20;
+/// Quality for lossy JPEG/WebP re-encodes.
+const LOSSY_QUALITY: &str = "82";
+
+pub struct OptimizeParams {
+    pub path: PathBuf,
+    /// Allow quality loss (pngquant, lower JPEG/WebP quality)
+    pub lossy: bool,
+    /// Shrink images whose longer side exceeds this many pixels
+    pub max_size: Option<u32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Kind {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl Kind {
+    fn from_path(path: &Path) -> Option<Kind> {
+        match path.extension()?.to_string_lossy().to_lowercase().as_str() {
+            "png" => Some(Kind::Png),
+            "jpg" | "jpeg" => Some(Kind::Jpeg),
+            "webp" => Some(Kind::Webp),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Png => "PNG",
+            Kind::Jpeg => "JPEG",
+            Kind::Webp => "WebP",
+        }
+    }
+
+    /// What to install when nothing can handle this format.
+    fn suggestion(self) -> &'static str {
+        match self {
+            Kind::Png => "oxipng",
+            Kind::Jpeg => "jpegoptim",
+            Kind::Webp => "libwebp",
+        }
+    }
+}
+
+/// Installed tools, looked up once.
+struct Tools {
+    oxipng: bool,
+    optipng: bool,
+    pngquant: bool,
+    jpegoptim: bool,
+    cwebp: bool,
+    /// ImageMagick 7 (`magick`) or 6 (`convert`)
+    magick: Option<&'static str>,
+}
+
+impl Tools {
+    fn detect() -> Self {
+        let has = |t: &str| which(t).is_ok();
+        Self {
+            oxipng: has("oxipng"),
+            optipng: has("optipng"),
+            pngquant: has("pngquant"),
+            jpegoptim: has("jpegoptim"),
+            cwebp: has("cwebp"),
+            magick: ["magick", "convert"].into_iter().find(|t| has(t)),
+        }
+    }
+
+    fn can(&self, kind: Kind) -> bool {
+        self.magick.is_some() || match kind {
+            Kind::Png => self.oxipng || self.optipng || self.pngquant,
+            Kind::Jpeg => self.jpegoptim,
+            Kind::Webp => self.cwebp,
+        }
+    }
+}
+
+fn run(mut cmd: Command) -> Result<std::process::ExitStatus> {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    let out = crate::logging::output(&mut cmd).with_context(|| format!("Failed to run {}", program))?;
+    Ok(out.status)
+}
+
+fn run_ok(cmd: Command) -> Result<()> {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    let status = run(cmd)?;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", program, status.code().unwrap_or(-1)));
+    }
+    Ok(())
+}
+
+fn cmd(program: &str, args: &[&str], file: &Path) -> Command {
+    let mut c = Command::new(program);
+    c.args(args).arg(file);
+    c
+}
+
+/// Optimise `tmp` (a copy of the original) in place with whatever is installed.
+fn optimize(tmp: &Path, kind: Kind, params: &OptimizeParams, tools: &Tools) -> Result<()> {
+    if let (Some(max), Some(magick)) = (params.max_size, tools.magick) {
+        // `>` only shrinks; smaller images are left alone
+        let geometry = format!("{}x{}>", max, max);
+        let mut c = cmd(magick, &[], tmp);
+        c.args(["-resize", &geometry]).arg(tmp);
+        run_ok(c)?;
+    }
+
+    match kind {
+        Kind::Png => {
+            if params.lossy && tools.pngquant {
+                // 98/99 = result would be larger or below quality; the file is left as it was
+                let mut c = Command::new("pngquant");
+                c.args(["--force", "--skip-if-larger", "--quality", "65-90", "--output"]).arg(tmp).arg(tmp);
+                run(c)?;
+            }
+            if tools.oxipng {
+                run_ok(cmd("oxipng", &["-o", "3", "--strip", "safe", "-q"], tmp))?;
+            } else if tools.optipng {
+                run_ok(cmd("optipng", &["-quiet", "-o2", "-strip", "all"], tmp))?;
+            } else if let Some(magick) = tools.magick {
+                let mut c = cmd(magick, &[], tmp);
+                c.args(["-strip", "-define", "png:compression-level=9"]).arg(tmp);
+                run_ok(c)?;
+            }
+        }
+        Kind::Jpeg => {
+            if tools.jpegoptim {
+                let max = format!("--max={}", LOSSY_QUALITY);
+                let mut args = vec!["--strip-all", "-q"];
+                if params.lossy {
+                    args.push(&max);
+                }
+                run_ok(cmd("jpegoptim", &args, tmp))?;
+            } else if let Some(magick) = tools.magick {
+                let mut c = cmd(magick, &[], tmp);
+                c.args(["-strip", "-interlace", "Plane"]);
+                if params.lossy {
+                    c.args(["-quality", LOSSY_QUALITY]);
+                }
+                c.arg(tmp);
+                run_ok(c)?;
+            }
+        }
+        Kind::Webp => {
+            if tools.cwebp {
+                let out = tmp.with_extension("out.webp");
+                let mut c = Command::new("cwebp");
+                if params.lossy { c.args(["-q", LOSSY_QUALITY]) } else { c.arg("-lossless") };
+                c.args(["-metadata", "none", "-quiet"]).arg(tmp).arg("-o").arg(&out);
+                run_ok(c)?;
+                std::fs::rename(&out, tmp)?;
+            } else if let Some(magick) = tools.magick {
+                let mut c = cmd(magick, &[], tmp);
+                c.arg("-strip");
+                if params.lossy { c.args(["-quality", LOSSY_QUALITY]) } else { c.args(["-define", "webp:lossless=true"]) };
+                c.arg(tmp);
+                run_ok(c)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Work on a hidden copy next to the original, so a failing tool never leaves a broken image.
+fn temp_copy(path: &Path) -> Result<PathBuf> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = path.with_file_name(format!(".vg-img-{}-{}", std::process::id(), name));
+    std::fs::copy(path, &tmp).with_context(|| format!("Failed to copy {}", path.display()))?;
+    Ok(tmp)
+}
+
+struct Outcome {
+    path: PathBuf,
+    before: u64,
+    /// Optimised copy, only kept when it is smaller
+    smaller: Option<(PathBuf, u64)>,
+    error: Option<String>,
+}
+
+fn process(path: &Path, kind: Kind, params: &OptimizeParams, tools: &Tools) -> Outcome {
+    let before = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let mut outcome = Outcome { path: path.to_path_buf(), before, smaller: None, error: None };
+    let tmp = match temp_copy(path) {
+        Ok(t) => t,
+        Err(e) => {
+            outcome.error = Some(e.to_string());
+            return outcome;
+        }
+    };
+    match optimize(&tmp, kind, params, tools) {
+        Ok(()) => {
+            let after = std::fs::metadata(&tmp).map(|m| m.len()).unwrap_or(u64::MAX);
+            if after < before {
+                outcome.smaller = Some((tmp, after));
+                return outcome;
+            }
+        }
+        Err(e) => outcome.error = Some(format!("{:#}", e)),
+    }
+    let _ = std::fs::remove_file(&tmp);
+    outcome
+}
+
+fn collect(root: &Path) -> Vec<(PathBuf, Kind)> {
+    if root.is_file() {
+        return Kind::from_path(root).map(|k| vec![(root.to_path_buf(), k)]).unwrap_or_default();
+    }
+    let options = crate::walk::Options { ignore_files: true, skip_hidden: true, ..Default::default() };
+    let mut files: Vec<(PathBuf, Kind)> = crate::walk::walk(root, &options, |e| {
+        if !e.file_type().is_some_and(|t| t.is_file()) || e.file_name().to_string_lossy().starts_with(".vg-img-") {
+            return None;
+        }
+        Kind::from_path(e.path()).map(|k| (e.path().to_path_buf(), k))
+    }).items;
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+/// `vg img optimize [path]` — recompress PNG/JPEG/WebP files and report the bytes saved.
+pub fn optimize_cmd(params: OptimizeParams) -> Result<()> {
+    ui::print_header("IMAGE OPTIMIZE");
+    if !params.path.exists() {
+        return Err(anyhow!("{} does not exist", params.path.display()));
+    }
+    let tools = Tools::detect();
+    if params.max_size.is_some() && tools.magick.is_none() {
+        return Err(anyhow!("--max-size needs ImageMagick — install it with: vg install imagemagick"));
+    }
+
+    let files = collect(&params.path);
+    if files.is_empty() {
+        ui::skip("No PNG, JPEG or WebP files found");
+        return Ok(());
+    }
+    let mut missing: Vec<Kind> = Vec::new();
+    for (_, kind) in &files {
+        if !tools.can(*kind) && !missing.contains(kind) {
+            missing.push(*kind);
+        }
+    }
+    for kind in &missing {
+        ui::skip(&format!("No optimizer for {} files — install one with: vg install {}", kind.name(), kind.suggestion()));
+    }
+    let work: Vec<&(PathBuf, Kind)> = files.iter().filter(|(_, k)| !missing.contains(k)).collect();
+    if work.is_empty() {
+        return Ok(());
+    }
+    ui::info_line("Images", &format!("{}{}", work.len(), if params.lossy { " (lossy)" } else { "" }));
+    println!();
+
+    let outcomes: Vec<Outcome> = work.par_iter().map(|(p, k)| process(p, *k, &params, &tools)).collect();
+
+    // Replace originals through the undo journal, one at a time
+    let mut recorder = Recorder::new("img optimize", &params.path.display().to_string());
+    let mut saved: Vec<(&Outcome, u64)> = Vec::new();
+    let mut failed = 0;
+    for o in &outcomes {
+        if let Some(err) = &o.error {
+            failed += 1;
+            ui::fail(&format!("{}: {}", o.path.display(), err));
+        }
+        let Some((tmp, after)) = &o.smaller else { continue };
+        let bytes = std::fs::read(tmp)?;
+        let _ = std::fs::remove_file(tmp);
+        recorder.write(&o.path, &bytes)?;
+        saved.push((o, *after));
+    }
+    let journaled = recorder.finish()?.is_some();
+
+    if saved.is_empty() {
+        ui::success("Every image is already as small as these tools get it");
+        return Ok(());
+    }
+    saved.sort_by_key(|(o, after)| std::cmp::Reverse(o.before - after));
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("File").add_attribute(Attribute::Bold),
+        Cell::new("Before").add_attribute(Attribute::Bold),
+        Cell::new("After").add_attribute(Attribute::Bold),
+        Cell::new("Saved").add_attribute(Attribute::Bold),
+    ]);
+    for (o, after) in saved.iter().take(MAX_ROWS) {
+        let rel = o.path.strip_prefix(&params.path).ok().filter(|r| !r.as_os_str().is_empty()).unwrap_or(&o.path);
+        table.add_row(vec![
+            Cell::new(rel.display()),
+            Cell::new(super::search::fmt_bytes(o.before)),
+            Cell::new(super::search::fmt_bytes(*after)),
+            Cell::new(format!("{:.0}%", (o.before - after) as f64 * 100.0 / o.before.max(1) as f64)).fg(Color::Green),
+        ]);
+    }
+    println!("{}", table);
+    if saved.len() > MAX_ROWS {
+        ui::skip(&format!("… and {} more", saved.len() - MAX_ROWS));
+    }
+    println!();
+
+    let before: u64 = saved.iter().map(|(o, _)| o.before).sum();
+    let after: u64 = saved.iter().map(|(_, a)| *a).sum();
+    ui::success(&format!(
+        "Saved {} across {} of {} images ({:.0}%)",
+        super::search::fmt_bytes(before - after), saved.len(), work.len(),
+        (before - after) as f64 * 100.0 / before.max(1) as f64,
+    ));
+    if failed > 0 {
+        ui::skip(&format!("{} images could not be processed", failed));
+    }
+    if journaled {
+        ui::skip("Changed your mind? vg undo");
+    }
+    Ok(())
+}
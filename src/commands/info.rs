@@ -1,37 +1,229 @@
 use crate::ui;
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
 use sysinfo::System;
 
-pub fn run() {
-    ui::print_header("SYSTEM INFO");
+#[derive(Serialize)]
+struct SystemInfo {
+    os: String,
+    os_version: String,
+    kernel: String,
+    hostname: String,
+    arch: String,
+    uptime_secs: u64,
+    cpu_model: String,
+    cpu_cores: usize,
+    cpu_freq_mhz: u64,
+    memory_used_mb: u64,
+    memory_total_mb: u64,
+    swap_total_mb: u64,
+    gpus: Vec<super::gpu::Gpu>,
+    username: String,
+    home: String,
+    shell: String,
+}
 
+fn collect() -> SystemInfo {
     let mut sys = System::new_all();
     sys.refresh_all();
+    let cpu = sys.cpus().first();
+    SystemInfo {
+        os: System::name().unwrap_or_default(),
+        os_version: System::os_version().unwrap_or_default(),
+        kernel: System::kernel_version().unwrap_or_default(),
+        hostname: System::host_name().unwrap_or_default(),
+        arch: std::env::consts::ARCH.to_string(),
+        uptime_secs: System::uptime(),
+        cpu_model: cpu.map(|c| c.brand().trim().to_string()).unwrap_or_default(),
+        cpu_cores: sys.cpus().len(),
+        cpu_freq_mhz: cpu.map(|c| c.frequency()).unwrap_or_default(),
+        memory_used_mb: sys.used_memory() / 1024 / 1024,
+        memory_total_mb: sys.total_memory() / 1024 / 1024,
+        swap_total_mb: sys.total_swap() / 1024 / 1024,
+        gpus: super::gpu::detect(),
+        username: whoami::username(),
+        home: dirs::home_dir().unwrap_or_default().to_string_lossy().to_string(),
+        shell: std::env::var("SHELL").ok()
+            .and_then(|s| s.rsplit('/').next().map(str::to_string))
+            .unwrap_or_default(),
+    }
+}
+
+pub fn run(json: bool, fetch: bool) -> Result<()> {
+    let info = collect();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+    if fetch {
+        print_fetch(&info);
+        return Ok(());
+    }
+
+    ui::print_header("SYSTEM INFO");
 
     ui::section("Hardware");
-    ui::info_line("OS", &System::name().unwrap_or_default());
-    ui::info_line("OS Version", &System::os_version().unwrap_or_default());
-    ui::info_line("Kernel", &System::kernel_version().unwrap_or_default());
-    ui::info_line("Hostname", &System::host_name().unwrap_or_default());
-    ui::info_line("Architecture", std::env::consts::ARCH);
+    ui::info_line("OS", &info.os);
+    ui::info_line("OS Version", &info.os_version);
+    ui::info_line("Kernel", &info.kernel);
+    ui::info_line("Hostname", &info.hostname);
+    ui::info_line("Architecture", &info.arch);
 
     ui::section("CPU");
-    let cpus = sys.cpus();
-    if let Some(cpu) = cpus.first() {
-        ui::info_line("Model", cpu.brand());
-        ui::info_line("Cores", &cpus.len().to_string());
-        ui::info_line("Freq", &format!("{} MHz", cpu.frequency()));
+    if info.cpu_cores > 0 {
+        ui::info_line("Model", &info.cpu_model);
+        ui::info_line("Cores", &info.cpu_cores.to_string());
+        ui::info_line("Freq", &format!("{} MHz", info.cpu_freq_mhz));
     }
 
     ui::section("Memory");
-    let total = sys.total_memory() / 1024 / 1024;
-    let used = sys.used_memory() / 1024 / 1024;
-    ui::info_line("RAM", &format!("{} / {} MB", used, total));
-    let swap_total = sys.total_swap() / 1024 / 1024;
-    ui::info_line("Swap", &format!("{} MB total", swap_total));
+    ui::info_line("RAM", &format!("{} / {} MB", info.memory_used_mb, info.memory_total_mb));
+    ui::info_line("Swap", &format!("{} MB total", info.swap_total_mb));
+
+    ui::section("GPU");
+    super::gpu::print(&info.gpus);
 
     ui::section("User");
-    ui::info_line("Username", &whoami::username());
-    ui::info_line("Home", &dirs::home_dir().unwrap_or_default().to_string_lossy());
+    ui::info_line("Username", &info.username);
+    ui::info_line("Home", &info.home);
+
+    println!();
+    Ok(())
+}
+
+// ── Fetch banner ───────────────────────────────────────────────────────────────
 
+const LOGO_ARCH: &[&str] = &[
+    "        /\\        ",
+    "       /  \\       ",
+    "      /\\   \\      ",
+    "     /  __  \\     ",
+    "    /  (  )  \\    ",
+    "   / __|  |__\\\\   ",
+    "  /.`        `.\\  ",
+];
+const LOGO_MANJARO: &[&str] = &[
+    "  ██████████  ███  ",
+    "  ██████████  ███  ",
+    "  ███         ███  ",
+    "  ███  ████   ███  ",
+    "  ███  ████   ███  ",
+    "  ███  ████   ███  ",
+    "  ███  ████   ███  ",
+];
+const LOGO_DEBIAN: &[&str] = &[
+    "    _____     ",
+    "   /  __ \\    ",
+    "  |  /    |   ",
+    "  |  \\___-    ",
+    "  -_          ",
+    "    --_       ",
+    "              ",
+];
+const LOGO_UBUNTU: &[&str] = &[
+    "          _     ",
+    "      ---(_)    ",
+    "  _/  ---  \\    ",
+    " (_) |   |      ",
+    "   \\  --- _/    ",
+    "      ---(_)    ",
+    "                ",
+];
+const LOGO_FEDORA: &[&str] = &[
+    "      _____     ",
+    "     /   __)\\   ",
+    "     |  /  \\ \\  ",
+    "  ___|  |__/ /  ",
+    " / (_    _)_/   ",
+    "/ /  |  |       ",
+    "\\ \\__/  |       ",
+];
+const LOGO_MACOS: &[&str] = &[
+    "        .:'     ",
+    "    __ :'__     ",
+    " .'`  `-'  ``.  ",
+    ":          .-'  ",
+    ":         :     ",
+    " :         `-;  ",
+    "  `.__.-.__.'   ",
+];
+const LOGO_WINDOWS: &[&str] = &[
+    "  ████████  ████████  ",
+    "  ████████  ████████  ",
+    "  ████████  ████████  ",
+    "                      ",
+    "  ████████  ████████  ",
+    "  ████████  ████████  ",
+    "  ████████  ████████  ",
+];
+const LOGO_LINUX: &[&str] = &[
+    "      ___     ",
+    "     (.. |    ",
+    "     (<> |    ",
+    "    / __  \\   ",
+    "   ( /  \\ /|  ",
+    "  _/\\ __)/_)  ",
+    "  \\/-____\\/   ",
+];
+
+fn logo(os: &str) -> &'static [&'static str] {
+    let os = os.to_lowercase();
+    if os.contains("manjaro") {
+        LOGO_MANJARO
+    } else if os.contains("arch") || os.contains("endeavour") {
+        LOGO_ARCH
+    } else if os.contains("ubuntu") || os.contains("mint") || os.contains("pop") {
+        LOGO_UBUNTU
+    } else if os.contains("debian") {
+        LOGO_DEBIAN
+    } else if os.contains("fedora") {
+        LOGO_FEDORA
+    } else if os.contains("mac") || os.contains("darwin") {
+        LOGO_MACOS
+    } else if os.contains("windows") {
+        LOGO_WINDOWS
+    } else {
+        LOGO_LINUX
+    }
+}
+
+/// neofetch-style banner: OS logo on the left, key facts on the right.
+fn print_fetch(info: &SystemInfo) {
+    let title = format!("{}@{}", info.username, info.hostname);
+    let up = info.uptime_secs;
+    let mut lines: Vec<String> = vec![
+        title.truecolor(96, 165, 250).bold().to_string(),
+        "─".repeat(title.chars().count()).truecolor(71, 85, 105).to_string(),
+    ];
+    let mut field = |label: &str, value: String| {
+        if !value.trim().is_empty() {
+            lines.push(format!("{} {}", format!("{}:", label).truecolor(96, 165, 250).bold(), value.truecolor(224, 242, 254)));
+        }
+    };
+    field("OS", format!("{} {} {}", info.os, info.os_version, info.arch));
+    field("Kernel", info.kernel.clone());
+    field("Uptime", format!("{}d {}h {}m", up / 86400, (up % 86400) / 3600, up % 3600 / 60));
+    field("Shell", info.shell.clone());
+    field("CPU", format!("{} ({})", info.cpu_model, info.cpu_cores));
+    for gpu in &info.gpus {
+        field("GPU", gpu.model.clone());
+    }
+    field("Memory", format!("{} / {} MB", info.memory_used_mb, info.memory_total_mb));
+    lines.push(String::new());
+    // Palette swatches, as every fetch tool does
+    lines.push([(71, 85, 105), (96, 165, 250), (147, 197, 253), (224, 242, 254), (248, 113, 113), (74, 222, 128), (251, 191, 36)]
+        .iter()
+        .map(|(r, g, b)| "███".truecolor(*r, *g, *b).to_string())
+        .collect());
+
+    let art = logo(&info.os);
+    let width = art.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    println!();
+    for i in 0..art.len().max(lines.len()) {
+        let left = art.get(i).copied().unwrap_or("");
+        let pad = " ".repeat(width - left.chars().count());
+        println!("  {}{}  {}", left.truecolor(96, 165, 250).bold(), pad, lines.get(i).map(String::as_str).unwrap_or(""));
+    }
     println!();
 }
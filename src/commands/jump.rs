@@ -0,0 +1,183 @@
+use crate::ui;
+use crate::config::ConfigManager;
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Once the summed visit counts pass this, every entry is aged so old habits fade out.
+const MAX_TOTAL_RANK: f64 = 10_000.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    /// Visit count, decayed over time
+    rank: f64,
+    /// Unix seconds of the last visit
+    last: u64,
+}
+
+type Db = BTreeMap<String, Entry>;
+
+fn db_path() -> PathBuf {
+    ConfigManager::data_dir().join("jump.json")
+}
+
+fn load() -> Db {
+    std::fs::read_to_string(db_path()).ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn save(db: &Db) -> Result<()> {
+    let path = db_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // Write-then-rename so two shells cd-ing at once can't leave a torn file
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string(db)?)?;
+    std::fs::rename(tmp, path)?;
+    Ok(())
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Frequency weighted by recency, as in z/zoxide.
+fn frecency(e: &Entry, now: u64) -> f64 {
+    let age = now.saturating_sub(e.last);
+    let weight = match age {
+        0..=3_599 => 4.0,
+        3_600..=86_399 => 2.0,
+        86_400..=604_799 => 0.5,
+        _ => 0.25,
+    };
+    e.rank * weight
+}
+
+/// `vg jump add <dir>` — called by the shell hook on every directory change.
+pub fn add(dir: &Path) -> Result<()> {
+    let Ok(dir) = dir.canonicalize() else { return Ok(()) };
+    // The home directory is one keystroke away already
+    if Some(dir.as_path()) == dirs::home_dir().as_deref() {
+        return Ok(());
+    }
+    let mut db = load();
+    let entry = db.entry(dir.to_string_lossy().to_string()).or_insert(Entry { rank: 0.0, last: 0 });
+    entry.rank += 1.0;
+    entry.last = now();
+
+    if db.values().map(|e| e.rank).sum::<f64>() > MAX_TOTAL_RANK {
+        for e in db.values_mut() {
+            e.rank *= 0.9;
+        }
+        db.retain(|_, e| e.rank >= 1.0);
+    }
+    save(&db)
+}
+
+/// Best match for `query`: fuzzy score on the path, boosted by frecency. Missing directories are pruned.
+fn best(query: &str) -> Result<Option<String>> {
+    let mut db = load();
+    let before = db.len();
+    db.retain(|path, _| Path::new(path).is_dir());
+    if db.len() != before {
+        save(&db)?;
+    }
+
+    let paths: Vec<String> = db.keys().cloned().collect();
+    let now = now();
+    let cwd = std::env::current_dir().ok().map(|p| p.to_string_lossy().to_string());
+    Ok(super::search::fuzzy_rank(query, &paths).into_iter()
+        .filter(|(i, _)| Some(&paths[*i]) != cwd.as_ref())
+        .map(|(i, score)| (score as f64 * (1.0 + frecency(&db[&paths[i]], now)).ln(), i))
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, i)| paths[i].clone()))
+}
+
+/// `vg jump <query>` — print the best directory for the shell function to cd into.
+pub fn query(words: &[String]) -> Result<()> {
+    let q = words.join(" ");
+    match best(&q)? {
+        Some(path) => {
+            println!("{}", path);
+            Ok(())
+        }
+        None => Err(anyhow!("No directory matches '{}' — visit it once so vg can learn it", q)),
+    }
+}
+
+/// `vg jump list`
+pub fn list(limit: usize) -> Result<()> {
+    ui::print_header("JUMP");
+    let db = load();
+    if db.is_empty() {
+        ui::skip("No directories recorded yet.");
+        ui::skip("Enable the shell hook: eval \"$(vg jump init bash)\"  (or zsh / fish)");
+        return Ok(());
+    }
+    let now = now();
+    let mut entries: Vec<(f64, &String)> = db.iter().map(|(p, e)| (frecency(e, now), p)).collect();
+    entries.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (score, path) in entries.iter().take(limit) {
+        println!("  {:>8}  {}", format!("{:.1}", score).truecolor(96, 165, 250), path.truecolor(224, 242, 254));
+    }
+    println!();
+    ui::skip(&format!("{} directories tracked", db.len()));
+    Ok(())
+}
+
+/// `vg jump remove <dir>`
+pub fn remove(dir: &Path) -> Result<()> {
+    let key = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf()).to_string_lossy().to_string();
+    let mut db = load();
+    if db.remove(&key).is_none() {
+        return Err(anyhow!("{} is not tracked", key));
+    }
+    save(&db)?;
+    ui::success(&format!("Forgot {}", key));
+    Ok(())
+}
+
+/// `vg jump init <shell>` — print the hook and the `j` function for the given shell.
+pub fn init(shell: &str) -> Result<()> {
+    let script = match shell {
+        "bash" => r#"__vg_jump_hook() {
+  if [ "$__vg_jump_last" != "$PWD" ]; then
+    __vg_jump_last="$PWD"
+    (command vg jump add "$PWD" >/dev/null 2>&1 &)
+  fi
+}
+case ";$PROMPT_COMMAND;" in
+  *";__vg_jump_hook;"*) ;;
+  *) PROMPT_COMMAND="__vg_jump_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}" ;;
+esac
+j() {
+  local dir
+  dir="$(command vg jump "$@")" && cd "$dir"
+}"#,
+        "zsh" => r#"__vg_jump_hook() {
+  (command vg jump add "$PWD" >/dev/null 2>&1 &)
+}
+autoload -Uz add-zsh-hook
+add-zsh-hook chpwd __vg_jump_hook
+j() {
+  local dir
+  dir="$(command vg jump "$@")" && cd "$dir"
+}"#,
+        "fish" => r#"function __vg_jump_hook --on-variable PWD
+    command vg jump add "$PWD" >/dev/null 2>&1 &
+end
+function j
+    set -l dir (command vg jump $argv); and cd $dir
+end"#,
+        other => return Err(anyhow!("Unsupported shell '{}' — use bash, zsh or fish", other)),
+    };
+    println!("{}", script);
+    Ok(())
+}
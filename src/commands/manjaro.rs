@@ -405,6 +405,8 @@ fn ask_iso_manually() -> Result<IsoSource> {
 // ── Public entry point ────────────────────────────────────────────────────────
 
 pub fn run() -> Result<()> {
+    // Every step asks something (which drive to overwrite, above all)
+    ui::ensure_interactive("create the stick with Ventoy by hand")?;
     ui::print_header("MANJARO LIVE USB");
     println!("  Creates a bootable Manjaro KDE USB stick with Ventoy.");
 
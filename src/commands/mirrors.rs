@@ -0,0 +1,203 @@
+use crate::package_managers::{run_cmd, run_with_spinner};
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use inquire::Confirm;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use which::which;
+
+const PACMAN_MIRRORLIST: &str = "/etc/pacman.d/mirrorlist";
+const DNF_CONF: &str = "/etc/dnf/dnf.conf";
+/// Ubuntu's GeoIP-based list of mirrors near the caller.
+const UBUNTU_MIRRORS: &str = "http://mirrors.ubuntu.com/mirrors.txt";
+const APT_METHOD: &str = "apt mirror ranking";
+/// How many Ubuntu mirrors are timed.
+const APT_CANDIDATES: usize = 12;
+
+/// Keep the current mirror list next to the original before replacing it.
+fn backup(path: &str) -> Result<()> {
+    if Path::new(path).exists() {
+        run_cmd(&["cp", path, &format!("{}.vg-bak", path)], true)?;
+    }
+    Ok(())
+}
+
+fn arch(manjaro: bool) -> Result<&'static str> {
+    if manjaro {
+        // Manjaro's own tool also switches branches' mirrors consistently
+        run_with_spinner(&["pacman-mirrors", "--fasttrack", "5"], true, "Ranking Manjaro mirrors…")?;
+        return Ok("pacman-mirrors --fasttrack");
+    }
+    if which("rate-mirrors").is_ok() {
+        let tmp = tempfile::Builder::new().prefix("vg-mirrorlist-").tempfile()?;
+        let out = tmp.path().to_string_lossy().to_string();
+        run_with_spinner(&["rate-mirrors", "--save", &out, "arch"], false, "Ranking Arch mirrors…")?;
+        backup(PACMAN_MIRRORLIST)?;
+        run_cmd(&["install", "-m", "644", &out, PACMAN_MIRRORLIST], true)?;
+        return Ok("rate-mirrors");
+    }
+    crate::tools::require("reflector")?;
+    backup(PACMAN_MIRRORLIST)?;
+    run_with_spinner(
+        &["reflector", "--latest", "20", "--protocol", "https", "--sort", "rate", "--save", PACMAN_MIRRORLIST],
+        true,
+        "Ranking Arch mirrors…",
+    )?;
+    Ok("reflector")
+}
+
+fn os_release(key: &str) -> Option<String> {
+    std::fs::read_to_string("/etc/os-release").ok()?
+        .lines()
+        .find_map(|l| l.strip_prefix(&format!("{}=", key)).map(|v| v.trim_matches('"').to_string()))
+}
+
+/// apt source files and the archive URIs in them (one-line and deb822 formats).
+fn apt_sources() -> Vec<(PathBuf, Vec<String>)> {
+    let mut files = vec![PathBuf::from("/etc/apt/sources.list")];
+    if let Ok(dir) = std::fs::read_dir("/etc/apt/sources.list.d") {
+        let mut extra: Vec<PathBuf> = dir.flatten().map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "list" || e == "sources"))
+            .collect();
+        extra.sort();
+        files.extend(extra);
+    }
+    files.into_iter().filter_map(|path| {
+        let text = std::fs::read_to_string(&path).ok()?;
+        let uris: Vec<String> = text.lines().flat_map(|line| {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("URIs:") {
+                return rest.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+            }
+            if !line.starts_with("deb ") {
+                return Vec::new();
+            }
+            // "deb [arch=amd64 signed-by=…] http://… suite components"
+            let rest = match line[4..].trim_start().strip_prefix('[') {
+                Some(opts) => opts.split_once(']').map(|(_, r)| r).unwrap_or(""),
+                None => &line[4..],
+            };
+            rest.split_whitespace().next().map(str::to_string).into_iter().collect()
+        }).collect();
+        Some((path, uris))
+    }).collect()
+}
+
+/// Time fetching the suite's Release file from each mirror; fastest first.
+fn rank(mirrors: &[String], codename: &str) -> Vec<(String, Duration)> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .user_agent("vg-mirrors")
+        .build();
+    let Ok(client) = client else { return Vec::new() };
+    let mut timed: Vec<(String, Duration)> = std::thread::scope(|s| {
+        mirrors.iter()
+            .map(|m| {
+                let client = &client;
+                s.spawn(move || {
+                    let url = format!("{}/dists/{}/Release", m.trim_end_matches('/'), codename);
+                    let started = Instant::now();
+                    let ok = client.get(&url).send().and_then(|r| r.error_for_status()).and_then(|r| r.bytes()).is_ok();
+                    ok.then(|| (m.clone(), started.elapsed()))
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .filter_map(|h| h.join().ok().flatten())
+            .collect()
+    });
+    timed.sort_by_key(|(_, t)| *t);
+    timed
+}
+
+/// Ubuntu only: Debian's deb.debian.org is a CDN that already routes to a nearby mirror.
+fn apt(yes: bool) -> Result<&'static str> {
+    let codename = os_release("VERSION_CODENAME").ok_or_else(|| anyhow!("Cannot read the Ubuntu release from /etc/os-release"))?;
+    let sources = apt_sources();
+    let current = sources.iter().flat_map(|(_, uris)| uris)
+        .find(|u| u.contains("/ubuntu") && !u.contains("security.ubuntu.com"))
+        .cloned()
+        .ok_or_else(|| anyhow!("No Ubuntu archive found in /etc/apt/sources.list*"))?;
+
+    let list = reqwest::blocking::Client::builder().timeout(Duration::from_secs(10)).build()?
+        .get(UBUNTU_MIRRORS).send().and_then(|r| r.error_for_status()).and_then(|r| r.text())
+        .context("Cannot fetch the Ubuntu mirror list")?;
+    let mut candidates: Vec<String> = list.lines().map(str::trim).filter(|l| l.starts_with("http")).map(str::to_string)
+        .take(APT_CANDIDATES).collect();
+    candidates.push(current.clone());
+    let ranked = rank(&candidates, &codename);
+    let Some((fastest, _)) = ranked.first() else {
+        return Err(anyhow!("No mirror answered within 5 s"));
+    };
+    for (mirror, time) in ranked.iter().take(3) {
+        ui::info_line(&format!("{} ms", time.as_millis()), mirror);
+    }
+    let same = |a: &str, b: &str| a.trim_end_matches('/') == b.trim_end_matches('/');
+    if same(fastest, &current) {
+        ui::success(&format!("Already on the fastest mirror ({})", current));
+        return Ok(APT_METHOD);
+    }
+
+    if !yes {
+        if !crate::ui::interactive() {
+            ui::skip(&format!("Fastest is {} — pass --yes to switch", fastest));
+            return Ok(APT_METHOD);
+        }
+        if !Confirm::new(&format!("Switch from {} to {}?", current, fastest)).with_default(true).prompt()? {
+            return Ok(APT_METHOD);
+        }
+    }
+    let expr = format!("s|{}|{}|g", current.trim_end_matches('/'), fastest.trim_end_matches('/'));
+    for (path, uris) in &sources {
+        if uris.iter().any(|u| same(u, &current)) {
+            // sed keeps the original as <file>.vg-bak
+            run_cmd(&["sed", "-i.vg-bak", &expr, &path.to_string_lossy()], true)?;
+        }
+    }
+    ui::success(&format!("Switched to {}", fastest));
+    Ok(APT_METHOD)
+}
+
+fn dnf() -> Result<&'static str> {
+    let conf = std::fs::read_to_string(DNF_CONF).unwrap_or_default();
+    let enabled = conf.lines().any(|l| {
+        let l = l.replace(' ', "").to_lowercase();
+        l == "fastestmirror=true" || l == "fastestmirror=1" || l == "fastestmirror=yes"
+    });
+    if !enabled {
+        backup(DNF_CONF)?;
+        // dnf.conf normally holds only [main]; drop any older setting before appending
+        run_cmd(&["sed", "-i", "/^fastestmirror/d", DNF_CONF], true)?;
+        run_cmd(&["sh", "-c", &format!("echo fastestmirror=True >> {}", DNF_CONF)], true)?;
+        ui::success("Enabled fastestmirror in dnf.conf");
+    }
+    run_with_spinner(&["dnf", "makecache", "--refresh"], true, "Refreshing metadata from the fastest mirrors…")?;
+    Ok("dnf fastestmirror")
+}
+
+/// Refresh the distro package manager's mirrors. Used by `vg mirrors` and, with
+/// `system.update_mirrors`, before `vg update`.
+pub fn refresh(yes: bool) -> Result<()> {
+    let started = Instant::now();
+    let method = if which("pacman").is_ok() {
+        arch(which("pacman-mirrors").is_ok())?
+    } else if which("apt").is_ok() && os_release("ID").as_deref() == Some("ubuntu") {
+        apt(yes)?
+    } else if which("apt").is_ok() {
+        ui::skip("deb.debian.org already picks a nearby mirror — nothing to rank");
+        return Ok(());
+    } else if which("dnf").is_ok() {
+        dnf()?
+    } else {
+        ui::skip("No distro package manager with mirrors found");
+        return Ok(());
+    };
+    ui::success(&format!("Mirrors refreshed with {} in {:.1} s", method, started.elapsed().as_secs_f64()));
+    Ok(())
+}
+
+/// `vg mirrors`
+pub fn run(yes: bool) -> Result<()> {
+    ui::print_header("MIRRORS");
+    refresh(yes)
+}
@@ -9,3 +9,53 @@ pub mod self_update;
 pub mod config_cmd;
 pub mod config_tui;
 pub mod manjaro;
+pub mod doctor;
+pub mod git;
+pub mod onboarding;
+pub mod weather;
+pub mod gen;
+pub mod archive;
+pub mod service;
+pub mod boot;
+pub mod sensors;
+pub mod gpu;
+pub mod ps;
+pub mod port;
+pub mod disks;
+pub mod ssh;
+pub mod jump;
+pub mod snip;
+pub mod http;
+pub mod grep;
+pub mod replace;
+pub mod undo;
+pub mod rename_batch;
+pub mod perms;
+pub mod startup;
+pub mod clean;
+pub mod containers;
+pub mod hooks;
+pub mod toolchain;
+pub mod run;
+pub mod bootstrap;
+pub mod update_check;
+pub mod tidy;
+pub mod serve;
+pub mod benchmark;
+pub mod img;
+pub mod pdf;
+pub mod shot;
+pub mod qr;
+pub mod remind;
+pub mod track;
+pub mod data;
+pub mod sync;
+pub mod self_check;
+pub mod firmware;
+pub mod mirrors;
+pub mod rollback;
+pub mod pkg;
+pub mod dash;
+pub mod notify;
+pub mod remote;
+pub mod fleet;
@@ -0,0 +1,48 @@
+use crate::config::NotifyConfig;
+use crate::notify::{self, Payload};
+use crate::ui;
+use anyhow::Result;
+
+pub fn test(config: &NotifyConfig) -> Result<()> {
+    ui::print_header("NOTIFY TEST");
+    let host = sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string());
+    let title = "vg test notification";
+    let body = format!("Sent from {} — notifications work", host);
+    let mut failed = false;
+    let mut check = |channel: &str, result: Result<()>| match result {
+        Ok(()) => ui::success(channel),
+        Err(e) => {
+            ui::fail(&format!("{}: {:#}", channel, e));
+            failed = true;
+        }
+    };
+
+    if config.desktop {
+        let sent = notify::desktop(title, &body);
+        check("Desktop", if sent { Ok(()) } else { Err(anyhow::anyhow!("no notifier — install one with: vg install libnotify")) });
+    } else {
+        ui::skip("Desktop: off (notify.desktop)");
+    }
+    if !config.bell {
+        ui::skip("Terminal bell: off (notify.bell)");
+    } else if notify::bell() {
+        ui::success("Terminal bell");
+    } else {
+        ui::skip("Terminal bell: not a terminal");
+    }
+    if config.ntfy.is_empty() {
+        ui::skip("ntfy: no topic (notify.ntfy)");
+    } else {
+        check(&format!("ntfy ({})", config.ntfy), notify::ntfy(&config.ntfy, title, &body, "vg,test_tube"));
+    }
+    if config.webhook.is_empty() {
+        ui::skip("Webhook: no URL (notify.webhook)");
+    } else {
+        let payload = Payload { title, body: &body, command: "notify", status: "ok" };
+        check("Webhook", notify::webhook(config, &payload));
+    }
+    if failed {
+        crate::exit::set(crate::exit::NEGATIVE);
+    }
+    Ok(())
+}
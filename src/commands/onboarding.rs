@@ -0,0 +1,113 @@
+// src/commands/onboarding.rs
+use crate::ui;
+use crate::config::{Config, ConfigManager, LANGUAGES};
+use anyhow::Result;
+use inquire::{Confirm, InquireError, MultiSelect, Select, Text};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+/// Home sub-directories offered as search paths when they exist.
+const COMMON_DIRS: &[&str] = &["Documents", "Downloads", "Desktop", "Pictures", "Music", "Videos", "Projects", "dev", "src"];
+
+/// Guided setup shown the first time vg runs interactively. Answers are saved only once every
+/// question is answered: when it is cancelled or can't prompt, the saved defaults apply and the
+/// setup is offered again next time. Never fails the command the user actually ran.
+pub fn run(config: &mut ConfigManager) {
+    if !crate::ui::interactive() || !std::io::stdout().is_terminal() {
+        return;
+    }
+    let mut answers = config.config.clone();
+    match ask(&mut answers) {
+        Ok(()) => {}
+        Err(e) if is_cancel(&e) => {
+            println!();
+            ui::skip("Setup skipped — using defaults for now; it runs again next time");
+            println!();
+            return;
+        }
+        Err(e) => {
+            ui::fail(&format!("Setup failed: {:#}", e));
+            ui::skip("Using defaults for now; it runs again next time");
+            println!();
+            return;
+        }
+    }
+    config.config = answers;
+    if let Err(e) = config.save() {
+        ui::fail(&format!("Could not save settings: {:#}", e));
+        return;
+    }
+    config.finish_onboarding();
+    println!();
+    ui::success(&format!("Settings saved to {}", config.config_path().display()));
+
+    // ── Initial index ────────────────────────────────────────────
+    let build_now = Confirm::new("Build the search index now?")
+        .with_default(true)
+        .with_help_message("Otherwise it is built in the background on a later run")
+        .prompt()
+        .unwrap_or(false);
+    if build_now {
+        let paths: Vec<PathBuf> = config.config.search.default_paths.iter().map(PathBuf::from).collect();
+        match super::search::build_index(paths, config) {
+            Ok(()) => ConfigManager::touch_auto_index_stamp(),
+            Err(e) => ui::fail(&format!("Indexing failed: {:#} — run vg index to try again", e)),
+        }
+    }
+
+    println!();
+    ui::divider();
+    println!();
+}
+
+/// Esc or Ctrl-C at a prompt.
+fn is_cancel(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<InquireError>(),
+        Some(InquireError::OperationCanceled | InquireError::OperationInterrupted)
+    )
+}
+
+fn ask(config: &mut Config) -> Result<()> {
+    ui::print_header("WELCOME TO GENESIS");
+    println!("  A few quick questions to set things up. You can change any of this later with vg config.");
+
+    // ── Language ─────────────────────────────────────────────────
+    ui::section("Language");
+    let names: Vec<&str> = LANGUAGES.iter().map(|(_, name)| *name).collect();
+    let current = LANGUAGES.iter().position(|(code, _)| *code == config.system.language).unwrap_or(0);
+    let name = Select::new("Language for greetings?", names).with_starting_cursor(current).prompt()?;
+    if let Some((code, _)) = LANGUAGES.iter().find(|(_, n)| *n == name) {
+        config.system.language = code.to_string();
+    }
+
+    // ── Search paths ─────────────────────────────────────────────
+    ui::section("File search");
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    let candidates: Vec<String> = std::iter::once(home.clone())
+        .chain(COMMON_DIRS.iter().map(|d| home.join(d)))
+        .filter(|p| p.is_dir())
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+
+    let mut paths = MultiSelect::new("Which folders should vg search index?", candidates)
+        .with_default(&[0])
+        .with_help_message("↑↓ move · space toggle · enter confirm")
+        .prompt()?;
+    let extra = Text::new("Any other folders? (comma-separated, optional)")
+        .with_default("")
+        .prompt()?;
+    paths.extend(extra.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()));
+    if paths.is_empty() {
+        paths = config.search.default_paths.clone();
+    }
+    config.search.default_paths = paths;
+
+    // ── Analytics ────────────────────────────────────────────────
+    ui::section("Privacy");
+    ui::skip("vg can send one anonymous ping per day (version, OS, architecture — no paths or names).");
+    config.analytics.enabled = Confirm::new("Send the anonymous daily ping?")
+        .with_default(config.analytics.enabled)
+        .prompt()?;
+    Ok(())
+}
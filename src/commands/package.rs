@@ -1,9 +1,12 @@
 use crate::ui;
 use crate::package_managers::{get_available_managers, PmPackage};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use rayon::prelude::*;
 use comfy_table::{Table, Cell, Color, Attribute};
-use inquire::Select;
+use inquire::{MultiSelect, Select};
+
+/// AUR results shown by `--pick`, most voted first.
+const MAX_AUR_RESULTS: usize = 30;
 
 pub fn install(pkg: &str, yes: bool) -> Result<()> {
     ui::print_header(&format!("INSTALL  {}", pkg));
@@ -72,8 +75,15 @@ pub fn install(pkg: &str, yes: bool) -> Result<()> {
         return Ok(());
     }
 
-    let selection = Select::new("Select package to install:", options.clone()).prompt()?;
-    let idx = options.iter().position(|o| o == &selection).unwrap_or(0);
+    let idx = if crate::ui::interactive() {
+        let selection = Select::new("Select package to install:", options.clone()).prompt()?;
+        options.iter().position(|o| o == &selection).unwrap_or(0)
+    } else {
+        // Without a prompt, only an exact name match from the first manager that has one is installed
+        all.iter().position(|(_, p)| p.name == pkg)
+            .filter(|_| yes)
+            .ok_or_else(|| crate::error::Error::not_a_terminal("pass the exact package name with --yes"))?
+    };
 
     let (pm_id, selected_pkg) = &all[idx];
 
@@ -84,6 +94,7 @@ pub fn install(pkg: &str, yes: bool) -> Result<()> {
     if let Some(m) = manager {
         ui::section(&format!("Installing via {}", m.display_name()));
         m.install(&selected_pkg.name, yes)?;
+        super::pkg::record("install", m.id(), &selected_pkg.name);
         ui::success(&format!("'{}' installed successfully.", selected_pkg.name));
     } else {
         ui::fail("Package manager not found.");
@@ -103,6 +114,7 @@ pub fn uninstall(pkg: &str) -> Result<()> {
     for m in &managers {
         match m.uninstall(pkg) {
             Ok(()) => {
+                super::pkg::record("remove", m.id(), pkg);
                 ui::success(&format!("Removed '{}' via {}", pkg, m.display_name()));
                 removed = true;
                 break;
@@ -119,3 +131,123 @@ pub fn uninstall(pkg: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// AUR web API; search results carry votes and popularity, which `yay -Ss` only shows inconsistently.
+const AUR_RPC: &str = "https://aur.archlinux.org/rpc/v5/search";
+
+struct Candidate {
+    name: String,
+    version: String,
+    /// "core", "extra", … or "aur"
+    repo: String,
+    votes: Option<u64>,
+    description: String,
+}
+
+fn repo_candidates(query: &str) -> Vec<Candidate> {
+    let Ok(out) = crate::logging::output(std::process::Command::new("pacman").args(["-Ss", query])) else { return vec![] };
+    // "extra/ripgrep 14.1.0-1 [installed]" followed by an indented description
+    let text = String::from_utf8_lossy(&out.stdout);
+    let mut lines = text.lines().peekable();
+    let mut found = Vec::new();
+    while let Some(line) = lines.next() {
+        if line.starts_with(' ') { continue; }
+        let Some((repo, rest)) = line.split_once('/') else { continue };
+        let mut parts = rest.split_whitespace();
+        let (Some(name), Some(version)) = (parts.next(), parts.next()) else { continue };
+        let description = lines.next_if(|l| l.starts_with(' ')).map(|l| l.trim().to_string()).unwrap_or_default();
+        found.push(Candidate { name: name.into(), version: version.into(), repo: repo.into(), votes: None, description });
+    }
+    found
+}
+
+fn aur_candidates(query: &str) -> Result<Vec<Candidate>> {
+    let client = reqwest::blocking::Client::builder().timeout(std::time::Duration::from_secs(10)).user_agent("vg-install").build()?;
+    let json: serde_json::Value = client.get(format!("{}/{}", AUR_RPC, query)).send()?.error_for_status()?.json()?;
+    if let Some(err) = json["error"].as_str() {
+        return Err(anyhow!("AUR: {}", err));
+    }
+    let mut found: Vec<Candidate> = json["results"].as_array().into_iter().flatten()
+        .map(|r| Candidate {
+            name: r["Name"].as_str().unwrap_or("?").into(),
+            version: r["Version"].as_str().unwrap_or("?").into(),
+            repo: "aur".into(),
+            votes: r["NumVotes"].as_u64(),
+            description: r["Description"].as_str().unwrap_or("").into(),
+        })
+        .collect();
+    found.sort_by_key(|c| std::cmp::Reverse(c.votes.unwrap_or(0)));
+    Ok(found)
+}
+
+/// `vg install --pick <query>` (Arch) — search the repos and the AUR, install any number of results
+/// with the first available manager from `system.package_manager_priority`.
+pub fn pick(query: &str, priority: &[String], yes: bool) -> Result<()> {
+    ui::print_header(&format!("INSTALL  {}", query));
+    if !crate::package_managers::is_available("pacman") {
+        return Err(anyhow!("--pick searches the Arch repos and the AUR; use vg install {} here", query));
+    }
+    let managers = get_available_managers();
+    let helper = priority.iter()
+        .find_map(|id| managers.iter().find(|m| m.id() == id))
+        .or_else(|| managers.iter().find(|m| m.id() == "pacman"))
+        .ok_or_else(|| anyhow!("None of system.package_manager_priority is installed"))?;
+    let aur_capable = helper.id() != "pacman";
+
+    ui::section("Searching repos and AUR");
+    let mut candidates = repo_candidates(query);
+    match aur_candidates(query) {
+        Ok(aur) => candidates.extend(aur.into_iter().take(MAX_AUR_RESULTS)),
+        Err(e) => ui::skip(&format!("AUR search failed: {}", e)),
+    }
+    if candidates.is_empty() {
+        ui::fail(&format!("No results found for '{}'", query));
+        return Ok(());
+    }
+    if !crate::ui::interactive() {
+        return Err(anyhow!("--pick needs a terminal; install by name with vg install <package>"));
+    }
+
+    let labels: Vec<String> = candidates.iter()
+        .map(|c| {
+            let votes = c.votes.map(|v| format!("  +{}", v)).unwrap_or_default();
+            let desc: String = c.description.chars().take(60).collect();
+            format!("{}/{} {}{}  {}", c.repo, c.name, c.version, votes, desc)
+        })
+        .collect();
+    let picked = MultiSelect::new(&format!("Install with {}:", helper.display_name()), labels.clone())
+        .with_page_size(15)
+        .prompt()?;
+    let chosen: Vec<&Candidate> = picked.iter()
+        .filter_map(|p| labels.iter().position(|l| l == p))
+        .map(|i| &candidates[i])
+        .collect();
+    if chosen.is_empty() {
+        ui::skip("Nothing selected");
+        return Ok(());
+    }
+
+    for c in chosen {
+        ui::section(&format!("Installing {} via {}", c.name, helper.display_name()));
+        if c.repo == "aur" && !aur_capable {
+            ui::fail(&format!("{} is in the AUR — install yay or paru (or put one first in system.package_manager_priority)", c.name));
+            continue;
+        }
+        // pamac builds AUR packages with a separate subcommand
+        let result = if c.repo == "aur" && helper.id() == "pamac" {
+            let mut args = vec!["pamac", "build", c.name.as_str()];
+            if yes { args.push("--no-confirm"); }
+            crate::package_managers::run_cmd(&args, false)
+        } else {
+            helper.install(&c.name, yes)
+        };
+        match result {
+            Ok(()) => {
+                super::pkg::record("install", helper.id(), &c.name);
+                ui::success(&format!("'{}' installed successfully.", c.name));
+            }
+            Err(e) => ui::fail(&format!("{}: {}", c.name, e)),
+        }
+    }
+    Ok(())
+}
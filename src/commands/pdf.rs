@@ -0,0 +1,388 @@
+use crate::history::Recorder;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use lopdf::{dictionary, Document, Object, ObjectId, SaveOptions};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use which::which;
+
+/// Page attributes a page may inherit from its parent `Pages` node instead of carrying them itself.
+const INHERITABLE: &[&[u8]] = &[b"Resources", b"MediaBox", b"CropBox", b"Rotate"];
+
+/// Ghostscript presets for `vg pdf compress`.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum Quality {
+    /// 72 dpi images — smallest, fine for reading on screen
+    Screen,
+    /// 150 dpi images
+    Ebook,
+    /// 300 dpi images
+    Printer,
+}
+
+impl Quality {
+    fn preset(self) -> &'static str {
+        match self {
+            Quality::Screen => "/screen",
+            Quality::Ebook => "/ebook",
+            Quality::Printer => "/printer",
+        }
+    }
+}
+
+/// A page selection like `1-3,5,8-`; each comma-separated part is one group.
+struct Range {
+    label: String,
+    pages: Vec<u32>,
+}
+
+fn parse_ranges(spec: &str, total: u32) -> Result<Vec<Range>> {
+    let mut ranges = Vec::new();
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let page = |s: &str, default: u32| -> Result<u32> {
+            if s.is_empty() {
+                return Ok(default);
+            }
+            let n: u32 = s.parse().map_err(|_| anyhow!("Invalid page '{}' in '{}'", s, spec))?;
+            if n == 0 || n > total {
+                return Err(anyhow!("Page {} is out of range — the document has {} pages", n, total));
+            }
+            Ok(n)
+        };
+        let (from, to) = match part.split_once('-') {
+            Some((a, b)) => (page(a.trim(), 1)?, page(b.trim(), total)?),
+            None => {
+                let n = page(part, 1)?;
+                (n, n)
+            }
+        };
+        if from > to {
+            return Err(anyhow!("Range {} runs backwards", part));
+        }
+        let label = if from == to { from.to_string() } else { format!("{}-{}", from, to) };
+        ranges.push(Range { label, pages: (from..=to).collect() });
+    }
+    if ranges.is_empty() {
+        return Err(anyhow!("No pages selected by '{}'", spec));
+    }
+    Ok(ranges)
+}
+
+fn tool(name: &str) -> Option<&str> {
+    which(name).is_ok().then_some(name)
+}
+
+fn run_ok(mut cmd: Command) -> Result<()> {
+    let program = cmd.get_program().to_string_lossy().to_string();
+    let out = crate::logging::output(&mut cmd).with_context(|| format!("Failed to run {}", program))?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        return Err(anyhow!("{} failed: {}", program, stderr.lines().next().unwrap_or("no output").trim()));
+    }
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<Document> {
+    let doc = Document::load(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    if doc.is_encrypted() {
+        return Err(anyhow!("{} is password-protected — decrypt it first (qpdf --decrypt)", path.display()));
+    }
+    Ok(doc)
+}
+
+/// Copy inherited attributes onto every page, so pages can be moved to a new page tree.
+fn flatten_inherited(doc: &mut Document) {
+    let pages: Vec<ObjectId> = doc.page_iter().collect();
+    for page_id in pages {
+        let Ok(page) = doc.get_dictionary(page_id) else { continue };
+        let mut missing: Vec<&[u8]> = INHERITABLE.iter().copied().filter(|k| !page.has(k)).collect();
+        let mut found: Vec<(&[u8], Object)> = Vec::new();
+        let mut parent = page.get(b"Parent").and_then(Object::as_reference).ok();
+        // Bounded, in case a damaged file has a loop in its page tree
+        for _ in 0..32 {
+            let Some(node) = parent.and_then(|id| doc.get_dictionary(id).ok()) else { break };
+            missing.retain(|k| match node.get(k) {
+                Ok(v) => {
+                    found.push((k, v.clone()));
+                    false
+                }
+                Err(_) => true,
+            });
+            parent = node.get(b"Parent").and_then(Object::as_reference).ok();
+        }
+        if let Ok(page) = doc.get_dictionary_mut(page_id) {
+            for (key, value) in found {
+                page.set(key, value);
+            }
+        }
+    }
+}
+
+/// A copy of `doc` containing only `pages`, in that order.
+fn subset(doc: &Document, pages: &[u32]) -> Result<Document> {
+    let mut doc = doc.clone();
+    let by_number = doc.get_pages();
+    let kids: Vec<ObjectId> = pages.iter().filter_map(|n| by_number.get(n).copied()).collect();
+    let root = doc.catalog()?.get(b"Pages")?.as_reference()?;
+    for id in &kids {
+        doc.get_dictionary_mut(*id)?.set("Parent", root);
+    }
+    let tree = doc.get_dictionary_mut(root)?;
+    tree.set("Kids", kids.iter().map(|id| Object::Reference(*id)).collect::<Vec<_>>());
+    tree.set("Count", kids.len() as i64);
+    // Bookmarks would point at pages that are gone
+    doc.catalog_mut()?.remove(b"Outlines");
+    doc.prune_objects();
+    doc.renumber_objects();
+    Ok(doc)
+}
+
+fn merge_docs(inputs: &[PathBuf]) -> Result<Document> {
+    let mut out = Document::with_version("1.5");
+    let pages_id = out.new_object_id();
+    let mut kids: Vec<ObjectId> = Vec::new();
+    let mut next_id = out.max_id + 1;
+    for path in inputs {
+        let mut doc = load(path)?;
+        flatten_inherited(&mut doc);
+        doc.renumber_objects_with(next_id);
+        next_id = doc.max_id + 1;
+        let pages: Vec<ObjectId> = doc.get_pages().into_values().collect();
+        for (id, object) in doc.objects {
+            // Each input's catalog, page tree and bookmarks are replaced by the merged ones
+            if !matches!(object.type_name().unwrap_or(b""), b"Catalog" | b"Pages" | b"Outlines" | b"Outline") {
+                out.objects.insert(id, object);
+            }
+        }
+        for id in pages {
+            out.get_dictionary_mut(id)?.set("Parent", pages_id);
+            kids.push(id);
+        }
+    }
+    out.max_id = next_id - 1;
+    out.objects.insert(pages_id, Object::Dictionary(dictionary! {
+        "Type" => "Pages",
+        "Kids" => kids.iter().map(|id| Object::Reference(*id)).collect::<Vec<_>>(),
+        "Count" => kids.len() as i64,
+    }));
+    let catalog_id = out.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    out.trailer.set("Root", catalog_id);
+    out.prune_objects();
+    out.renumber_objects();
+    Ok(out)
+}
+
+fn ensure_new(path: &Path) -> Result<()> {
+    if path.exists() {
+        return Err(anyhow!("{} already exists — choose another with --output", path.display()));
+    }
+    Ok(())
+}
+
+fn size(path: &Path) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+/// `vg pdf merge a.pdf b.pdf … -o out.pdf`
+pub fn merge(inputs: Vec<PathBuf>, output: PathBuf) -> Result<()> {
+    ui::print_header("PDF MERGE");
+    if inputs.len() < 2 {
+        return Err(anyhow!("Give at least two PDFs to merge"));
+    }
+    if let Some(missing) = inputs.iter().find(|p| !p.is_file()) {
+        return Err(anyhow!("{} does not exist", missing.display()));
+    }
+    ensure_new(&output)?;
+
+    match merge_docs(&inputs) {
+        Ok(mut doc) => {
+            doc.save(&output).with_context(|| format!("Cannot write {}", output.display()))?;
+        }
+        Err(e) => {
+            let Some(qpdf) = tool("qpdf") else {
+                return Err(e.context("Install qpdf for PDFs this reader can't handle: vg install qpdf"));
+            };
+            ui::skip(&format!("{:#} — falling back to qpdf", e));
+            let mut c = Command::new(qpdf);
+            c.arg("--empty").arg("--pages").args(&inputs).arg("--").arg(&output);
+            run_ok(c)?;
+        }
+    }
+    ui::info_line("Inputs", &inputs.len().to_string());
+    ui::success(&format!("Wrote {} ({})", output.display(), super::search::fmt_bytes(size(&output))));
+    Ok(())
+}
+
+/// `vg pdf split in.pdf [--pages 1-3,4-]` — one file per page, or per comma-separated range.
+pub fn split(input: PathBuf, pages: Option<String>, output: Option<PathBuf>) -> Result<()> {
+    ui::print_header("PDF SPLIT");
+    let dir = output.unwrap_or_else(|| input.parent().map(Path::to_path_buf).unwrap_or_default());
+    let stem = input.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let target = |label: &str| dir.join(format!("{}_{}.pdf", stem, label));
+
+    let doc = load(&input);
+    let total = match &doc {
+        Ok(d) => d.get_pages().len() as u32,
+        Err(_) => qpdf_pages(&input)?,
+    };
+    let ranges = match &pages {
+        Some(spec) => parse_ranges(spec, total)?,
+        None => (1..=total).map(|n| Range { label: n.to_string(), pages: vec![n] }).collect(),
+    };
+    for r in &ranges {
+        ensure_new(&target(&r.label))?;
+    }
+    std::fs::create_dir_all(&dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+
+    match doc {
+        Ok(mut doc) => {
+            flatten_inherited(&mut doc);
+            for r in &ranges {
+                subset(&doc, &r.pages)?.save(target(&r.label))?;
+            }
+        }
+        Err(e) => {
+            ui::skip(&format!("{:#} — falling back to qpdf", e));
+            for r in &ranges {
+                let mut c = Command::new("qpdf");
+                c.arg("--empty").arg("--pages").arg(&input).arg(&r.label).arg("--").arg(target(&r.label));
+                run_ok(c)?;
+            }
+        }
+    }
+    ui::info_line("Pages", &format!("{} of {}", ranges.iter().map(|r| r.pages.len()).sum::<usize>(), total));
+    ui::success(&format!("Wrote {} files to {}", ranges.len(), if dir.as_os_str().is_empty() { Path::new(".") } else { &dir }.display()));
+    Ok(())
+}
+
+/// Page count via qpdf, for files lopdf can't parse.
+fn qpdf_pages(input: &Path) -> Result<u32> {
+    if tool("qpdf").is_none() {
+        load(input)?;
+    }
+    let mut c = Command::new("qpdf");
+    c.arg("--show-npages").arg(input);
+    let out = crate::logging::output(&mut c).context("Failed to run qpdf")?;
+    String::from_utf8_lossy(&out.stdout).trim().parse()
+        .map_err(|_| anyhow!("qpdf could not read {}", input.display()))
+}
+
+/// `vg pdf extract-text in.pdf [--pages …] [-o out.txt]` — plain text to stdout or a file.
+pub fn extract_text(input: PathBuf, pages: Option<String>, output: Option<PathBuf>) -> Result<()> {
+    let text = match load(&input).and_then(|doc| {
+        let total = doc.get_pages().len() as u32;
+        let numbers: Vec<u32> = match &pages {
+            Some(spec) => parse_ranges(spec, total)?.into_iter().flat_map(|r| r.pages).collect(),
+            None => (1..=total).collect(),
+        };
+        Ok(doc.extract_text(&numbers)?)
+    }) {
+        Ok(text) if !text.trim().is_empty() => text,
+        // Scanned pages and unusual font encodings come out empty; poppler handles more of them
+        result => match tool("pdftotext") {
+            Some(pdftotext) => pdftotext_text(pdftotext, &input, pages.as_deref())?,
+            None => match result {
+                Ok(_) => return Err(anyhow!("No text found — scanned PDFs need OCR, or try: vg install poppler-utils")),
+                Err(e) => return Err(e.context("Install pdftotext for PDFs this reader can't handle: vg install poppler-utils")),
+            },
+        },
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, &text).with_context(|| format!("Cannot write {}", path.display()))?;
+            ui::success(&format!("Wrote {} lines to {}", text.lines().count(), path.display()));
+        }
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+fn pdftotext_text(program: &str, input: &Path, pages: Option<&str>) -> Result<String> {
+    let groups: Vec<Option<(u32, u32)>> = match pages {
+        Some(spec) => parse_ranges(spec, u32::MAX)?.into_iter()
+            .map(|r| Some((r.pages[0], *r.pages.last().unwrap_or(&r.pages[0]))))
+            .collect(),
+        None => vec![None],
+    };
+    let mut text = String::new();
+    for group in groups {
+        let mut c = Command::new(program);
+        c.arg("-layout");
+        if let Some((first, last)) = group {
+            c.arg("-f").arg(first.to_string()).arg("-l").arg(last.to_string());
+        }
+        c.arg(input).arg("-");
+        let out = crate::logging::output(&mut c).with_context(|| format!("Failed to run {}", program))?;
+        if !out.status.success() {
+            return Err(anyhow!("{} could not read {}", program, input.display()));
+        }
+        text.push_str(&String::from_utf8_lossy(&out.stdout));
+    }
+    Ok(text)
+}
+
+/// `vg pdf compress in.pdf` — Ghostscript when installed (downsamples images), otherwise a lossless
+/// rewrite with compressed object streams. Replaces the input through the undo journal unless
+/// --output is given, and only when the result is smaller.
+pub fn compress(input: PathBuf, output: Option<PathBuf>, quality: Quality) -> Result<()> {
+    ui::print_header("PDF COMPRESS");
+    if !input.is_file() {
+        return Err(anyhow!("{} does not exist", input.display()));
+    }
+    if let Some(out) = &output {
+        ensure_new(out)?;
+    }
+    let before = size(&input);
+
+    let bytes = match tool("gs") {
+        Some(gs) => {
+            ui::info_line("Using", &format!("Ghostscript ({})", quality.preset().trim_start_matches('/')));
+            let tmp = tempfile::Builder::new().prefix("vg-pdf-").suffix(".pdf").tempfile()?;
+            let mut c = Command::new(gs);
+            c.args(["-sDEVICE=pdfwrite", "-dCompatibilityLevel=1.5", "-dNOPAUSE", "-dQUIET", "-dBATCH", "-dSAFER"])
+                .arg(format!("-dPDFSETTINGS={}", quality.preset()))
+                .arg(format!("-sOutputFile={}", tmp.path().display()))
+                .arg(&input);
+            run_ok(c)?;
+            std::fs::read(tmp.path())?
+        }
+        None => {
+            ui::info_line("Using", "built-in (lossless) — install ghostscript to downsample images");
+            let mut doc = load(&input)?;
+            doc.delete_zero_length_streams();
+            doc.prune_objects();
+            doc.compress();
+            let mut bytes = Vec::new();
+            let options = SaveOptions::builder().use_object_streams(true).use_xref_streams(true).compression_level(9).build();
+            doc.save_with_options(&mut bytes, options)?;
+            bytes
+        }
+    };
+    let after = bytes.len() as u64;
+    ui::info_line("Before", &super::search::fmt_bytes(before));
+    ui::info_line("After", &super::search::fmt_bytes(after));
+
+    if after >= before {
+        ui::success("Already as small as it gets — left unchanged");
+        return Ok(());
+    }
+    let saved = format!(
+        "Saved {} ({:.0}%)",
+        super::search::fmt_bytes(before - after), (before - after) as f64 * 100.0 / before.max(1) as f64,
+    );
+    match output {
+        Some(out) => {
+            std::fs::write(&out, &bytes).with_context(|| format!("Cannot write {}", out.display()))?;
+            ui::success(&format!("{} — wrote {}", saved, out.display()));
+        }
+        None => {
+            let mut recorder = Recorder::new("pdf compress", &input.display().to_string());
+            recorder.write(&input, &bytes)?;
+            recorder.finish()?;
+            ui::success(&saved);
+            ui::skip("Changed your mind? vg undo");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use crate::ui;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use inquire::MultiSelect;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Issue {
+    WorldWritable,
+    Setuid,
+    BrokenSymlink,
+    ForeignOwner,
+    /// Keys and credentials readable by group or others
+    PrivateReadable,
+}
+
+impl Issue {
+    fn label(&self) -> &'static str {
+        match self {
+            Issue::WorldWritable => "world-writable",
+            Issue::Setuid => "setuid/setgid",
+            Issue::BrokenSymlink => "broken symlink",
+            Issue::ForeignOwner => "foreign owner",
+            Issue::PrivateReadable => "private file readable by others",
+        }
+    }
+
+    fn fix(&self) -> &'static str {
+        match self {
+            Issue::WorldWritable => "chmod o-w",
+            Issue::Setuid => "chmod ug-s",
+            Issue::BrokenSymlink => "remove the link",
+            Issue::ForeignOwner => "sudo chown to you",
+            Issue::PrivateReadable => "chmod go-rwx",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding {
+    pub issue: Issue,
+    pub path: PathBuf,
+    pub detail: String,
+}
+
+/// Directories under home whose files must stay private.
+const PRIVATE_DIRS: &[&str] = &[".ssh", ".gnupg", ".aws", ".kube", ".docker", ".netrc"];
+
+#[cfg(unix)]
+fn scan(root: &Path) -> Vec<Finding> {
+    use std::os::unix::fs::MetadataExt;
+
+    let uid = unsafe { libc::getuid() };
+    let home = dirs::home_dir().unwrap_or_default();
+    let private: Vec<PathBuf> = PRIVATE_DIRS.iter().map(|d| home.join(d)).collect();
+    let ssh_config = home.join(".ssh").join("config");
+
+    let options = crate::walk::Options { same_file_system: true, ..Default::default() };
+    let walked = crate::walk::walk(root, &options, |entry| {
+        let path = entry.path();
+        let meta = std::fs::symlink_metadata(path).ok()?;
+        let mode = meta.mode();
+        let mut findings = Vec::new();
+
+        if meta.file_type().is_symlink() {
+            if std::fs::metadata(path).is_err() {
+                let target = std::fs::read_link(path).map(|t| t.display().to_string()).unwrap_or_default();
+                findings.push(Finding { issue: Issue::BrokenSymlink, path: path.to_path_buf(), detail: format!("→ {}", target) });
+            }
+            return Some(findings);
+        }
+        // /tmp-style directories are world-writable on purpose; the sticky bit makes that safe
+        if mode & 0o002 != 0 && !(meta.is_dir() && mode & 0o1000 != 0) {
+            findings.push(Finding { issue: Issue::WorldWritable, path: path.to_path_buf(), detail: format!("mode {:o}", mode & 0o7777) });
+        }
+        if meta.is_file() && mode & 0o6000 != 0 {
+            findings.push(Finding { issue: Issue::Setuid, path: path.to_path_buf(), detail: format!("mode {:o}", mode & 0o7777) });
+        }
+        if path.starts_with(&home) && meta.uid() != uid {
+            let owner = users_name(meta.uid());
+            findings.push(Finding { issue: Issue::ForeignOwner, path: path.to_path_buf(), detail: format!("owned by {}", owner) });
+        }
+        if meta.is_file() && mode & 0o077 != 0 && private.iter().any(|p| path.starts_with(p)) {
+            // Public keys, known_hosts and ~/.ssh/config are meant to be readable; ~/.aws/config and the like are not
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if !name.ends_with(".pub") && name != "known_hosts" && path != ssh_config {
+                findings.push(Finding { issue: Issue::PrivateReadable, path: path.to_path_buf(), detail: format!("mode {:o}", mode & 0o777) });
+            }
+        }
+        Some(findings)
+    });
+    let mut findings: Vec<Finding> = walked.items.into_iter().flatten().collect();
+    findings.sort_by(|a, b| a.path.cmp(&b.path));
+    findings
+}
+
+#[cfg(not(unix))]
+fn scan(_root: &Path) -> Vec<Finding> {
+    Vec::new()
+}
+
+/// Best-effort uid → name via /etc/passwd.
+#[cfg(unix)]
+fn users_name(uid: u32) -> String {
+    std::fs::read_to_string("/etc/passwd").ok()
+        .and_then(|p| p.lines()
+            .map(|l| l.split(':').collect::<Vec<_>>())
+            .find(|f| f.len() > 2 && f[2] == uid.to_string())
+            .map(|f| f[0].to_string()))
+        .unwrap_or_else(|| format!("uid {}", uid))
+}
+
+#[cfg(unix)]
+fn apply_fix(f: &Finding) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let set_mode = |clear: u32| -> Result<()> {
+        let mode = std::fs::metadata(&f.path)?.permissions().mode();
+        std::fs::set_permissions(&f.path, std::fs::Permissions::from_mode(mode & !clear))?;
+        Ok(())
+    };
+    match f.issue {
+        Issue::WorldWritable => set_mode(0o002),
+        Issue::Setuid => set_mode(0o6000),
+        Issue::PrivateReadable => set_mode(0o077),
+        Issue::BrokenSymlink => Ok(std::fs::remove_file(&f.path)?),
+        Issue::ForeignOwner => {
+            let owner = format!("{}:", whoami::username());
+            let path = f.path.to_string_lossy();
+            crate::package_managers::run_cmd(&["chown", "-h", &owner, &path], true)
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn apply_fix(_f: &Finding) -> Result<()> {
+    Ok(())
+}
+
+fn write_report(findings: &[Finding], path: &Path) -> Result<()> {
+    let content = if path.extension().is_some_and(|e| e == "json") {
+        serde_json::to_string_pretty(findings)?
+    } else {
+        let mut out = format!("vg perms audit — {}\n\n", chrono::Local::now().format("%Y-%m-%d %H:%M"));
+        for f in findings {
+            out.push_str(&format!("{:<32} {}  ({})\n", f.issue.label(), f.path.display(), f.detail));
+        }
+        out
+    };
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// `vg perms audit [path]`
+pub fn audit(path: Option<PathBuf>, fix: bool, report: Option<PathBuf>, json: bool) -> Result<()> {
+    let root = path.or_else(dirs::home_dir).unwrap_or_else(|| PathBuf::from("."));
+    if !cfg!(unix) {
+        ui::skip("Permission auditing is available on Linux and macOS.");
+        return Ok(());
+    }
+    let findings = scan(&root);
+    if !findings.is_empty() {
+        crate::exit::set(crate::exit::NEGATIVE);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&findings)?);
+        return Ok(());
+    }
+
+    ui::print_header("PERMISSIONS AUDIT");
+    ui::info_line("Scanned", &root.display().to_string());
+
+    let order = [Issue::PrivateReadable, Issue::Setuid, Issue::WorldWritable, Issue::ForeignOwner, Issue::BrokenSymlink];
+    for issue in order {
+        let group: Vec<&Finding> = findings.iter().filter(|f| f.issue == issue).collect();
+        if group.is_empty() {
+            continue;
+        }
+        ui::section(&format!("{} ({})", issue.label(), group.len()));
+        for f in group.iter().take(25) {
+            println!("  {} {}  {}", "✗".truecolor(248, 113, 113), f.path.display().to_string().truecolor(224, 242, 254), f.detail.truecolor(100, 116, 139));
+        }
+        if group.len() > 25 {
+            ui::skip(&format!("… and {} more (use --report to see all)", group.len() - 25));
+        }
+    }
+    println!();
+
+    if let Some(report) = &report {
+        write_report(&findings, report)?;
+        ui::success(&format!("Report written to {}", report.display()));
+    }
+    if findings.is_empty() {
+        ui::success("No permission problems found");
+        return Ok(());
+    }
+    if !fix {
+        ui::skip(&format!("{} issues — run with --fix to repair them interactively", findings.len()));
+        return Ok(());
+    }
+    if !crate::ui::interactive() {
+        ui::skip("--fix needs an interactive terminal");
+        return Ok(());
+    }
+
+    let options: Vec<String> = findings.iter()
+        .map(|f| format!("{} — {} ({})", f.path.display(), f.issue.label(), f.issue.fix()))
+        .collect();
+    let all: Vec<usize> = (0..options.len()).collect();
+    let chosen = MultiSelect::new("Fix which?", options.clone())
+        .with_default(&all)
+        .with_page_size(15)
+        .prompt()?;
+
+    let mut fixed = 0;
+    for choice in chosen {
+        let Some(idx) = options.iter().position(|o| *o == choice) else { continue };
+        let f = &findings[idx];
+        match apply_fix(f) {
+            Ok(()) => {
+                fixed += 1;
+                ui::success(&format!("{}: {}", f.issue.fix(), f.path.display()));
+            }
+            Err(e) => ui::fail(&format!("{}: {}", f.path.display(), e)),
+        }
+    }
+    println!();
+    ui::success(&format!("Fixed {} of {} issues", fixed, findings.len()));
+    Ok(())
+}
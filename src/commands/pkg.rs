@@ -0,0 +1,385 @@
+use crate::config::ConfigManager;
+use crate::package_managers::{get_available_managers, run_cmd, PmOrphan};
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+use inquire::{Confirm, MultiSelect};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use which::which;
+
+/// One install or removal done through vg.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    time: DateTime<Utc>,
+    /// "install" or "remove"
+    action: String,
+    /// Package manager id (see `PackageManager::id`)
+    manager: String,
+    package: String,
+}
+
+fn history_path() -> PathBuf {
+    ConfigManager::data_dir().join("packages.json")
+}
+
+fn load_history(path: &Path) -> Result<Vec<Entry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Append to the package history. Failures are logged, never fatal: the package is already installed.
+pub fn record(action: &str, manager: &str, package: &str) {
+    let path = history_path();
+    let mut entries = match load_history(&path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::warn!(error = %e, "package history unreadable, not recording");
+            return;
+        }
+    };
+    entries.push(Entry { time: Utc::now(), action: action.into(), manager: manager.into(), package: package.into() });
+    let written = path.parent().map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(&path, serde_json::to_string_pretty(&entries).unwrap_or_default()));
+    if let Err(e) = written {
+        tracing::warn!(error = %e, "could not write package history");
+    }
+}
+
+/// Packages installed through vg and not removed since: (manager, package) in install order.
+fn net_set(entries: &[Entry]) -> Vec<(String, String)> {
+    let mut set: Vec<(String, String)> = Vec::new();
+    for e in entries {
+        let key = (e.manager.clone(), e.package.clone());
+        set.retain(|k| *k != key);
+        if e.action == "install" {
+            set.push(key);
+        }
+    }
+    set
+}
+
+fn fmt_size(size: Option<u64>) -> String {
+    size.map(super::search::fmt_bytes).unwrap_or_else(|| "?".into())
+}
+
+/// dnf has no backend in package_managers yet, so its orphans are read here directly.
+fn dnf_orphans() -> Vec<PmOrphan> {
+    let out = crate::logging::output(Command::new("dnf").args(["repoquery", "--unneeded", "--installed", "--qf", "%{name} %{installsize}\n"]));
+    let Ok(out) = out else { return vec![] };
+    String::from_utf8_lossy(&out.stdout).lines()
+        .filter_map(|l| {
+            let (name, size) = l.trim().split_once(' ')?;
+            Some((name.to_string(), size.parse().ok()))
+        })
+        .collect()
+}
+
+/// `vg pkg autoremove` — remove dependencies nothing needs any more.
+pub fn autoremove(dry_run: bool, yes: bool) -> Result<()> {
+    ui::print_header("AUTOREMOVE");
+
+    // (manager id, display name, orphans)
+    let mut found: Vec<(String, String, Vec<PmOrphan>)> = get_available_managers().iter()
+        .map(|m| (m.id().to_string(), m.display_name().to_string(), m.orphans()))
+        .filter(|(_, _, orphans)| !orphans.is_empty())
+        .collect();
+    if which("dnf").is_ok() {
+        let orphans = dnf_orphans();
+        if !orphans.is_empty() {
+            found.push(("dnf".into(), "DNF (Fedora)".into(), orphans));
+        }
+    }
+    if found.is_empty() {
+        ui::success("No orphaned packages");
+        return Ok(());
+    }
+
+    let mut labels = Vec::new();
+    for (_, display, orphans) in &found {
+        ui::section(display);
+        for (name, size) in orphans {
+            println!("  {:>10}  {}", fmt_size(*size).truecolor(96, 165, 250), name.truecolor(224, 242, 254));
+            labels.push(format!("{}  [{}]  {}", name, display, fmt_size(*size)));
+        }
+    }
+    let total: u64 = found.iter().flat_map(|(_, _, o)| o).filter_map(|(_, s)| *s).sum();
+    println!();
+    ui::info_line("Reclaimable", &format!("about {}", super::search::fmt_bytes(total)));
+    println!();
+    if dry_run {
+        ui::skip("Dry run — nothing removed");
+        return Ok(());
+    }
+
+    // Same order as `labels`
+    let all: Vec<(usize, &PmOrphan)> = found.iter().enumerate()
+        .flat_map(|(i, (_, _, orphans))| orphans.iter().map(move |o| (i, o)))
+        .collect();
+    let chosen: Vec<usize> = if yes {
+        (0..all.len()).collect()
+    } else if crate::ui::interactive() {
+        let defaults: Vec<usize> = (0..all.len()).collect();
+        let picked = MultiSelect::new("Remove which?", labels.clone()).with_default(&defaults).prompt()?;
+        picked.iter().filter_map(|p| labels.iter().position(|l| l == p)).collect()
+    } else {
+        ui::skip("Not a terminal — pass --yes to remove all of them");
+        return Ok(());
+    };
+    if chosen.is_empty() {
+        ui::skip("Nothing selected");
+        return Ok(());
+    }
+
+    let mut freed = 0;
+    for (i, (id, display, _)) in found.iter().enumerate() {
+        let picked: Vec<&PmOrphan> = chosen.iter().map(|&c| all[c]).filter(|(m, _)| *m == i).map(|(_, o)| o).collect();
+        if picked.is_empty() {
+            continue;
+        }
+        let names: Vec<String> = picked.iter().map(|(n, _)| n.clone()).collect();
+        let result = if id == "dnf" {
+            let mut args = vec!["dnf", "remove", "-y"];
+            args.extend(names.iter().map(String::as_str));
+            run_cmd(&args, true)
+        } else {
+            get_available_managers().into_iter().find(|m| m.id() == id)
+                .ok_or_else(|| anyhow!("Unknown package manager {}", id))
+                .and_then(|m| m.remove_orphans(&names))
+        };
+        match result {
+            Ok(()) => {
+                names.iter().for_each(|n| record("remove", id, n));
+                freed += picked.iter().filter_map(|(_, s)| *s).sum::<u64>();
+                ui::success(&format!("{}: removed {} package(s)", display, names.len()));
+            }
+            Err(e) => {
+                crate::exit::set(crate::exit::ERROR);
+                ui::fail(&format!("{}: {}", display, e));
+            }
+        }
+    }
+    println!();
+    ui::success(&format!("Freed about {}", super::search::fmt_bytes(freed)));
+    Ok(())
+}
+
+/// Package databases that know which files they installed.
+const FILE_DBS: [&str; 4] = ["pacman", "dpkg", "rpm", "brew"];
+
+fn query(program: &str, args: &[&str]) -> Option<String> {
+    let out = crate::logging::output(Command::new(program).env("LC_ALL", "C").args(args)).ok()?;
+    out.status.success().then(|| String::from_utf8_lossy(&out.stdout).trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Owning package of `path` according to one database.
+fn owner(db: &str, path: &str) -> Option<String> {
+    match db {
+        "pacman" => query("pacman", &["-Qoq", path]),
+        // "coreutils: /usr/bin/ls"; diversions add extra lines
+        "dpkg" => query("dpkg", &["-S", path])?.lines()
+            .find(|l| !l.starts_with("diversion"))
+            .and_then(|l| l.split_once(": ")).map(|(pkgs, _)| pkgs.to_string()),
+        "rpm" => query("rpm", &["-qf", "--qf", "%{NAME}\n", path]),
+        // Homebrew links into its prefix from Cellar/<formula>/<version>/…
+        "brew" => {
+            let real = std::fs::canonicalize(path).ok()?;
+            let mut parts = real.components().map(|c| c.as_os_str().to_string_lossy().to_string());
+            parts.find(|c| c == "Cellar" || c == "Caskroom")?;
+            parts.next()
+        }
+        _ => None,
+    }
+}
+
+/// `vg pkg owns <path>` — which package installed a file. Bare names are looked up on PATH.
+pub fn owns(path: &str) -> Result<()> {
+    ui::print_header("PKG OWNS");
+    let resolved = if path.contains(std::path::MAIN_SEPARATOR) {
+        std::path::absolute(path)?
+    } else {
+        which(path).map_err(|_| anyhow!("{} is neither a file path nor a command on PATH", path))?
+    };
+    if !resolved.exists() {
+        return Err(anyhow!("{} does not exist", resolved.display()));
+    }
+    let path = resolved.to_string_lossy().to_string();
+    ui::info_line("File", &path);
+    if let Ok(real) = std::fs::canonicalize(&resolved) {
+        if real != resolved {
+            ui::info_line("Links to", &real.to_string_lossy());
+        }
+    }
+    println!();
+
+    // On merged-/usr systems dpkg records /bin/ls while PATH finds /usr/bin/ls
+    let mut candidates = vec![path.clone()];
+    if let Ok(real) = std::fs::canonicalize(&resolved) {
+        candidates.push(real.to_string_lossy().to_string());
+    }
+    for (merged, legacy) in [("/usr/bin/", "/bin/"), ("/usr/sbin/", "/sbin/"), ("/usr/lib/", "/lib/")] {
+        if let Some(rest) = path.strip_prefix(merged) {
+            candidates.push(format!("{}{}", legacy, rest));
+        }
+    }
+    candidates.dedup();
+
+    let mut found = false;
+    for db in FILE_DBS.iter().filter(|db| which(db).is_ok()) {
+        if let Some(pkg) = candidates.iter().find_map(|c| owner(db, c)) {
+            ui::success(&format!("{}  ({})", pkg, db));
+            found = true;
+        }
+    }
+    if !found {
+        ui::skip("No package database owns it — installed by hand, by a script, or by a language package manager");
+    }
+    Ok(())
+}
+
+/// `vg pkg files <package>` — files a package installed.
+pub fn files(package: &str) -> Result<()> {
+    ui::print_header(&format!("PKG FILES  {}", package));
+    for db in FILE_DBS.iter().filter(|db| which(db).is_ok()) {
+        let list = match *db {
+            "pacman" => query("pacman", &["-Qlq", package]),
+            "dpkg" => query("dpkg", &["-L", package]),
+            "rpm" => query("rpm", &["-ql", package]).filter(|l| !l.contains("is not installed")),
+            "brew" => query("brew", &["list", "--verbose", package]),
+            _ => None,
+        };
+        let Some(list) = list else { continue };
+        // Directories are listed too; only files are interesting
+        let files: Vec<&str> = list.lines().filter(|l| !std::path::Path::new(l).is_dir()).collect();
+        ui::section(&format!("{} ({} files)", db, files.len()));
+        for file in files {
+            println!("  {}", file);
+        }
+        return Ok(());
+    }
+    Err(anyhow!("{} is not installed through pacman, dpkg, rpm or brew", package))
+}
+
+/// `vg pkg history [--export file]` — installs and removals done through vg.
+pub fn history(export: Option<PathBuf>) -> Result<()> {
+    ui::print_header("PKG HISTORY");
+    let entries = load_history(&history_path())?;
+    if entries.is_empty() {
+        ui::skip("Nothing installed through vg yet");
+        return Ok(());
+    }
+
+    if let Some(out) = export {
+        let set = net_set(&entries);
+        let mut text = format!(
+            "# Packages installed with vg on {} — vg pkg replay {}\n",
+            sysinfo::System::host_name().unwrap_or_default(),
+            out.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+        );
+        for (manager, package) in &set {
+            text.push_str(&format!("{} {}\n", manager, package));
+        }
+        std::fs::write(&out, text).with_context(|| format!("Cannot write {}", out.display()))?;
+        ui::success(&format!("{} packages written to {}", set.len(), out.display()));
+        return Ok(());
+    }
+
+    for e in &entries {
+        let (mark, color) = if e.action == "install" { ("+", (74, 222, 128)) } else { ("-", (248, 113, 113)) };
+        println!(
+            "  {}  {} {}  {}",
+            e.time.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string().truecolor(71, 85, 105),
+            mark.truecolor(color.0, color.1, color.2),
+            e.package.truecolor(224, 242, 254),
+            format!("({})", e.manager).truecolor(96, 165, 250),
+        );
+    }
+    println!();
+    ui::info_line("Still installed", &net_set(&entries).len().to_string());
+    ui::skip("Take them to another machine: vg pkg history --export packages.txt, then vg pkg replay packages.txt");
+    Ok(())
+}
+
+/// Packages listed in a replay file: `manager package` lines (or bare package names),
+/// or a copy of another machine's packages.json.
+fn read_replay(path: &Path) -> Result<Vec<(Option<String>, String)>> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    if let Ok(entries) = serde_json::from_str::<Vec<Entry>>(&text) {
+        return Ok(net_set(&entries).into_iter().map(|(m, p)| (Some(m), p)).collect());
+    }
+    Ok(text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| match l.split_once(char::is_whitespace) {
+            Some((manager, package)) => (Some(manager.to_string()), package.trim().to_string()),
+            None => (None, l.to_string()),
+        })
+        .collect())
+}
+
+/// `vg pkg replay <file>` — install the packages from another machine's history.
+pub fn replay(file: &Path, yes: bool) -> Result<()> {
+    ui::print_header("PKG REPLAY");
+    let wanted = read_replay(file)?;
+    if wanted.is_empty() {
+        ui::skip("No packages in the file");
+        return Ok(());
+    }
+    let managers = get_available_managers();
+    // Bare names go to the first available manager, which is the system one when there is one
+    let plan: Vec<(Option<&dyn crate::package_managers::PackageManager>, &str, &str)> = wanted.iter()
+        .map(|(manager, package)| {
+            let m = match manager {
+                Some(id) => managers.iter().find(|m| m.id() == id),
+                None => managers.first(),
+            };
+            (m.map(|m| m.as_ref()), manager.as_deref().unwrap_or("?"), package.as_str())
+        })
+        .collect();
+
+    for (m, id, package) in &plan {
+        match m {
+            Some(m) => ui::info_line(m.id(), package),
+            None => ui::skip(&format!("{} — {} is not available here", package, id)),
+        }
+    }
+    let todo: Vec<_> = plan.iter().filter_map(|(m, _, p)| m.map(|m| (m, *p))).collect();
+    println!();
+    if todo.is_empty() {
+        return Err(anyhow!("None of the package managers in {} are available here", file.display()));
+    }
+    if !yes {
+        if !crate::ui::interactive() {
+            return Err(crate::error::Error::not_a_terminal(&format!("pass --yes to install {} packages", todo.len())).into());
+        }
+        if !Confirm::new(&format!("Install {} packages?", todo.len())).with_default(true).prompt()? {
+            return Ok(());
+        }
+    }
+
+    let mut failed = 0;
+    for (m, package) in &todo {
+        ui::section(&format!("{} via {}", package, m.display_name()));
+        match m.install(package, yes) {
+            Ok(()) => {
+                record("install", m.id(), package);
+                ui::success(&format!("Installed {}", package));
+            }
+            Err(e) => {
+                failed += 1;
+                ui::fail(&format!("{}: {}", package, e));
+            }
+        }
+    }
+    println!();
+    if failed > 0 {
+        return Err(anyhow!("{} of {} packages failed to install", failed, todo.len()));
+    }
+    ui::success(&format!("All {} packages installed", todo.len()));
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::ui;
+use anyhow::Result;
+use colored::Colorize;
+use inquire::Confirm;
+
+/// `vg port <port>` — which process is listening on a port, with an offer to stop it.
+pub fn run(port: u16, kill: bool, force: bool) -> Result<()> {
+    ui::print_header("PORT");
+
+    let listeners: Vec<_> = super::ps::listeners().into_iter().filter(|l| l.port == port).collect();
+    if listeners.is_empty() {
+        ui::success(&format!("Nothing is listening on port {}", port));
+        return Ok(());
+    }
+
+    let procs = super::ps::collect();
+    let mut pids: Vec<u32> = Vec::new();
+    for l in &listeners {
+        ui::section(&format!("{} {} on {}", l.proto.to_uppercase(), port, l.address));
+        let Some(pid) = l.pid else {
+            ui::skip("Owner not visible — run with sudo to see other users' processes");
+            continue;
+        };
+        match procs.iter().find(|p| p.pid == pid) {
+            Some(p) => {
+                ui::info_line("Process", &p.name);
+                ui::info_line("PID", &pid.to_string());
+                ui::info_line("User", &p.user);
+                if !p.command.is_empty() {
+                    ui::info_line("Command", &p.command.chars().take(120).collect::<String>());
+                }
+            }
+            None => ui::info_line("PID", &pid.to_string()),
+        }
+        if !pids.contains(&pid) {
+            pids.push(pid);
+        }
+    }
+
+    if pids.is_empty() {
+        return Ok(());
+    }
+    println!();
+    let confirmed = kill || (crate::ui::interactive()
+        && Confirm::new(&format!("Stop {} (PID {})?", if pids.len() == 1 { "this process" } else { "these processes" },
+            pids.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", ")))
+            .with_default(false)
+            .prompt()?);
+    if !confirmed {
+        println!("  {} {}", "Tip:".truecolor(71, 85, 105), format!("vg port {} --kill", port).truecolor(100, 116, 139));
+        return Ok(());
+    }
+    for pid in pids {
+        match super::ps::kill(pid, force) {
+            Ok(_) => ui::success(&format!("Sent {} to PID {}", if force { "SIGKILL" } else { "SIGTERM" }, pid)),
+            Err(e) => ui::fail(&e.to_string()),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,326 @@
+use crate::ui;
+use anyhow::{anyhow, Result};
+use comfy_table::{Attribute, Cell, CellAlignment, Color, Table};
+use serde::Serialize;
+use std::collections::HashMap;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, Signal, System, Users};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Proc {
+    pub pid: u32,
+    pub ppid: Option<u32>,
+    pub name: String,
+    pub user: String,
+    pub cpu_percent: f32,
+    pub mem_mb: u64,
+    pub command: String,
+    /// Ports this process is listening on
+    pub ports: Vec<u16>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum SortKey {
+    Cpu,
+    Mem,
+    Pid,
+    Name,
+}
+
+/// A listening socket and the process that owns it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Listener {
+    pub port: u16,
+    pub proto: &'static str,
+    pub address: String,
+    pub pid: Option<u32>,
+}
+
+/// Snapshot of all processes. Sleeps briefly so CPU usage has two samples to compare.
+pub fn collect() -> Vec<Proc> {
+    let mut sys = System::new();
+    let kind = ProcessRefreshKind::everything();
+    sys.refresh_processes_specifics(ProcessesToUpdate::All, true, kind);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    sys.refresh_processes_specifics(ProcessesToUpdate::All, true, kind);
+
+    let users = Users::new_with_refreshed_list();
+    let mut ports: HashMap<u32, Vec<u16>> = HashMap::new();
+    for l in listeners() {
+        if let Some(pid) = l.pid {
+            let entry = ports.entry(pid).or_default();
+            if !entry.contains(&l.port) {
+                entry.push(l.port);
+            }
+        }
+    }
+
+    sys.processes().values()
+        // Linux lists threads as tasks too; keep real processes only
+        .filter(|p| p.thread_kind().is_none())
+        .map(|p| {
+            let pid = p.pid().as_u32();
+            let cmd = p.cmd().iter().map(|a| a.to_string_lossy()).collect::<Vec<_>>().join(" ");
+            Proc {
+                pid,
+                ppid: p.parent().map(|pp| pp.as_u32()),
+                name: p.name().to_string_lossy().to_string(),
+                user: p.user_id()
+                    .and_then(|uid| users.get_user_by_id(uid))
+                    .map(|u| u.name().to_string())
+                    .unwrap_or_default(),
+                cpu_percent: p.cpu_usage(),
+                mem_mb: p.memory() / 1024 / 1024,
+                command: cmd,
+                ports: ports.remove(&pid).unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+/// Send SIGTERM (or SIGKILL with `force`) to `pid`.
+pub fn kill(pid: u32, force: bool) -> Result<()> {
+    let mut sys = System::new();
+    let target = Pid::from_u32(pid);
+    sys.refresh_processes(ProcessesToUpdate::Some(&[target]), true);
+    let process = sys.process(target).ok_or_else(|| anyhow!("No process with PID {}", pid))?;
+    let sent = if force {
+        process.kill()
+    } else {
+        // Windows has no SIGTERM; fall back to a hard kill there
+        process.kill_with(Signal::Term).unwrap_or_else(|| process.kill())
+    };
+    if !sent {
+        return Err(anyhow!("Could not signal PID {} — try again with sudo", pid));
+    }
+    Ok(())
+}
+
+// ── Listening sockets ──────────────────────────────────────────────────────────
+
+/// All listening TCP sockets and bound UDP sockets, with their owning PID when visible.
+pub fn listeners() -> Vec<Listener> {
+    if cfg!(target_os = "linux") {
+        linux_listeners()
+    } else if cfg!(target_os = "windows") {
+        netstat_listeners()
+    } else {
+        lsof_listeners()
+    }
+}
+
+fn linux_listeners() -> Vec<Listener> {
+    // socket inode → pid, from /proc/<pid>/fd/* → "socket:[inode]"
+    let mut owners: HashMap<u64, u32> = HashMap::new();
+    if let Ok(procs) = std::fs::read_dir("/proc") {
+        for entry in procs.filter_map(|e| e.ok()) {
+            let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else { continue };
+            let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else { continue };
+            for fd in fds.filter_map(|e| e.ok()) {
+                let Ok(target) = std::fs::read_link(fd.path()) else { continue };
+                let target = target.to_string_lossy();
+                if let Some(inode) = target.strip_prefix("socket:[").and_then(|t| t.strip_suffix(']')) {
+                    if let Ok(inode) = inode.parse() {
+                        owners.insert(inode, pid);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    for (file, proto) in [("tcp", "tcp"), ("tcp6", "tcp"), ("udp", "udp"), ("udp6", "udp")] {
+        let Ok(text) = std::fs::read_to_string(format!("/proc/net/{}", file)) else { continue };
+        for line in text.lines().skip(1) {
+            // sl local_address rem_address st tx:rx tr:when retrnsmt uid timeout inode
+            let cols: Vec<&str> = line.split_whitespace().collect();
+            if cols.len() < 10 {
+                continue;
+            }
+            // 0A = TCP_LISTEN; 07 = unconnected UDP socket
+            let wanted = if proto == "tcp" { "0A" } else { "07" };
+            if cols[3] != wanted {
+                continue;
+            }
+            let Some((addr_hex, port_hex)) = cols[1].rsplit_once(':') else { continue };
+            let Ok(port) = u16::from_str_radix(port_hex, 16) else { continue };
+            let inode: u64 = cols[9].parse().unwrap_or(0);
+            out.push(Listener {
+                port,
+                proto,
+                address: decode_addr(addr_hex),
+                pid: owners.get(&inode).copied(),
+            });
+        }
+    }
+    out.sort_by_key(|l| (l.port, l.proto));
+    out
+}
+
+/// /proc/net addresses are little-endian hex per 32-bit word.
+fn decode_addr(hex: &str) -> String {
+    let bytes: Vec<u8> = (0..hex.len()).step_by(2)
+        .filter_map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect();
+    let words: Vec<u8> = bytes.chunks(4).flat_map(|w| w.iter().rev().copied()).collect();
+    match words.len() {
+        4 => std::net::Ipv4Addr::new(words[0], words[1], words[2], words[3]).to_string(),
+        16 => {
+            let arr: [u8; 16] = words.try_into().unwrap_or([0; 16]);
+            std::net::Ipv6Addr::from(arr).to_string()
+        }
+        _ => hex.to_string(),
+    }
+}
+
+fn lsof_listeners() -> Vec<Listener> {
+    let Ok(out) = std::process::Command::new("lsof").args(["-nP", "-iTCP", "-sTCP:LISTEN", "-iUDP", "-F", "pPn"]).output() else {
+        return Vec::new();
+    };
+    // Field output: p<pid>, then P<proto> and n<addr:port> per file
+    let mut result = Vec::new();
+    let (mut pid, mut proto) = (None, "tcp");
+    for line in String::from_utf8_lossy(&out.stdout).lines() {
+        let (tag, value) = line.split_at(1.min(line.len()));
+        match tag {
+            "p" => pid = value.parse().ok(),
+            "P" => proto = if value.eq_ignore_ascii_case("udp") { "udp" } else { "tcp" },
+            "n" => {
+                if let Some((addr, port)) = value.rsplit_once(':') {
+                    if let Ok(port) = port.parse() {
+                        result.push(Listener { port, proto, address: addr.to_string(), pid });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    result.sort_by_key(|l| (l.port, l.proto));
+    result.dedup_by(|a, b| a.port == b.port && a.proto == b.proto && a.pid == b.pid);
+    result
+}
+
+fn netstat_listeners() -> Vec<Listener> {
+    let Ok(out) = std::process::Command::new("netstat").args(["-ano"]).output() else {
+        return Vec::new();
+    };
+    // Proto  Local Address  Foreign Address  State  PID   (UDP rows have no State)
+    let mut result: Vec<Listener> = String::from_utf8_lossy(&out.stdout).lines()
+        .filter_map(|line| {
+            let cols: Vec<&str> = line.split_whitespace().collect();
+            let proto = match cols.first()?.to_ascii_uppercase().as_str() {
+                "TCP" if cols.get(3) == Some(&"LISTENING") => "tcp",
+                "UDP" => "udp",
+                _ => return None,
+            };
+            let (addr, port) = cols.get(1)?.rsplit_once(':')?;
+            Some(Listener { port: port.parse().ok()?, proto, address: addr.to_string(), pid: cols.last()?.parse().ok() })
+        })
+        .collect();
+    result.sort_by_key(|l| (l.port, l.proto));
+    result
+}
+
+// ── vg ps ──────────────────────────────────────────────────────────────────────
+
+fn sort(procs: &mut [Proc], key: SortKey) {
+    match key {
+        SortKey::Cpu => procs.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent)),
+        SortKey::Mem => procs.sort_by_key(|p| std::cmp::Reverse(p.mem_mb)),
+        SortKey::Pid => procs.sort_by_key(|p| p.pid),
+        SortKey::Name => procs.sort_by_key(|p| p.name.to_lowercase()),
+    }
+}
+
+pub struct Filters {
+    pub name: Option<String>,
+    pub user: Option<String>,
+    pub port: Option<u16>,
+}
+
+impl Filters {
+    fn matches(&self, p: &Proc) -> bool {
+        self.name.as_ref().is_none_or(|n| {
+            let n = n.to_lowercase();
+            p.name.to_lowercase().contains(&n) || p.command.to_lowercase().contains(&n)
+        })
+            && self.user.as_ref().is_none_or(|u| p.user == *u)
+            && self.port.is_none_or(|port| p.ports.contains(&port))
+    }
+}
+
+/// `vg ps`
+pub fn run(filters: Filters, sort_key: SortKey, limit: Option<usize>, tree: bool, json: bool) -> Result<()> {
+    let all = collect();
+    let mut procs: Vec<Proc> = all.iter().filter(|p| filters.matches(p)).cloned().collect();
+    sort(&mut procs, sort_key);
+
+    if json {
+        let procs: Vec<&Proc> = procs.iter().take(limit.unwrap_or(usize::MAX)).collect();
+        println!("{}", serde_json::to_string_pretty(&procs)?);
+        return Ok(());
+    }
+
+    ui::print_header("PROCESSES");
+    if procs.is_empty() {
+        ui::skip("No matching processes");
+        return Ok(());
+    }
+
+    let rows: Vec<(usize, &Proc)> = if tree {
+        tree_order(&procs)
+    } else {
+        procs.iter().map(|p| (0, p)).collect()
+    };
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("PID").add_attribute(Attribute::Bold),
+        Cell::new("User").add_attribute(Attribute::Bold),
+        Cell::new("CPU%").add_attribute(Attribute::Bold),
+        Cell::new("Mem").add_attribute(Attribute::Bold),
+        Cell::new("Ports").add_attribute(Attribute::Bold),
+        Cell::new("Name").add_attribute(Attribute::Bold),
+    ]);
+    for (depth, p) in rows.iter().take(limit.unwrap_or(usize::MAX)) {
+        let name = if tree && *depth > 0 {
+            format!("{}└ {}", "  ".repeat(depth - 1), p.name)
+        } else {
+            p.name.clone()
+        };
+        let ports = p.ports.iter().map(|port| port.to_string()).collect::<Vec<_>>().join(",");
+        table.add_row(vec![
+            Cell::new(p.pid),
+            Cell::new(&p.user).fg(Color::Cyan),
+            Cell::new(format!("{:.1}", p.cpu_percent)).set_alignment(CellAlignment::Right),
+            Cell::new(format!("{} MB", p.mem_mb)).set_alignment(CellAlignment::Right),
+            Cell::new(ports),
+            Cell::new(name).fg(Color::Blue),
+        ]);
+    }
+    println!("{}", table);
+    println!();
+    ui::skip(&format!("{} of {} processes", rows.len().min(limit.unwrap_or(usize::MAX)), all.len()));
+    Ok(())
+}
+
+/// Depth-first order with depth, roots being processes whose parent isn't in the list.
+fn tree_order(procs: &[Proc]) -> Vec<(usize, &Proc)> {
+    let pids: std::collections::HashSet<u32> = procs.iter().map(|p| p.pid).collect();
+    let mut children: HashMap<u32, Vec<&Proc>> = HashMap::new();
+    let mut roots = Vec::new();
+    for p in procs {
+        match p.ppid.filter(|pp| pids.contains(pp) && *pp != p.pid) {
+            Some(pp) => children.entry(pp).or_default().push(p),
+            None => roots.push(p),
+        }
+    }
+    let mut out = Vec::new();
+    let mut stack: Vec<(usize, &Proc)> = roots.into_iter().rev().map(|p| (0, p)).collect();
+    while let Some((depth, p)) = stack.pop() {
+        out.push((depth, p));
+        if let Some(kids) = children.get(&p.pid) {
+            stack.extend(kids.iter().rev().map(|k| (depth + 1, *k)));
+        }
+    }
+    out
+}
@@ -0,0 +1,101 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use qrcode::render::unicode::Dense1x2;
+use qrcode::{Color, QrCode};
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Pixels per module in PNG output.
+const PNG_SCALE: usize = 10;
+/// Light border around the code, in modules; scanners need at least 4.
+const QUIET_ZONE: usize = 4;
+
+pub struct QrParams {
+    /// Text or URL; read from stdin when omitted
+    pub text: Option<String>,
+    /// Also write a PNG here
+    pub png: Option<PathBuf>,
+    /// Dark modules as blocks, for terminals with a light background
+    pub invert: bool,
+}
+
+fn write_png(code: &QrCode, path: &Path) -> Result<()> {
+    let modules = code.width();
+    let colors = code.to_colors();
+    let side = (modules + 2 * QUIET_ZONE) * PNG_SCALE;
+    let mut pixels = vec![255u8; side * side];
+    for (i, color) in colors.iter().enumerate() {
+        if *color != Color::Dark {
+            continue;
+        }
+        let (x, y) = ((i % modules + QUIET_ZONE) * PNG_SCALE, (i / modules + QUIET_ZONE) * PNG_SCALE);
+        for row in y..y + PNG_SCALE {
+            pixels[row * side + x..row * side + x + PNG_SCALE].fill(0);
+        }
+    }
+
+    let file = std::fs::File::create(path).with_context(|| format!("Cannot write {}", path.display()))?;
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), side as u32, side as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(())
+}
+
+/// `vg qr "text"` — show a QR code in the terminal, optionally saving it as PNG.
+pub fn generate(params: QrParams) -> Result<()> {
+    let text = match params.text {
+        Some(t) => t,
+        None if !std::io::stdin().is_terminal() => {
+            let mut s = String::new();
+            std::io::stdin().read_to_string(&mut s)?;
+            s.trim_end_matches(['\n', '\r']).to_string()
+        }
+        None => return Err(anyhow!("Give the text or URL to encode, e.g. vg qr https://example.com")),
+    };
+    if text.is_empty() {
+        return Err(anyhow!("Nothing to encode"));
+    }
+    let code = QrCode::new(text.as_bytes()).map_err(|e| anyhow!("Cannot encode {} bytes as a QR code: {}", text.len(), e))?;
+
+    ui::print_header("QR CODE");
+    // Most terminals draw light text on a dark background, so by default the light modules are the blocks
+    let (dark, light) = if params.invert { (Dense1x2::Dark, Dense1x2::Light) } else { (Dense1x2::Light, Dense1x2::Dark) };
+    let art = code.render::<Dense1x2>().dark_color(dark).light_color(light).quiet_zone(true).build();
+    for line in art.lines() {
+        println!("  {}", line);
+    }
+    println!();
+    ui::info_line("Encoded", &if text.chars().count() > 60 { format!("{}…", text.chars().take(60).collect::<String>()) } else { text.clone() });
+
+    if let Some(path) = params.png {
+        write_png(&code, &path)?;
+        ui::success(&format!("Saved {}", path.display()));
+    }
+    if !params.invert {
+        ui::skip("Won't scan? Light terminal backgrounds need --invert");
+    }
+    Ok(())
+}
+
+/// `vg qr read image.png` — decode every QR code (and barcode) in an image with zbarimg.
+pub fn read(image: PathBuf) -> Result<()> {
+    if !image.is_file() {
+        return Err(anyhow!("{} does not exist", image.display()));
+    }
+    let zbarimg = crate::tools::require("zbarimg")?;
+    let mut cmd = Command::new(zbarimg);
+    cmd.args(["--raw", "--quiet"]).arg(&image);
+    let out = crate::logging::output(&mut cmd).context("Failed to run zbarimg")?;
+    let text = String::from_utf8_lossy(&out.stdout);
+    // zbarimg exits with 4 when the image was readable but held no code
+    if text.trim().is_empty() {
+        return Err(match out.status.code() {
+            Some(4) => anyhow!("No QR code found in {}", image.display()),
+            _ => anyhow!("zbarimg could not read {}: {}", image.display(), String::from_utf8_lossy(&out.stderr).trim()),
+        });
+    }
+    print!("{}", text);
+    Ok(())
+}
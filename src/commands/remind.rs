@@ -0,0 +1,290 @@
+use crate::config::{ConfigManager, NotifyConfig};
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use which::which;
+
+/// A waiting reminder re-checks the clock (and whether it was cancelled) at least this often,
+/// so suspend and clock changes don't delay it by more than this.
+const WAKE_INTERVAL: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Backend {
+    /// Transient systemd user timer `vg-remind-<id>.timer`
+    Systemd,
+    /// Detached `vg remind fire <id> --wait` process
+    Process,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Reminder {
+    id: u32,
+    message: String,
+    due: DateTime<Utc>,
+    backend: Backend,
+}
+
+fn store_path() -> PathBuf {
+    ConfigManager::data_dir().join("reminders.json")
+}
+
+fn load() -> Result<Vec<Reminder>> {
+    let path = store_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn save(reminders: &[Reminder]) -> Result<()> {
+    std::fs::create_dir_all(ConfigManager::data_dir())?;
+    std::fs::write(store_path(), serde_json::to_string_pretty(reminders)?)?;
+    Ok(())
+}
+
+/// "45m", "1h30m", "90s", "2d".
+fn parse_in(text: &str) -> Result<chrono::Duration> {
+    let mut secs: i64 = 0;
+    let mut num = String::new();
+    for c in text.trim().chars() {
+        if c.is_ascii_digit() {
+            num.push(c);
+            continue;
+        }
+        let n: i64 = num.parse().map_err(|_| anyhow!("Invalid duration '{}' — use e.g. 45m, 1h30m or 90s", text))?;
+        secs += n * match c {
+            'd' => 86_400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(anyhow!("Unknown unit '{}' in '{}' — use d, h, m or s", c, text)),
+        };
+        num.clear();
+    }
+    if !num.is_empty() {
+        // A bare number means minutes
+        secs += num.parse::<i64>()? * 60;
+    }
+    if secs <= 0 {
+        return Err(anyhow!("Invalid duration '{}' — use e.g. 45m, 1h30m or 90s", text));
+    }
+    Ok(chrono::Duration::seconds(secs))
+}
+
+/// "17:30" (today, or tomorrow once it has passed) or "2026-10-17 09:00".
+fn parse_at(text: &str, now: DateTime<Local>) -> Result<DateTime<Local>> {
+    let text = text.trim();
+    let local = |naive: NaiveDateTime| {
+        Local.from_local_datetime(&naive).earliest().ok_or_else(|| anyhow!("{} does not exist in your time zone", text))
+    };
+    for fmt in ["%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(text, fmt) {
+            return local(naive);
+        }
+    }
+    let time = ["%H:%M", "%H:%M:%S"].iter()
+        .find_map(|fmt| NaiveTime::parse_from_str(text, fmt).ok())
+        .ok_or_else(|| anyhow!("Invalid time '{}' — use HH:MM or YYYY-MM-DD HH:MM", text))?;
+    let today = local(now.date_naive().and_time(time))?;
+    if today > now {
+        Ok(today)
+    } else {
+        local((now.date_naive() + chrono::Days::new(1)).and_time(time))
+    }
+}
+
+fn fmt_until(due: DateTime<Utc>) -> String {
+    let secs = (due - Utc::now()).num_seconds();
+    match secs {
+        ..=0 => "now".to_string(),
+        1..=59 => format!("in {}s", secs),
+        60..=3599 => format!("in {}m", secs / 60),
+        3600..=86_399 => format!("in {}h {}m", secs / 3600, secs % 3600 / 60),
+        _ => format!("in {}d", secs / 86_400),
+    }
+}
+
+fn systemd_available() -> bool {
+    cfg!(target_os = "linux")
+        && which("systemd-run").is_ok()
+        && Command::new("systemctl").args(["--user", "show-environment"])
+            .stdout(Stdio::null()).stderr(Stdio::null())
+            .status().is_ok_and(|s| s.success())
+}
+
+fn unit(id: u32) -> String {
+    format!("vg-remind-{}", id)
+}
+
+fn schedule(reminder: &Reminder) -> Result<Backend> {
+    let exe = std::env::current_exe().context("Cannot locate the vg executable")?;
+    if systemd_available() {
+        let when = reminder.due.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string();
+        let mut cmd = Command::new("systemd-run");
+        cmd.args(["--user", "--quiet", "--collect", "--unit", &unit(reminder.id), "--on-calendar", &when, "--timer-property=AccuracySec=1s"])
+            .arg(&exe).args(["remind", "fire", &reminder.id.to_string()]);
+        let out = crate::logging::output(&mut cmd).context("Failed to run systemd-run")?;
+        if out.status.success() {
+            return Ok(Backend::Systemd);
+        }
+        tracing::debug!(stderr = %String::from_utf8_lossy(&out.stderr), "systemd-run failed, falling back to a waiting process");
+    }
+
+    let mut cmd = Command::new(exe);
+    cmd.args(["remind", "fire", &reminder.id.to_string(), "--wait"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .stdin(Stdio::null());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Detach from process group so it survives terminal close
+        unsafe { cmd.pre_exec(|| { libc::setsid(); Ok(()) }); }
+    }
+    cmd.spawn().context("Failed to start the reminder process")?;
+    Ok(Backend::Process)
+}
+
+/// `vg remind "stand up" --in 45m` / `--at 17:30`
+pub fn add(message: String, after: Option<String>, at: Option<String>) -> Result<()> {
+    ui::print_header("REMIND");
+    let now = Local::now();
+    let due = match (after, at) {
+        (Some(d), None) => now + parse_in(&d)?,
+        (None, Some(t)) => parse_at(&t, now)?,
+        _ => return Err(anyhow!("Say when with --in 45m or --at 17:30")),
+    };
+
+    let mut reminders = load()?;
+    let reminder = Reminder {
+        id: reminders.iter().map(|r| r.id).max().unwrap_or(0) + 1,
+        message,
+        due: due.with_timezone(&Utc),
+        backend: Backend::Process,
+    };
+    // Saved before scheduling, so a reminder that fires right away finds itself
+    reminders.push(reminder.clone());
+    save(&reminders)?;
+    let backend = match schedule(&reminder) {
+        Ok(backend) => backend,
+        Err(e) => {
+            reminders.retain(|r| r.id != reminder.id);
+            save(&reminders)?;
+            return Err(e);
+        }
+    };
+    // Reloaded: a reminder due within a second may already have fired and removed itself
+    let mut reminders = load()?;
+    if let Some(r) = reminders.iter_mut().find(|r| r.id == reminder.id) {
+        r.backend = backend;
+        save(&reminders)?;
+    }
+
+    ui::success(&format!("Reminder #{} at {} ({})", reminder.id, due.format("%a %H:%M"), fmt_until(reminder.due)));
+    if backend == Backend::Process {
+        ui::skip("Waiting in the background — reminders without systemd don't survive a reboot or logout");
+    }
+    if !cfg!(target_os = "macos") && which("notify-send").is_err() {
+        ui::skip("No notify-send found — install it with: vg install libnotify");
+    }
+    Ok(())
+}
+
+/// `vg remind list`
+pub fn list() -> Result<()> {
+    ui::print_header("REMINDERS");
+    let mut reminders = load()?;
+    // Transient timers are gone after a reboot; anything well past due never fired
+    let (missed, pending): (Vec<Reminder>, Vec<Reminder>) =
+        reminders.drain(..).partition(|r| Utc::now() - r.due > chrono::Duration::minutes(1));
+    if !missed.is_empty() {
+        save(&pending)?;
+    }
+    for r in &missed {
+        println!(
+            "  {}  {}  {}",
+            format!("#{:<3}", r.id).truecolor(71, 85, 105),
+            format!("missed {}", r.due.with_timezone(&Local).format("%a %H:%M")).red(),
+            r.message.truecolor(224, 242, 254),
+        );
+    }
+    if pending.is_empty() {
+        if missed.is_empty() {
+            ui::skip("No reminders. Add one with vg remind \"stand up\" --in 45m");
+        }
+        return Ok(());
+    }
+    for r in &pending {
+        println!(
+            "  {}  {:<10} {}  {}",
+            format!("#{:<3}", r.id).truecolor(71, 85, 105),
+            r.due.with_timezone(&Local).format("%a %H:%M").to_string().truecolor(96, 165, 250).bold(),
+            r.message.truecolor(224, 242, 254),
+            format!("({})", fmt_until(r.due)).truecolor(100, 116, 139),
+        );
+    }
+    println!();
+    ui::skip("Cancel one with vg remind cancel <id>");
+    Ok(())
+}
+
+/// Reminders still to fire before midnight, soonest first: (local time, message, "in 2h 5m"). For `vg dash`.
+pub(crate) fn due_today() -> Vec<(String, String, String)> {
+    let now = Local::now();
+    let mut due: Vec<Reminder> = load().unwrap_or_default().into_iter()
+        .filter(|r| r.due >= Utc::now() && r.due.with_timezone(&Local).date_naive() == now.date_naive())
+        .collect();
+    due.sort_by_key(|r| r.due);
+    due.into_iter()
+        .map(|r| (r.due.with_timezone(&Local).format("%H:%M").to_string(), r.message, fmt_until(r.due)))
+        .collect()
+}
+
+/// `vg remind cancel <id>`
+pub fn cancel(id: u32) -> Result<()> {
+    ui::print_header("REMIND");
+    let mut reminders = load()?;
+    let Some(pos) = reminders.iter().position(|r| r.id == id) else {
+        return Err(anyhow!("No reminder #{} — see vg remind list", id));
+    };
+    let reminder = reminders.remove(pos);
+    save(&reminders)?;
+    if reminder.backend == Backend::Systemd {
+        let _ = Command::new("systemctl").args(["--user", "stop", &format!("{}.timer", unit(id))])
+            .stdout(Stdio::null()).stderr(Stdio::null()).status();
+    }
+    // A waiting process notices on its next wake-up that its reminder is gone
+    ui::success(&format!("Cancelled #{} — {}", id, reminder.message));
+    Ok(())
+}
+
+/// `vg remind fire <id> [--wait]` — run by the timer or the background process.
+pub fn fire(id: u32, wait: bool, notify: &NotifyConfig) -> Result<()> {
+    let find = || load().ok().and_then(|rs| rs.into_iter().find(|r| r.id == id));
+    let Some(mut reminder) = find() else { return Ok(()) };
+    if wait {
+        loop {
+            let left = (reminder.due - Utc::now()).num_seconds();
+            if left <= 0 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_secs((left as u64).min(WAKE_INTERVAL)));
+            match find() {
+                Some(r) => reminder = r,
+                None => return Ok(()),
+            }
+        }
+    }
+    if !crate::notify::send(notify, "Reminder", &reminder.message) {
+        tracing::debug!(id, "no notifier available");
+    }
+    let mut reminders = load()?;
+    reminders.retain(|r| r.id != id);
+    save(&reminders)
+}
@@ -0,0 +1,100 @@
+// src/commands/remote.rs
+// `vg --host user@server <command>`: run a read-only command on another machine over SSH.
+// The remote vg does the work and its output (colours included) is shown here as it comes in,
+// so `vg --host web1 health` reads like a local run and exits with the remote exit code.
+
+use anyhow::{anyhow, Result};
+use std::ffi::OsString;
+use std::io::IsTerminal;
+use std::process::Command;
+
+/// Named in the error for unsupported commands; `remote_supported` in main.rs is the check itself.
+pub const COMMANDS: &str = "health, info, disks, sensors, gpu, ps, updates, service list/status/logs";
+
+/// ssh's own exit code for connection and authentication failures
+const SSH_FAILED: i32 = 255;
+/// What the remote shell returns when `vg` isn't on its PATH
+const NOT_FOUND: i32 = 127;
+
+/// Quote for the remote POSIX shell.
+fn quote(arg: &str) -> String {
+    if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./=:@,".contains(c)) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// The command line for the remote machine: `args` (vg's own, program name first) without `--host`.
+pub fn remote_args(args: &[OsString]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut iter = args.iter().skip(1).map(|a| a.to_string_lossy().into_owned());
+    while let Some(arg) = iter.next() {
+        if arg == "--host" {
+            iter.next();
+        } else if !arg.starts_with("--host=") {
+            out.push(arg);
+        }
+    }
+    out
+}
+
+/// Why `host` can't be passed to ssh, if it can't: a leading `-` would be read as an ssh option.
+pub fn host_problem(host: &str) -> Option<String> {
+    if host.is_empty() {
+        Some("host is empty".to_string())
+    } else if host.starts_with('-') {
+        Some(format!("'{}' is not a host name (it starts with -)", host))
+    } else if host.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        Some(format!("'{}' is not a host name (it contains whitespace)", host))
+    } else {
+        None
+    }
+}
+
+/// ssh running `vg <args>` on `host` (anything ssh accepts: user@server, or an alias from ~/.ssh/config).
+fn command(host: &str, args: &[String], colour: bool) -> Result<Command> {
+    if let Some(problem) = host_problem(host) {
+        return Err(anyhow!("--host: {}", problem));
+    }
+    let ssh = crate::tools::require("ssh")?;
+    // Non-login shells often lack ~/.local/bin and ~/.cargo/bin; never prompt on the far side
+    let mut line = String::from(r#"PATH="$PATH:/usr/local/bin:$HOME/.local/bin:$HOME/.cargo/bin""#);
+    if colour {
+        line.push_str(" CLICOLOR_FORCE=1");
+    }
+    line.push_str(" vg --non-interactive");
+    for arg in args {
+        line.push(' ');
+        line.push_str(&quote(arg));
+    }
+    let mut cmd = Command::new(ssh);
+    cmd.args(["-T", "-o", "BatchMode=yes", "-o", "ConnectTimeout=10", "--", host, &line]);
+    Ok(cmd)
+}
+
+/// Turn ssh's own failures into errors; otherwise the remote vg's exit code.
+fn remote_code(host: &str, code: Option<i32>) -> Result<i32> {
+    match code {
+        Some(SSH_FAILED) => Err(anyhow!("Could not reach {} over SSH (key-based login is required)", host)),
+        Some(NOT_FOUND) => Err(anyhow!("vg is not installed on {}", host)),
+        Some(code) => Ok(code),
+        None => Err(anyhow!("ssh to {} was stopped by a signal", host)),
+    }
+}
+
+/// Run `vg <args>` on `host`, showing its output as it comes in.
+pub fn run(host: &str, args: &[String]) -> Result<()> {
+    let mut cmd = command(host, args, std::io::stdout().is_terminal())?;
+    let status = crate::logging::status(&mut cmd).map_err(|e| anyhow!("Failed to run ssh: {}", e))?;
+    crate::exit::set(remote_code(host, status.code())?);
+    Ok(())
+}
+
+/// Run `vg <args>` on `host` and return what it printed, whatever its exit code.
+pub fn output(host: &str, args: &[String]) -> Result<String> {
+    let mut cmd = command(host, args, false)?;
+    let out = crate::logging::output(&mut cmd).map_err(|e| anyhow!("Failed to run ssh: {}", e))?;
+    remote_code(host, out.status.code())?;
+    Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
@@ -0,0 +1,266 @@
+use crate::history::Recorder;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local};
+use clap::ValueEnum;
+use comfy_table::{Attribute, Cell, Color, Table};
+use inquire::Confirm;
+use regex::Regex;
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Case {
+    Lower,
+    Upper,
+    Title,
+    Snake,
+    Kebab,
+}
+
+pub struct RenameParams {
+    pub paths: Vec<PathBuf>,
+    /// Regex matched against the file name; only matching files are renamed
+    pub pattern: Option<String>,
+    /// New name template
+    pub template: Option<String>,
+    pub case: Option<Case>,
+    pub date_prefix: bool,
+    pub start: usize,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+/// Files to rename: given files as-is, directories contribute their direct children.
+fn collect(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for p in paths {
+        if p.is_dir() {
+            let mut children: Vec<PathBuf> = std::fs::read_dir(p)
+                .with_context(|| format!("Cannot read {}", p.display()))?
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_file())
+                .filter(|p| !p.file_name().map(|n| n.to_string_lossy().starts_with('.')).unwrap_or(true))
+                .collect();
+            children.sort();
+            files.extend(children);
+        } else if p.is_file() {
+            files.push(p.clone());
+        } else {
+            return Err(anyhow!("{} does not exist", p.display()));
+        }
+    }
+    Ok(files)
+}
+
+/// Capture date for photos (EXIF), otherwise the modification date.
+fn file_date(path: &Path) -> String {
+    exif_date(path).unwrap_or_else(|| {
+        std::fs::metadata(path).and_then(|m| m.modified())
+            .map(|t| DateTime::<Local>::from(t).format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    })
+}
+
+/// EXIF stores dates as ASCII "YYYY:MM:DD HH:MM:SS" near the start of the file;
+/// scanning for that shape avoids pulling in a full EXIF parser.
+fn exif_date(path: &Path) -> Option<String> {
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    if !matches!(ext.as_str(), "jpg" | "jpeg" | "tif" | "tiff" | "heic" | "dng" | "cr2" | "nef" | "arw") {
+        return None;
+    }
+    let mut head = Vec::new();
+    std::fs::File::open(path).ok()?.take(128 * 1024).read_to_end(&mut head).ok()?;
+    let text = String::from_utf8_lossy(&head);
+    let re = Regex::new(r"((?:19|20)\d\d):([01]\d):([0-3]\d) [0-2]\d:[0-5]\d:[0-5]\d").ok()?;
+    let c = re.captures(&text)?;
+    Some(format!("{}-{}-{}", &c[1], &c[2], &c[3]))
+}
+
+fn words(s: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut cur = String::new();
+    let mut prev_lower = false;
+    for ch in s.chars() {
+        if !ch.is_alphanumeric() {
+            if !cur.is_empty() { out.push(std::mem::take(&mut cur)); }
+            prev_lower = false;
+            continue;
+        }
+        // camelCase boundary
+        if ch.is_uppercase() && prev_lower && !cur.is_empty() {
+            out.push(std::mem::take(&mut cur));
+        }
+        prev_lower = ch.is_lowercase() || ch.is_ascii_digit();
+        cur.push(ch);
+    }
+    if !cur.is_empty() { out.push(cur); }
+    out
+}
+
+fn apply_case(stem: &str, case: Case) -> String {
+    match case {
+        Case::Lower => stem.to_lowercase(),
+        Case::Upper => stem.to_uppercase(),
+        Case::Title => words(stem).iter().map(|w| {
+            let mut c = w.chars();
+            c.next().map(|f| f.to_uppercase().collect::<String>() + &c.as_str().to_lowercase()).unwrap_or_default()
+        }).collect::<Vec<_>>().join(" "),
+        Case::Snake => words(stem).join("_").to_lowercase(),
+        Case::Kebab => words(stem).join("-").to_lowercase(),
+    }
+}
+
+/// Expand `{stem}`, `{ext}`, `{date}` and `{n}` / `{n:3}` (zero-padded counter).
+fn expand(template: &str, stem: &str, ext: &str, date: &str, n: usize) -> String {
+    let counter = Regex::new(r"\{n(?::(\d+))?\}").expect("valid counter regex");
+    let out = counter.replace_all(template, |c: &regex::Captures| {
+        let width = c.get(1).and_then(|w| w.as_str().parse().ok()).unwrap_or(0);
+        format!("{:0width$}", n, width = width)
+    });
+    out.replace("{stem}", stem).replace("{ext}", ext).replace("{date}", date)
+}
+
+fn new_name(path: &Path, params: &RenameParams, re: Option<&Regex>, n: usize) -> Option<String> {
+    let name = path.file_name()?.to_string_lossy().to_string();
+    let (stem, ext) = match name.rsplit_once('.') {
+        Some((s, e)) if !s.is_empty() => (s.to_string(), format!(".{}", e)),
+        _ => (name.clone(), String::new()),
+    };
+    let needs_date = params.date_prefix || params.template.as_deref().is_some_and(|t| t.contains("{date}"));
+    let date = if needs_date { file_date(path) } else { String::new() };
+
+    let mut renamed = match (re, &params.template) {
+        (Some(re), template) => {
+            let caps = re.captures(&name)?;
+            // The template is the whole new name: capture groups first ($1, ${name}), then our own tokens
+            let mut replaced = String::new();
+            caps.expand(template.as_deref().unwrap_or("{stem}{ext}"), &mut replaced);
+            expand(&replaced, &stem, &ext, &date, n)
+        }
+        (None, Some(t)) => expand(t, &stem, &ext, &date, n),
+        (None, None) => name.clone(),
+    };
+
+    if let Some(case) = params.case {
+        let (s, e) = match renamed.rsplit_once('.') {
+            Some((s, e)) if !s.is_empty() => (s.to_string(), format!(".{}", e.to_lowercase())),
+            _ => (renamed.clone(), String::new()),
+        };
+        renamed = format!("{}{}", apply_case(&s, case), e);
+    }
+    if params.date_prefix && !date.is_empty() && !renamed.starts_with(&date) {
+        renamed = format!("{}_{}", date, renamed);
+    }
+    Some(renamed)
+}
+
+/// True when `to` names `from` itself: a case-only rename on a case-insensitive filesystem
+/// (macOS, Windows), where `to.exists()` finds the source.
+fn same_file(from: &Path, to: &Path) -> bool {
+    let folded = |p: &Path| p.file_name().map(|n| n.to_string_lossy().to_lowercase());
+    if folded(from) != folded(to) {
+        return false;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        match (std::fs::symlink_metadata(from), std::fs::symlink_metadata(to)) {
+            (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+            _ => false,
+        }
+    }
+    #[cfg(not(unix))]
+    true
+}
+
+pub fn run(params: RenameParams) -> Result<()> {
+    ui::print_header("RENAME BATCH");
+    if params.pattern.is_none() && params.template.is_none() && params.case.is_none() && !params.date_prefix {
+        return Err(anyhow!("Nothing to do — give --match/--to, --case or --date-prefix"));
+    }
+    let re = params.pattern.as_deref().map(Regex::new).transpose().context("Invalid --match pattern")?;
+    let files = collect(&params.paths)?;
+
+    let mut plan: Vec<(PathBuf, PathBuf)> = Vec::new();
+    let mut n = params.start;
+    for f in &files {
+        if let Some(name) = new_name(f, &params, re.as_ref(), n) {
+            n += 1;
+            if name.is_empty() || name.contains('/') || (cfg!(windows) && name.contains('\\')) {
+                return Err(anyhow!("'{}' would become '{}', which is not a valid file name", f.display(), name));
+            }
+            let target = f.with_file_name(&name);
+            if target != *f {
+                plan.push((f.clone(), target));
+            }
+        }
+    }
+    if plan.is_empty() {
+        ui::skip("No files would change");
+        return Ok(());
+    }
+
+    // Two files onto one name, or onto a file that isn't itself being renamed, would lose data
+    let sources: HashSet<&PathBuf> = plan.iter().map(|(from, _)| from).collect();
+    let mut targets = HashSet::new();
+    let mut conflicts = 0;
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("From").add_attribute(Attribute::Bold),
+        Cell::new("To").add_attribute(Attribute::Bold),
+        Cell::new("").add_attribute(Attribute::Bold),
+    ]);
+    for (from, to) in &plan {
+        let clash = !targets.insert(to.clone()) || (to.exists() && !sources.contains(to) && !same_file(from, to));
+        if clash {
+            conflicts += 1;
+        }
+        table.add_row(vec![
+            Cell::new(from.file_name().unwrap_or_default().to_string_lossy()),
+            Cell::new(to.file_name().unwrap_or_default().to_string_lossy()).fg(if clash { Color::Red } else { Color::Green }),
+            Cell::new(if clash { "conflict" } else { "" }).fg(Color::Red),
+        ]);
+    }
+    println!("{}", table);
+    println!();
+
+    if conflicts > 0 {
+        return Err(anyhow!("{} target name(s) clash with other files — adjust the template", conflicts));
+    }
+    if params.dry_run {
+        ui::skip(&format!("Dry run: {} files would be renamed", plan.len()));
+        return Ok(());
+    }
+    if !params.yes {
+        if !crate::ui::interactive() {
+            return Err(crate::error::Error::not_a_terminal("pass --yes to apply or --dry-run to preview").into());
+        }
+        if !Confirm::new(&format!("Rename {} files?", plan.len())).with_default(true).prompt()? {
+            ui::skip("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let mut recorder = Recorder::new("rename-batch", &format!("{} files", plan.len()));
+    // Swaps and shifts (a→b, b→c) need a temporary name first
+    let chained = plan.iter().any(|(_, to)| sources.contains(to));
+    let result = if chained {
+        let staged: Vec<(PathBuf, PathBuf, PathBuf)> = plan.iter().enumerate()
+            .map(|(i, (from, to))| (from.clone(), from.with_file_name(format!(".vg-rename-{}-{}", std::process::id(), i)), to.clone()))
+            .collect();
+        staged.iter().try_for_each(|(from, tmp, _)| recorder.rename(from, tmp))
+            .and_then(|_| staged.iter().try_for_each(|(_, tmp, to)| recorder.rename(tmp, to)))
+    } else {
+        plan.iter().try_for_each(|(from, to)| recorder.rename(from, to))
+    };
+    // Journal whatever happened, even on failure, so `vg undo` can roll back a partial run
+    recorder.finish()?;
+    result?;
+
+    ui::success(&format!("Renamed {} files", plan.len()));
+    ui::skip("Changed your mind? vg undo");
+    Ok(())
+}
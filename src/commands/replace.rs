@@ -0,0 +1,131 @@
+use crate::config::ConfigManager;
+use crate::history::Recorder;
+use crate::ui;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use inquire::Select;
+use regex::{NoExpand, Regex, RegexBuilder};
+use std::path::{Path, PathBuf};
+
+pub struct ReplaceParams {
+    pub pattern: String,
+    pub replacement: String,
+    pub path: Option<PathBuf>,
+    pub ignore_case: bool,
+    pub fixed: bool,
+    pub hidden: bool,
+    pub yes: bool,
+    pub dry_run: bool,
+}
+
+/// One file's pending edit.
+struct FileEdit {
+    path: PathBuf,
+    updated: String,
+    /// (line number, old line, new line)
+    lines: Vec<(usize, String, String)>,
+    count: usize,
+}
+
+/// Replace line by line so a match can never span lines and the preview is exactly what gets written.
+fn plan(path: &Path, re: &Regex, replacement: &str, literal: bool) -> Option<FileEdit> {
+    let bytes = std::fs::read(path).ok()?;
+    if bytes.iter().take(8192).any(|&b| b == 0) {
+        return None;
+    }
+    // Only touch valid UTF-8 so a rewrite can never mangle encodings
+    let original = String::from_utf8(bytes).ok()?;
+    let mut updated = String::with_capacity(original.len());
+    let mut lines = Vec::new();
+    let mut count = 0;
+    for (i, raw) in original.split_inclusive('\n').enumerate() {
+        let body = raw.trim_end_matches(['\n', '\r']);
+        let ending = &raw[body.len()..];
+        let hits = re.find_iter(body).count();
+        if hits == 0 {
+            updated.push_str(raw);
+            continue;
+        }
+        let new = if literal { re.replace_all(body, NoExpand(replacement)) } else { re.replace_all(body, replacement) };
+        if new != body {
+            count += hits;
+            lines.push((i + 1, body.to_string(), new.to_string()));
+        }
+        updated.push_str(&new);
+        updated.push_str(ending);
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    Some(FileEdit { path: path.to_path_buf(), updated, lines, count })
+}
+
+fn print_diff(edit: &FileEdit) {
+    println!();
+    println!("{}  {}", edit.path.display().to_string().truecolor(96, 165, 250).bold(), format!("({} replacements)", edit.count).truecolor(100, 116, 139));
+    for (n, old, new) in &edit.lines {
+        println!("{:>6} {} {}", n.to_string().truecolor(100, 116, 139), "-".truecolor(248, 113, 113), old.trim_end().truecolor(248, 113, 113));
+        println!("{:>6} {} {}", "", "+".truecolor(74, 222, 128), new.trim_end().truecolor(74, 222, 128));
+    }
+}
+
+pub fn run(params: ReplaceParams, config: &ConfigManager) -> Result<()> {
+    let pattern = if params.fixed { regex::escape(&params.pattern) } else { params.pattern.clone() };
+    let re = RegexBuilder::new(&pattern)
+        .case_insensitive(params.ignore_case)
+        .build()
+        .with_context(|| format!("Invalid pattern '{}'", params.pattern))?;
+
+    let root = params.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let files = if root.is_file() { vec![root.clone()] } else { super::grep::files(&root, params.hidden, config) };
+    let mut edits: Vec<FileEdit> = files.iter().filter_map(|p| plan(p, &re, &params.replacement, params.fixed)).collect();
+    edits.sort_by(|a, b| a.path.cmp(&b.path));
+
+    if edits.is_empty() {
+        ui::skip(&format!("No matches for '{}' in {}", params.pattern, root.display()));
+        return Ok(());
+    }
+
+    let interactive = !params.yes && !params.dry_run && crate::ui::interactive();
+    if !interactive && !params.yes && !params.dry_run {
+        return Err(crate::error::Error::not_a_terminal("pass --yes to apply or --dry-run to preview").into());
+    }
+
+    let summary = format!("'{}' → '{}' in {}", params.pattern, params.replacement, root.display());
+    let mut recorder = Recorder::new("replace", &summary);
+    let mut apply_all = params.yes;
+    let (mut files_changed, mut replacements) = (0, 0);
+
+    for edit in &edits {
+        print_diff(edit);
+        if params.dry_run {
+            continue;
+        }
+        if !apply_all {
+            let choice = Select::new("Apply?", vec!["Yes", "No", "All remaining", "Quit"]).prompt()?;
+            match choice {
+                "No" => continue,
+                "All remaining" => apply_all = true,
+                "Quit" => break,
+                _ => {}
+            }
+        }
+        recorder.write(&edit.path, edit.updated.as_bytes())?;
+        files_changed += 1;
+        replacements += edit.count;
+    }
+
+    println!();
+    if params.dry_run {
+        let total: usize = edits.iter().map(|e| e.count).sum();
+        ui::skip(&format!("Dry run: {} replacements in {} files — nothing written", total, edits.len()));
+        return Ok(());
+    }
+    if recorder.finish()?.is_some() {
+        ui::success(&format!("{} replacements in {} files", replacements, files_changed));
+        ui::skip("Changed your mind? vg undo");
+    } else {
+        ui::skip("No files changed");
+    }
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use crate::package_managers::run_cmd;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use inquire::Select;
+use std::path::PathBuf;
+use std::process::Command;
+use which::which;
+
+const PACMAN_LOG: &str = "/var/log/pacman.log";
+const PACMAN_CACHE: &str = "/var/cache/pacman/pkg";
+const APT_HISTORY: &str = "/var/log/apt/history.log";
+/// Recent upgrades offered when no package is named.
+const RECENT: usize = 30;
+
+pub struct RollbackParams {
+    /// Package to downgrade; picked from recent upgrades when omitted
+    pub package: Option<String>,
+    /// Version to go back to; picked from the candidates when omitted
+    pub to: Option<String>,
+    /// Restore a Timeshift or Snapper snapshot instead of single packages
+    pub snapshot: bool,
+    pub yes: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Backend {
+    Pacman,
+    Apt,
+    Dnf,
+}
+
+fn backend() -> Option<Backend> {
+    if which("pacman").is_ok() {
+        Some(Backend::Pacman)
+    } else if which("apt").is_ok() {
+        Some(Backend::Apt)
+    } else if which("dnf").is_ok() {
+        Some(Backend::Dnf)
+    } else {
+        None
+    }
+}
+
+fn stdout(program: &str, args: &[&str]) -> Result<String> {
+    let out = crate::logging::output(Command::new(program).args(args)).with_context(|| format!("Failed to run {}", program))?;
+    if !out.status.success() {
+        return Err(anyhow!("{} failed: {}", program, String::from_utf8_lossy(&out.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).to_string())
+}
+
+fn pick(prompt: &str, options: Vec<String>) -> Result<String> {
+    if !crate::ui::interactive() {
+        return Err(crate::error::Error::not_a_terminal("name the package and pass --to <version>").into());
+    }
+    Ok(Select::new(prompt, options).with_page_size(15).prompt()?)
+}
+
+/// Recent upgrades from the package manager's log, newest first: (package, from, to).
+fn recent_upgrades(backend: Backend) -> Vec<(String, String, String)> {
+    let mut upgrades = Vec::new();
+    match backend {
+        Backend::Pacman => {
+            // "[2026-10-01T09:12:44+0200] [ALPM] upgraded linux (6.6.1-1 -> 6.6.2-1)"
+            for line in std::fs::read_to_string(PACMAN_LOG).unwrap_or_default().lines() {
+                let Some((_, rest)) = line.split_once("[ALPM] upgraded ") else { continue };
+                let Some((name, versions)) = rest.split_once(" (") else { continue };
+                let Some((from, to)) = versions.trim_end_matches(')').split_once(" -> ") else { continue };
+                upgrades.push((name.to_string(), from.to_string(), to.to_string()));
+            }
+        }
+        Backend::Apt => {
+            // "Upgrade: libc6:amd64 (2.36-9, 2.36-9+deb12u1), tzdata:amd64 (2024a-0, 2024b-0)"
+            for line in std::fs::read_to_string(APT_HISTORY).unwrap_or_default().lines() {
+                let Some(list) = line.strip_prefix("Upgrade: ") else { continue };
+                for entry in list.split("), ") {
+                    let Some((name, versions)) = entry.split_once(" (") else { continue };
+                    let Some((from, to)) = versions.trim_end_matches(')').split_once(", ") else { continue };
+                    let name = name.split(':').next().unwrap_or(name);
+                    upgrades.push((name.to_string(), from.to_string(), to.to_string()));
+                }
+            }
+        }
+        Backend::Dnf => {}
+    }
+    upgrades.reverse();
+    let mut seen = std::collections::HashSet::new();
+    upgrades.retain(|(name, _, _)| seen.insert(name.clone()));
+    upgrades.truncate(RECENT);
+    upgrades
+}
+
+fn installed_version(backend: Backend, package: &str) -> Option<String> {
+    match backend {
+        Backend::Pacman => stdout("pacman", &["-Q", package]).ok()?.split_whitespace().nth(1).map(str::to_string),
+        Backend::Apt => stdout("dpkg-query", &["-W", "-f=${Version}", package]).ok().filter(|v| !v.is_empty()),
+        Backend::Dnf => stdout("rpm", &["-q", "--qf", "%{EVR}", package]).ok().filter(|v| !v.is_empty()),
+    }
+}
+
+/// Versions that can be installed instead of the current one, newest first.
+/// For pacman these are the packages still in the cache.
+fn candidates(backend: Backend, package: &str) -> Result<Vec<(String, Option<PathBuf>)>> {
+    match backend {
+        Backend::Pacman => {
+            let mut found: Vec<(String, PathBuf, std::time::SystemTime)> = std::fs::read_dir(PACMAN_CACHE)
+                .with_context(|| format!("Cannot read {}", PACMAN_CACHE))?
+                .flatten()
+                .filter_map(|e| {
+                    let file = e.file_name().to_string_lossy().to_string();
+                    let stem = file.strip_suffix(".pkg.tar.zst").or_else(|| file.strip_suffix(".pkg.tar.xz"))?;
+                    // name-version-release-arch; names may contain dashes themselves
+                    let mut parts = stem.rsplitn(4, '-');
+                    let (_arch, rel, ver, name) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+                    let modified = e.metadata().and_then(|m| m.modified()).ok()?;
+                    (name == package).then(|| (format!("{}-{}", ver, rel), e.path(), modified))
+                })
+                .collect();
+            found.sort_by_key(|(_, _, modified)| std::cmp::Reverse(*modified));
+            Ok(found.into_iter().map(|(v, p, _)| (v, Some(p))).collect())
+        }
+        Backend::Apt => {
+            // " libc6 | 2.36-9+deb12u1 | http://deb.debian.org/debian bookworm/main amd64 Packages"
+            let mut versions: Vec<(String, Option<PathBuf>)> = Vec::new();
+            for line in stdout("apt-cache", &["madison", package])?.lines() {
+                if let Some(version) = line.split('|').nth(1).map(str::trim) {
+                    if !versions.iter().any(|(v, _)| v == version) {
+                        versions.push((version.to_string(), None));
+                    }
+                }
+            }
+            Ok(versions)
+        }
+        Backend::Dnf => {
+            // "bash.x86_64    5.2.15-3.fc38    updates" under Installed/Available Packages headers
+            let mut versions: Vec<(String, Option<PathBuf>)> = Vec::new();
+            for line in stdout("dnf", &["list", "--showduplicates", "--quiet", package])?.lines() {
+                let mut fields = line.split_whitespace();
+                let (Some(name), Some(version)) = (fields.next(), fields.next()) else { continue };
+                if name.rsplit_once('.').map(|(n, _)| n) != Some(package) {
+                    continue;
+                }
+                // rpm -q leaves out a zero epoch, dnf list shows it
+                let version = version.strip_prefix("0:").unwrap_or(version);
+                if !versions.iter().any(|(v, _)| v == version) {
+                    versions.push((version.to_string(), None));
+                }
+            }
+            versions.reverse();
+            Ok(versions)
+        }
+    }
+}
+
+fn downgrade(backend: Backend, params: RollbackParams) -> Result<()> {
+    let package = match params.package {
+        Some(p) => p,
+        None if matches!(backend, Backend::Dnf) => return Err(anyhow!("Name the package to go back to --to: vg update rollback <package> --to <version>")),
+        None => {
+            let recent = recent_upgrades(backend);
+            if recent.is_empty() {
+                return Err(anyhow!("No upgrades in the package log — name the package: vg update rollback <package>"));
+            }
+            let labels: Vec<String> = recent.iter().map(|(n, from, to)| format!("{}  {} → {}", n, from, to)).collect();
+            let choice = pick("Roll back which package?", labels.clone())?;
+            let idx = labels.iter().position(|l| *l == choice).unwrap_or(0);
+            recent[idx].0.clone()
+        }
+    };
+    let current = installed_version(backend, &package).ok_or_else(|| anyhow!("{} is not installed", package))?;
+    let options: Vec<(String, Option<PathBuf>)> = candidates(backend, &package)?
+        .into_iter().filter(|(v, _)| *v != current).collect();
+    if options.is_empty() {
+        return Err(anyhow!(match backend {
+            Backend::Pacman => format!("No other version of {} in {} — the archive (archive.archlinux.org) has older ones", package, PACMAN_CACHE),
+            _ => format!("No other version of {} is available from your sources", package),
+        }));
+    }
+
+    ui::info_line("Package", &package);
+    ui::info_line("Installed", &current);
+    let (version, file) = match params.to {
+        Some(to) => options.into_iter().find(|(v, _)| *v == to)
+            .ok_or_else(|| anyhow!("{} {} is not available — run without --to to see the candidates", package, to))?,
+        None => {
+            let labels: Vec<String> = options.iter().map(|(v, _)| v.clone()).collect();
+            let choice = pick("Go back to:", labels)?;
+            options.into_iter().find(|(v, _)| *v == choice).unwrap_or_default()
+        }
+    };
+    println!();
+
+    match (backend, file) {
+        (Backend::Pacman, Some(file)) => {
+            let file = file.to_string_lossy().to_string();
+            let mut args = vec!["pacman", "-U", file.as_str()];
+            if params.yes { args.push("--noconfirm"); }
+            run_cmd(&args, true)?;
+            ui::success(&format!("{} is back at {}", package, version));
+            ui::skip(&format!("Keep it there until a fix lands: add IgnorePkg = {} to /etc/pacman.conf", package));
+        }
+        (Backend::Dnf, _) => {
+            let target = format!("{}-{}", package, version);
+            let mut args = vec!["dnf", "downgrade", target.as_str()];
+            if params.yes { args.push("-y"); }
+            run_cmd(&args, true)?;
+            ui::success(&format!("{} is back at {}", package, version));
+            ui::skip(&format!("Keep it there until a fix lands: sudo dnf versionlock add {}", package));
+        }
+        _ => {
+            let target = format!("{}={}", package, version);
+            let mut args = vec!["apt", "install", "--allow-downgrades", target.as_str()];
+            if params.yes { args.push("-y"); }
+            run_cmd(&args, true)?;
+            ui::success(&format!("{} is back at {}", package, version));
+            ui::skip(&format!("Keep it there until a fix lands: sudo apt-mark hold {}", package));
+        }
+    }
+    Ok(())
+}
+
+/// dnf records whole transactions; undoing one restores every package it changed.
+fn dnf_undo(yes: bool) -> Result<()> {
+    // "    12 | upgrade            | 2026-10-01 09:12 | Upgrade        |   31   "
+    let history = stdout("dnf", &["history", "list"])?;
+    let rows: Vec<String> = history.lines()
+        .filter(|l| l.trim_start().chars().next().is_some_and(|c| c.is_ascii_digit()))
+        .map(|l| l.split('|').map(str::trim).collect::<Vec<_>>().join("  "))
+        .take(RECENT)
+        .collect();
+    if rows.is_empty() {
+        return Err(anyhow!("dnf history is empty"));
+    }
+    let choice = pick("Undo which transaction?", rows)?;
+    let id = choice.split_whitespace().next().unwrap_or_default().to_string();
+    let mut args = vec!["dnf", "history", "undo", id.as_str()];
+    if yes { args.push("-y"); }
+    run_cmd(&args, true)?;
+    ui::success(&format!("Transaction {} undone", id));
+    Ok(())
+}
+
+/// Whole-system restore from a Timeshift or Snapper snapshot.
+fn snapshot() -> Result<()> {
+    if which("timeshift").is_ok() {
+        // Timeshift lists and confirms snapshots itself, including where to restore the bootloader
+        run_cmd(&["timeshift", "--restore"], true)?;
+        return Ok(());
+    }
+    if which("snapper").is_ok() {
+        // "number,date,description" rows; 0 is the live system
+        let list = stdout("snapper", &["--csvout", "list", "--columns", "number,date,description"])?;
+        let rows: Vec<String> = list.lines().skip(1)
+            .filter(|l| !l.starts_with("0,"))
+            .map(|l| l.replacen(',', "  ", 2))
+            .collect();
+        if rows.is_empty() {
+            return Err(anyhow!("Snapper has no snapshots"));
+        }
+        let choice = pick("Roll back to which snapshot?", rows.into_iter().rev().collect())?;
+        let number = choice.split_whitespace().next().unwrap_or_default().to_string();
+        run_cmd(&["snapper", "rollback", &number], true)?;
+        ui::success(&format!("Snapshot {} becomes the system on the next boot — reboot to finish", number));
+        return Ok(());
+    }
+    Err(anyhow!("No snapshot tool found — install timeshift or snapper"))
+}
+
+/// `vg update rollback [package] [--to version] [--snapshot]`
+pub fn run(params: RollbackParams) -> Result<()> {
+    ui::print_header("UPDATE ROLLBACK");
+    if params.snapshot {
+        return snapshot();
+    }
+    match backend() {
+        // dnf's log has no per-package upgrade list to pick from, so without a package it undoes a transaction
+        Some(Backend::Dnf) if params.package.is_none() && params.to.is_none() => dnf_undo(params.yes),
+        Some(backend) => downgrade(backend, params),
+        None => Err(anyhow!("Rollback supports pacman, apt and dnf; for others try --snapshot")),
+    }
+}
@@ -0,0 +1,225 @@
+use crate::config::ConfigManager;
+use crate::ui;
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use inquire::Select;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Recent tasks remembered per project.
+const MAX_RECENT: usize = 10;
+
+const MAKEFILES: &[&str] = &["GNUmakefile", "makefile", "Makefile"];
+const JUSTFILES: &[&str] = &["justfile", "Justfile", ".justfile"];
+
+struct Task {
+    /// Where the task comes from: cargo, npm/pnpm/yarn/bun, make or just
+    source: &'static str,
+    name: String,
+    command: Vec<String>,
+}
+
+impl Task {
+    fn label(&self) -> String {
+        format!("{}:{}", self.source, self.name)
+    }
+}
+
+/// Project root → task labels, most recent first.
+type Recent = BTreeMap<String, Vec<String>>;
+
+fn recent_path() -> PathBuf {
+    ConfigManager::data_dir().join("run_recent.json")
+}
+
+fn load_recent() -> Recent {
+    std::fs::read_to_string(recent_path()).ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn remember(root: &Path, label: &str) -> Result<()> {
+    let mut recent = load_recent();
+    let list = recent.entry(root.display().to_string()).or_default();
+    list.retain(|l| l != label);
+    list.insert(0, label.to_string());
+    list.truncate(MAX_RECENT);
+    let path = recent_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&recent)?)?;
+    Ok(())
+}
+
+/// Nearest ancestor with any task source.
+fn find_root(start: &Path) -> Option<PathBuf> {
+    start.ancestors()
+        .find(|d| ["Cargo.toml", "package.json"].iter().chain(MAKEFILES).chain(JUSTFILES).any(|f| d.join(f).is_file()))
+        .map(Path::to_path_buf)
+}
+
+fn cargo_tasks(root: &Path) -> Vec<Task> {
+    let Some(manifest) = std::fs::read_to_string(root.join("Cargo.toml")).ok()
+        .and_then(|c| c.parse::<toml::Value>().ok()) else { return vec![] };
+    let mut bins: Vec<String> = manifest.get("bin").and_then(|b| b.as_array())
+        .map(|a| a.iter().filter_map(|b| b.get("name")?.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+    if root.join("src/main.rs").exists() {
+        if let Some(name) = manifest.get("package").and_then(|p| p.get("name")).and_then(|n| n.as_str()) {
+            bins.push(name.to_string());
+        }
+    }
+    for entry in std::fs::read_dir(root.join("src/bin")).into_iter().flatten().flatten() {
+        let p = entry.path();
+        if p.extension().is_some_and(|e| e == "rs") || p.join("main.rs").exists() {
+            if let Some(stem) = p.file_stem() {
+                bins.push(stem.to_string_lossy().to_string());
+            }
+        }
+    }
+    bins.sort();
+    bins.dedup();
+    bins.into_iter()
+        .map(|b| Task { source: "cargo", command: vec!["cargo".into(), "run".into(), "--bin".into(), b.clone(), "--".into()], name: b })
+        .collect()
+}
+
+fn npm_tasks(root: &Path) -> Vec<Task> {
+    let Some(pkg) = std::fs::read_to_string(root.join("package.json")).ok()
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok()) else { return vec![] };
+    // Use whichever package manager the lockfile belongs to
+    let runner = [("pnpm-lock.yaml", "pnpm"), ("yarn.lock", "yarn"), ("bun.lockb", "bun"), ("bun.lock", "bun")]
+        .iter()
+        .find(|(lock, _)| root.join(lock).exists())
+        .map(|(_, r)| *r)
+        .unwrap_or("npm");
+    pkg.get("scripts").and_then(|s| s.as_object())
+        .map(|scripts| scripts.keys()
+            .map(|name| {
+                let mut command = vec![runner.to_string(), "run".into(), name.clone()];
+                // npm only forwards extra arguments after `--`
+                if runner == "npm" {
+                    command.push("--".into());
+                }
+                Task { source: runner, name: name.clone(), command }
+            })
+            .collect())
+        .unwrap_or_default()
+}
+
+/// Targets are `name:` at the start of a line; variables (`:=`), pattern rules and special targets are skipped.
+fn make_tasks(root: &Path) -> Vec<Task> {
+    let Some(content) = MAKEFILES.iter().find_map(|f| std::fs::read_to_string(root.join(f)).ok()) else { return vec![] };
+    let re = Regex::new(r"^([A-Za-z0-9][A-Za-z0-9_./-]*)\s*:([^=]|$)").expect("valid make target regex");
+    let mut seen = std::collections::HashSet::new();
+    content.lines()
+        .filter_map(|l| re.captures(l).map(|c| c[1].to_string()))
+        .filter(|n| seen.insert(n.clone()))
+        .map(|n| Task { source: "make", command: vec!["make".into(), n.clone()], name: n })
+        .collect()
+}
+
+fn just_tasks(root: &Path) -> Vec<Task> {
+    let Some(content) = JUSTFILES.iter().find_map(|f| std::fs::read_to_string(root.join(f)).ok()) else { return vec![] };
+    let re = Regex::new(r"^@?([A-Za-z_][A-Za-z0-9_-]*)(\s[^:]*)?:([^=]|$)").expect("valid just recipe regex");
+    content.lines()
+        .filter_map(|l| re.captures(l).map(|c| c[1].to_string()))
+        .filter(|n| !matches!(n.as_str(), "set" | "export" | "alias" | "import" | "mod"))
+        .map(|n| Task { source: "just", command: vec!["just".into(), n.clone()], name: n })
+        .collect()
+}
+
+fn collect(root: &Path) -> Vec<Task> {
+    let mut tasks = cargo_tasks(root);
+    tasks.extend(npm_tasks(root));
+    tasks.extend(make_tasks(root));
+    tasks.extend(just_tasks(root));
+    tasks
+}
+
+/// Recent tasks first, in the order they were last run.
+fn sort_by_recent(tasks: &mut [Task], recent: &[String]) {
+    tasks.sort_by_key(|t| recent.iter().position(|r| *r == t.label()).unwrap_or(usize::MAX));
+}
+
+fn print_list(tasks: &[Task], recent: &[String]) {
+    for t in tasks {
+        let mark = if recent.contains(&t.label()) { "↺" } else { " " };
+        println!("  {} {:<6} {}", mark.truecolor(100, 116, 139), t.source.truecolor(71, 85, 105), t.name.truecolor(96, 165, 250));
+    }
+}
+
+/// `vg run [task] [args…]` — run a task from Cargo.toml, package.json, a Makefile or a justfile.
+pub fn run(task: Option<String>, args: Vec<String>, list: bool) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let Some(root) = find_root(&cwd) else {
+        return Err(anyhow!("No Cargo.toml, package.json, Makefile or justfile found here or in a parent directory"));
+    };
+    let key = root.display().to_string();
+    let recent = load_recent().remove(&key).unwrap_or_default();
+    let mut tasks = collect(&root);
+    if tasks.is_empty() {
+        ui::skip(&format!("No tasks found in {}", root.display()));
+        return Ok(());
+    }
+    sort_by_recent(&mut tasks, &recent);
+
+    let picked = match task {
+        _ if list => None,
+        // An exact name or source:name wins, otherwise the best fuzzy match once confirmed
+        Some(q) => match tasks.iter().position(|t| t.name == q || t.label() == q) {
+            Some(i) => Some(i),
+            None => {
+                let labels: Vec<String> = tasks.iter().map(|t| t.label()).collect();
+                let (i, _) = super::search::fuzzy_rank(&q, &labels).into_iter().next()
+                    .ok_or_else(|| anyhow!("No task matches '{}' — vg run --list", q))?;
+                if !ui::confirm_match(&q, &labels[i])? {
+                    ui::skip("Aborted.");
+                    return Ok(());
+                }
+                Some(i)
+            }
+        },
+        None if crate::ui::interactive() => {
+            let options: Vec<String> = tasks.iter()
+                .map(|t| format!("{} {}  ({})", if recent.contains(&t.label()) { "↺" } else { " " }, t.name, t.source))
+                .collect();
+            let choice = Select::new("Run which task?", options.clone()).with_page_size(15).prompt()?;
+            options.iter().position(|o| *o == choice)
+        }
+        None => None,
+    };
+
+    let Some(idx) = picked else {
+        ui::print_header("TASKS");
+        ui::info_line("Project", &root.display().to_string());
+        println!();
+        print_list(&tasks, &recent);
+        println!();
+        ui::skip("Run one with: vg run <task> [args…]");
+        return Ok(());
+    };
+
+    let t = &tasks[idx];
+    let mut command = t.command.clone();
+    command.extend(args);
+    // cargo and npm tasks end in `--`; drop it when there is nothing after it
+    if command.last().is_some_and(|c| c == "--") {
+        command.pop();
+    }
+    println!("{} {}", "▶".truecolor(96, 165, 250), command.join(" ").truecolor(224, 242, 254));
+    remember(&root, &t.label())?;
+
+    let status = crate::logging::status(Command::new(&command[0]).args(&command[1..]).current_dir(&root))
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => anyhow!("{} is not installed — vg install {}", command[0], command[0]),
+            _ => anyhow!("Failed to start {}: {}", command[0], e),
+        })?;
+    if !status.success() {
+        return Err(anyhow!("Task '{}' exited with {}", t.name, status.code().map(|c| c.to_string()).unwrap_or_else(|| "a signal".into())));
+    }
+    Ok(())
+}
@@ -5,7 +5,6 @@ use anyhow::{Result, Context};
 use colored::Colorize;
 use rusqlite::{Connection, params};
 use std::path::PathBuf;
-use ignore::WalkBuilder;
 use directories::ProjectDirs;
 use chrono::Utc;
 use nucleo_matcher::{Matcher, Config as NucleoConfig};
@@ -25,8 +24,9 @@ const FUZZY_SCAN_LIMIT: i64 = 50_000;
 const FUZZY_SCORE_THRESHOLD: u32 = 150;
 const FUZZY_MAX_RESULTS: usize = 5;
 const FUZZY_FALLBACK_THRESHOLD: usize = 5;
-const PROGRESS_INTERVAL: u64 = 10_000;
 const INDEX_BATCH_SIZE: usize = 500;
+const BUILD_HISTORY_LEN: usize = 10;
+const STALE_SAMPLE: i64 = 2_000;
 
 pub(crate) fn get_db_path() -> PathBuf {
     if let Some(proj_dirs) = ProjectDirs::from("", "volantic", "genesis") {
@@ -38,7 +38,7 @@ pub(crate) fn get_db_path() -> PathBuf {
     }
 }
 
-fn open_db() -> Result<Connection> {
+pub(crate) fn open_db() -> Result<Connection> {
     let db_path = get_db_path();
     if let Some(parent) = db_path.parent() {
         std::fs::create_dir_all(parent).context("Failed to create data directory")?;
@@ -90,6 +90,30 @@ fn migrate_schema(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    // Add mime column if missing (non-destructive; filled on the next index build)
+    let mime_count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('files_meta') WHERE name='mime'",
+        [],
+        |r| r.get(0),
+    ).unwrap_or(0);
+    if mime_count == 0 {
+        conn.execute_batch(
+            "ALTER TABLE files_meta ADD COLUMN mime TEXT NOT NULL DEFAULT '';"
+        )?;
+    }
+
+    // Add link_target column if missing (non-destructive)
+    let link_target_count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('files_meta') WHERE name='link_target'",
+        [],
+        |r| r.get(0),
+    ).unwrap_or(0);
+    if link_target_count == 0 {
+        conn.execute_batch(
+            "ALTER TABLE files_meta ADD COLUMN link_target TEXT NOT NULL DEFAULT '';"
+        )?;
+    }
+
     Ok(())
 }
 
@@ -111,7 +135,9 @@ fn init_db(conn: &Connection) -> Result<()> {
             modified TEXT NOT NULL,
             ext TEXT NOT NULL DEFAULT '',
             modified_unix INTEGER NOT NULL DEFAULT 0,
-            scope TEXT NOT NULL DEFAULT 'user'
+            scope TEXT NOT NULL DEFAULT 'user',
+            mime TEXT NOT NULL DEFAULT '',
+            link_target TEXT NOT NULL DEFAULT ''
         );
     ")?;
     Ok(())
@@ -140,6 +166,18 @@ fn read_file_content(path: &str, ext: &str) -> String {
     }
 }
 
+/// Content-based type from the file's magic bytes, so `report` (no extension) or a
+/// misnamed `photo.txt` still get the right MIME. Text files have no magic; they fall
+/// back to text/plain when their extension is one we index content for.
+fn detect_mime(path: &str, ext: &str, size: i64) -> String {
+    if size > 0 {
+        if let Ok(Some(kind)) = infer::get_from_path(path) {
+            return kind.mime_type().to_string();
+        }
+    }
+    if is_text_extension(ext) { "text/plain".to_string() } else { String::new() }
+}
+
 struct FileEntry {
     name: String,
     path: String,
@@ -148,6 +186,9 @@ struct FileEntry {
     modified_unix: i64,
     ext: String,
     content: String,
+    mime: String,
+    /// Real path, when the file was reached through a symlink
+    link_target: String,
     scope: &'static str,
 }
 
@@ -160,6 +201,7 @@ pub fn build_index(user_paths: Vec<PathBuf>, config: &ConfigManager) -> Result<(
 
     let ignore_patterns = config.config.search.ignore_patterns.clone();
     let max_depth = config.config.search.max_depth;
+    let follow_links = config.config.search.follow_links;
     let exclude_hidden = config.config.search.exclude_hidden;
     let full_system = config.config.search.full_system_index;
     let system_roots: Vec<PathBuf> = config.config.search.system_index_roots
@@ -173,8 +215,8 @@ pub fn build_index(user_paths: Vec<PathBuf>, config: &ConfigManager) -> Result<(
     // ── User paths (scope = "user") ──────────────────────────────
     for base_path in &user_paths {
         index_path_into(
-            base_path, "user", Some(max_depth), exclude_hidden,
-            &ignore_patterns, &[], &conn, &mut user_count, &index_start,
+            base_path, "user", Some(max_depth), exclude_hidden, follow_links,
+            &ignore_patterns, &[], &conn, &mut user_count,
         )?;
     }
 
@@ -187,17 +229,18 @@ pub fn build_index(user_paths: Vec<PathBuf>, config: &ConfigManager) -> Result<(
         for root in &system_roots {
             if !root.exists() { continue; }
             index_path_into(
-                root, "system", None, false,
-                &[], &system_excludes, &conn, &mut system_count, &index_start,
+                root, "system", None, false, false,
+                &[], &system_excludes, &conn, &mut system_count,
             )?;
         }
         // Subtract user-path files that got double-counted
-        // (WalkBuilder will enter user dirs again — mark them system, that's fine,
+        // (the walker will enter user dirs again — mark them system, that's fine,
         //  but we skip paths already indexed under user scope to avoid duplicates)
     }
 
     let total = user_count + system_count;
     let now = Utc::now().to_rfc3339();
+    record_build(&conn, &now, index_start.elapsed().as_secs_f64(), total)?;
     conn.execute(
         "INSERT OR REPLACE INTO index_meta(key, value) VALUES ('last_updated', ?1)",
         params![now],
@@ -218,7 +261,7 @@ pub fn build_index(user_paths: Vec<PathBuf>, config: &ConfigManager) -> Result<(
     println!();
     if total == 0 {
         ui::fail("No files indexed — all configured paths were missing or empty.");
-        ui::skip("Update your paths:  vg config edit");
+        ui::skip("Update your paths:  vg config");
         ui::skip("Or specify directly: vg index --paths /home/you");
     } else {
         let system_note = if full_system {
@@ -238,20 +281,16 @@ pub fn build_index(user_paths: Vec<PathBuf>, config: &ConfigManager) -> Result<(
     Ok(())
 }
 
-fn is_excluded(path_str: &str, excludes: &[String]) -> bool {
-    excludes.iter().any(|ex| path_str == ex.as_str() || path_str.starts_with(&format!("{}/", ex)))
-}
-
 fn index_path_into(
     base_path: &PathBuf,
     scope: &'static str,
     max_depth: Option<usize>,
     exclude_hidden: bool,
+    follow_links: bool,
     ignore_patterns: &[String],
     hard_excludes: &[String],
     conn: &Connection,
     count: &mut u64,
-    index_start: &std::time::Instant,
 ) -> Result<()> {
     if !base_path.exists() {
         if scope == "user" {
@@ -263,63 +302,69 @@ fn index_path_into(
         ui::info_line("Indexing", &base_path.display().to_string());
     }
 
-    let mut walker = WalkBuilder::new(base_path);
-    walker
-        .max_depth(max_depth)
-        .hidden(exclude_hidden)
-        .git_ignore(scope == "user")
-        .git_global(scope == "user")
-        .ignore(scope == "user")
-        .follow_links(false);
-    let walker = walker.build();
-
-    let mut pending: Vec<(String, String, i64, String, i64, String)> = Vec::new();
-
-    for entry in walker {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(_) => continue,
-        };
-        let path_str = entry.path().to_string_lossy().to_string();
-
-        // Hard-exclude certain filesystem paths (e.g. /proc, /sys)
-        if is_excluded(&path_str, hard_excludes) {
-            continue;
-        }
+    let options = crate::walk::Options {
+        ignore_files: scope == "user",
+        skip_hidden: exclude_hidden,
+        // Link loops are reported as walk errors and skipped
+        follow_links,
+        max_depth,
+        // Hard-excluded filesystem paths (e.g. /proc, /sys) aren't entered at all
+        skip_paths: hard_excludes.iter().map(PathBuf::from).collect(),
+        ..Default::default()
+    };
+    let base_canon = if follow_links { base_path.canonicalize().ok() } else { None };
 
+    let scanning = crate::progress::counter("Scanning", "files");
+    let pending: Vec<(String, String, i64, String, i64, String, String)> = crate::walk::walk(base_path, &options, |entry| {
         if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
-            continue;
+            return None;
         }
+        let path_str = entry.path().to_string_lossy().to_string();
 
         // User ignore patterns (substring match)
         if ignore_patterns.iter().any(|p| path_str.contains(p.as_str())) {
-            continue;
+            return None;
         }
 
-        if let Ok(meta) = entry.metadata() {
-            let name = entry.file_name().to_string_lossy().to_string();
-            let size = meta.len() as i64;
-            let modified_unix = meta.modified()
-                .map(|t| t.duration_since(std::time::UNIX_EPOCH)
-                    .map(|d| d.as_secs() as i64)
-                    .unwrap_or(0))
-                .unwrap_or(0);
-            let modified = meta.modified()
-                .map(|t| chrono::DateTime::<Utc>::from(t).to_rfc3339())
-                .unwrap_or_default();
-            let ext = entry.path()
-                .extension()
-                .map(|s| s.to_string_lossy().to_lowercase().to_string())
-                .unwrap_or_default();
-            pending.push((name, path_str, size, modified, modified_unix, ext));
-        }
-    }
+        let meta = entry.metadata().ok()?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let size = meta.len() as i64;
+        let modified_unix = meta.modified()
+            .map(|t| t.duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0))
+            .unwrap_or(0);
+        let modified = meta.modified()
+            .map(|t| chrono::DateTime::<Utc>::from(t).to_rfc3339())
+            .unwrap_or_default();
+        let ext = entry.path()
+            .extension()
+            .map(|s| s.to_string_lossy().to_lowercase().to_string())
+            .unwrap_or_default();
+        // Reached through a link (the file itself or a directory above it): keep the real path
+        let link_target = match &base_canon {
+            Some(base) => {
+                let expected = base.join(entry.path().strip_prefix(base_path).unwrap_or(entry.path()));
+                entry.path().canonicalize().ok()
+                    .filter(|real| *real != expected)
+                    .map(|real| real.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            }
+            None => String::new(),
+        };
+        scanning.inc(1);
+        Some((name, path_str, size, modified, modified_unix, ext, link_target))
+    }).items;
+    scanning.finish_and_clear();
+
+    let reading = crate::progress::bar(pending.len() as u64, "Reading");
 
     for chunk in pending.chunks(INDEX_BATCH_SIZE) {
         let entries: Vec<FileEntry> = chunk
             .par_iter()
-            .map(|(name, path, size, modified, modified_unix, ext)| {
+            .map(|(name, path, size, modified, modified_unix, ext, link_target)| {
                 let content = read_file_content(path, ext);
+                let mime = detect_mime(path, ext, *size);
                 FileEntry {
                     name: name.clone(),
                     path: path.clone(),
@@ -328,6 +373,8 @@ fn index_path_into(
                     modified_unix: *modified_unix,
                     ext: ext.clone(),
                     content,
+                    mime,
+                    link_target: link_target.clone(),
                     scope,
                 }
             })
@@ -340,18 +387,14 @@ fn index_path_into(
             )?;
             let rowid = conn.last_insert_rowid();
             conn.execute(
-                "INSERT INTO files_meta(rowid, size, modified, ext, modified_unix, scope) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                params![rowid, fe.size, fe.modified, fe.ext, fe.modified_unix, fe.scope],
+                "INSERT INTO files_meta(rowid, size, modified, ext, modified_unix, scope, mime, link_target) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![rowid, fe.size, fe.modified, fe.ext, fe.modified_unix, fe.scope, fe.mime, fe.link_target],
             )?;
             *count += 1;
-
-            if *count % PROGRESS_INTERVAL == 0 {
-                let elapsed = index_start.elapsed().as_secs_f64();
-                let rate = if elapsed > 0.0 { *count as f64 / elapsed } else { 0.0 };
-                ui::info_line("Progress", &format!("{} files ({:.0}/s)...", format_number(*count), rate));
-            }
+            reading.inc(1);
         }
     }
+    reading.finish_and_clear();
     Ok(())
 }
 
@@ -359,10 +402,63 @@ pub struct SearchParams {
     pub query: String,
     pub ext: Option<String>,
     pub path_filter: Option<String>,
+    /// Detected content types, comma-separated; `*` wildcards allowed (e.g. image/*)
+    pub mime: Option<String>,
     pub limit: Option<usize>,
     pub verbose: bool,
     /// Include system-indexed paths in results (default: user only)
     pub all_scopes: bool,
+    /// Machine-readable output instead of the result list
+    pub format: Option<Format>,
+}
+
+/// Output for scripts and desktop launchers.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum Format {
+    /// Array of result objects
+    Json,
+    /// Alfred Script Filter items
+    Alfred,
+    /// One path per line, for rofi -dmenu or a rofi script mode
+    Rofi,
+    /// Albert external-extension items
+    Albert,
+}
+
+/// Print `results` (from `query_json`) the way `format` expects.
+fn print_formatted(format: Format, results: &[serde_json::Value]) -> Result<()> {
+    let field = |r: &serde_json::Value, key: &str| r[key].as_str().unwrap_or_default().to_string();
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(results)?),
+        Format::Rofi => {
+            for r in results {
+                println!("{}", field(r, "path"));
+            }
+        }
+        Format::Alfred => {
+            let items: Vec<serde_json::Value> = results.iter().map(|r| serde_json::json!({
+                "uid": field(r, "path"),
+                "type": "file",
+                "title": field(r, "name"),
+                "subtitle": field(r, "path"),
+                "arg": field(r, "path"),
+                "icon": { "type": "fileicon", "path": field(r, "path") },
+            })).collect();
+            println!("{}", serde_json::to_string(&serde_json::json!({ "items": items }))?);
+        }
+        Format::Albert => {
+            let opener = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+            let items: Vec<serde_json::Value> = results.iter().map(|r| serde_json::json!({
+                "id": field(r, "path"),
+                "name": field(r, "name"),
+                "description": field(r, "path"),
+                "icon": "document",
+                "actions": [{ "name": "Open", "command": opener, "arguments": [field(r, "path")] }],
+            })).collect();
+            println!("{}", serde_json::to_string(&serde_json::json!({ "items": items }))?);
+        }
+    }
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -380,12 +476,38 @@ struct SearchResult {
     modified_unix: i64,
     final_score: f64,
     scope: String,
+    link_target: String,
+}
+
+impl SearchResult {
+    /// `path -> target` for entries indexed through a symlink.
+    fn display_path(&self) -> String {
+        if self.link_target.is_empty() {
+            self.path.clone()
+        } else {
+            format!("{} -> {}", self.path, self.link_target)
+        }
+    }
 }
 
 fn validate_ext_part(ext: &str) -> bool {
     ext.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-')
 }
 
+fn validate_mime_part(mime: &str) -> bool {
+    mime.chars().all(|c| c.is_ascii_alphanumeric() || "/*.+-_".contains(c))
+}
+
+/// SQL condition for `--mime image/*,application/pdf`, or None when nothing valid was given.
+fn mime_clause(mime: Option<&str>) -> Option<String> {
+    let parts: Vec<String> = mime?.split(',')
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty() && validate_mime_part(s))
+        .map(|s| format!("m.mime GLOB '{}'", s))
+        .collect();
+    if parts.is_empty() { None } else { Some(format!("({})", parts.join(" OR "))) }
+}
+
 pub(crate) fn is_glob_pattern(query: &str) -> bool {
     query.contains('*') || query.contains('?')
 }
@@ -487,14 +609,18 @@ fn run_glob_search(
     pattern: &str,
     limit: usize,
     all_scopes: bool,
+    mime: Option<&str>,
     conn: &Connection,
 ) -> Result<Vec<SearchResult>> {
     let (col, glob_pat) = expand_glob(pattern);
-    let scope_filter = if all_scopes { "" } else { " AND m.scope = 'user'" };
+    let mut scope_filter = if all_scopes { String::new() } else { " AND m.scope = 'user'".to_string() };
+    if let Some(mc) = mime_clause(mime) {
+        scope_filter.push_str(&format!(" AND {}", mc));
+    }
     let fetch_limit = (limit * 2) as i64;
 
     let sql = format!(
-        "SELECT f.rowid, f.name, f.path, m.size, m.ext, m.modified_unix, m.scope
+        "SELECT f.rowid, f.name, f.path, m.size, m.ext, m.modified_unix, m.scope, m.link_target
          FROM files f
          JOIN files_meta m ON f.rowid = m.rowid
          WHERE f.{} GLOB ?1{}
@@ -513,9 +639,10 @@ fn run_glob_search(
             row.get::<_, String>(4)?,
             row.get::<_, i64>(5)?,
             row.get::<_, String>(6)?,
+            row.get::<_, String>(7)?,
         )))?
         .filter_map(|r| r.ok())
-        .map(|(rowid, name, path, size, ext, modified_unix, scope)| SearchResult {
+        .map(|(rowid, name, path, size, ext, modified_unix, scope, link_target)| SearchResult {
             rowid,
             name,
             path,
@@ -528,6 +655,7 @@ fn run_glob_search(
             modified_unix,
             final_score: modified_unix as f64, // sort by recency
             scope,
+            link_target,
         })
         .collect();
 
@@ -542,6 +670,7 @@ fn print_results(
 ) {
     if results.is_empty() {
         ui::skip("No results found.");
+        crate::exit::set(crate::exit::NEGATIVE);
         return;
     }
 
@@ -558,7 +687,7 @@ fn print_results(
     for (i, r) in results.iter().take(top_count).enumerate() {
         let rank_str = format!("{}", i + 1).truecolor(96, 165, 250);
         let star = "★".truecolor(250, 204, 21);
-        let path_colored = color_by_match_type(&r.path, &r.match_type);
+        let path_colored = color_by_match_type(&r.display_path(), &r.match_type);
         let badge = format_badge(&r.match_type);
         let age = fmt_age(r.modified_unix);
         let size_str = fmt_bytes(r.size as u64);
@@ -589,7 +718,7 @@ fn print_results(
         println!();
         for (i, r) in results.iter().enumerate().skip(3) {
             let rank_str = format!("{:>3}", i + 1).truecolor(96, 165, 250);
-            let path_colored = color_by_match_type(&r.path, &r.match_type);
+            let path_colored = color_by_match_type(&r.display_path(), &r.match_type);
             let badge = format_badge(&r.match_type);
             let age = fmt_age(r.modified_unix);
             let size_str = fmt_bytes(r.size as u64);
@@ -611,27 +740,21 @@ fn print_results(
     }
 }
 
-pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
-    ui::print_header("SEARCH");
-
-    let db_path = get_db_path();
-    if !db_path.exists() {
-        ui::skip("No index found. Run 'vg index' first.");
-        return Ok(());
-    }
-
-    let conn = open_db()?;
-    ui::section(&format!("Results for '{}'", params.query));
+/// Per-stage timings for `vg search --verbose`.
+struct Timing {
+    fts: std::time::Duration,
+    fuzzy: std::time::Duration,
+}
 
-    let start = std::time::Instant::now();
+/// Run a query against the index: a glob, or FTS with a fuzzy fallback. Results are best
+/// first, up to twice the limit so callers can tell whether there are more.
+fn find(params: &SearchParams, conn: &Connection) -> Result<(Vec<SearchResult>, Option<Timing>)> {
     let limit = params.limit.unwrap_or(10);
 
     // ── Glob shortcut: query contains * or ? ──────────────────────────────────
     if is_glob_pattern(&params.query) {
-        let results = run_glob_search(&params.query, limit, params.all_scopes, &conn)?;
-        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
-        print_results(results, limit, elapsed_ms, params.verbose);
-        return Ok(());
+        let results = run_glob_search(&params.query, limit, params.all_scopes, params.mime.as_deref(), conn)?;
+        return Ok((results, None));
     }
 
     let fts_query = sanitize_fts_query(&params.query);
@@ -660,6 +783,9 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
         if let Some(ref ec) = ext_clause {
             conditions.push(ec.clone());
         }
+        if let Some(mc) = mime_clause(params.mime.as_deref()) {
+            conditions.push(mc);
+        }
         if path_pattern.is_some() {
             conditions.push("f.path LIKE ?3".to_string());
         }
@@ -668,7 +794,8 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
                     snippet(files, 2, '[', ']', '...', 20) as snip,
                     bm25(files, 10.0, 5.0, 1.0) as bm25_score,
                     m.modified_unix,
-                    m.scope
+                    m.scope,
+                    m.link_target
              FROM files f
              JOIN files_meta m ON f.rowid = m.rowid
              WHERE {}
@@ -683,7 +810,7 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
     let mut fts_results: Vec<SearchResult> = {
         let mut stmt = conn.prepare(&sql)?;
 
-        type Row = (i64, String, String, i64, String, String, f64, i64, String);
+        type Row = (i64, String, String, i64, String, String, f64, i64, String, String);
         let map_row = |row: &rusqlite::Row| Ok((
             row.get::<_, i64>(0)?,
             row.get::<_, String>(1)?,
@@ -694,6 +821,7 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
             row.get::<_, f64>(6)?,
             row.get::<_, i64>(7)?,
             row.get::<_, String>(8)?,
+            row.get::<_, String>(9)?,
         ));
         let rows: Vec<Row> = if path_pattern.is_some() {
             stmt.query_map(params![fts_query, fetch_limit, path_pattern.as_deref()], map_row)?
@@ -703,11 +831,11 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
                 .filter_map(|r| r.ok()).collect()
         };
 
-        rows.into_iter().map(|(rowid, name, path, size, ext, snip, bm25, modified_unix, scope)| {
+        rows.into_iter().map(|(rowid, name, path, size, ext, snip, bm25, modified_unix, scope, link_target)| {
             let match_type = determine_match_type(&params.query, &name, &path, false);
             let snippet = if snip.contains('[') { Some(snip) } else { None };
             let final_score = compute_score(bm25, &name, &path, &params.query, modified_unix);
-            SearchResult { rowid, name, path, size, ext, snippet, match_type, is_fuzzy: false, bm25, modified_unix, final_score, scope }
+            SearchResult { rowid, name, path, size, ext, snippet, match_type, is_fuzzy: false, bm25, modified_unix, final_score, scope, link_target }
         }).collect()
     };
 
@@ -722,16 +850,20 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
     if fts_results.len() < FUZZY_FALLBACK_THRESHOLD {
         let existing_rowids: std::collections::HashSet<i64> = fts_results.iter().map(|r| r.rowid).collect();
 
-        let scope_filter = if params.all_scopes { "" } else { " AND m.scope = 'user'" };
+        let mut scope_filter = if params.all_scopes { String::new() } else { " AND m.scope = 'user'".to_string() };
+        if let Some(mc) = mime_clause(params.mime.as_deref()) {
+            scope_filter.push_str(&format!(" AND {}", mc));
+        }
         let fuzzy_sql = format!(
-            "SELECT f.rowid, f.name, f.path, m.size, m.ext, m.modified_unix, m.scope
+            "SELECT f.rowid, f.name, f.path, m.size, m.ext, m.modified_unix, m.scope, m.link_target
              FROM files f JOIN files_meta m ON f.rowid = m.rowid
              WHERE 1=1{} LIMIT ?1",
             scope_filter
         );
         let mut scan_stmt = conn.prepare(&fuzzy_sql)?;
 
-        let fuzzy_candidates: Vec<(i64, String, String, i64, String, i64, String)> = scan_stmt
+        type Candidate = (i64, String, String, i64, String, i64, String, String);
+        let fuzzy_candidates: Vec<Candidate> = scan_stmt
             .query_map(params![FUZZY_SCAN_LIMIT], |row| Ok((
                 row.get::<_, i64>(0)?,
                 row.get::<_, String>(1)?,
@@ -740,6 +872,7 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
                 row.get::<_, String>(4)?,
                 row.get::<_, i64>(5)?,
                 row.get::<_, String>(6)?,
+                row.get::<_, String>(7)?,
             )))?
             .filter_map(|r| r.ok())
             .collect();
@@ -747,14 +880,14 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
         let mut matcher = Matcher::new(NucleoConfig::DEFAULT.match_paths());
         let pattern = Pattern::parse(&params.query, CaseMatching::Smart, Normalization::Smart);
 
-        let mut fuzzy_scored: Vec<(u32, i64, String, String, i64, String, i64, String)> = fuzzy_candidates
+        let mut fuzzy_scored: Vec<(u32, i64, String, String, i64, String, i64, String, String)> = fuzzy_candidates
             .into_iter()
-            .filter(|(rowid, _, _, _, _, _, _)| !existing_rowids.contains(rowid))
-            .filter_map(|(rowid, name, path, size, ext, modified_unix, scope)| {
+            .filter(|(rowid, _, _, _, _, _, _, _)| !existing_rowids.contains(rowid))
+            .filter_map(|(rowid, name, path, size, ext, modified_unix, scope, link_target)| {
                 let haystack = nucleo_matcher::Utf32String::from(name.as_str());
                 let score = pattern.score(haystack.slice(..), &mut matcher)?;
                 if score >= FUZZY_SCORE_THRESHOLD {
-                    Some((score, rowid, name, path, size, ext, modified_unix, scope))
+                    Some((score, rowid, name, path, size, ext, modified_unix, scope, link_target))
                 } else {
                     None
                 }
@@ -764,7 +897,7 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
         fuzzy_scored.sort_by(|a, b| b.0.cmp(&a.0));
         fuzzy_scored.truncate(FUZZY_MAX_RESULTS);
 
-        for (_, rowid, name, path, size, ext, modified_unix, scope) in fuzzy_scored {
+        for (_, rowid, name, path, size, ext, modified_unix, scope, link_target) in fuzzy_scored {
             let match_type = determine_match_type(&params.query, &name, &path, true);
             fts_results.push(SearchResult {
                 rowid,
@@ -779,27 +912,95 @@ pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
                 modified_unix,
                 final_score: 0.0,
                 scope,
+                link_target,
             });
         }
     }
 
     let fuzzy_elapsed = fuzzy_start.elapsed();
+    Ok((fts_results, Some(Timing { fts: fts_elapsed, fuzzy: fuzzy_elapsed })))
+}
 
-    let rank_start = std::time::Instant::now();
-    let elapsed = start.elapsed();
-    let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
-    let rank_elapsed = rank_start.elapsed();
+/// A fresh index database at `path`, for benchmarks that must not touch the real one.
+pub(crate) fn create_db_at(path: &std::path::Path) -> Result<Connection> {
+    let conn = Connection::open(path).context("Failed to open SQLite database")?;
+    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")?;
+    init_db(&conn)?;
+    Ok(conn)
+}
+
+/// Index `root` into `conn` as user scope with the configured filters; returns the file count.
+pub(crate) fn index_into(conn: &Connection, root: &PathBuf, config: &ConfigManager) -> Result<u64> {
+    let search = &config.config.search;
+    let mut count = 0;
+    index_path_into(
+        root, "user", Some(search.max_depth), search.exclude_hidden, false,
+        &search.ignore_patterns, &[], conn, &mut count,
+    )?;
+    Ok(count)
+}
+
+/// Number of results `vg search` would rank for `params` (before the display limit).
+pub(crate) fn count_matches(params: &SearchParams, conn: &Connection) -> Result<usize> {
+    Ok(find(params, conn)?.0.len())
+}
+
+/// Results as JSON objects, for `vg serve` and machine-readable output.
+pub(crate) fn query_json(params: &SearchParams, conn: &Connection) -> Result<Vec<serde_json::Value>> {
+    let limit = params.limit.unwrap_or(10);
+    let (mut results, _) = find(params, conn)?;
+    results.truncate(limit);
+    Ok(results.into_iter().map(|r| serde_json::json!({
+        "path": r.path,
+        "name": r.name,
+        "size": r.size,
+        "modified": r.modified_unix,
+        "match": r.match_type,
+        "scope": r.scope,
+        "link_target": if r.link_target.is_empty() { None } else { Some(r.link_target) },
+        "snippet": r.snippet,
+    })).collect())
+}
+
+pub fn search(params: SearchParams, _config: &ConfigManager) -> Result<()> {
+    if let Some(format) = params.format {
+        // Launchers call this on every keystroke: no header, and an empty list rather than an error
+        let results = if get_db_path().exists() && !params.query.trim().is_empty() {
+            query_json(&params, &open_db()?)?
+        } else {
+            Vec::new()
+        };
+        crate::exit::set(match results.is_empty() {
+            _ if !get_db_path().exists() => crate::exit::ERROR,
+            true => crate::exit::NEGATIVE,
+            false => crate::exit::OK,
+        });
+        return print_formatted(format, &results);
+    }
+    ui::print_header("SEARCH");
 
-    if params.verbose {
+    let db_path = get_db_path();
+    if !db_path.exists() {
+        return Err(crate::error::Error::IndexMissing.into());
+    }
+
+    let conn = open_db()?;
+    ui::section(&format!("Results for '{}'", params.query));
+
+    let start = std::time::Instant::now();
+    let limit = params.limit.unwrap_or(10);
+    let (results, timing) = find(&params, &conn)?;
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    if let (true, Some(t)) = (params.verbose, timing) {
         println!();
-        println!("  {} FTS: {:.1}ms  Fuzzy: {:.1}ms  Rank: {:.1}ms",
+        println!("  {} FTS: {:.1}ms  Fuzzy: {:.1}ms",
             "timing:".truecolor(71, 85, 105),
-            fts_elapsed.as_secs_f64() * 1000.0,
-            fuzzy_elapsed.as_secs_f64() * 1000.0,
-            rank_elapsed.as_secs_f64() * 1000.0,
+            t.fts.as_secs_f64() * 1000.0,
+            t.fuzzy.as_secs_f64() * 1000.0,
         );
     }
-    print_results(fts_results, limit, elapsed_ms, params.verbose);
+    print_results(results, limit, elapsed_ms, params.verbose);
 
     Ok(())
 }
@@ -825,6 +1026,21 @@ fn format_badge(match_type: &str) -> colored::ColoredString {
     }
 }
 
+/// Fuzzy-rank `haystacks` against `query` with the same nucleo matcher search uses.
+/// Returns (index, score) for every match, best first.
+pub(crate) fn fuzzy_rank(query: &str, haystacks: &[String]) -> Vec<(usize, u32)> {
+    let mut matcher = Matcher::new(NucleoConfig::DEFAULT.match_paths());
+    let pattern = Pattern::parse(query, CaseMatching::Smart, Normalization::Smart);
+    let mut ranked: Vec<(usize, u32)> = haystacks.iter().enumerate()
+        .filter_map(|(i, h)| {
+            let haystack = nucleo_matcher::Utf32String::from(h.as_str());
+            pattern.score(haystack.slice(..), &mut matcher).map(|score| (i, score))
+        })
+        .collect();
+    ranked.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+    ranked
+}
+
 pub(crate) fn fmt_bytes(bytes: u64) -> String {
     const UNIT: u64 = 1024;
     if bytes < UNIT { return format!("{} B", bytes); }
@@ -844,6 +1060,51 @@ fn format_number(n: u64) -> String {
     result.chars().rev().collect()
 }
 
+/// One `vg index` run, kept in index_meta for `vg index --info`.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Build {
+    at: String,
+    secs: f64,
+    files: u64,
+}
+
+fn build_history(conn: &Connection) -> Vec<Build> {
+    conn.query_row("SELECT value FROM index_meta WHERE key='build_history'", [], |r| r.get::<_, String>(0))
+        .ok()
+        .and_then(|v| serde_json::from_str(&v).ok())
+        .unwrap_or_default()
+}
+
+fn record_build(conn: &Connection, at: &str, secs: f64, files: u64) -> Result<()> {
+    let mut history = build_history(conn);
+    history.push(Build { at: at.to_string(), secs, files });
+    let excess = history.len().saturating_sub(BUILD_HISTORY_LEN);
+    history.drain(..excess);
+    conn.execute(
+        "INSERT OR REPLACE INTO index_meta(key, value) VALUES ('build_history', ?1)",
+        params![serde_json::to_string(&history)?],
+    )?;
+    Ok(())
+}
+
+/// The database plus its WAL and shared-memory files.
+fn db_size_on_disk(db_path: &std::path::Path) -> u64 {
+    ["", "-wal", "-shm"].iter()
+        .filter_map(|suffix| std::fs::metadata(format!("{}{}", db_path.display(), suffix)).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Share of a random sample of indexed paths that no longer exist.
+fn stale_sample(conn: &Connection) -> Result<(usize, usize)> {
+    let mut stmt = conn.prepare("SELECT path FROM files ORDER BY RANDOM() LIMIT ?1")?;
+    let paths: Vec<String> = stmt.query_map(params![STALE_SAMPLE], |r| r.get(0))?
+        .filter_map(|r| r.ok())
+        .collect();
+    let stale = paths.par_iter().filter(|p| std::fs::symlink_metadata(p).is_err()).count();
+    Ok((stale, paths.len()))
+}
+
 pub fn info() -> Result<()> {
     ui::print_header("INDEX INFO");
 
@@ -881,5 +1142,77 @@ pub fn info() -> Result<()> {
         ui::info_line("DB size", &fmt_bytes(meta.len()));
     }
 
+    ui::section("Health");
+    ui::info_line("Size on disk", &format!("{} (database + write-ahead log)", fmt_bytes(db_size_on_disk(&db_path))));
+    let (stale, sampled) = stale_sample(&conn)?;
+    if sampled > 0 {
+        let pct = stale as f64 * 100.0 / sampled as f64;
+        let scope = if (sampled as i64) < count { format!("of {} sampled", format_number(sampled as u64)) } else { "of all".to_string() };
+        ui::info_line("Stale entries", &format!("{:.1}% {} paths no longer exist", pct, scope));
+        if stale > 0 {
+            ui::skip("Prune them with: vg index --verify   (or rebuild with vg index)");
+        }
+    }
+    let types: Vec<(String, i64)> = conn.prepare(
+        "SELECT CASE WHEN mime = '' THEN 'unknown' ELSE substr(mime, 1, instr(mime || '/', '/') - 1) END AS kind, COUNT(*)
+         FROM files_meta GROUP BY kind ORDER BY COUNT(*) DESC LIMIT 6",
+    )?
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    if !types.is_empty() {
+        let line = types.iter().map(|(k, n)| format!("{} {}", k, format_number(*n as u64))).collect::<Vec<_>>().join(" · ");
+        ui::info_line("File types", &line);
+    }
+
+    let history = build_history(&conn);
+    if !history.is_empty() {
+        ui::section("Recent Builds");
+        for b in history.iter().rev().take(5) {
+            let when = chrono::DateTime::parse_from_rfc3339(&b.at)
+                .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|_| b.at.clone());
+            ui::info_line(&when, &format!("{} files in {:.1}s", format_number(b.files), b.secs));
+        }
+    }
+
+    Ok(())
+}
+
+/// `vg index --verify` — drop entries whose files were deleted or moved since the last build.
+pub fn verify() -> Result<()> {
+    ui::print_header("INDEX VERIFY");
+
+    let db_path = get_db_path();
+    if !db_path.exists() {
+        return Err(crate::error::Error::IndexMissing.into());
+    }
+
+    let mut conn = open_db()?;
+    let rows: Vec<(i64, String)> = conn.prepare("SELECT rowid, path FROM files")?
+        .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    let dead: Vec<i64> = rows.par_iter()
+        .filter(|(_, path)| std::fs::symlink_metadata(path).is_err())
+        .map(|(rowid, _)| *rowid)
+        .collect();
+    ui::info_line("Checked", &format!("{} entries", format_number(rows.len() as u64)));
+
+    if dead.is_empty() {
+        ui::success("Every indexed path still exists");
+        return Ok(());
+    }
+    let before = db_size_on_disk(&db_path);
+    let tx = conn.transaction()?;
+    for rowid in &dead {
+        tx.execute("DELETE FROM files WHERE rowid = ?1", params![rowid])?;
+        tx.execute("DELETE FROM files_meta WHERE rowid = ?1", params![rowid])?;
+    }
+    tx.commit()?;
+    conn.execute_batch("INSERT INTO files(files) VALUES('optimize'); VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")?;
+
+    ui::success(&format!("Pruned {} dead entries", format_number(dead.len() as u64)));
+    ui::info_line("Size on disk", &format!("{} → {}", fmt_bytes(before), fmt_bytes(db_size_on_disk(&db_path))));
     Ok(())
 }
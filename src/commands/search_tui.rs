@@ -365,9 +365,8 @@ pub fn run_interactive(config: &ConfigManager) -> Result<()> {
 
 pub fn run_interactive_with_query(_config: &ConfigManager, initial_query: &str) -> Result<()> {
     // TTY check
-    if !io::stdout().is_terminal() {
-        println!("vg search: interactive mode requires a terminal (stdout is not a TTY)");
-        return Ok(());
+    if !io::stdout().is_terminal() || !crate::ui::interactive() {
+        return Err(crate::error::Error::not_a_terminal("pass a query: vg search <query>").into());
     }
 
     let db_path = get_db_path();
@@ -0,0 +1,130 @@
+use crate::config::ConfigManager;
+use crate::ui;
+use anyhow::Result;
+use std::path::Path;
+
+/// Create and remove a file in `dir`.
+fn writable(dir: &Path) -> bool {
+    tempfile::NamedTempFile::new_in(dir).is_ok()
+}
+
+fn check_dir(label: &str, dir: &Path) -> usize {
+    if !dir.exists() {
+        // Created on first use
+        ui::skip(&format!("{}: {} (not created yet)", label, dir.display()));
+        return 0;
+    }
+    if writable(dir) {
+        ui::success(&format!("{}: {}", label, dir.display()));
+        return 0;
+    }
+    ui::fail(&format!("{} is not writable: {}", label, dir.display()));
+    ui::skip(&format!("Fix: sudo chown -R $USER {}", dir.display()));
+    1
+}
+
+fn check_files(config: &ConfigManager) -> usize {
+    let mut issues = 0;
+    ui::section("Files");
+    let config_dir = config.config_path().parent().unwrap_or(Path::new("."));
+    issues += check_dir("Config", config_dir);
+    issues += check_dir("Data", &ConfigManager::data_dir());
+
+    match &config.load_error {
+        Some(e) => {
+            ui::fail(&format!("config.toml is ignored: {}", e));
+            ui::skip("Fix: vg config edit");
+            issues += 1;
+        }
+        None => ui::success("config.toml parses"),
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let secrets = config_dir.join("secrets.toml");
+        if let Ok(meta) = std::fs::metadata(&secrets) {
+            if meta.permissions().mode() & 0o077 != 0 {
+                ui::fail("secrets.toml is readable by other users");
+                ui::skip(&format!("Fix: chmod 600 {}", secrets.display()));
+                issues += 1;
+            } else {
+                ui::success("secrets.toml is private (600)");
+            }
+        }
+    }
+    issues
+}
+
+fn check_index() -> usize {
+    ui::section("Search index");
+    let path = super::search::get_db_path();
+    if !path.exists() {
+        ui::skip("No index yet");
+        ui::skip("Build it with: vg index");
+        return 0;
+    }
+    let result = rusqlite::Connection::open_with_flags(&path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .and_then(|conn| {
+            let check: String = conn.query_row("PRAGMA quick_check", [], |r| r.get(0))?;
+            let files: i64 = conn.query_row("SELECT COUNT(*) FROM files_meta", [], |r| r.get(0))?;
+            Ok((check, files))
+        });
+    let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    match result {
+        Ok((check, files)) if check == "ok" => {
+            ui::success(&format!("{} files, {}", files, super::search::fmt_bytes(size)));
+            0
+        }
+        Ok((check, _)) => {
+            ui::fail(&format!("Index is damaged: {}", check));
+            ui::skip(&format!("Fix: rm {} && vg index", path.display()));
+            1
+        }
+        Err(e) => {
+            ui::fail(&format!("Cannot read the index: {}", e));
+            ui::skip(&format!("Fix: rm {} && vg index", path.display()));
+            1
+        }
+    }
+}
+
+/// Optional tools; missing ones only disable the commands that need them, so they aren't issues.
+fn check_tools() {
+    ui::section("External tools");
+    let mut missing = 0;
+    for tool in crate::tools::TOOLS {
+        match tool.installed() {
+            Some(bin) => ui::success(&format!("{:<16} {}", bin, tool.used_by)),
+            None if tool.applies() => {
+                ui::skip(&format!("{:<16} missing — {} · vg install {}", tool.bins[0], tool.used_by, tool.package()));
+                missing += 1;
+            }
+            None => {}
+        }
+    }
+    if missing > 0 {
+        println!();
+        ui::skip(&format!("{} optional tool{} missing — only the commands listed next to them are affected", missing, if missing == 1 { "" } else { "s" }));
+    }
+}
+
+/// `vg self check` — is vg's own environment in order?
+pub fn run(config: &ConfigManager) -> Result<()> {
+    ui::print_header("SELF CHECK");
+    ui::info_line("Version", env!("CARGO_PKG_VERSION"));
+    ui::info_line("Executable", &std::env::current_exe().map(|p| p.display().to_string()).unwrap_or_default());
+
+    let mut issues = check_files(config);
+    issues += check_index();
+    check_tools();
+
+    println!();
+    if issues == 0 {
+        ui::success("No problems found.");
+    } else {
+        ui::fail(&format!("{} issue{} found — see fixes above.", issues, if issues == 1 { "" } else { "s" }));
+        crate::exit::set(crate::exit::NEGATIVE);
+    }
+    Ok(())
+}
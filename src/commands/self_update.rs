@@ -7,8 +7,36 @@ use std::fs;
 use std::os::unix::fs::PermissionsExt;
 
 const API_URL: &str = "https://api.github.com/repos/Raindancer118/genesis/releases/latest";
+/// All releases, newest first — includes prereleases, used by the nightly channel.
+const RELEASES_URL: &str = "https://api.github.com/repos/Raindancer118/genesis/releases?per_page=10";
 pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Which releases `vg self-update` follows.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Channel {
+    /// Latest full release
+    Stable,
+    /// Newest release including prereleases (tags with a `-` suffix)
+    Nightly,
+}
+
+impl Channel {
+    /// Parse the `self_update.channel` config value; anything unknown falls back to stable.
+    pub fn from_config(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "nightly" => Channel::Nightly,
+            _ => Channel::Stable,
+        }
+    }
+
+    fn url(&self) -> &'static str {
+        match self {
+            Channel::Stable => API_URL,
+            Channel::Nightly => RELEASES_URL,
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct GithubRelease {
     tag_name: String,
@@ -26,9 +54,26 @@ pub struct GithubAsset {
 pub struct UpdateInfo {
     pub latest_version: String,
     pub asset: GithubAsset,
+    /// `<artifact>.sha256` published next to the archive, if the release has one
+    pub checksum_asset: Option<GithubAsset>,
     pub release_notes: Option<String>,
 }
 
+impl UpdateInfo {
+    fn from_release(release: GithubRelease) -> Option<Self> {
+        let artifact_name = detect_artifact();
+        let asset = release.assets.iter().find(|a| a.name == artifact_name)?.clone();
+        let checksum_name = format!("{}.sha256", artifact_name);
+        let checksum_asset = release.assets.iter().find(|a| a.name == checksum_name).cloned();
+        Some(UpdateInfo {
+            latest_version: release.tag_name,
+            asset,
+            checksum_asset,
+            release_notes: release.body,
+        })
+    }
+}
+
 fn detect_artifact() -> &'static str {
     #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
     return "vg-x86_64-linux.tar.gz";
@@ -40,14 +85,14 @@ fn detect_artifact() -> &'static str {
     "vg-x86_64-linux.tar.gz"
 }
 
-fn fetch_latest_release() -> Result<GithubRelease> {
+fn fetch_latest_release(channel: Channel) -> Result<GithubRelease> {
     let client = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(10))
         .user_agent("vg-self-update")
         .build()?;
 
     let resp = client
-        .get(API_URL)
+        .get(channel.url())
         .send()
         .context("Network unreachable — check your internet connection")?;
 
@@ -59,23 +104,29 @@ fn fetch_latest_release() -> Result<GithubRelease> {
         code => return Err(anyhow!("GitHub API returned unexpected status {}", code)),
     }
 
-    resp.json().context("Failed to parse release JSON — the API response was malformed")
+    parse_release(channel, resp)
 }
 
-fn version_is_newer(latest: &str, current: &str) -> bool {
-    let latest  = latest.trim_start_matches('v');
-    let current = current.trim_start_matches('v');
-
-    let parse = |v: &str| -> (u32, u32, u32) {
-        let parts: Vec<u32> = v.split('.').filter_map(|p| p.parse().ok()).collect();
-        (
-            parts.first().copied().unwrap_or(0),
-            parts.get(1).copied().unwrap_or(0),
-            parts.get(2).copied().unwrap_or(0),
-        )
-    };
+/// Decode the API response for `channel`: a single release for stable, the newest of a list for nightly.
+fn parse_release(channel: Channel, resp: reqwest::blocking::Response) -> Result<GithubRelease> {
+    match channel {
+        Channel::Stable => resp.json().context("Failed to parse release JSON — the API response was malformed"),
+        Channel::Nightly => {
+            let releases: Vec<GithubRelease> = resp.json()
+                .context("Failed to parse release JSON — the API response was malformed")?;
+            releases.into_iter().next().ok_or_else(|| anyhow!("No releases found on GitHub"))
+        }
+    }
+}
 
-    parse(latest) > parse(current)
+/// Semver order, so on the nightly channel 3.9.0-nightly.2 is newer than 3.9.0-nightly.1
+/// and 3.9.0 is newer than both. Tags that aren't versions never count as newer.
+fn version_is_newer(latest: &str, current: &str) -> bool {
+    let parse = |v: &str| semver::Version::parse(v.trim_start_matches('v')).ok();
+    match (parse(latest), parse(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
 }
 
 /// Try to atomically replace `dst` with `src` using rename().
@@ -98,23 +149,17 @@ fn replace_binary(src: &std::path::Path, dst: &std::path::Path) -> bool {
 }
 
 /// Check GitHub for a newer release. Returns `None` if already up to date or unreachable.
-pub fn check() -> Option<UpdateInfo> {
-    let release = fetch_latest_release().ok()?;
+pub fn check(channel: Channel) -> Option<UpdateInfo> {
+    let release = fetch_latest_release(channel).ok()?;
     if !version_is_newer(&release.tag_name, CURRENT_VERSION) {
         return None;
     }
-    let artifact_name = detect_artifact();
-    let asset = release.assets.iter().find(|a| a.name == artifact_name)?.clone();
-    Some(UpdateInfo {
-        latest_version: release.tag_name,
-        asset,
-        release_notes: release.body,
-    })
+    UpdateInfo::from_release(release)
 }
 
 /// ETag-aware poll: sends `If-None-Match` so GitHub returns 304 (free, no rate-limit cost)
 /// when nothing changed. Returns `(Option<UpdateInfo>, new_etag)`.
-pub fn check_with_etag(etag: Option<&str>) -> (Option<UpdateInfo>, Option<String>) {
+pub fn check_with_etag(etag: Option<&str>, channel: Channel) -> (Option<UpdateInfo>, Option<String>) {
     let client = match reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(10))
         .user_agent("vg-expect-update")
@@ -124,7 +169,7 @@ pub fn check_with_etag(etag: Option<&str>) -> (Option<UpdateInfo>, Option<String
         Err(_) => return (None, etag.map(str::to_string)),
     };
 
-    let mut req = client.get(API_URL);
+    let mut req = client.get(channel.url());
     if let Some(et) = etag {
         req = req.header("If-None-Match", et);
     }
@@ -145,7 +190,7 @@ pub fn check_with_etag(etag: Option<&str>) -> (Option<UpdateInfo>, Option<String
         return (None, new_etag.or_else(|| etag.map(str::to_string)));
     }
 
-    let release: GithubRelease = match resp.json() {
+    let release = match parse_release(channel, resp) {
         Ok(r) => r,
         Err(_) => return (None, new_etag),
     };
@@ -154,18 +199,32 @@ pub fn check_with_etag(etag: Option<&str>) -> (Option<UpdateInfo>, Option<String
         return (None, new_etag);
     }
 
-    let artifact_name = detect_artifact();
-    let asset = match release.assets.iter().find(|a| a.name == artifact_name).cloned() {
-        Some(a) => a,
-        None => return (None, new_etag),
-    };
+    (UpdateInfo::from_release(release), new_etag)
+}
 
-    let info = UpdateInfo {
-        latest_version: release.tag_name,
-        asset,
-        release_notes: release.body,
+/// Compare `bytes` against the `sha256sum`-style checksum file published with the release.
+fn verify_checksum(client: &reqwest::blocking::Client, info: &UpdateInfo, bytes: &[u8]) -> Result<()> {
+    use sha2::{Digest, Sha256};
+
+    let Some(checksum_asset) = &info.checksum_asset else {
+        return Err(anyhow!("Release has no checksum file for {} — not installing an unverified download", info.asset.name));
     };
-    (Some(info), new_etag)
+    let expected = client
+        .get(&checksum_asset.browser_download_url)
+        .send()
+        .and_then(|r| r.text())
+        .context("Failed to download checksum")?;
+    // Format: "<hex digest>  <file name>"
+    let expected = expected.split_whitespace().next().unwrap_or("").to_lowercase();
+    let actual = hex::encode(Sha256::digest(bytes));
+    if expected != actual {
+        return Err(anyhow!(
+            "Checksum mismatch for {} — expected {}, got {}. The download was not installed.",
+            info.asset.name, expected, actual
+        ));
+    }
+    ui::success("Checksum verified");
+    Ok(())
 }
 
 /// Download and install the update described by `info`. Shows progress via `ui`.
@@ -180,12 +239,20 @@ pub fn apply(info: &UpdateInfo) -> Result<()> {
         .user_agent("vg-self-update")
         .build()?;
 
-    let bytes = client
+    let mut response = client
         .get(&info.asset.browser_download_url)
         .send()
-        .context("Download failed")?
-        .bytes()
-        .context("Failed to read download")?;
+        .and_then(|r| r.error_for_status())
+        .context("Download failed")?;
+    let pb = match response.content_length() {
+        Some(len) => crate::progress::bytes(len, "Downloading"),
+        None => crate::progress::spinner("Downloading"),
+    };
+    let mut bytes = Vec::new();
+    response.copy_to(&mut pb.wrap_write(&mut bytes)).context("Failed to read download")?;
+    pb.finish_and_clear();
+
+    verify_checksum(&client, info, &bytes)?;
 
     fs::write(&archive_path, &bytes).context("Failed to write archive")?;
 
@@ -233,11 +300,12 @@ pub fn apply(info: &UpdateInfo) -> Result<()> {
 }
 
 /// Entry point for `vg expect-update` — blocks until a newer release is available, then installs it.
-pub fn expect_update(interval_secs: u64) -> Result<()> {
+pub fn expect_update(interval_secs: u64, channel: Channel) -> Result<()> {
     use colored::Colorize;
 
     ui::print_header("EXPECT UPDATE");
     ui::info_line("Current version", &format!("v{}", CURRENT_VERSION));
+    ui::info_line("Channel", &format!("{:?}", channel).to_lowercase());
     println!();
     println!("  Waiting for a new release… (polling every {}s, Ctrl+C to cancel)", interval_secs);
     println!();
@@ -247,7 +315,7 @@ pub fn expect_update(interval_secs: u64) -> Result<()> {
 
     loop {
         attempt += 1;
-        let (info, new_etag) = check_with_etag(etag.as_deref());
+        let (info, new_etag) = check_with_etag(etag.as_deref(), channel);
         etag = new_etag;
 
         if let Some(info) = info {
@@ -274,12 +342,13 @@ pub fn expect_update(interval_secs: u64) -> Result<()> {
 }
 
 /// Entry point for `vg self-update` — interactive, shows header + release notes.
-pub fn run() -> Result<()> {
+pub fn run(channel: Channel) -> Result<()> {
     ui::print_header("SELF UPDATE");
     ui::info_line("Current version", &format!("v{}", CURRENT_VERSION));
+    ui::info_line("Channel", &format!("{:?}", channel).to_lowercase());
     ui::section("Checking for updates");
 
-    let release = match fetch_latest_release() {
+    let release = match fetch_latest_release(channel) {
         Ok(r) => r,
         Err(e) => {
             println!();
@@ -294,20 +363,14 @@ pub fn run() -> Result<()> {
         return Ok(());
     }
 
-    let artifact_name = detect_artifact();
-    let Some(asset) = release.assets.iter().find(|a| a.name == artifact_name).cloned() else {
+    let tag = release.tag_name.clone();
+    let Some(info) = UpdateInfo::from_release(release) else {
         println!();
-        ui::fail(&format!("Release {} exists but has no binary for this platform ({})", release.tag_name, artifact_name));
+        ui::fail(&format!("Release {} exists but has no binary for this platform ({})", tag, detect_artifact()));
         ui::skip("The release may still be building — try again in a few minutes");
         return Ok(());
     };
 
-    let info = UpdateInfo {
-        latest_version: release.tag_name,
-        asset,
-        release_notes: release.body,
-    };
-
     ui::info_line("Latest version", &info.latest_version);
     ui::success(&format!("New version available: {}", info.latest_version));
 
@@ -328,3 +391,16 @@ pub fn run() -> Result<()> {
     ui::success(&format!("Updated to {} — restart vg to use the new version.", info.latest_version));
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::version_is_newer;
+
+    #[test]
+    fn prereleases_are_ordered() {
+        assert!(version_is_newer("v3.9.0-nightly.2", "3.9.0-nightly.1"));
+        assert!(version_is_newer("v3.9.0", "3.9.0-nightly.2"));
+        assert!(!version_is_newer("v3.9.0-nightly.1", "3.9.0"));
+        assert!(!version_is_newer("nightly", "3.8.3"));
+    }
+}
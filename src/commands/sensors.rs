@@ -0,0 +1,205 @@
+use crate::ui;
+use anyhow::Result;
+use colored::{ColoredString, Colorize};
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+use sysinfo::Components;
+
+/// Temperatures at or above these are shown yellow / red (unless the sensor reports its own critical point).
+const WARM_C: f32 = 65.0;
+const HOT_C: f32 = 80.0;
+/// Batteries below this share of their design capacity are flagged.
+const WORN_BATTERY_PCT: f64 = 80.0;
+
+#[derive(Serialize)]
+struct Temperature {
+    label: String,
+    celsius: f32,
+    critical: Option<f32>,
+}
+
+#[derive(Serialize)]
+struct Fan {
+    label: String,
+    rpm: u64,
+}
+
+#[derive(Serialize)]
+struct Battery {
+    name: String,
+    charge_percent: Option<u8>,
+    status: Option<String>,
+    cycle_count: Option<u64>,
+    /// Full-charge capacity as a share of design capacity
+    health_percent: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct Report {
+    temperatures: Vec<Temperature>,
+    fans: Vec<Fan>,
+    batteries: Vec<Battery>,
+}
+
+fn read_trim(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+fn read_num(path: &Path) -> Option<u64> {
+    read_trim(path)?.parse().ok()
+}
+
+fn temperatures() -> Vec<Temperature> {
+    let components = Components::new_with_refreshed_list();
+    components.iter()
+        .filter_map(|c| {
+            let celsius = c.temperature()?;
+            // Some drivers report 0 or absurd values for unpopulated sensors
+            if !(1.0..150.0).contains(&celsius) {
+                return None;
+            }
+            Some(Temperature { label: c.label().to_string(), celsius, critical: c.critical() })
+        })
+        .collect()
+}
+
+/// Fan speeds from hwmon on Linux (`fanN_input`, labelled by `fanN_label` when present).
+fn fans() -> Vec<Fan> {
+    let mut fans = Vec::new();
+    let Ok(hwmons) = std::fs::read_dir("/sys/class/hwmon") else { return fans };
+    for hwmon in hwmons.filter_map(|e| e.ok()).map(|e| e.path()) {
+        let chip = read_trim(&hwmon.join("name")).unwrap_or_else(|| "hwmon".into());
+        let Ok(entries) = std::fs::read_dir(&hwmon) else { continue };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let file = entry.file_name().to_string_lossy().to_string();
+            let Some(id) = file.strip_prefix("fan").and_then(|f| f.strip_suffix("_input")) else { continue };
+            let Some(rpm) = read_num(&entry.path()) else { continue };
+            let label = read_trim(&hwmon.join(format!("fan{}_label", id)))
+                .unwrap_or_else(|| format!("{} fan{}", chip, id));
+            fans.push(Fan { label, rpm });
+        }
+    }
+    fans.sort_by(|a, b| a.label.cmp(&b.label));
+    fans
+}
+
+fn batteries() -> Vec<Battery> {
+    if cfg!(target_os = "macos") {
+        return mac_battery().into_iter().collect();
+    }
+    let mut batteries = Vec::new();
+    let Ok(supplies) = std::fs::read_dir("/sys/class/power_supply") else { return batteries };
+    for dir in supplies.filter_map(|e| e.ok()).map(|e| e.path()) {
+        if read_trim(&dir.join("type")).as_deref() != Some("Battery") {
+            continue;
+        }
+        // Drivers expose either energy_* (µWh) or charge_* (µAh); the ratio is what matters
+        let full = read_num(&dir.join("energy_full")).or_else(|| read_num(&dir.join("charge_full")));
+        let design = read_num(&dir.join("energy_full_design")).or_else(|| read_num(&dir.join("charge_full_design")));
+        let health_percent = match (full, design) {
+            (Some(f), Some(d)) if d > 0 => Some(f as f64 / d as f64 * 100.0),
+            _ => None,
+        };
+        batteries.push(Battery {
+            name: dir.file_name().unwrap_or_default().to_string_lossy().to_string(),
+            charge_percent: read_num(&dir.join("capacity")).map(|c| c.min(100) as u8),
+            status: read_trim(&dir.join("status")),
+            cycle_count: read_num(&dir.join("cycle_count")).filter(|c| *c > 0),
+            health_percent,
+        });
+    }
+    batteries
+}
+
+/// macOS: AppleSmartBattery registry entry ("CycleCount" = 123 style lines).
+fn mac_battery() -> Option<Battery> {
+    let out = Command::new("ioreg").args(["-r", "-c", "AppleSmartBattery"]).output().ok()?;
+    let text = String::from_utf8_lossy(&out.stdout);
+    let field = |key: &str| -> Option<u64> {
+        text.lines()
+            .find_map(|l| l.trim().strip_prefix(&format!("\"{}\" = ", key)))
+            .and_then(|v| v.trim().parse().ok())
+    };
+    let design = field("DesignCapacity")?;
+    let full = field("AppleRawMaxCapacity").or_else(|| field("MaxCapacity"));
+    let current = field("AppleRawCurrentCapacity").or_else(|| field("CurrentCapacity"));
+    let charging = text.contains("\"IsCharging\" = Yes");
+    Some(Battery {
+        name: "InternalBattery".into(),
+        charge_percent: match (current, full) {
+            (Some(c), Some(f)) if f > 0 => Some((c * 100 / f).min(100) as u8),
+            _ => None,
+        },
+        status: Some(if charging { "Charging" } else { "Discharging" }.into()),
+        cycle_count: field("CycleCount"),
+        health_percent: full.filter(|_| design > 0).map(|f| f as f64 / design as f64 * 100.0),
+    })
+}
+
+fn colored_temp(t: &Temperature) -> ColoredString {
+    let text = format!("{:.0}°C", t.celsius);
+    let hot = t.critical.filter(|c| *c > 0.0).map(|c| c - 10.0).unwrap_or(HOT_C);
+    if t.celsius >= hot {
+        text.truecolor(248, 113, 113).bold()
+    } else if t.celsius >= WARM_C {
+        text.truecolor(251, 191, 36)
+    } else {
+        text.truecolor(74, 222, 128)
+    }
+}
+
+/// `vg sensors` — temperatures, fans and battery health.
+pub fn run(json: bool) -> Result<()> {
+    let report = Report { temperatures: temperatures(), fans: fans(), batteries: batteries() };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    ui::print_header("SENSORS");
+
+    ui::section("Temperatures");
+    if report.temperatures.is_empty() {
+        ui::skip("No temperature sensors exposed");
+    }
+    for t in &report.temperatures {
+        println!("  {:<28} {}", t.label.truecolor(100, 116, 139), colored_temp(t));
+    }
+
+    ui::section("Fans");
+    if report.fans.is_empty() {
+        ui::skip(if cfg!(target_os = "macos") {
+            "Fan speeds need the SMC (e.g. install istats)"
+        } else {
+            "No fan sensors exposed"
+        });
+    }
+    for f in &report.fans {
+        let rpm = if f.rpm == 0 { "stopped".to_string() } else { format!("{} RPM", f.rpm) };
+        ui::info_line(&f.label, &rpm);
+    }
+
+    ui::section("Battery");
+    if report.batteries.is_empty() {
+        ui::skip("No battery found");
+    }
+    for b in &report.batteries {
+        let charge = b.charge_percent.map(|c| format!("{}%", c)).unwrap_or_else(|| "?".into());
+        let status = b.status.clone().unwrap_or_default();
+        ui::info_line(&b.name, format!("{}  {}", charge, status).trim_end());
+        if let Some(cycles) = b.cycle_count {
+            ui::info_line("Cycles", &cycles.to_string());
+        }
+        if let Some(health) = b.health_percent {
+            if health < WORN_BATTERY_PCT {
+                ui::fail(&format!("Health {:.0}% of design capacity — battery is wearing out", health));
+            } else {
+                ui::info_line("Health", &format!("{:.0}% of design capacity", health));
+            }
+        }
+    }
+    println!();
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use super::search::{self, SearchParams};
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use rusqlite::Connection;
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Requests larger than this are rejected; a search URL is a few hundred bytes.
+const MAX_REQUEST: usize = 8 * 1024;
+/// A client that stops sending mid-request can't hold up the next one for longer than this.
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub struct ServeParams {
+    pub port: u16,
+    /// Listen on a Unix socket instead of TCP
+    pub socket: Option<PathBuf>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    host: Option<String>,
+}
+
+fn read_request(stream: impl Read) -> Result<Request> {
+    let mut reader = BufReader::new(stream.take(MAX_REQUEST as u64));
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(anyhow!("malformed request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut req = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query: form_urlencoded::parse(query.as_bytes()).into_owned().collect(),
+        host: None,
+    };
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("host") {
+                req.host = Some(value.trim().to_string());
+            }
+        }
+    }
+    Ok(req)
+}
+
+fn respond(mut stream: impl Write, status: &str, body: &serde_json::Value) -> Result<()> {
+    let body = serde_json::to_string(body)?;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, body.len(), body,
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Browsers let any page send requests to localhost; refusing foreign Host headers
+/// keeps a DNS-rebinding page from reading the index.
+fn local_host(host: Option<&str>) -> bool {
+    let Some(host) = host else { return true };
+    let name = host.rsplit_once(':').map(|(h, _)| h).unwrap_or(host);
+    matches!(name, "localhost" | "127.0.0.1" | "[::1]")
+}
+
+fn route(req: &Request, conn: &Connection) -> (&'static str, serde_json::Value) {
+    if req.method != "GET" {
+        return ("405 Method Not Allowed", json!({ "error": "only GET is supported" }));
+    }
+    if !local_host(req.host.as_deref()) {
+        return ("403 Forbidden", json!({ "error": "requests must be addressed to localhost" }));
+    }
+    match req.path.as_str() {
+        "/health" => {
+            let files: i64 = conn.query_row("SELECT COUNT(*) FROM files", [], |r| r.get(0)).unwrap_or(0);
+            ("200 OK", json!({ "status": "ok", "files": files, "version": env!("CARGO_PKG_VERSION") }))
+        }
+        "/search" => {
+            let Some(q) = req.query.get("q").filter(|q| !q.trim().is_empty()) else {
+                return ("400 Bad Request", json!({ "error": "missing q parameter" }));
+            };
+            let params = SearchParams {
+                query: q.clone(),
+                ext: req.query.get("ext").cloned(),
+                path_filter: req.query.get("path").cloned(),
+                mime: req.query.get("mime").cloned(),
+                limit: Some(req.query.get("limit").and_then(|l| l.parse().ok()).unwrap_or(20).clamp(1, 500)),
+                verbose: false,
+                all_scopes: matches!(req.query.get("all").map(String::as_str), Some("1" | "true")),
+                format: None,
+            };
+            let started = Instant::now();
+            match search::query_json(&params, conn) {
+                Ok(results) => ("200 OK", json!({
+                    "query": q,
+                    "took_ms": started.elapsed().as_secs_f64() * 1000.0,
+                    "results": results,
+                })),
+                Err(e) => ("500 Internal Server Error", json!({ "error": format!("{:#}", e) })),
+            }
+        }
+        _ => ("404 Not Found", json!({ "error": "unknown endpoint — try /search?q=… or /health" })),
+    }
+}
+
+fn handle<S: Read + Write>(mut stream: S, conn: &Connection) {
+    let (status, body) = match read_request(&mut stream) {
+        Ok(req) => {
+            let (status, body) = route(&req, conn);
+            tracing::debug!(path = %req.path, status, "request");
+            (status, body)
+        }
+        Err(e) => ("400 Bad Request", json!({ "error": e.to_string() })),
+    };
+    let _ = respond(&mut stream, status, &body);
+}
+
+fn print_usage(base: &str) {
+    ui::section("Endpoints");
+    ui::info_line("Search", &format!("{}/search?q=invoice&limit=20&ext=pdf&mime=image/*&all=1", base));
+    ui::info_line("Health", &format!("{}/health", base));
+    println!();
+    ui::skip("Results are JSON; requests are answered one at a time. Ctrl+C to stop.");
+}
+
+/// `vg serve` — answer search queries over local HTTP so launchers and editors can reuse the index.
+pub fn run(params: ServeParams) -> Result<()> {
+    ui::print_header("SERVE");
+    if !search::get_db_path().exists() {
+        return Err(crate::error::Error::IndexMissing.into());
+    }
+    let conn = search::open_db()?;
+
+    if let Some(path) = params.socket {
+        return serve_socket(path, &conn);
+    }
+
+    // Loopback only: the index lists every file name under your home directory
+    let listener = std::net::TcpListener::bind(("127.0.0.1", params.port))
+        .with_context(|| format!("Cannot listen on 127.0.0.1:{} — pick another with --port", params.port))?;
+    let base = format!("http://127.0.0.1:{}", params.port);
+    ui::success(&format!("Listening on {}", base));
+    print_usage(&base);
+    for stream in listener.incoming().flatten() {
+        let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+        handle(stream, &conn);
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn serve_socket(path: PathBuf, conn: &Connection) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixListener;
+
+    // A socket left by a previous run that was killed; anything else there is the user's
+    if let Ok(meta) = std::fs::symlink_metadata(&path) {
+        if !meta.file_type().is_socket() {
+            return Err(anyhow!("{} exists and is not a socket — pick another --socket path", path.display()));
+        }
+        std::fs::remove_file(&path).with_context(|| format!("Cannot replace {}", path.display()))?;
+    }
+    // Created owner-only from the start, so there's no window where others can connect
+    let old_umask = unsafe { libc::umask(0o177) };
+    let bound = UnixListener::bind(&path);
+    unsafe { libc::umask(old_umask) };
+    let listener = bound.with_context(|| format!("Cannot listen on {}", path.display()))?;
+    ui::success(&format!("Listening on {}", path.display()));
+    print_usage(&format!("curl --unix-socket {} http://localhost", path.display()));
+    for stream in listener.incoming().flatten() {
+        let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+        handle(stream, conn);
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn serve_socket(_path: PathBuf, _conn: &Connection) -> Result<()> {
+    Err(anyhow!("--socket needs Unix domain sockets — use --port on this platform"))
+}
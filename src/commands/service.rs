@@ -0,0 +1,288 @@
+use crate::ui;
+use crate::package_managers::run_cmd;
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color, Table};
+use inquire::Select;
+use std::process::Command;
+use which::which;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Backend {
+    Systemd,
+    Launchd,
+    Windows,
+}
+
+fn backend() -> Result<Backend> {
+    if cfg!(target_os = "windows") {
+        Ok(Backend::Windows)
+    } else if cfg!(target_os = "macos") {
+        Ok(Backend::Launchd)
+    } else if which("systemctl").is_ok() {
+        Ok(Backend::Systemd)
+    } else {
+        Err(anyhow!("No supported service manager found (systemd, launchd or Windows services)"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum State {
+    // Ordered so failed services sort first
+    Failed,
+    Running,
+    Stopped,
+    Other,
+}
+
+impl State {
+    fn label(&self) -> &'static str {
+        match self {
+            State::Failed => "failed",
+            State::Running => "running",
+            State::Stopped => "stopped",
+            State::Other => "other",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            State::Failed => Color::Red,
+            State::Running => Color::Green,
+            State::Stopped => Color::DarkGrey,
+            State::Other => Color::Yellow,
+        }
+    }
+}
+
+struct Service {
+    name: String,
+    state: State,
+    description: String,
+}
+
+fn output(prog: &str, args: &[&str]) -> String {
+    Command::new(prog).args(args).output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default()
+}
+
+/// Names of failed systemd units (empty elsewhere). Shared with `vg health`.
+pub fn failed_units() -> Vec<String> {
+    if !cfg!(target_os = "linux") {
+        return Vec::new();
+    }
+    output("systemctl", &["--failed", "--no-legend", "--plain"])
+        .lines()
+        .filter_map(|l| l.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+fn list_services(backend: Backend, user: bool) -> Vec<Service> {
+    let mut services: Vec<Service> = match backend {
+        Backend::Systemd => {
+            let mut args = vec!["list-units", "--type=service", "--all", "--no-legend", "--plain"];
+            if user {
+                args.insert(0, "--user");
+            }
+            // Columns: UNIT LOAD ACTIVE SUB DESCRIPTION
+            output("systemctl", &args).lines().filter_map(|l| {
+                let mut cols = l.split_whitespace();
+                let name = cols.next()?.trim_end_matches(".service").to_string();
+                let _load = cols.next()?;
+                let active = cols.next()?;
+                let _sub = cols.next()?;
+                let state = match active {
+                    "failed" => State::Failed,
+                    "active" => State::Running,
+                    "inactive" => State::Stopped,
+                    _ => State::Other,
+                };
+                Some(Service { name, state, description: cols.collect::<Vec<_>>().join(" ") })
+            }).collect()
+        }
+        Backend::Launchd => {
+            // Columns: PID STATUS LABEL — a dash PID with a non-zero status is a crashed job
+            output("launchctl", &["list"]).lines().skip(1).filter_map(|l| {
+                let mut cols = l.split_whitespace();
+                let pid = cols.next()?;
+                let status = cols.next()?;
+                let name = cols.next()?.to_string();
+                let state = if pid != "-" {
+                    State::Running
+                } else if status != "0" {
+                    State::Failed
+                } else {
+                    State::Stopped
+                };
+                Some(Service { name, state, description: String::new() })
+            }).collect()
+        }
+        Backend::Windows => {
+            let script = "Get-Service | ForEach-Object { \"$($_.Name)|$($_.Status)|$($_.DisplayName)\" }";
+            output("powershell", &["-NoProfile", "-Command", script]).lines().filter_map(|l| {
+                let mut cols = l.trim().splitn(3, '|');
+                let name = cols.next()?.to_string();
+                let state = match cols.next()? {
+                    "Running" => State::Running,
+                    "Stopped" => State::Stopped,
+                    _ => State::Other,
+                };
+                Some(Service { name, state, description: cols.next().unwrap_or("").to_string() })
+            }).collect()
+        }
+    };
+    services.sort_by(|a, b| a.state.cmp(&b.state).then_with(|| a.name.cmp(&b.name)));
+    services
+}
+
+/// Interactive picker, failed services first.
+fn pick(backend: Backend, user: bool) -> Result<String> {
+    ui::ensure_interactive("name the service")?;
+    let services = list_services(backend, user);
+    if services.is_empty() {
+        return Err(anyhow!("No services found"));
+    }
+    let options: Vec<String> = services.iter()
+        .map(|s| format!("{:<8} {}  {}", s.state.label(), s.name, s.description))
+        .collect();
+    let choice = Select::new("Select a service:", options.clone()).with_page_size(15).prompt()?;
+    let idx = options.iter().position(|o| *o == choice).unwrap_or(0);
+    Ok(services[idx].name.clone())
+}
+
+fn resolve(name: Option<String>, backend: Backend, user: bool) -> Result<String> {
+    match name {
+        Some(n) => Ok(n),
+        None => pick(backend, user),
+    }
+}
+
+/// `vg service list`
+pub fn list(failed_only: bool, user: bool) -> Result<()> {
+    ui::print_header("SERVICES");
+    let backend = backend()?;
+    let services: Vec<Service> = list_services(backend, user).into_iter()
+        .filter(|s| !failed_only || s.state == State::Failed)
+        .collect();
+
+    if services.is_empty() {
+        ui::success(if failed_only { "No failed services" } else { "No services found" });
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Service").add_attribute(Attribute::Bold),
+        Cell::new("State").add_attribute(Attribute::Bold),
+        Cell::new("Description").add_attribute(Attribute::Bold),
+    ]);
+    for s in &services {
+        table.add_row(vec![
+            Cell::new(&s.name).fg(Color::Blue),
+            Cell::new(s.state.label()).fg(s.state.color()),
+            Cell::new(s.description.chars().take(60).collect::<String>()),
+        ]);
+    }
+    println!("{}", table);
+
+    let failed = services.iter().filter(|s| s.state == State::Failed).count();
+    println!();
+    if failed > 0 {
+        ui::fail(&format!("{} failed — inspect with: vg service logs <name>", failed));
+    } else {
+        ui::success(&format!("{} services, none failed", services.len()));
+    }
+    Ok(())
+}
+
+/// `vg service status [name]`
+pub fn status(name: Option<String>, user: bool) -> Result<()> {
+    let backend = backend()?;
+    let name = resolve(name, backend, user)?;
+    ui::print_header("SERVICE STATUS");
+    match backend {
+        Backend::Systemd => {
+            let mut args = vec!["status", "--no-pager", name.as_str()];
+            if user {
+                args.insert(0, "--user");
+            }
+            // systemctl status exits non-zero for stopped units; the output is what matters
+            let _ = Command::new("systemctl").args(&args).status();
+        }
+        Backend::Launchd => { let _ = Command::new("launchctl").args(["list", &name]).status(); }
+        Backend::Windows => { let _ = Command::new("sc").args(["query", &name]).status(); }
+    }
+    Ok(())
+}
+
+/// `vg service start|stop|restart [name]`
+pub fn control(action: &str, name: Option<String>, user: bool) -> Result<()> {
+    let backend = backend()?;
+    let name = resolve(name, backend, user)?;
+    ui::print_header("SERVICE");
+    match backend {
+        Backend::Systemd => {
+            let mut args = vec!["systemctl", action, name.as_str()];
+            if user {
+                args.insert(1, "--user");
+            }
+            run_cmd(&args, !user)?;
+        }
+        Backend::Launchd => match action {
+            "restart" => {
+                let _ = run_cmd(&["launchctl", "stop", &name], false);
+                run_cmd(&["launchctl", "start", &name], false)?;
+            }
+            _ => run_cmd(&["launchctl", action, &name], false)?,
+        },
+        Backend::Windows => match action {
+            "restart" => {
+                let _ = run_cmd(&["sc", "stop", &name], false);
+                run_cmd(&["sc", "start", &name], false)?;
+            }
+            _ => run_cmd(&["sc", action, &name], false)?,
+        },
+    }
+    let done = match action {
+        "start" => "Started",
+        "stop" => "Stopped",
+        _ => "Restarted",
+    };
+    ui::success(&format!("{} {}", done, name));
+    Ok(())
+}
+
+/// `vg service logs [name]`
+pub fn logs(name: Option<String>, lines: usize, follow: bool, user: bool) -> Result<()> {
+    let backend = backend()?;
+    let name = resolve(name, backend, user)?;
+    ui::print_header("SERVICE LOGS");
+    ui::info_line("Service", &name);
+    println!();
+    match backend {
+        Backend::Systemd => {
+            let n = lines.to_string();
+            let mut args = vec![if user { "--user-unit" } else { "-u" }, name.as_str(), "-n", n.as_str(), "--no-pager"];
+            if follow {
+                args.push("-f");
+            }
+            Command::new("journalctl").args(&args).status()?;
+        }
+        Backend::Launchd => {
+            let predicate = format!("process == \"{}\"", name);
+            let mut args = vec!["show", "--last", "1h", "--predicate", predicate.as_str()];
+            if follow {
+                args[0] = "stream";
+                args.drain(1..3);
+            }
+            Command::new("log").args(&args).status()?;
+        }
+        Backend::Windows => {
+            ui::skip("Windows services log to the Event Viewer:");
+            println!("  {}", "eventvwr.msc".truecolor(224, 242, 254));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use which::which;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Mode {
+    Full,
+    Region,
+    Window,
+}
+
+impl Mode {
+    fn name(self) -> &'static str {
+        match self {
+            Mode::Full => "full screen",
+            Mode::Region => "region",
+            Mode::Window => "window",
+        }
+    }
+}
+
+fn supports(tool: &str, mode: Mode) -> bool {
+    match (tool, mode) {
+        ("grim" | "maim", Mode::Window) => false,
+        ("grim", Mode::Region) => which("slurp").is_ok(),
+        _ => true,
+    }
+}
+
+/// The command line for `tool` to capture `mode` into `file`, if the tool can.
+fn capture_cmd(tool: &str, mode: Mode, file: &Path) -> Option<Command> {
+    let mut c = Command::new(tool);
+    match (tool, mode) {
+        ("screencapture", Mode::Full) => c.arg("-x"),
+        ("screencapture", Mode::Region) => c.args(["-x", "-i", "-s"]),
+        ("screencapture", Mode::Window) => c.args(["-x", "-i", "-w"]),
+        ("grim", Mode::Full) => &mut c,
+        ("grim", Mode::Region) => {
+            // slurp prints the selected geometry; an empty selection means the user cancelled
+            let out = Command::new("slurp").output().ok()?;
+            let geometry = String::from_utf8_lossy(&out.stdout).trim().to_string();
+            c.arg("-g").arg(if geometry.is_empty() { "cancelled".to_string() } else { geometry })
+        }
+        ("gnome-screenshot", Mode::Full) => c.arg("-f"),
+        ("gnome-screenshot", Mode::Region) => c.args(["-a", "-f"]),
+        ("gnome-screenshot", Mode::Window) => c.args(["-w", "-f"]),
+        ("spectacle", Mode::Full) => c.args(["-b", "-n", "-f", "-o"]),
+        ("spectacle", Mode::Region) => c.args(["-b", "-n", "-r", "-o"]),
+        ("spectacle", Mode::Window) => c.args(["-b", "-n", "-a", "-o"]),
+        ("scrot", Mode::Full) => c.arg("-o"),
+        ("scrot", Mode::Region) => c.args(["-o", "-s"]),
+        ("scrot", Mode::Window) => c.args(["-o", "-u"]),
+        ("maim", Mode::Full) => &mut c,
+        ("maim", Mode::Region) => c.arg("-s"),
+        _ => return None,
+    };
+    c.arg(file);
+    Some(c)
+}
+
+/// Capture tools in order of preference for the current session.
+fn candidates() -> Vec<&'static str> {
+    if cfg!(target_os = "macos") {
+        return vec!["screencapture"];
+    }
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let mut tools = vec!["gnome-screenshot", "spectacle"];
+    if wayland {
+        // grim only works on wlroots compositors; GNOME and KDE have their own tools above
+        tools.push("grim");
+    } else {
+        tools.extend(["scrot", "maim"]);
+    }
+    tools
+}
+
+fn screenshots_dir() -> Result<PathBuf> {
+    let pictures = dirs::picture_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join("Pictures")))
+        .ok_or_else(|| anyhow!("Cannot determine your Pictures folder"))?;
+    Ok(pictures.join("Screenshots"))
+}
+
+/// `vg shot` — take a screenshot with the platform's tool, file it under Pictures/Screenshots
+/// and copy its path to the clipboard.
+pub fn run(mode: Mode, copy: bool) -> Result<()> {
+    ui::print_header("SCREENSHOT");
+    let tools = candidates();
+    let installed: Vec<&str> = tools.iter().copied().filter(|t| which(t).is_ok()).collect();
+    let Some(tool) = installed.iter().copied().find(|t| supports(t, mode)) else {
+        return Err(match installed.first() {
+            Some(t) => anyhow!("{} can't capture a {} — try another mode", t, mode.name()),
+            None => anyhow!("No screenshot tool found — install one with: vg install {}", tools.join(" / ")),
+        });
+    };
+
+    let dir = screenshots_dir()?;
+    std::fs::create_dir_all(&dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+    let file = dir.join(format!("Screenshot_{}.png", chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")));
+
+    let mut cmd = capture_cmd(tool, mode, &file).ok_or_else(|| anyhow!("{} can't capture a {}", tool, mode.name()))?;
+    let status = crate::logging::status(&mut cmd).with_context(|| format!("Failed to run {}", tool))?;
+    // Cancelling a selection exits non-zero with some tools and writes nothing with others
+    if !status.success() || !file.metadata().is_ok_and(|m| m.len() > 0) {
+        let _ = std::fs::remove_file(&file);
+        ui::skip("No screenshot taken");
+        return Ok(());
+    }
+
+    ui::success(&format!("Saved {}", file.display()));
+    if copy {
+        match super::gen::copy_to_clipboard(&file.to_string_lossy()) {
+            Ok(()) => ui::skip("Path copied to clipboard"),
+            Err(e) => ui::skip(&e.to_string()),
+        }
+    }
+    Ok(())
+}
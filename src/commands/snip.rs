@@ -0,0 +1,241 @@
+use crate::ui;
+use crate::config::ConfigManager;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use inquire::Text;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Snippet {
+    command: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+type Snippets = BTreeMap<String, Snippet>;
+
+fn store_path() -> PathBuf {
+    ConfigManager::data_dir().join("snippets.json")
+}
+
+fn load() -> Result<Snippets> {
+    let path = store_path();
+    if !path.exists() {
+        return Ok(Snippets::new());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn save(snippets: &Snippets) -> Result<()> {
+    let path = store_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(snippets)?)?;
+    Ok(())
+}
+
+/// Placeholders in a command: `{{host}}` or `{{host:default}}`, in order of first appearance.
+fn placeholders(command: &str) -> Vec<(String, Option<String>)> {
+    let mut found: Vec<(String, Option<String>)> = Vec::new();
+    let mut rest = command;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        let inner = rest[start + 2..start + 2 + len].trim();
+        let (name, default) = match inner.split_once(':') {
+            Some((n, d)) => (n.trim().to_string(), Some(d.to_string())),
+            None => (inner.to_string(), None),
+        };
+        if !name.is_empty() && !found.iter().any(|(n, _)| *n == name) {
+            found.push((name, default));
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    found
+}
+
+fn fill(command: &str, values: &BTreeMap<String, String>) -> String {
+    let mut out = String::new();
+    let mut rest = command;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        let inner = rest[start + 2..start + 2 + len].trim();
+        let name = inner.split_once(':').map(|(n, _)| n.trim()).unwrap_or(inner);
+        out.push_str(&rest[..start]);
+        match values.get(name) {
+            Some(v) => out.push_str(v),
+            None => out.push_str(&rest[start..start + 2 + len + 2]),
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Haystack for fuzzy matching: name, tags and description together.
+fn haystack(name: &str, s: &Snippet) -> String {
+    format!("{} {} {}", name, s.tags.join(" "), s.description)
+}
+
+/// Exact name first, otherwise the best fuzzy match once the user confirms it; None when they don't.
+fn resolve<'a>(snippets: &'a Snippets, query: &str) -> Result<Option<(&'a String, &'a Snippet)>> {
+    if let Some(entry) = snippets.get_key_value(query) {
+        return Ok(Some(entry));
+    }
+    let names: Vec<&String> = snippets.keys().collect();
+    let hay: Vec<String> = snippets.iter().map(|(n, s)| haystack(n, s)).collect();
+    let (idx, _) = super::search::fuzzy_rank(query, &hay).into_iter().next()
+        .ok_or_else(|| anyhow!("No snippet matches '{}'. See: vg snip list", query))?;
+    let (name, snippet) = (names[idx], &snippets[names[idx]]);
+    print_snippet(name, snippet);
+    Ok(ui::confirm_match(query, name)?.then_some((name, snippet)))
+}
+
+fn print_snippet(name: &str, s: &Snippet) {
+    let tags = if s.tags.is_empty() {
+        String::new()
+    } else {
+        format!("  {}", s.tags.iter().map(|t| format!("#{}", t)).collect::<Vec<_>>().join(" ")).truecolor(71, 85, 105).to_string()
+    };
+    println!("  {}{}", name.truecolor(96, 165, 250).bold(), tags);
+    if !s.description.is_empty() {
+        println!("    {}", s.description.truecolor(100, 116, 139));
+    }
+    println!("    {}", s.command.truecolor(224, 242, 254));
+}
+
+/// `vg snip add <name> [command]` — prompts for anything not given on the command line.
+pub fn add(name: &str, command: Option<String>, description: Option<String>, tags: Vec<String>) -> Result<()> {
+    ui::print_header("SNIP ADD");
+    let mut snippets = load()?;
+    let existing = snippets.get(name).cloned().unwrap_or_default();
+
+    let command = match command {
+        Some(c) => c,
+        None => {
+            ui::ensure_interactive("pass the command: vg snip add <name> '<command>'")?;
+            Text::new("Command:")
+                .with_initial_value(&existing.command)
+                .with_help_message("Use {{name}} or {{name:default}} for placeholders")
+                .prompt()?
+        }
+    };
+    if command.trim().is_empty() {
+        return Err(anyhow!("A snippet needs a command"));
+    }
+    let description = description.unwrap_or(existing.description);
+    let tags = if tags.is_empty() { existing.tags } else { tags };
+
+    let replaced = snippets.contains_key(name);
+    snippets.insert(name.to_string(), Snippet { command, description, tags });
+    save(&snippets)?;
+    ui::success(&format!("{} snippet '{}'", if replaced { "Updated" } else { "Saved" }, name));
+    Ok(())
+}
+
+/// `vg snip list [--tag t]`
+pub fn list(tag: Option<String>) -> Result<()> {
+    ui::print_header("SNIPPETS");
+    let snippets = load()?;
+    let shown: Vec<_> = snippets.iter()
+        .filter(|(_, s)| tag.as_ref().is_none_or(|t| s.tags.iter().any(|x| x.eq_ignore_ascii_case(t))))
+        .collect();
+    if shown.is_empty() {
+        ui::skip("No snippets yet. Add one: vg snip add <name> \"<command>\"");
+        return Ok(());
+    }
+    for (name, s) in shown {
+        print_snippet(name, s);
+    }
+    println!();
+    Ok(())
+}
+
+/// `vg snip search <query>` — fuzzy match over names, tags and descriptions.
+pub fn search(query: &str) -> Result<()> {
+    ui::print_header("SNIP SEARCH");
+    let snippets = load()?;
+    let names: Vec<&String> = snippets.keys().collect();
+    let hay: Vec<String> = snippets.iter().map(|(n, s)| haystack(n, s)).collect();
+    let ranked = super::search::fuzzy_rank(query, &hay);
+    if ranked.is_empty() {
+        ui::skip(&format!("No snippet matches '{}'", query));
+        return Ok(());
+    }
+    for (idx, _) in ranked {
+        print_snippet(names[idx], &snippets[names[idx]]);
+    }
+    println!();
+    Ok(())
+}
+
+/// `vg snip run <name> [--key=value ...]` — fill placeholders, then run through the shell.
+pub fn run(query: &str, args: &[String], dry_run: bool) -> Result<()> {
+    let snippets = load()?;
+    let Some((name, snippet)) = resolve(&snippets, query)? else {
+        ui::skip("Aborted.");
+        return Ok(());
+    };
+
+    let mut values = BTreeMap::new();
+    let mut extra = Vec::new();
+    for arg in args {
+        match arg.strip_prefix("--").and_then(|a| a.split_once('=')) {
+            Some((k, v)) => { values.insert(k.to_string(), v.to_string()); }
+            None => extra.push(arg.clone()),
+        }
+    }
+    for (key, default) in placeholders(&snippet.command) {
+        if values.contains_key(&key) {
+            continue;
+        }
+        if !crate::ui::interactive() {
+            let value = default.ok_or_else(|| anyhow!("Missing value for {{{{{}}}}} — pass --{}=<value>", key, key))?;
+            values.insert(key, value);
+            continue;
+        }
+        let label = format!("{}:", key);
+        let mut prompt = Text::new(&label);
+        if let Some(d) = default.as_deref() {
+            prompt = prompt.with_default(d);
+        }
+        values.insert(key, prompt.prompt()?);
+    }
+
+    let mut command = fill(&snippet.command, &values);
+    // Anything that isn't --key=value is appended, like arguments to an alias
+    if !extra.is_empty() {
+        command = format!("{} {}", command, extra.join(" "));
+    }
+    println!("  {} {}", "$".truecolor(71, 85, 105), command.truecolor(224, 242, 254));
+    if dry_run {
+        return Ok(());
+    }
+
+    let status = if cfg!(windows) {
+        crate::logging::status(Command::new("cmd").args(["/C", &command]))
+    } else {
+        crate::logging::status(Command::new("sh").args(["-c", &command]))
+    }.with_context(|| format!("Failed to run snippet '{}'", name))?;
+    if !status.success() {
+        return Err(anyhow!("Snippet '{}' exited with {}", name, status.code().map(|c| c.to_string()).unwrap_or_else(|| "a signal".into())));
+    }
+    Ok(())
+}
+
+/// `vg snip remove <name>`
+pub fn remove(name: &str) -> Result<()> {
+    let mut snippets = load()?;
+    if snippets.remove(name).is_none() {
+        return Err(anyhow!("No snippet named '{}'", name));
+    }
+    save(&snippets)?;
+    ui::success(&format!("Removed snippet '{}'", name));
+    Ok(())
+}
@@ -0,0 +1,378 @@
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use inquire::{Confirm, Select, Text};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+const REACH_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The fields of a `Host` block that vg reads and writes. Other directives are left untouched.
+#[derive(Debug, Clone, Default)]
+struct Host {
+    alias: String,
+    hostname: Option<String>,
+    user: Option<String>,
+    port: Option<u16>,
+    identity_file: Option<String>,
+}
+
+impl Host {
+    fn target(&self) -> String {
+        let host = self.hostname.clone().unwrap_or_else(|| self.alias.clone());
+        match &self.user {
+            Some(u) => format!("{}@{}", u, host),
+            None => host,
+        }
+    }
+}
+
+fn ssh_dir() -> PathBuf {
+    dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")).join(".ssh")
+}
+
+fn config_path() -> PathBuf {
+    ssh_dir().join("config")
+}
+
+/// "Key value" or "Key=value", keyword case-insensitive.
+fn split_directive(line: &str) -> Option<(String, String)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (key, value) = line.split_once(|c: char| c.is_whitespace() || c == '=')?;
+    Some((key.to_lowercase(), value.trim_start_matches(|c: char| c.is_whitespace() || c == '=').trim().to_string()))
+}
+
+/// Concrete hosts from ~/.ssh/config (wildcard patterns like `Host *` are skipped).
+fn parse_hosts(content: &str) -> Vec<Host> {
+    let mut hosts: Vec<Host> = Vec::new();
+    let mut current: Vec<usize> = Vec::new();
+    for line in content.lines() {
+        let Some((key, value)) = split_directive(line) else { continue };
+        match key.as_str() {
+            "host" => {
+                current.clear();
+                for alias in value.split_whitespace().filter(|a| !a.contains(['*', '?', '!'])) {
+                    hosts.push(Host { alias: alias.to_string(), ..Default::default() });
+                    current.push(hosts.len() - 1);
+                }
+            }
+            "match" => current.clear(),
+            _ => {
+                for &i in &current {
+                    let h = &mut hosts[i];
+                    match key.as_str() {
+                        "hostname" => h.hostname = Some(value.clone()),
+                        "user" => h.user = Some(value.clone()),
+                        "port" => h.port = value.parse().ok(),
+                        "identityfile" => h.identity_file = Some(value.clone()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    hosts
+}
+
+fn load_hosts() -> Vec<Host> {
+    parse_hosts(&std::fs::read_to_string(config_path()).unwrap_or_default())
+}
+
+fn reachable(host: &Host) -> bool {
+    let name = host.hostname.clone().unwrap_or_else(|| host.alias.clone());
+    let Ok(mut addrs) = (name.as_str(), host.port.unwrap_or(22)).to_socket_addrs() else { return false };
+    addrs.any(|addr| TcpStream::connect_timeout(&addr, REACH_TIMEOUT).is_ok())
+}
+
+/// `vg ssh list`
+pub fn list(check: bool) -> Result<()> {
+    ui::print_header("SSH HOSTS");
+    let hosts = load_hosts();
+    if hosts.is_empty() {
+        ui::skip(&format!("No hosts in {}", config_path().display()));
+        ui::skip("Add one with: vg ssh add");
+        return Ok(());
+    }
+
+    // Probe all hosts at once so one dead box doesn't stall the list
+    let status: Vec<Option<bool>> = if check {
+        std::thread::scope(|s| {
+            hosts.iter()
+                .map(|h| s.spawn(move || reachable(h)))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|j| Some(j.join().unwrap_or(false)))
+                .collect()
+        })
+    } else {
+        vec![None; hosts.len()]
+    };
+
+    for (h, up) in hosts.iter().zip(status) {
+        let port = h.port.filter(|p| *p != 22).map(|p| format!(":{}", p)).unwrap_or_default();
+        let mark = match up {
+            Some(true) => "●".truecolor(74, 222, 128),
+            Some(false) => "●".truecolor(248, 113, 113),
+            None => "·".truecolor(71, 85, 105),
+        };
+        println!(
+            "  {} {:<20} {}",
+            mark,
+            h.alias.truecolor(96, 165, 250).bold(),
+            format!("{}{}", h.target(), port).truecolor(224, 242, 254),
+        );
+    }
+    println!();
+    if !check {
+        ui::skip("Check reachability with: vg ssh list --check");
+    }
+    Ok(())
+}
+
+/// `vg ssh [query]` — fuzzy-pick a host and connect.
+pub fn connect(query: Option<String>) -> Result<()> {
+    let hosts = load_hosts();
+    if hosts.is_empty() {
+        return Err(anyhow!("No hosts in {} — add one with: vg ssh add", config_path().display()));
+    }
+    let labels: Vec<String> = hosts.iter().map(|h| format!("{}  {}", h.alias, h.target())).collect();
+
+    let alias = match query.as_deref().filter(|q| !q.is_empty()) {
+        Some(q) => {
+            let ranked = super::search::fuzzy_rank(q, &labels);
+            match ranked.as_slice() {
+                [] => return Err(anyhow!("No host matches '{}'", q)),
+                // A clear winner connects straight away
+                [(i, _)] => hosts[*i].alias.clone(),
+                [(i, best), (_, next), ..] if *best > next * 2 => hosts[*i].alias.clone(),
+                many => {
+                    let options: Vec<String> = many.iter().map(|(i, _)| labels[*i].clone()).collect();
+                    pick(&options)?
+                }
+            }
+        }
+        None => pick(&labels)?,
+    };
+
+    ui::success(&format!("Connecting to {}…", alias));
+    Command::new("ssh").arg(&alias).status().context("Failed to run ssh")?;
+    Ok(())
+}
+
+fn pick(options: &[String]) -> Result<String> {
+    ui::ensure_interactive("name a host that matches only one entry: vg ssh <alias>")?;
+    let choice = Select::new("Connect to:", options.to_vec()).with_page_size(15).prompt()?;
+    Ok(choice.split_whitespace().next().unwrap_or_default().to_string())
+}
+
+/// Existing private keys in ~/.ssh (those with a matching .pub).
+fn existing_keys() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(ssh_dir()) else { return Vec::new() };
+    let mut keys: Vec<String> = entries.filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "pub"))
+        .map(|p| p.with_extension(""))
+        .filter(|p| p.exists())
+        .map(|p| tilde(&p))
+        .collect();
+    keys.sort();
+    keys
+}
+
+fn tilde(path: &Path) -> String {
+    match dirs::home_dir().and_then(|h| path.strip_prefix(h).ok().map(Path::to_path_buf)) {
+        Some(rest) => format!("~/{}", rest.display()),
+        None => path.display().to_string(),
+    }
+}
+
+fn expand(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+fn ensure_ssh_dir() -> Result<()> {
+    let dir = ssh_dir();
+    std::fs::create_dir_all(&dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
+}
+
+fn write_config(content: &str) -> Result<()> {
+    ensure_ssh_dir()?;
+    let path = config_path();
+    std::fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+fn optional(answer: String) -> Option<String> {
+    let t = answer.trim();
+    if t.is_empty() { None } else { Some(t.to_string()) }
+}
+
+/// Ask for host fields, pre-filled from `h`.
+fn prompt_fields(h: &mut Host) -> Result<()> {
+    h.hostname = optional(Text::new("HostName (address or DNS name):")
+        .with_default(h.hostname.as_deref().unwrap_or(""))
+        .prompt()?);
+    h.user = optional(Text::new("User:")
+        .with_default(h.user.as_deref().unwrap_or(&whoami::username()))
+        .prompt()?);
+    let port = Text::new("Port:")
+        .with_default(&h.port.unwrap_or(22).to_string())
+        .prompt()?;
+    h.port = port.trim().parse().ok().filter(|p| *p != 22);
+
+    const KEEP: &str = "Keep current key";
+    const NEW: &str = "Generate a new ed25519 key";
+    const NONE: &str = "No specific key (ssh default)";
+    let mut options: Vec<String> = Vec::new();
+    if h.identity_file.is_some() {
+        options.push(KEEP.into());
+    }
+    options.push(NEW.into());
+    options.extend(existing_keys());
+    options.push(NONE.into());
+    match Select::new("Identity file:", options).prompt()?.as_str() {
+        KEEP => {}
+        NONE => h.identity_file = None,
+        NEW => h.identity_file = Some(generate_key(&h.alias)?),
+        existing => h.identity_file = Some(existing.to_string()),
+    }
+    Ok(())
+}
+
+/// Run ssh-keygen for a per-host key and return its ~-relative path.
+fn generate_key(alias: &str) -> Result<String> {
+    ensure_ssh_dir()?;
+    let safe: String = alias.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' }).collect();
+    let path = ssh_dir().join(format!("id_ed25519_{}", safe));
+    if path.exists() {
+        ui::skip(&format!("{} already exists — reusing it", tilde(&path)));
+        return Ok(tilde(&path));
+    }
+    let comment = format!("{}@{}", whoami::username(), whoami::fallible::hostname().unwrap_or_default());
+    let status = Command::new("ssh-keygen")
+        .args(["-t", "ed25519", "-C", &comment, "-f"])
+        .arg(&path)
+        .status()
+        .context("Failed to run ssh-keygen")?;
+    if !status.success() {
+        return Err(anyhow!("ssh-keygen failed"));
+    }
+    Ok(tilde(&path))
+}
+
+fn render_block(h: &Host) -> String {
+    let mut block = format!("Host {}\n", h.alias);
+    if let Some(v) = &h.hostname { block += &format!("    HostName {}\n", v); }
+    if let Some(v) = &h.user { block += &format!("    User {}\n", v); }
+    if let Some(v) = h.port { block += &format!("    Port {}\n", v); }
+    if let Some(v) = &h.identity_file {
+        block += &format!("    IdentityFile {}\n", v);
+        block += "    IdentitiesOnly yes\n";
+    }
+    block
+}
+
+fn offer_copy_id(h: &Host) -> Result<()> {
+    let Some(key) = &h.identity_file else { return Ok(()) };
+    let public = format!("{}.pub", expand(key).display());
+    if !ui::interactive() || !Confirm::new("Install the public key on the server with ssh-copy-id?").with_default(true).prompt()? {
+        return Ok(());
+    }
+    let status = Command::new("ssh-copy-id").args(["-i", &public, &h.alias]).status()
+        .context("Failed to run ssh-copy-id")?;
+    if status.success() {
+        ui::success(&format!("Key installed — connect with: vg ssh {}", h.alias));
+    } else {
+        ui::fail("ssh-copy-id failed");
+    }
+    Ok(())
+}
+
+/// `vg ssh add`
+pub fn add() -> Result<()> {
+    ui::ensure_interactive("edit ~/.ssh/config directly")?;
+    ui::print_header("SSH ADD HOST");
+    let hosts = load_hosts();
+    let alias = Text::new("Alias (used as `ssh <alias>`):").prompt()?.trim().to_string();
+    if alias.is_empty() || alias.contains(char::is_whitespace) {
+        return Err(anyhow!("Alias must be a single word"));
+    }
+    if hosts.iter().any(|h| h.alias == alias) {
+        return Err(anyhow!("{} already exists — change it with: vg ssh edit {}", alias, alias));
+    }
+
+    let mut host = Host { alias, ..Default::default() };
+    prompt_fields(&mut host)?;
+
+    let mut content = std::fs::read_to_string(config_path()).unwrap_or_default();
+    if !content.is_empty() && !content.ends_with("\n\n") {
+        content += if content.ends_with('\n') { "\n" } else { "\n\n" };
+    }
+    content += &render_block(&host);
+    write_config(&content)?;
+    ui::success(&format!("Added {} to {}", host.alias, config_path().display()));
+    offer_copy_id(&host)
+}
+
+/// `vg ssh edit <alias>` — rewrite the managed fields of an existing block in place.
+pub fn edit(alias: &str) -> Result<()> {
+    ui::ensure_interactive("edit ~/.ssh/config directly")?;
+    ui::print_header("SSH EDIT HOST");
+    let content = std::fs::read_to_string(config_path())
+        .with_context(|| format!("Failed to read {}", config_path().display()))?;
+    let mut host = parse_hosts(&content).into_iter().find(|h| h.alias == alias)
+        .ok_or_else(|| anyhow!("No host named {}", alias))?;
+    let before = host.clone();
+    prompt_fields(&mut host)?;
+
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.iter().position(|l| {
+        split_directive(l).is_some_and(|(k, v)| k == "host" && v.split_whitespace().any(|a| a == alias))
+    }).ok_or_else(|| anyhow!("No host named {}", alias))?;
+    let end = lines.iter().enumerate().skip(start + 1)
+        .find(|(_, l)| split_directive(l).is_some_and(|(k, _)| k == "host" || k == "match"))
+        .map(|(i, _)| i)
+        .unwrap_or(lines.len());
+
+    // Keep every directive vg doesn't manage; replace the ones it does
+    let managed = ["hostname", "user", "port", "identityfile", "identitiesonly"];
+    let mut block: Vec<String> = vec![lines[start].to_string()];
+    let rendered = render_block(&host);
+    block.extend(rendered.lines().skip(1).map(str::to_string));
+    for line in &lines[start + 1..end] {
+        match split_directive(line) {
+            Some((k, _)) if managed.contains(&k.as_str()) => {}
+            _ => block.push(line.to_string()),
+        }
+    }
+
+    let mut out: Vec<String> = lines[..start].iter().map(|l| l.to_string()).collect();
+    out.extend(block);
+    out.extend(lines[end..].iter().map(|l| l.to_string()));
+    write_config(&(out.join("\n") + "\n"))?;
+    ui::success(&format!("Updated {}", alias));
+
+    if host.identity_file != before.identity_file {
+        offer_copy_id(&host)?;
+    }
+    Ok(())
+}
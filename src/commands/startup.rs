@@ -0,0 +1,332 @@
+use crate::config::ConfigManager;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use inquire::Select;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use which::which;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Source {
+    /// ~/.config/autostart and /etc/xdg/autostart .desktop files
+    Autostart,
+    SystemdUser,
+    LoginItem,
+    LaunchAgent,
+    RunKey,
+}
+
+impl Source {
+    fn label(&self) -> &'static str {
+        match self {
+            Source::Autostart => "autostart",
+            Source::SystemdUser => "systemd --user",
+            Source::LoginItem => "login item",
+            Source::LaunchAgent => "launch agent",
+            Source::RunKey => "Run key",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    source: Source,
+    /// Identifier used to toggle it: file name, unit, item or value name
+    id: String,
+    name: String,
+    command: String,
+    enabled: bool,
+    /// Seconds from `systemd-analyze --user blame`, where available
+    impact: Option<f64>,
+}
+
+/// Entries we removed on platforms with no "disabled" state, so `enable` can put them back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Stashed {
+    source: Source,
+    id: String,
+    command: String,
+}
+
+fn stash_path() -> PathBuf {
+    ConfigManager::data_dir().join("startup_disabled.json")
+}
+
+fn load_stash() -> Vec<Stashed> {
+    std::fs::read_to_string(stash_path()).ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn save_stash(stash: &[Stashed]) -> Result<()> {
+    let path = stash_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(stash)?)?;
+    Ok(())
+}
+
+// ── XDG autostart ──────────────────────────────────────────────────────────────
+
+fn user_autostart_dir() -> PathBuf {
+    dirs::config_dir().unwrap_or_default().join("autostart")
+}
+
+fn desktop_value(content: &str, key: &str) -> Option<String> {
+    content.lines()
+        .find_map(|l| l.strip_prefix(key).and_then(|r| r.strip_prefix('=')))
+        .map(|v| v.trim().to_string())
+}
+
+fn autostart_entries() -> Vec<Entry> {
+    // A user file with the same name overrides the system one
+    let mut files: std::collections::BTreeMap<String, PathBuf> = std::collections::BTreeMap::new();
+    for dir in [PathBuf::from("/etc/xdg/autostart"), user_autostart_dir()] {
+        let Ok(read) = std::fs::read_dir(&dir) else { continue };
+        for e in read.filter_map(|e| e.ok()) {
+            let name = e.file_name().to_string_lossy().to_string();
+            if name.ends_with(".desktop") {
+                files.insert(name, e.path());
+            }
+        }
+    }
+    files.into_iter().filter_map(|(id, path)| {
+        let content = std::fs::read_to_string(&path).ok()?;
+        // OnlyShowIn/NotShowIn entries belong to other desktops; show them anyway, they still start
+        let hidden = desktop_value(&content, "Hidden").is_some_and(|v| v == "true");
+        let gnome_off = desktop_value(&content, "X-GNOME-Autostart-enabled").is_some_and(|v| v == "false");
+        Some(Entry {
+            source: Source::Autostart,
+            name: desktop_value(&content, "Name").unwrap_or_else(|| id.trim_end_matches(".desktop").to_string()),
+            command: desktop_value(&content, "Exec").unwrap_or_default(),
+            enabled: !hidden && !gnome_off,
+            impact: None,
+            id,
+        })
+    }).collect()
+}
+
+/// Toggle via a user copy with `Hidden=` set, which is what desktop settings panels do.
+fn set_autostart(id: &str, enabled: bool) -> Result<()> {
+    let user_dir = user_autostart_dir();
+    let user_file = user_dir.join(id);
+    let source = if user_file.exists() { user_file.clone() } else { Path::new("/etc/xdg/autostart").join(id) };
+    let content = std::fs::read_to_string(&source).with_context(|| format!("Cannot read {}", source.display()))?;
+
+    let mut lines: Vec<String> = content.lines()
+        .filter(|l| !l.starts_with("Hidden=") && !l.starts_with("X-GNOME-Autostart-enabled="))
+        .map(str::to_string)
+        .collect();
+    let insert_at = lines.iter().position(|l| l.trim() == "[Desktop Entry]").map(|i| i + 1).unwrap_or(lines.len());
+    lines.insert(insert_at, format!("Hidden={}", !enabled));
+    std::fs::create_dir_all(&user_dir)?;
+    std::fs::write(&user_file, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+// ── systemd user services ──────────────────────────────────────────────────────
+
+fn systemd_user_entries() -> Vec<Entry> {
+    if which("systemctl").is_err() {
+        return Vec::new();
+    }
+    let Ok(out) = Command::new("systemctl")
+        .args(["--user", "list-unit-files", "--type=service", "--no-legend", "--no-pager"])
+        .output() else { return Vec::new() };
+    let blame = super::boot::blame(true).unwrap_or_default();
+    String::from_utf8_lossy(&out.stdout).lines().filter_map(|l| {
+        let mut cols = l.split_whitespace();
+        let unit = cols.next()?.to_string();
+        let state = cols.next()?;
+        // static/indirect/template units can't be toggled on their own
+        if !matches!(state, "enabled" | "disabled") || unit.contains('@') {
+            return None;
+        }
+        Some(Entry {
+            source: Source::SystemdUser,
+            name: unit.trim_end_matches(".service").to_string(),
+            command: String::new(),
+            enabled: state == "enabled",
+            impact: blame.iter().find(|(_, u)| *u == unit).map(|(s, _)| *s),
+            id: unit,
+        })
+    }).collect()
+}
+
+// ── macOS ──────────────────────────────────────────────────────────────────────
+
+fn osascript(script: &str) -> Result<String> {
+    let out = Command::new("osascript").args(["-e", script]).output()?;
+    if !out.status.success() {
+        return Err(anyhow!("osascript failed: {}", String::from_utf8_lossy(&out.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn macos_entries() -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let names = osascript("tell application \"System Events\" to get the name of every login item").unwrap_or_default();
+    let paths = osascript("tell application \"System Events\" to get the path of every login item").unwrap_or_default();
+    for (name, path) in names.split(", ").zip(paths.split(", ")).filter(|(n, _)| !n.is_empty()) {
+        entries.push(Entry { source: Source::LoginItem, id: name.to_string(), name: name.to_string(), command: path.to_string(), enabled: true, impact: None });
+    }
+    let agents = dirs::home_dir().unwrap_or_default().join("Library/LaunchAgents");
+    let loaded = Command::new("launchctl").arg("list").output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default();
+    if let Ok(read) = std::fs::read_dir(&agents) {
+        for e in read.filter_map(|e| e.ok()) {
+            let file = e.file_name().to_string_lossy().to_string();
+            let Some(label) = file.strip_suffix(".plist") else { continue };
+            entries.push(Entry {
+                source: Source::LaunchAgent,
+                id: file.clone(),
+                name: label.to_string(),
+                command: e.path().display().to_string(),
+                enabled: loaded.lines().any(|l| l.ends_with(label)),
+                impact: None,
+            });
+        }
+    }
+    entries
+}
+
+// ── Windows ────────────────────────────────────────────────────────────────────
+
+const RUN_KEY: &str = r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run";
+
+fn windows_entries() -> Vec<Entry> {
+    let Ok(out) = Command::new("reg").args(["query", RUN_KEY]).output() else { return Vec::new() };
+    // "    Name    REG_SZ    C:\path\app.exe --minimized"
+    String::from_utf8_lossy(&out.stdout).lines().filter_map(|l| {
+        let (name, rest) = l.trim().split_once("    REG_")?;
+        let command = rest.split_once("    ").map(|(_, c)| c.trim()).unwrap_or("");
+        Some(Entry { source: Source::RunKey, id: name.trim().to_string(), name: name.trim().to_string(), command: command.to_string(), enabled: true, impact: None })
+    }).collect()
+}
+
+fn run(args: &[&str]) -> Result<()> {
+    let status = Command::new(args[0]).args(&args[1..]).status()
+        .with_context(|| format!("Failed to run {}", args[0]))?;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", args.join(" "), status));
+    }
+    Ok(())
+}
+
+// ── Commands ───────────────────────────────────────────────────────────────────
+
+fn entries() -> Vec<Entry> {
+    let mut all = if cfg!(target_os = "macos") {
+        macos_entries()
+    } else if cfg!(target_os = "windows") {
+        windows_entries()
+    } else {
+        let mut e = autostart_entries();
+        e.extend(systemd_user_entries());
+        e
+    };
+    // Things we removed ourselves still show up, as disabled
+    for s in load_stash() {
+        if !all.iter().any(|e| e.source == s.source && e.id == s.id) {
+            all.push(Entry { source: s.source, name: s.id.clone(), id: s.id, command: s.command, enabled: false, impact: None });
+        }
+    }
+    all
+}
+
+/// `vg startup` / `vg startup list`
+pub fn list() -> Result<()> {
+    ui::print_header("STARTUP");
+    let mut all = entries();
+    if all.is_empty() {
+        ui::skip("No startup applications found");
+        return Ok(());
+    }
+    all.sort_by(|a, b| b.enabled.cmp(&a.enabled)
+        .then(b.impact.unwrap_or(0.0).total_cmp(&a.impact.unwrap_or(0.0)))
+        .then(a.name.to_lowercase().cmp(&b.name.to_lowercase())));
+
+    for e in &all {
+        let state = if e.enabled { "●".truecolor(74, 222, 128) } else { "○".truecolor(71, 85, 105) };
+        let impact = match e.impact {
+            Some(secs) if secs >= 1.0 => format!("{:>8}", super::boot::fmt_secs(secs)).truecolor(251, 191, 36).to_string(),
+            Some(secs) => format!("{:>8}", super::boot::fmt_secs(secs)).truecolor(100, 116, 139).to_string(),
+            None => format!("{:>8}", "—").truecolor(71, 85, 105).to_string(),
+        };
+        let name = if e.enabled { e.name.truecolor(224, 242, 254) } else { e.name.truecolor(100, 116, 139) };
+        println!("  {} {}  {:<36} {}", state, impact, name, e.source.label().truecolor(71, 85, 105));
+    }
+    println!();
+    ui::skip("Impact is the start time measured by systemd for this session, where available.");
+    ui::skip("Toggle with: vg startup disable <name>  ·  vg startup enable <name>");
+    Ok(())
+}
+
+/// Exact id/name first, then a fuzzy match once confirmed (None when declined); prompts when no name is given.
+fn pick(query: Option<String>, want_enabled: bool) -> Result<Option<Entry>> {
+    let candidates: Vec<Entry> = entries().into_iter().filter(|e| e.enabled != want_enabled).collect();
+    if candidates.is_empty() {
+        return Err(anyhow!("Nothing to {}", if want_enabled { "enable" } else { "disable" }));
+    }
+    let Some(query) = query else {
+        if !crate::ui::interactive() {
+            return Err(anyhow!("Pass the name of the entry"));
+        }
+        let labels: Vec<String> = candidates.iter().map(|e| format!("{} ({})", e.name, e.source.label())).collect();
+        let choice = Select::new(if want_enabled { "Enable which?" } else { "Disable which?" }, labels.clone()).prompt()?;
+        let idx = labels.iter().position(|l| *l == choice).unwrap_or(0);
+        return Ok(Some(candidates[idx].clone()));
+    };
+    if let Some(e) = candidates.iter().find(|e| e.id.eq_ignore_ascii_case(&query) || e.name.eq_ignore_ascii_case(&query)) {
+        return Ok(Some(e.clone()));
+    }
+    let hay: Vec<String> = candidates.iter().map(|e| format!("{} {}", e.name, e.id)).collect();
+    let (idx, _) = super::search::fuzzy_rank(&query, &hay).into_iter().next()
+        .ok_or_else(|| anyhow!("No {} startup entry matches '{}'", if want_enabled { "disabled" } else { "enabled" }, query))?;
+    let found = &candidates[idx];
+    Ok(ui::confirm_match(&query, &format!("{} ({})", found.name, found.source.label()))?.then(|| found.clone()))
+}
+
+/// `vg startup enable|disable [name]`
+pub fn set(query: Option<String>, enabled: bool) -> Result<()> {
+    let Some(entry) = pick(query, enabled)? else {
+        ui::skip("Aborted.");
+        return Ok(());
+    };
+    let mut stash = load_stash();
+    match (entry.source, enabled) {
+        (Source::Autostart, _) => set_autostart(&entry.id, enabled)?,
+        (Source::SystemdUser, true) => run(&["systemctl", "--user", "enable", &entry.id])?,
+        (Source::SystemdUser, false) => run(&["systemctl", "--user", "disable", &entry.id])?,
+        (Source::LaunchAgent, _) => {
+            let plist = dirs::home_dir().unwrap_or_default().join("Library/LaunchAgents").join(&entry.id);
+            let plist = plist.to_string_lossy();
+            run(&["launchctl", if enabled { "load" } else { "unload" }, "-w", &plist])?;
+        }
+        (Source::LoginItem, false) => {
+            osascript(&format!("tell application \"System Events\" to delete login item \"{}\"", entry.id))?;
+            stash.push(Stashed { source: entry.source, id: entry.id.clone(), command: entry.command.clone() });
+        }
+        (Source::LoginItem, true) => {
+            osascript(&format!(
+                "tell application \"System Events\" to make login item at end with properties {{path:\"{}\", hidden:false}}",
+                entry.command
+            ))?;
+        }
+        (Source::RunKey, false) => {
+            run(&["reg", "delete", RUN_KEY, "/v", &entry.id, "/f"])?;
+            stash.push(Stashed { source: entry.source, id: entry.id.clone(), command: entry.command.clone() });
+        }
+        (Source::RunKey, true) => run(&["reg", "add", RUN_KEY, "/v", &entry.id, "/t", "REG_SZ", "/d", &entry.command, "/f"])?,
+    }
+    if enabled {
+        stash.retain(|s| !(s.source == entry.source && s.id == entry.id));
+    }
+    save_stash(&stash)?;
+    ui::success(&format!("{} {} ({})", if enabled { "Enabled" } else { "Disabled" }, entry.name, entry.source.label()));
+    Ok(())
+}
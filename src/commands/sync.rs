@@ -0,0 +1,331 @@
+use crate::config::{ConfigManager, SyncConfig};
+use crate::history::Recorder;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use comfy_table::{Attribute, Cell, Color, Table};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Stores kept in sync, all in the data dir. Each is a JSON object keyed by name,
+/// so both sides' changes can be merged entry by entry.
+const SYNCED: &[&str] = &["snippets.json", "http_requests.json"];
+
+/// Suffix for the other machine's version of an entry both sides changed.
+const CONFLICT_SUFFIX: &str = "~remote";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Backend {
+    Git,
+    Rclone,
+}
+
+/// Local copy of the remote, refreshed before every operation.
+fn mirror() -> PathBuf {
+    ConfigManager::data_dir().join("sync").join("remote")
+}
+
+/// Each store as of the last sync — the common ancestor for three-way merges.
+fn base_dir() -> PathBuf {
+    ConfigManager::data_dir().join("sync").join("base")
+}
+
+fn backend(cfg: &SyncConfig) -> Result<Backend> {
+    let backend = match cfg.backend.as_str() {
+        "git" => Backend::Git,
+        "rclone" => Backend::Rclone,
+        "" => return Err(anyhow!("Sync is not set up — set sync.backend (git or rclone) and sync.remote with vg config")),
+        other => return Err(anyhow!("Unknown sync.backend '{}' — use git or rclone", other)),
+    };
+    if cfg.remote.trim().is_empty() {
+        return Err(anyhow!("sync.remote is empty — set it to a git URL or an rclone remote like webdav:genesis"));
+    }
+    crate::tools::require(if backend == Backend::Git { "git" } else { "rclone" })?;
+    Ok(backend)
+}
+
+fn run(program: &str, args: &[&str], dir: Option<&Path>) -> Result<String> {
+    let mut cmd = Command::new(program);
+    if let Some(dir) = dir {
+        cmd.arg("-C").arg(dir);
+    }
+    cmd.args(args);
+    let out = crate::logging::output(&mut cmd).with_context(|| format!("Failed to run {}", program))?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        return Err(anyhow!("{} {} failed: {}", program, args.first().unwrap_or(&""), stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// Bring the mirror up to date with the remote.
+fn fetch(backend: Backend, remote: &str) -> Result<()> {
+    let dir = mirror();
+    match backend {
+        Backend::Git => {
+            if !dir.join(".git").exists() {
+                let _ = std::fs::remove_dir_all(&dir);
+                std::fs::create_dir_all(dir.parent().unwrap_or(&dir))?;
+                run("git", &["clone", "--quiet", remote, &dir.to_string_lossy()], None)?;
+                return Ok(());
+            }
+            run("git", &["fetch", "--quiet", "origin"], Some(&dir))?;
+            let branch = run("git", &["symbolic-ref", "--short", "HEAD"], Some(&dir))?;
+            let upstream = format!("origin/{}", branch);
+            // Nothing to reset to while the remote repository is still empty
+            if run("git", &["rev-parse", "--verify", "--quiet", &upstream], Some(&dir)).is_ok() {
+                run("git", &["reset", "--hard", "--quiet", &upstream], Some(&dir))?;
+            }
+        }
+        Backend::Rclone => {
+            std::fs::create_dir_all(&dir)?;
+            run("rclone", &["mkdir", remote], None)?;
+            run("rclone", &["sync", remote, &dir.to_string_lossy()], None)?;
+        }
+    }
+    Ok(())
+}
+
+/// Upload the mirror.
+fn publish(backend: Backend, remote: &str) -> Result<()> {
+    let dir = mirror();
+    match backend {
+        Backend::Git => {
+            run("git", &["add", "-A"], Some(&dir))?;
+            if run("git", &["status", "--porcelain"], Some(&dir))?.is_empty() {
+                return Ok(());
+            }
+            let host = sysinfo::System::host_name().unwrap_or_else(|| "unknown".into());
+            let message = format!("vg sync from {}", host);
+            // Fresh machines often have no git identity yet
+            let mut args: Vec<String> = Vec::new();
+            if run("git", &["config", "user.email"], Some(&dir)).map_or(true, |e| e.is_empty()) {
+                args.extend(["-c".into(), "user.name=vg".into(), "-c".into(), format!("user.email=vg@{}", host)]);
+            }
+            args.extend(["commit".into(), "--quiet".into(), "-m".into(), message]);
+            run("git", &args.iter().map(String::as_str).collect::<Vec<_>>(), Some(&dir))?;
+            run("git", &["push", "--quiet", "origin", "HEAD"], Some(&dir))
+                .context("Push rejected — another machine synced meanwhile; run vg sync push again")?;
+        }
+        Backend::Rclone => {
+            run("rclone", &["copy", &dir.to_string_lossy(), remote], None)?;
+        }
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Option<Vec<u8>> {
+    std::fs::read(path).ok()
+}
+
+fn parse(bytes: Option<&[u8]>) -> Option<Value> {
+    bytes.and_then(|b| serde_json::from_slice(b).ok())
+}
+
+/// Same content, ignoring JSON formatting.
+fn same(a: Option<&[u8]>, b: Option<&[u8]>) -> bool {
+    a == b || matches!((parse(a), parse(b)), (Some(x), Some(y)) if x == y)
+}
+
+struct Merge {
+    content: Option<Vec<u8>>,
+    /// Entries both machines changed differently
+    conflicts: Vec<String>,
+}
+
+/// Three-way merge of one store. Entries changed on one side win; for entries changed on
+/// both, this machine's version is kept and the other one is added as `<name>~remote`.
+fn merge(base: Option<&[u8]>, local: Option<&[u8]>, remote: Option<&[u8]>) -> Merge {
+    let keep = |content: Option<&[u8]>| Merge { content: content.map(<[u8]>::to_vec), conflicts: Vec::new() };
+    if same(local, remote) || same(remote, base) {
+        return keep(local);
+    }
+    if same(local, base) {
+        return keep(remote);
+    }
+
+    let object = |bytes: Option<&[u8]>| match bytes {
+        None => Some(Map::new()),
+        Some(_) => parse(bytes).and_then(|v| v.as_object().cloned()),
+    };
+    let (Some(b), Some(l), Some(r)) = (object(base), object(local), object(remote)) else {
+        // Not mergeable entry by entry; keep ours whole
+        return Merge { content: local.map(<[u8]>::to_vec), conflicts: vec!["(whole file)".into()] };
+    };
+    let keys: BTreeSet<&String> = b.keys().chain(l.keys()).chain(r.keys()).collect();
+    let mut out = Map::new();
+    let mut conflicts = Vec::new();
+    for key in keys {
+        let (bv, lv, rv) = (b.get(key), l.get(key), r.get(key));
+        let chosen = if lv == rv || rv == bv {
+            lv
+        } else if lv == bv {
+            rv
+        } else {
+            conflicts.push(key.clone());
+            if let Some(rv) = rv {
+                out.insert(format!("{}{}", key, CONFLICT_SUFFIX), rv.clone());
+            }
+            lv
+        };
+        if let Some(v) = chosen {
+            out.insert(key.clone(), v.clone());
+        }
+    }
+    let content = serde_json::to_vec_pretty(&Value::Object(out)).ok();
+    Merge { content, conflicts }
+}
+
+fn write_or_remove(path: &Path, content: Option<&[u8]>) -> Result<()> {
+    match content {
+        Some(bytes) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, bytes).with_context(|| format!("Cannot write {}", path.display()))
+        }
+        None => match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
+    }
+}
+
+struct Sides {
+    name: &'static str,
+    local: Option<Vec<u8>>,
+    remote: Option<Vec<u8>>,
+    base: Option<Vec<u8>>,
+}
+
+fn sides() -> Vec<Sides> {
+    SYNCED.iter().map(|name| Sides {
+        name,
+        local: read(&ConfigManager::data_dir().join(name)),
+        remote: read(&mirror().join(name)),
+        base: read(&base_dir().join(name)),
+    }).collect()
+}
+
+fn report_conflicts(name: &str, conflicts: &[String]) {
+    for key in conflicts {
+        ui::fail(&format!("{}: '{}' changed on both machines — kept this one, the other is '{}{}'", name, key, key, CONFLICT_SUFFIX));
+    }
+}
+
+/// Merge the remote into the local stores; returns the number of stores changed locally.
+fn apply_local(files: &[Sides], merged: &[Merge]) -> Result<usize> {
+    let mut recorder = Recorder::new("sync", "merged remote changes");
+    let mut changed = 0;
+    for (s, m) in files.iter().zip(merged) {
+        if same(s.local.as_deref(), m.content.as_deref()) {
+            continue;
+        }
+        let path = ConfigManager::data_dir().join(s.name);
+        match (&s.local, &m.content) {
+            // Existing stores go through the undo journal
+            (Some(_), Some(bytes)) => recorder.write(&path, bytes)?,
+            (_, content) => write_or_remove(&path, content.as_deref())?,
+        }
+        changed += 1;
+    }
+    recorder.finish()?;
+    Ok(changed)
+}
+
+/// `vg sync status` — what push and pull would do.
+pub fn status(config: &ConfigManager) -> Result<()> {
+    ui::print_header("SYNC STATUS");
+    let cfg = &config.config.sync;
+    let backend = backend(cfg)?;
+    ui::info_line("Remote", &format!("{} ({})", cfg.remote, cfg.backend));
+    fetch(backend, &cfg.remote)?;
+    println!();
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Store").add_attribute(Attribute::Bold),
+        Cell::new("State").add_attribute(Attribute::Bold),
+    ]);
+    for s in sides() {
+        let (local, remote, base) = (s.local.as_deref(), s.remote.as_deref(), s.base.as_deref());
+        let (state, color) = if same(local, remote) {
+            ("in sync".to_string(), Color::Green)
+        } else if same(remote, base) {
+            ("changed here — vg sync push".to_string(), Color::Yellow)
+        } else if same(local, base) {
+            ("changed remotely — vg sync pull".to_string(), Color::Cyan)
+        } else {
+            match merge(base, local, remote).conflicts.len() {
+                0 => ("changed on both — merges cleanly".to_string(), Color::Yellow),
+                n => (format!("changed on both — {} conflicting entries", n), Color::Red),
+            }
+        };
+        table.add_row(vec![Cell::new(s.name), Cell::new(state).fg(color)]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+/// `vg sync pull` — merge the remote's changes into this machine.
+pub fn pull(config: &ConfigManager) -> Result<()> {
+    ui::print_header("SYNC PULL");
+    let cfg = &config.config.sync;
+    let backend = backend(cfg)?;
+    fetch(backend, &cfg.remote)?;
+
+    let files = sides();
+    let merged: Vec<Merge> = files.iter()
+        .map(|s| merge(s.base.as_deref(), s.local.as_deref(), s.remote.as_deref()))
+        .collect();
+    let changed = apply_local(&files, &merged)?;
+    for (s, m) in files.iter().zip(&merged) {
+        report_conflicts(s.name, &m.conflicts);
+        // The remote is what both sides now share; local-only changes stay pending for push
+        write_or_remove(&base_dir().join(s.name), s.remote.as_deref())?;
+    }
+    if changed == 0 {
+        ui::success("Already up to date");
+    } else {
+        ui::success(&format!("Updated {} of {} stores", changed, files.len()));
+        ui::skip("Changed your mind? vg undo");
+    }
+    Ok(())
+}
+
+/// `vg sync push` — merge, then upload this machine's stores.
+pub fn push(config: &ConfigManager) -> Result<()> {
+    ui::print_header("SYNC PUSH");
+    let cfg = &config.config.sync;
+    let backend = backend(cfg)?;
+    fetch(backend, &cfg.remote)?;
+
+    let files = sides();
+    let merged: Vec<Merge> = files.iter()
+        .map(|s| merge(s.base.as_deref(), s.local.as_deref(), s.remote.as_deref()))
+        .collect();
+    let pulled = apply_local(&files, &merged)?;
+    let mut uploaded = 0;
+    for (s, m) in files.iter().zip(&merged) {
+        report_conflicts(s.name, &m.conflicts);
+        if !same(s.remote.as_deref(), m.content.as_deref()) {
+            write_or_remove(&mirror().join(s.name), m.content.as_deref())?;
+            uploaded += 1;
+        }
+    }
+    publish(backend, &cfg.remote)?;
+    for (s, m) in files.iter().zip(&merged) {
+        write_or_remove(&base_dir().join(s.name), m.content.as_deref())?;
+    }
+
+    if pulled > 0 {
+        ui::info_line("Merged in", &format!("{} stores changed remotely", pulled));
+    }
+    if uploaded == 0 {
+        ui::success("Remote already up to date");
+    } else {
+        ui::success(&format!("Pushed {} stores to {}", uploaded, cfg.remote));
+    }
+    Ok(())
+}
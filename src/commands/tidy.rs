@@ -0,0 +1,185 @@
+use crate::history::Recorder;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use comfy_table::{Attribute, Cell, Color, Table};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use inquire::Confirm;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Names of editor/browser leftovers; only removed when they are empty.
+const TEMP_PATTERNS: &[&str] = &[
+    "*.tmp", "*.temp", "*~", "~$*", ".~lock.*#", "*.part", "*.crdownload", "*.swp", ".DS_Store.tmp",
+];
+
+/// Version control metadata is never walked into.
+const VCS_DIRS: &[&str] = &[".git", ".hg", ".svn"];
+
+pub struct TidyParams {
+    pub path: PathBuf,
+    /// Globs matched against the file name and the path relative to `path`
+    pub exclude: Vec<String>,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    BrokenLink,
+    EmptyFile,
+    EmptyDir,
+}
+
+impl Kind {
+    fn label(self) -> &'static str {
+        match self {
+            Kind::BrokenLink => "broken link",
+            Kind::EmptyFile => "empty temp file",
+            Kind::EmptyDir => "empty directory",
+        }
+    }
+}
+
+fn globset(patterns: &[String]) -> Result<GlobSet> {
+    let mut b = GlobSetBuilder::new();
+    for p in patterns {
+        b.add(Glob::new(p).with_context(|| format!("Invalid --exclude pattern '{}'", p))?);
+    }
+    Ok(b.build()?)
+}
+
+/// XDG folders like ~/Templates or ~/Public are empty on most systems but expected to exist.
+fn protected_dirs() -> HashSet<PathBuf> {
+    [
+        dirs::home_dir(), dirs::desktop_dir(), dirs::document_dir(), dirs::download_dir(),
+        dirs::audio_dir(), dirs::picture_dir(), dirs::video_dir(), dirs::public_dir(), dirs::template_dir(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|p| p.canonicalize().ok())
+    .collect()
+}
+
+/// Everything under `root` that can go, files and links first, then directories deepest first.
+fn scan(root: &Path, exclude: &GlobSet) -> Vec<(Kind, PathBuf)> {
+    let temp: Vec<String> = TEMP_PATTERNS.iter().map(|p| p.to_string()).collect();
+    let temp = globset(&temp).expect("built-in patterns are valid");
+    let excluded = |p: &Path| {
+        let rel = p.strip_prefix(root).unwrap_or(p);
+        exclude.is_match(rel) || p.file_name().is_some_and(|n| exclude.is_match(n))
+    };
+
+    let options = crate::walk::Options {
+        skip_names: VCS_DIRS.iter().map(|v| v.to_string()).collect(),
+        ..Default::default()
+    };
+    // Directories come back without a kind; whether they're empty is decided below
+    let walked = crate::walk::walk(root, &options, |entry| {
+        let path = entry.path();
+        if entry.depth() == 0 || excluded(path) {
+            return None;
+        }
+        let ft = entry.file_type()?;
+        if ft.is_symlink() {
+            std::fs::metadata(path).is_err().then(|| (Some(Kind::BrokenLink), entry.depth(), path.to_path_buf()))
+        } else if ft.is_dir() {
+            Some((None, entry.depth(), path.to_path_buf()))
+        } else if ft.is_file()
+            && entry.metadata().is_ok_and(|m| m.len() == 0)
+            && path.file_name().is_some_and(|n| temp.is_match(n))
+        {
+            Some((Some(Kind::EmptyFile), entry.depth(), path.to_path_buf()))
+        } else {
+            None
+        }
+    });
+    let mut found = Vec::new();
+    let mut dirs = Vec::new();
+    for (kind, depth, path) in walked.items {
+        match kind {
+            Some(kind) => found.push((kind, path)),
+            None => dirs.push((depth, path)),
+        }
+    }
+    found.sort_by(|a, b| a.1.cmp(&b.1));
+
+    // A directory is empty once everything in it is going too
+    let protected = protected_dirs();
+    let mut going: HashSet<PathBuf> = found.iter().map(|(_, p)| p.clone()).collect();
+    dirs.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    for (_, dir) in dirs {
+        if excluded(&dir) || dir.canonicalize().is_ok_and(|c| protected.contains(&c)) {
+            continue;
+        }
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        if entries.flatten().all(|e| going.contains(&e.path())) {
+            going.insert(dir.clone());
+            found.push((Kind::EmptyDir, dir));
+        }
+    }
+    found
+}
+
+/// `vg tidy [path]` — remove empty directories, broken symlinks and empty temp files.
+pub fn run(params: TidyParams) -> Result<()> {
+    ui::print_header("TIDY");
+    if !params.path.is_dir() {
+        return Err(anyhow!("{} is not a directory", params.path.display()));
+    }
+    let exclude = globset(&params.exclude)?;
+    let found = scan(&params.path, &exclude);
+    if found.is_empty() {
+        ui::success("Nothing to tidy");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Path").add_attribute(Attribute::Bold),
+        Cell::new("").add_attribute(Attribute::Bold),
+    ]);
+    for (kind, path) in &found {
+        let rel = path.strip_prefix(&params.path).unwrap_or(path);
+        let color = match kind {
+            Kind::BrokenLink => Color::Red,
+            Kind::EmptyFile => Color::Yellow,
+            Kind::EmptyDir => Color::DarkGrey,
+        };
+        table.add_row(vec![Cell::new(rel.display()), Cell::new(kind.label()).fg(color)]);
+    }
+    println!("{}", table);
+    println!();
+
+    let count = |k: Kind| found.iter().filter(|(kind, _)| *kind == k).count();
+    let summary = format!(
+        "{} empty directories, {} broken links, {} empty temp files",
+        count(Kind::EmptyDir), count(Kind::BrokenLink), count(Kind::EmptyFile),
+    );
+    if params.dry_run {
+        ui::skip(&format!("Dry run: would remove {}", summary));
+        return Ok(());
+    }
+    if !params.yes {
+        if !crate::ui::interactive() {
+            return Err(crate::error::Error::not_a_terminal("pass --yes to apply or --dry-run to preview").into());
+        }
+        if !Confirm::new(&format!("Remove {} entries?", found.len())).with_default(true).prompt()? {
+            ui::skip("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let mut recorder = Recorder::new("tidy", &summary);
+    let result = found.iter().try_for_each(|(kind, path)| match kind {
+        Kind::BrokenLink => recorder.remove_link(path),
+        Kind::EmptyFile => recorder.remove_file(path),
+        Kind::EmptyDir => recorder.remove_dir(path),
+    });
+    // Journal whatever happened, even on failure, so `vg undo` can roll back a partial run
+    recorder.finish()?;
+    result?;
+
+    ui::success(&format!("Removed {}", summary));
+    ui::skip("Changed your mind? vg undo");
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use crate::config::ToolchainConfig;
+use crate::package_managers::run_cmd;
+use crate::ui;
+use anyhow::{anyhow, Result};
+use chrono::{Local, Months, NaiveDate};
+use clap::ValueEnum;
+use comfy_table::{Attribute, Cell, Color, Table};
+use regex::Regex;
+use std::process::Command;
+use which::which;
+
+/// Versions whose end of life is closer than this are flagged.
+const EOL_WARN_DAYS: i64 = 180;
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Runtime {
+    Rust,
+    Node,
+    Python,
+    Go,
+    Java,
+}
+
+impl Runtime {
+    const ALL: [Runtime; 5] = [Runtime::Rust, Runtime::Node, Runtime::Python, Runtime::Go, Runtime::Java];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Runtime::Rust => "Rust",
+            Runtime::Node => "Node.js",
+            Runtime::Python => "Python",
+            Runtime::Go => "Go",
+            Runtime::Java => "Java",
+        }
+    }
+
+    /// Binary and the argument that prints its version.
+    fn probe(&self) -> (&'static str, &'static str) {
+        match self {
+            Runtime::Rust => ("rustc", "--version"),
+            Runtime::Node => ("node", "--version"),
+            Runtime::Python => (if cfg!(windows) { "python" } else { "python3" }, "--version"),
+            Runtime::Go => ("go", "version"),
+            Runtime::Java => ("java", "-version"),
+        }
+    }
+
+    /// Package searched for by `vg install` when no dedicated installer is used.
+    fn package(&self) -> &'static str {
+        match self {
+            Runtime::Rust => "rust",
+            Runtime::Node => "nodejs",
+            Runtime::Python => "python",
+            Runtime::Go => "go",
+            Runtime::Java => "openjdk",
+        }
+    }
+}
+
+struct Installed {
+    version: String,
+    major: u32,
+    minor: u32,
+    path: String,
+}
+
+fn detect(rt: Runtime) -> Option<Installed> {
+    let (bin, arg) = rt.probe();
+    let path = which(bin).ok()?;
+    let out = crate::logging::output(Command::new(bin).arg(arg)).ok()?;
+    // java -version writes to stderr, everything else to stdout
+    let text = format!("{}{}", String::from_utf8_lossy(&out.stdout), String::from_utf8_lossy(&out.stderr));
+    let re = Regex::new(r"(\d+)\.(\d+)(?:\.(\d+))?").expect("valid version regex");
+    let caps = re.captures(&text)?;
+    let mut major: u32 = caps[1].parse().ok()?;
+    let mut minor: u32 = caps[2].parse().ok()?;
+    // Java 8 and older report themselves as 1.8
+    if rt == Runtime::Java && major == 1 {
+        major = minor;
+        minor = 0;
+    }
+    Some(Installed { version: caps[0].to_string(), major, minor, path: path.display().to_string() })
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap_or_default()
+}
+
+fn add_months(start: NaiveDate, months: i64) -> NaiveDate {
+    if months >= 0 {
+        start.checked_add_months(Months::new(months as u32)).unwrap_or(start)
+    } else {
+        start.checked_sub_months(Months::new((-months) as u32)).unwrap_or(start)
+    }
+}
+
+/// End of life from each project's release cadence, so the table doesn't go stale:
+/// Node even majors get 3 years from April, Python 5 years from October,
+/// Go supports the two newest minors, Java non-LTS ends with the next release.
+fn end_of_life(rt: Runtime, major: u32, minor: u32) -> Option<NaiveDate> {
+    match rt {
+        Runtime::Node if major.is_multiple_of(2) => Some(date(2013 + major as i32 / 2 + 3, 4, 30)),
+        Runtime::Node => Some(date(2013 + (major as i32 - 1) / 2 + 1, 6, 1)),
+        Runtime::Python if major == 2 => Some(date(2020, 1, 1)),
+        Runtime::Python => Some(date(2011 + minor as i32 + 5, 10, 31)),
+        // 1.22 shipped in February 2024, one minor every six months
+        Runtime::Go => Some(add_months(date(2024, 2, 1), (minor as i64 + 2 - 22) * 6)),
+        Runtime::Java if major == 8 => Some(date(2030, 12, 31)),
+        Runtime::Java if major < 10 => Some(date(2018, 3, 1)),
+        Runtime::Java => {
+            // Java 10 shipped in March 2018, one release every six months; LTS every fourth from 17
+            let release = |v: u32| add_months(date(2018, 3, 1), (v as i64 - 10) * 6);
+            let lts = major == 11 || (major >= 17 && (major - 17).is_multiple_of(4));
+            Some(if lts { add_months(release(major), 8 * 12) } else { release(major + 1) })
+        }
+        Runtime::Rust => None,
+    }
+}
+
+/// Rust only supports the latest stable, released every six weeks since 1.0 (2015-05-15).
+fn rust_releases_behind(minor: u32) -> u32 {
+    let days = (Local::now().date_naive() - date(2015, 5, 15)).num_days().max(0);
+    ((days / 42) as u32).saturating_sub(minor)
+}
+
+/// Status text and colour for an installed runtime; `None` colour means healthy.
+fn status(rt: Runtime, inst: &Installed) -> (String, Option<Color>) {
+    let today = Local::now().date_naive();
+    if rt == Runtime::Rust {
+        let behind = rust_releases_behind(inst.minor);
+        return if behind > 2 {
+            (format!("{} releases behind stable", behind), Some(Color::Yellow))
+        } else {
+            ("supported".to_string(), None)
+        };
+    }
+    match end_of_life(rt, inst.major, inst.minor) {
+        Some(eol) if eol <= today => (format!("end of life since {}", eol), Some(Color::Red)),
+        Some(eol) if (eol - today).num_days() < EOL_WARN_DAYS => (format!("end of life on {}", eol), Some(Color::Yellow)),
+        Some(eol) => (format!("supported until {}", eol), None),
+        None => ("supported".to_string(), None),
+    }
+}
+
+/// `vg toolchain` — installed runtimes, versions and support status.
+pub fn list() -> Result<()> {
+    ui::print_header("TOOLCHAIN");
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Runtime").add_attribute(Attribute::Bold),
+        Cell::new("Version").add_attribute(Attribute::Bold),
+        Cell::new("Status").add_attribute(Attribute::Bold),
+        Cell::new("Path").add_attribute(Attribute::Bold),
+    ]);
+
+    let mut outdated = Vec::new();
+    for rt in Runtime::ALL {
+        match detect(rt) {
+            Some(inst) => {
+                let (text, color) = status(rt, &inst);
+                if color.is_some() {
+                    outdated.push(rt);
+                }
+                table.add_row(vec![
+                    Cell::new(rt.name()).fg(Color::Blue),
+                    Cell::new(&inst.version),
+                    Cell::new(text).fg(color.unwrap_or(Color::Green)),
+                    Cell::new(&inst.path).fg(Color::DarkGrey),
+                ]);
+            }
+            None => {
+                table.add_row(vec![
+                    Cell::new(rt.name()).fg(Color::DarkGrey),
+                    Cell::new("—").fg(Color::DarkGrey),
+                    Cell::new("not installed").fg(Color::DarkGrey),
+                    Cell::new(""),
+                ]);
+            }
+        }
+    }
+    println!("{}", table);
+    println!();
+    for rt in &outdated {
+        let key = format!("{:?}", rt).to_lowercase();
+        ui::skip(&format!("Fix: vg toolchain update {}", key));
+    }
+    if outdated.is_empty() {
+        ui::success("All installed runtimes are supported");
+    }
+    Ok(())
+}
+
+/// Which installer handles a runtime: a version manager from config (or the first one found), else the system package manager.
+fn installer(rt: Runtime, config: &ToolchainConfig) -> &'static str {
+    let (setting, managers): (&str, &[&'static str]) = match rt {
+        Runtime::Rust => ("auto", &["rustup"]),
+        Runtime::Node => (config.node.as_str(), &["fnm", "volta"]),
+        Runtime::Python => (config.python.as_str(), &["pyenv", "uv"]),
+        Runtime::Go | Runtime::Java => ("package", &[]),
+    };
+    match setting {
+        "auto" => managers.iter().copied().find(|m| which(m).is_ok()).unwrap_or("package"),
+        other => managers.iter().copied().find(|m| *m == other).unwrap_or("package"),
+    }
+}
+
+/// `vg toolchain update <runtime>` — install or update through the configured installer.
+pub fn update(rt: Runtime, config: &ToolchainConfig, yes: bool) -> Result<()> {
+    ui::print_header(&format!("TOOLCHAIN  {}", rt.name()));
+    let before = detect(rt).map(|i| i.version);
+    let via = installer(rt, config);
+    ui::info_line("Installed", before.as_deref().unwrap_or("none"));
+    ui::info_line("Installer", via);
+    println!();
+
+    if via != "package" && which(via).is_err() {
+        return Err(anyhow!("{} is configured but not installed — vg install {}", via, via));
+    }
+    match via {
+        "rustup" => run_cmd(&["rustup", "update", "stable"], false)?,
+        "fnm" => {
+            run_cmd(&["fnm", "install", "--lts"], false)?;
+            run_cmd(&["fnm", "default", "lts-latest"], false)?;
+        }
+        "volta" => run_cmd(&["volta", "install", "node@lts"], false)?,
+        "pyenv" => {
+            let out = crate::logging::output(Command::new("pyenv").args(["latest", "-k", "3"]))?;
+            let latest = String::from_utf8_lossy(&out.stdout).trim().to_string();
+            if latest.is_empty() {
+                return Err(anyhow!("pyenv doesn't know any Python 3 release — update pyenv first"));
+            }
+            run_cmd(&["pyenv", "install", "-s", &latest], false)?;
+            ui::skip(&format!("Make it the default with: pyenv global {}", latest));
+        }
+        "uv" => run_cmd(&["uv", "python", "install"], false)?,
+        _ => super::package::install(rt.package(), yes)?,
+    }
+
+    let after = detect(rt).map(|i| i.version);
+    println!();
+    match (&before, &after) {
+        (_, None) => ui::skip("Open a new shell if the runtime isn't on PATH yet"),
+        (Some(b), Some(a)) if a == b => ui::skip(&format!("{} {} is still the active version", rt.name(), a)),
+        (_, Some(a)) => ui::success(&format!("{} {} is active", rt.name(), a)),
+    }
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use crate::config::ConfigManager;
+use crate::ui;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Utc};
+use comfy_table::{Attribute, Cell, Color, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    label: String,
+    start: DateTime<Utc>,
+    /// None while the timer is running
+    end: Option<DateTime<Utc>>,
+}
+
+impl Entry {
+    fn end_or_now(&self) -> DateTime<Utc> {
+        self.end.unwrap_or_else(Utc::now)
+    }
+
+    /// Seconds of this entry that fall within [from, to).
+    fn overlap(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> i64 {
+        let start = self.start.max(from);
+        let end = self.end_or_now().min(to);
+        (end - start).num_seconds().max(0)
+    }
+}
+
+fn store_path() -> PathBuf {
+    ConfigManager::data_dir().join("track.json")
+}
+
+fn load() -> Result<Vec<Entry>> {
+    let path = store_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn save(entries: &[Entry]) -> Result<()> {
+    std::fs::create_dir_all(ConfigManager::data_dir())?;
+    std::fs::write(store_path(), serde_json::to_string_pretty(entries)?)?;
+    Ok(())
+}
+
+fn fmt_hm(secs: i64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
+fn midnight(date: NaiveDate) -> DateTime<Utc> {
+    Local.from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .earliest()
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now)
+}
+
+/// Close the running entry, if any, and return it.
+fn stop_running(entries: &mut [Entry]) -> Option<Entry> {
+    let running = entries.iter_mut().find(|e| e.end.is_none())?;
+    running.end = Some(Utc::now());
+    Some(running.clone())
+}
+
+/// `vg track start <label>` — stops whatever was running first.
+pub fn start(label: String) -> Result<()> {
+    ui::print_header("TRACK");
+    let label = label.trim().to_string();
+    if label.is_empty() {
+        return Err(anyhow!("Give a label, e.g. vg track start \"client X\""));
+    }
+    let mut entries = load()?;
+    if let Some(prev) = stop_running(&mut entries) {
+        ui::info_line("Stopped", &format!("{} after {}", prev.label, fmt_hm((prev.end_or_now() - prev.start).num_seconds())));
+    }
+    entries.push(Entry { label: label.clone(), start: Utc::now(), end: None });
+    save(&entries)?;
+    ui::success(&format!("Tracking {}", label));
+    Ok(())
+}
+
+/// `vg track stop`
+pub fn stop() -> Result<()> {
+    ui::print_header("TRACK");
+    let mut entries = load()?;
+    let Some(prev) = stop_running(&mut entries) else {
+        ui::skip("Nothing is being tracked");
+        return Ok(());
+    };
+    save(&entries)?;
+    ui::success(&format!("Stopped {} after {}", prev.label, fmt_hm((prev.end_or_now() - prev.start).num_seconds())));
+    Ok(())
+}
+
+/// `vg track status` — what's running and today's total.
+pub fn status() -> Result<()> {
+    ui::print_header("TRACK");
+    let entries = load()?;
+    let today = midnight(Local::now().date_naive());
+    match entries.iter().find(|e| e.end.is_none()) {
+        Some(e) => ui::info_line("Running", &format!(
+            "{} for {} (since {})",
+            e.label, fmt_hm((Utc::now() - e.start).num_seconds()), e.start.with_timezone(&Local).format("%H:%M"),
+        )),
+        None => ui::info_line("Running", "nothing"),
+    }
+    let total: i64 = entries.iter().map(|e| e.overlap(today, Utc::now())).sum();
+    ui::info_line("Today", &fmt_hm(total));
+    Ok(())
+}
+
+/// Today at a glance, for `vg dash`.
+pub(crate) struct Today {
+    /// Label and time so far ("1h 05m")
+    pub running: Option<(String, String)>,
+    /// Time per label, longest first
+    pub labels: Vec<(String, String)>,
+}
+
+pub(crate) fn today() -> Today {
+    let entries = load().unwrap_or_default();
+    let today = midnight(Local::now().date_naive());
+    let running = entries.iter().find(|e| e.end.is_none())
+        .map(|e| (e.label.clone(), fmt_hm((Utc::now() - e.start).num_seconds())));
+    let mut totals: BTreeMap<String, i64> = BTreeMap::new();
+    for e in &entries {
+        let secs = e.overlap(today, Utc::now());
+        if secs > 0 {
+            *totals.entry(e.label.clone()).or_default() += secs;
+        }
+    }
+    let mut totals: Vec<(String, i64)> = totals.into_iter().collect();
+    totals.sort_by_key(|(_, secs)| std::cmp::Reverse(*secs));
+    Today { running, labels: totals.into_iter().map(|(label, secs)| (label, fmt_hm(secs))).collect() }
+}
+
+/// `vg track report [--week]` — time per label for today or the current week.
+pub fn report(week: bool) -> Result<()> {
+    let now = Local::now();
+    let first = if week {
+        now.date_naive() - chrono::Days::new(now.weekday().num_days_from_monday() as u64)
+    } else {
+        now.date_naive()
+    };
+    ui::print_header(if week { "TRACK · THIS WEEK" } else { "TRACK · TODAY" });
+
+    let entries = load()?;
+    let days: Vec<NaiveDate> = first.iter_days().take_while(|d| *d <= now.date_naive()).collect();
+    // label → seconds per day
+    let mut totals: BTreeMap<&str, Vec<i64>> = BTreeMap::new();
+    for e in &entries {
+        for (i, day) in days.iter().enumerate() {
+            let secs = e.overlap(midnight(*day), midnight(*day + chrono::Days::new(1)));
+            if secs > 0 {
+                totals.entry(&e.label).or_insert_with(|| vec![0; days.len()])[i] += secs;
+            }
+        }
+    }
+    if totals.is_empty() {
+        ui::skip("Nothing tracked yet. Start with vg track start <label>");
+        return Ok(());
+    }
+
+    let mut rows: Vec<(&str, Vec<i64>)> = totals.into_iter().collect();
+    rows.sort_by_key(|(_, secs)| std::cmp::Reverse(secs.iter().sum::<i64>()));
+    let mut table = Table::new();
+    let mut header = vec![Cell::new("Label").add_attribute(Attribute::Bold)];
+    if week {
+        header.extend(days.iter().map(|d| Cell::new(d.format("%a")).add_attribute(Attribute::Bold)));
+    }
+    header.push(Cell::new("Total").add_attribute(Attribute::Bold));
+    table.set_header(header);
+    let mut day_totals = vec![0; days.len()];
+    for (label, secs) in &rows {
+        let mut row = vec![Cell::new(label)];
+        if week {
+            row.extend(secs.iter().map(|s| Cell::new(if *s > 0 { fmt_hm(*s) } else { String::new() })));
+        }
+        row.push(Cell::new(fmt_hm(secs.iter().sum())).fg(Color::Green));
+        table.add_row(row);
+        for (t, s) in day_totals.iter_mut().zip(secs) {
+            *t += s;
+        }
+    }
+    if week && rows.len() > 1 {
+        let mut row = vec![Cell::new("Total").add_attribute(Attribute::Bold)];
+        row.extend(day_totals.iter().map(|s| Cell::new(fmt_hm(*s))));
+        row.push(Cell::new(fmt_hm(day_totals.iter().sum())).fg(Color::Green).add_attribute(Attribute::Bold));
+        table.add_row(row);
+    }
+    println!("{}", table);
+    if !week {
+        println!();
+        ui::info_line("Total", &fmt_hm(day_totals.iter().sum()));
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::history::{self, Reverted};
+use crate::ui;
+use anyhow::Result;
+use colored::Colorize;
+use inquire::Confirm;
+
+/// `vg undo --list` — journaled file operations, newest first.
+pub fn list() -> Result<()> {
+    ui::print_header("UNDO HISTORY");
+    let ops = history::load()?;
+    if ops.is_empty() {
+        ui::skip("Nothing recorded yet. vg replace, vg rename-batch and vg tidy record their changes here.");
+        return Ok(());
+    }
+    for op in ops.iter().rev() {
+        println!(
+            "  {}  {:<13} {}  {}",
+            op.id.truecolor(71, 85, 105),
+            op.command.truecolor(96, 165, 250).bold(),
+            op.summary.truecolor(224, 242, 254),
+            format!("({} changes, {})", op.changes.len(), op.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")).truecolor(100, 116, 139),
+        );
+    }
+    println!();
+    ui::skip("Revert the newest with `vg undo`, or a specific one with `vg undo <id>`");
+    Ok(())
+}
+
+/// `vg undo [id]` — revert the last (or given) journaled run.
+pub fn run(id: Option<String>, yes: bool) -> Result<()> {
+    ui::print_header("UNDO");
+    let ops = history::load()?;
+    let Some(op) = (match &id {
+        Some(id) => ops.iter().find(|o| &o.id == id),
+        None => ops.last(),
+    }) else {
+        ui::skip("Nothing to undo");
+        return Ok(());
+    };
+
+    ui::info_line("Operation", &format!("{} {}", op.command, op.summary));
+    ui::info_line("Changes", &op.changes.len().to_string());
+    if !yes && crate::ui::interactive() {
+        let ok = Confirm::new("Revert it?").with_default(true).prompt()?;
+        if !ok {
+            ui::skip("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let (_, results) = history::undo(id.as_deref())?;
+    let mut skipped = 0;
+    for r in &results {
+        match r {
+            Reverted::Done(msg) => ui::success(msg),
+            Reverted::Skipped(msg) => {
+                skipped += 1;
+                ui::fail(msg);
+            }
+        }
+    }
+    println!();
+    if skipped > 0 {
+        ui::skip(&format!("{} of {} changes could not be reverted", skipped, results.len()));
+    }
+    Ok(())
+}
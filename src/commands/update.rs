@@ -1,6 +1,6 @@
 use crate::ui;
 use crate::package_managers::get_available_managers;
-use crate::commands::self_update::{self, CURRENT_VERSION};
+use crate::commands::self_update::{self, Channel, CURRENT_VERSION};
 use anyhow::Result;
 use colored::Colorize;
 
@@ -24,9 +24,66 @@ fn print_pkg_row(name: &str, old_ver: &str, new_ver: &str, done: bool) {
     );
 }
 
-pub fn run(yes: bool) -> Result<()> {
+/// Numeric parts of a kernel release, for ordering: "6.6.10-arch1-1" → [6, 6, 10, 1, 1].
+fn kernel_key(release: &str) -> Vec<u64> {
+    release.split(|c: char| !c.is_ascii_digit()).filter_map(|p| p.parse().ok()).collect()
+}
+
+/// Why the machine should be restarted to finish updates, if it should.
+pub fn reboot_required() -> Option<String> {
+    if cfg!(windows) {
+        let pending = crate::logging::output(std::process::Command::new("reg")
+            .args(["query", r"HKLM\SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update\RebootRequired"]))
+            .is_ok_and(|o| o.status.success());
+        return pending.then(|| "Windows Update is waiting for a restart".to_string());
+    }
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    // Debian/Ubuntu packages drop this file when they need a restart
+    if let Ok(pkgs) = std::fs::read_to_string("/var/run/reboot-required.pkgs") {
+        let pkgs: Vec<&str> = pkgs.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        return Some(format!("updated {}", pkgs.join(", ")));
+    }
+    if std::path::Path::new("/var/run/reboot-required").exists() {
+        return Some("an updated package asked for it".to_string());
+    }
+    // A container runs the host's kernel; its /lib/modules says nothing about it
+    if ["/.dockerenv", "/run/.containerenv"].iter().any(|p| std::path::Path::new(p).exists()) {
+        return None;
+    }
+    let running = sysinfo::System::kernel_version()?;
+    let installed: Vec<String> = std::fs::read_dir("/lib/modules").ok()?
+        .flatten()
+        .filter(|e| e.path().join("modules.dep").exists())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    let newest = installed.iter().max_by_key(|r| kernel_key(r))?;
+    if !installed.contains(&running) {
+        // Arch removes the old modules on upgrade: the running kernel can no longer load any
+        Some(format!("kernel {} is running, but only {} is installed", running, newest))
+    } else if kernel_key(newest) > kernel_key(&running) {
+        Some(format!("kernel {} is installed, {} is still running", newest, running))
+    } else {
+        None
+    }
+}
+
+pub fn run(yes: bool, firmware: bool, mirrors: bool, channel: Channel) -> Result<()> {
     ui::print_header("SYSTEM UPDATE");
 
+    if mirrors {
+        ui::section("Refreshing mirrors");
+        // Slow or unreachable mirrors shouldn't block the update itself
+        if let Err(e) = super::mirrors::refresh(yes) {
+            if crate::process::interrupted() {
+                return Err(e);
+            }
+            ui::fail(&format!("Mirror refresh failed: {:#}", e));
+        }
+        println!();
+    }
+
     let managers = get_available_managers();
 
     if managers.is_empty() {
@@ -97,15 +154,14 @@ pub fn run(yes: bool) -> Result<()> {
                     ui::success(&format!("{} — up to date", manager.display_name()));
                 } else {
                     println!();
-                    ui::success(&format!(
-                        "{} — {} package{} updated",
-                        manager.display_name(),
-                        n,
-                        if n == 1 { "" } else { "s" }
-                    ));
+                    let done = format!("{} — {} package{} updated", manager.display_name(), n, if n == 1 { "" } else { "s" });
+                    ui::success(&done);
+                    crate::notify::note(done);
                     any_updated = true;
                 }
             }
+            // Ctrl-C stops the whole update, not just this manager
+            Err(e) if crate::process::interrupted() => return Err(e),
             Err(e) => {
                 // Still mark any pending packages to avoid leaving · rows dangling
                 for (name, old_ver, new_ver) in pending.iter() {
@@ -114,7 +170,37 @@ pub fn run(yes: bool) -> Result<()> {
                     }
                 }
                 ui::fail(&format!("{} failed: {}", manager.display_name(), e));
+                crate::notify::note(format!("{} failed: {}", manager.display_name(), e));
+            }
+        }
+        println!();
+    }
+
+    if firmware {
+        ui::section("Updating firmware (fwupd)");
+        match super::firmware::refresh() {
+            Ok(devices) if devices.is_empty() => ui::success("Firmware — up to date"),
+            Ok(devices) => {
+                for (device, old_ver, new_ver) in &devices {
+                    print_pkg_row(device, old_ver, new_ver, false);
+                }
+                println!();
+                match super::firmware::apply(yes) {
+                    Ok(()) => {
+                        let n = devices.len();
+                        let done = format!("Firmware — {} device{} updated", n, if n == 1 { "" } else { "s" });
+                        ui::success(&done);
+                        crate::notify::note(done);
+                        ui::skip("Most firmware is flashed during the next restart");
+                        any_updated = true;
+                    }
+                    Err(e) => {
+                        ui::fail(&format!("Firmware update failed: {:#}", e));
+                        crate::notify::note(format!("Firmware update failed: {:#}", e));
+                    }
+                }
             }
+            Err(e) => ui::fail(&format!("Firmware: {:#}", e)),
         }
         println!();
     }
@@ -123,7 +209,7 @@ pub fn run(yes: bool) -> Result<()> {
     ui::section("Updating Volantic Genesis");
 
     println!("  {}", "Checking for new release...".truecolor(71, 85, 105));
-    match self_update::check() {
+    match self_update::check(channel) {
         None => {
             // Clear the "checking..." line with a done status
             print!("\x1b[1A\x1b[2K");
@@ -154,5 +240,9 @@ pub fn run(yes: bool) -> Result<()> {
     } else {
         ui::success("Everything is up to date.");
     }
+    if let Some(reason) = reboot_required() {
+        ui::fail(&format!("Reboot required — {}", reason));
+        crate::notify::note(format!("Reboot required — {}", reason));
+    }
     Ok(())
 }
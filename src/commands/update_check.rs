@@ -0,0 +1,155 @@
+use crate::config::ConfigManager;
+use crate::package_managers::get_available_managers;
+use crate::ui;
+use super::self_update::{self, Channel};
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Result of the last background check, read by `vg greet` and `vg updates` without blocking.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cached {
+    /// Unix time of the check
+    pub checked: u64,
+    /// Pending updates per package manager display name
+    pub packages: BTreeMap<String, usize>,
+    /// Newer vg release, if any
+    pub vg_latest: Option<String>,
+}
+
+impl Cached {
+    pub fn total(&self) -> usize {
+        self.packages.values().sum()
+    }
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn cache_path() -> PathBuf {
+    ConfigManager::data_dir().join("update_check.json")
+}
+
+/// Written when a check is spawned, so concurrent vg invocations don't all start one.
+fn stamp_path() -> PathBuf {
+    ConfigManager::data_dir().join("last_update_check")
+}
+
+pub fn load() -> Option<Cached> {
+    std::fs::read_to_string(cache_path()).ok().and_then(|c| serde_json::from_str(&c).ok())
+}
+
+/// Drop the cached counts after `vg update` / `vg self-update` so greet doesn't report stale numbers.
+pub fn invalidate() {
+    let _ = std::fs::remove_file(cache_path());
+}
+
+/// Query every package manager and GitHub, then cache the result.
+pub fn refresh(channel: Channel) -> Result<Cached> {
+    let managers = get_available_managers();
+    let packages: BTreeMap<String, usize> = std::thread::scope(|s| {
+        managers.iter()
+            .map(|m| (m.display_name().to_string(), s.spawn(|| m.list_updates().len())))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(name, h)| (name, h.join().unwrap_or(0)))
+            .collect()
+    });
+    let cached = Cached {
+        checked: now(),
+        packages,
+        vg_latest: self_update::check(channel).map(|i| i.latest_version),
+    };
+    let path = cache_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&cached)?)?;
+    Ok(cached)
+}
+
+/// Spawn `vg updates --background` when the last check is older than `update_check.interval_hours`.
+pub fn maybe_spawn(config: &ConfigManager) {
+    let cfg = &config.config.update_check;
+    if !cfg.enabled {
+        return;
+    }
+    let last = std::fs::read_to_string(stamp_path()).ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    if now().saturating_sub(last) < cfg.interval_hours * 3600 {
+        return;
+    }
+    let stamp = stamp_path();
+    if let Some(parent) = stamp.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = std::fs::write(&stamp, now().to_string());
+
+    let Ok(exe) = std::env::current_exe() else { return };
+    let mut cmd = std::process::Command::new(exe);
+    cmd.arg("updates").arg("--background")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .stdin(std::process::Stdio::null());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Detach from process group so it survives terminal close
+        unsafe { cmd.pre_exec(|| { libc::setsid(); Ok(()) }); }
+    }
+    let _ = cmd.spawn();
+}
+
+fn fmt_age(secs: u64) -> String {
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+/// One-line summary for greet: "12 pending — run vg update (checked 3h ago)".
+pub fn summary(cached: &Cached) -> String {
+    let age = fmt_age(now().saturating_sub(cached.checked));
+    match cached.total() {
+        0 => format!("system is up to date (checked {})", age),
+        n => format!("{} pending — run vg update (checked {})", n, age),
+    }
+}
+
+/// `vg updates` — check now and show pending updates per package manager.
+pub fn run(config: &ConfigManager, background: bool) -> Result<()> {
+    let channel = Channel::from_config(&config.config.self_update.channel);
+    if background {
+        refresh(channel)?;
+        return Ok(());
+    }
+    ui::print_header("UPDATES");
+    ui::skip("Asking every package manager — this can take a moment");
+    println!();
+    let cached = refresh(channel)?;
+    for (manager, count) in &cached.packages {
+        let value = if *count == 0 { "up to date".truecolor(74, 222, 128) } else { format!("{} pending", count).truecolor(251, 191, 36) };
+        println!("  {:<20} {}", manager.truecolor(224, 242, 254), value);
+    }
+    println!();
+    match &cached.vg_latest {
+        Some(v) => ui::info_line("vg", &format!("{} available — run vg self-update", v)),
+        None => ui::info_line("vg", &format!("{} is the latest", self_update::CURRENT_VERSION)),
+    }
+    println!();
+    if cached.total() > 0 {
+        ui::skip(&format!("{} updates pending — run vg update", cached.total()));
+    } else {
+        ui::success("Everything is up to date");
+    }
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use crate::ui;
+use crate::config::ConfigManager;
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color, Table};
+use serde::Deserialize;
+use std::time::Duration;
+
+// Open-Meteo is free and needs no API key
+const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+#[derive(Deserialize)]
+struct GeoResponse {
+    #[serde(default)]
+    results: Vec<Place>,
+}
+
+#[derive(Deserialize)]
+struct Place {
+    name: String,
+    latitude: f64,
+    longitude: f64,
+    #[serde(default)]
+    country: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Forecast {
+    current: Current,
+    daily: Daily,
+}
+
+#[derive(Deserialize)]
+struct Current {
+    temperature_2m: f64,
+    weather_code: u32,
+    wind_speed_10m: f64,
+}
+
+#[derive(Deserialize)]
+struct Daily {
+    time: Vec<String>,
+    weather_code: Vec<u32>,
+    temperature_2m_max: Vec<f64>,
+    temperature_2m_min: Vec<f64>,
+    precipitation_probability_max: Vec<Option<u32>>,
+}
+
+/// Temperature and wind units for the configured unit system.
+fn units(system: &str) -> (&'static str, &'static str, &'static str, &'static str) {
+    // (temperature_unit, wind_speed_unit, temperature suffix, wind suffix)
+    if system == "imperial" {
+        ("fahrenheit", "mph", "°F", "mph")
+    } else {
+        ("celsius", "kmh", "°C", "km/h")
+    }
+}
+
+/// Short description for a WMO weather code.
+fn describe(code: u32) -> &'static str {
+    match code {
+        0 => "Clear sky",
+        1 => "Mainly clear",
+        2 => "Partly cloudy",
+        3 => "Overcast",
+        45 | 48 => "Fog",
+        51 | 53 | 55 => "Drizzle",
+        56 | 57 => "Freezing drizzle",
+        61 | 63 | 65 => "Rain",
+        66 | 67 => "Freezing rain",
+        71 | 73 | 75 | 77 => "Snow",
+        80..=82 => "Rain showers",
+        85 | 86 => "Snow showers",
+        95 => "Thunderstorm",
+        96 | 99 => "Thunderstorm with hail",
+        _ => "Unknown",
+    }
+}
+
+fn client(timeout: Duration) -> Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder()
+        .timeout(timeout)
+        .user_agent("vg-weather")
+        .build()?)
+}
+
+fn geocode(client: &reqwest::blocking::Client, city: &str) -> Result<Place> {
+    let resp: GeoResponse = client
+        .get(GEOCODING_URL)
+        .query(&[("name", city), ("count", "1"), ("format", "json")])
+        .send()
+        .context("Network unreachable — check your internet connection")?
+        .error_for_status()?
+        .json()?;
+    resp.results.into_iter().next().ok_or_else(|| anyhow!("No location found for '{}'", city))
+}
+
+fn forecast(client: &reqwest::blocking::Client, place: &Place, unit_system: &str) -> Result<Forecast> {
+    let (temp_unit, wind_unit, _, _) = units(unit_system);
+    let lat = place.latitude.to_string();
+    let lon = place.longitude.to_string();
+    Ok(client
+        .get(FORECAST_URL)
+        .query(&[
+            ("latitude", lat.as_str()),
+            ("longitude", lon.as_str()),
+            ("current", "temperature_2m,weather_code,wind_speed_10m"),
+            ("daily", "weather_code,temperature_2m_max,temperature_2m_min,precipitation_probability_max"),
+            ("temperature_unit", temp_unit),
+            ("wind_speed_unit", wind_unit),
+            ("timezone", "auto"),
+            ("forecast_days", "3"),
+        ])
+        .send()
+        .context("Network unreachable — check your internet connection")?
+        .error_for_status()?
+        .json()?)
+}
+
+/// `vg weather [city]` — current conditions and a 3-day forecast.
+pub fn run(city: Option<String>, config: &ConfigManager) -> Result<()> {
+    ui::print_header("WEATHER");
+
+    let city = city.unwrap_or_else(|| config.config.weather.location.clone());
+    if city.trim().is_empty() {
+        ui::fail("No location given.");
+        ui::skip("Pass a city (vg weather Berlin) or set one: vg config set weather.location Berlin");
+        return Ok(());
+    }
+
+    let unit_system = config.config.weather.units.as_str();
+    let (_, _, temp_suffix, wind_suffix) = units(unit_system);
+    let client = client(Duration::from_secs(10))?;
+    let place = geocode(&client, &city)?;
+    let fc = forecast(&client, &place, unit_system)?;
+
+    ui::section("Now");
+    let location = match &place.country {
+        Some(c) => format!("{}, {}", place.name, c),
+        None => place.name.clone(),
+    };
+    ui::info_line("Location", &location);
+    ui::info_line("Conditions", describe(fc.current.weather_code));
+    ui::info_line("Temperature", &format!("{:.1}{}", fc.current.temperature_2m, temp_suffix));
+    ui::info_line("Wind", &format!("{:.0} {}", fc.current.wind_speed_10m, wind_suffix));
+
+    ui::section("Forecast");
+    let mut table = Table::new();
+    table.set_header(vec![
+        Cell::new("Day").add_attribute(Attribute::Bold),
+        Cell::new("Conditions").add_attribute(Attribute::Bold),
+        Cell::new("Low").add_attribute(Attribute::Bold),
+        Cell::new("High").add_attribute(Attribute::Bold),
+        Cell::new("Rain").add_attribute(Attribute::Bold),
+    ]);
+    let d = &fc.daily;
+    for i in 0..d.time.len() {
+        let day = chrono::NaiveDate::parse_from_str(&d.time[i], "%Y-%m-%d")
+            .map(|date| date.format("%a %b %-d").to_string())
+            .unwrap_or_else(|_| d.time[i].clone());
+        let rain = d.precipitation_probability_max.get(i).copied().flatten()
+            .map(|p| format!("{}%", p))
+            .unwrap_or_else(|| "-".into());
+        table.add_row(vec![
+            Cell::new(day),
+            Cell::new(describe(d.weather_code.get(i).copied().unwrap_or(u32::MAX))).fg(Color::Blue),
+            Cell::new(format!("{:.0}{}", d.temperature_2m_min.get(i).copied().unwrap_or_default(), temp_suffix)),
+            Cell::new(format!("{:.0}{}", d.temperature_2m_max.get(i).copied().unwrap_or_default(), temp_suffix)),
+            Cell::new(rain).fg(Color::Cyan),
+        ]);
+    }
+    println!("{}", table);
+    println!();
+    println!("  {} {}", "Data:".truecolor(71, 85, 105), "open-meteo.com".truecolor(100, 116, 139));
+    Ok(())
+}
+
+/// One-line summary for `vg greet`, e.g. "Berlin · Partly cloudy, 14°C". None on any failure.
+pub fn summary_line(config: &ConfigManager) -> Option<String> {
+    let w = &config.config.weather;
+    if w.location.trim().is_empty() {
+        return None;
+    }
+    // Keep it short — greet runs from shell startup
+    let client = client(Duration::from_secs(3)).ok()?;
+    let place = geocode(&client, &w.location).ok()?;
+    let fc = forecast(&client, &place, &w.units).ok()?;
+    let (_, _, temp_suffix, _) = units(&w.units);
+    Some(format!("{} · {}, {:.0}{}", place.name, describe(fc.current.weather_code), fc.current.temperature_2m, temp_suffix))
+}
@@ -4,8 +4,58 @@ use std::path::{Path, PathBuf};
 use directories::ProjectDirs;
 use anyhow::{Result, Context};
 
+/// Current config schema. Bump it together with a new entry in `MIGRATIONS`.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// One schema change: rewrites a raw config from version `to - 1` to `to`
+/// and returns a line per key it renamed or removed.
+struct Migration {
+    to: u32,
+    apply: fn(&mut toml::Table) -> Vec<String>,
+}
+
+/// Applied in order to configs older than `CONFIG_VERSION`.
+const MIGRATIONS: &[Migration] = &[
+    // 1: config_version introduced; earlier configs need no rewriting
+    Migration { to: 1, apply: |_| Vec::new() },
+    // 2: command scripts moved from [hooks] into [hooks.scripts]
+    Migration { to: 2, apply: move_hook_scripts },
+];
+
+/// `[hooks] pre_update = "…"` is also accepted by hand in current files: moved into `[hooks.scripts]`,
+/// where an entry that is already there wins.
+fn move_hook_scripts(raw: &mut toml::Table) -> Vec<String> {
+    let Some(toml::Value::Table(hooks)) = raw.get_mut("hooks") else { return Vec::new() };
+    let keys: Vec<String> = hooks.iter()
+        .filter(|(k, v)| (k.starts_with("pre_") || k.starts_with("post_")) && v.is_str())
+        .map(|(k, _)| k.clone())
+        .collect();
+    if keys.is_empty() {
+        return Vec::new();
+    }
+    let mut scripts = match hooks.remove("scripts") {
+        Some(toml::Value::Table(scripts)) => scripts,
+        _ => toml::Table::new(),
+    };
+    let mut notes = Vec::new();
+    for key in keys {
+        let Some(script) = hooks.remove(&key) else { continue };
+        if scripts.contains_key(&key) {
+            notes.push(format!("Removed hooks.{}: hooks.scripts.{} is already set", key, key));
+        } else {
+            notes.push(format!("Moved hooks.{} to hooks.scripts.{}", key, key));
+            scripts.insert(key, script);
+        }
+    }
+    hooks.insert("scripts".into(), toml::Value::Table(scripts));
+    notes
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Config {
+    /// Schema version the file was written with; missing means 0
+    #[serde(default)]
+    pub config_version: u32,
     #[serde(default)]
     pub search: SearchConfig,
     #[serde(default)]
@@ -16,6 +66,245 @@ pub struct Config {
     pub auto_index: AutoIndexConfig,
     #[serde(default)]
     pub expect_update: ExpectUpdateConfig,
+    #[serde(default)]
+    pub self_update: SelfUpdateConfig,
+    #[serde(default)]
+    pub secrets: SecretsConfig,
+    #[serde(default)]
+    pub greet: GreetConfig,
+    #[serde(default)]
+    pub weather: WeatherConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub toolchain: ToolchainConfig,
+    #[serde(default)]
+    pub update_check: UpdateCheckConfig,
+    #[serde(default)]
+    pub sync: SyncConfig,
+    #[serde(default)]
+    pub timeouts: TimeoutsConfig,
+    #[serde(default)]
+    pub dash: DashConfig,
+    #[serde(default)]
+    pub notify: NotifyConfig,
+    #[serde(default)]
+    pub fleet: FleetConfig,
+    /// Custom commands: `u = "update --yes"` makes `vg u` run `vg update --yes`
+    #[serde(default)]
+    pub aliases: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct UpdateCheckConfig {
+    /// Count pending package updates and new vg releases in the background
+    pub enabled: bool,
+    /// How often to check (hours). Default: 24
+    pub interval_hours: u64,
+}
+
+impl Default for UpdateCheckConfig {
+    fn default() -> Self {
+        Self { enabled: true, interval_hours: 24 }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct ToolchainConfig {
+    /// Installer for `vg toolchain update node`: "auto", "fnm", "volta" or "package"
+    pub node: String,
+    /// Installer for `vg toolchain update python`: "auto", "pyenv", "uv" or "package"
+    pub python: String,
+}
+
+impl Default for ToolchainConfig {
+    fn default() -> Self {
+        Self { node: "auto".into(), python: "auto".into() }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SyncConfig {
+    /// "git" or "rclone"; empty disables `vg sync`
+    pub backend: String,
+    /// Git URL, or an rclone remote path like `webdav:genesis`
+    pub remote: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct TimeoutsConfig {
+    /// Seconds a process vg reads output from may run (update lists, searches, probes); 0 = no limit
+    pub query: u64,
+    /// Seconds a non-interactive package operation behind a spinner may run; 0 = no limit
+    pub task: u64,
+}
+
+impl Default for TimeoutsConfig {
+    fn default() -> Self {
+        Self { query: 300, task: 3600 }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct NotifyConfig {
+    /// Commands that notify when they finish (after `min_seconds`), e.g. "update", "index"
+    pub commands: Vec<String>,
+    /// Commands finishing sooner than this don't notify
+    pub min_seconds: u64,
+    /// Desktop notification (notify-send, or Notification Center on macOS)
+    pub desktop: bool,
+    /// Ring the terminal bell
+    pub bell: bool,
+    /// ntfy topic URL to push to as well, e.g. https://ntfy.sh/<topic>; empty = off
+    pub ntfy: String,
+    /// Commands whose results go to ntfy and the webhook every run: health, update, data
+    pub reports: Vec<String>,
+    /// Slack or Discord incoming-webhook URL, or any URL taking a JSON POST; empty = off
+    pub webhook: String,
+    /// JSON body for the webhook with {title}, {body}, {host}, {command} and {status}; empty = suited to the URL
+    pub template: String,
+}
+
+impl Default for NotifyConfig {
+    fn default() -> Self {
+        Self {
+            commands: vec!["update".to_string()],
+            min_seconds: 60,
+            desktop: true,
+            bell: true,
+            ntfy: String::new(),
+            reports: Vec::new(),
+            webhook: String::new(),
+            template: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FleetConfig {
+    /// Machines `vg fleet status` checks over SSH: user@server or aliases from ~/.ssh/config
+    pub hosts: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct DashConfig {
+    /// Tiles shown by `vg dash`, two per row in this order: system, updates, reminders, track, search
+    pub widgets: Vec<String>,
+    /// Seconds between refreshes
+    pub refresh_secs: u64,
+}
+
+impl Default for DashConfig {
+    fn default() -> Self {
+        Self {
+            widgets: crate::commands::dash::WIDGETS.iter().map(|w| w.to_string()).collect(),
+            refresh_secs: 2,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Pre-commit commands per language ("rust", "node", "python", "go").
+    /// A language listed here replaces its built-in commands; an empty list disables them.
+    pub commands: std::collections::BTreeMap<String, Vec<String>>,
+    /// Commands run in every project, after the language ones
+    pub extra: Vec<String>,
+    /// File name globs that must never be committed (patterns with '/' match the full path)
+    pub forbidden: Vec<String>,
+    /// Scripts run around vg commands, keyed `pre_<command>` / `post_<command>` (e.g. `pre_update`)
+    pub scripts: std::collections::BTreeMap<String, String>,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            commands: Default::default(),
+            extra: vec![],
+            forbidden: [".env", ".env.local", "*.pem", "*.key", "*.p12", "*.pfx", "id_rsa", "id_ecdsa", "id_ed25519"]
+                .iter().map(|s| s.to_string()).collect(),
+            scripts: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct WeatherConfig {
+    /// City used by `vg weather` and the greeting when none is given
+    pub location: String,
+    /// "metric" (°C, km/h) or "imperial" (°F, mph)
+    pub units: String,
+}
+
+impl Default for WeatherConfig {
+    fn default() -> Self {
+        Self { location: String::new(), units: "metric".into() }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct GreetConfig {
+    /// Show system uptime
+    pub show_uptime: bool,
+    /// Show the number of pending package updates (queries every package manager)
+    pub show_updates: bool,
+    /// Warn about disks above disk_warn_percent usage
+    pub show_disks: bool,
+    /// Show a random vg tip
+    pub show_tip: bool,
+    /// Show current weather for weather.location (needs network)
+    pub show_weather: bool,
+    /// Usage percentage at which a disk is reported. Default: 90
+    pub disk_warn_percent: u8,
+}
+
+impl Default for GreetConfig {
+    fn default() -> Self {
+        Self {
+            show_uptime: true,
+            show_updates: true,
+            show_disks: true,
+            show_tip: true,
+            show_weather: false,
+            disk_warn_percent: 90,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct SecretsConfig {
+    /// Where API keys are stored: "auto" (keyring, else file), "keyring" or "file"
+    pub backend: String,
+}
+
+impl Default for SecretsConfig {
+    fn default() -> Self {
+        Self { backend: "auto".into() }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct SelfUpdateConfig {
+    /// Release channel to follow: "stable" or "nightly" (includes prereleases)
+    pub channel: String,
+}
+
+impl Default for SelfUpdateConfig {
+    fn default() -> Self {
+        Self { channel: "stable".into() }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -71,6 +360,8 @@ pub struct SearchConfig {
     pub exclude_hidden: bool,
     pub lightspeed_mode: bool,
     pub fuzzy_threshold: usize,
+    /// Follow symlinks into files and directories when indexing user paths
+    pub follow_links: bool,
 }
 
 impl Default for SearchConfig {
@@ -98,6 +389,7 @@ impl Default for SearchConfig {
             exclude_hidden: true,
             lightspeed_mode: true,
             fuzzy_threshold: 2,
+            follow_links: false,
         }
     }
 }
@@ -107,13 +399,22 @@ impl Default for SearchConfig {
 pub struct SystemConfig {
     pub package_manager_priority: Vec<String>,
     pub auto_confirm_update: bool,
+    /// Refresh distro mirrors (reflector, apt ranking, dnf fastestmirror) before `vg update`
+    pub update_mirrors: bool,
+    /// Language code from `LANGUAGES` for greetings; everything else is English for now
+    pub language: String,
 }
 
+/// Languages vg can greet in: code and name shown in onboarding.
+pub const LANGUAGES: &[(&str, &str)] = &[("en", "English"), ("de", "Deutsch")];
+
 impl Default for SystemConfig {
     fn default() -> Self {
         Self {
             package_manager_priority: vec!["pamac".into(), "yay".into(), "paru".into(), "pacman".into()],
             auto_confirm_update: false,
+            update_mirrors: false,
+            language: "en".into(),
         }
     }
 }
@@ -142,11 +443,26 @@ impl Default for AnalyticsConfig {
 pub struct ConfigManager {
     config_path: PathBuf,
     pub config: Config,
+    /// True until first-run onboarding has been completed (drives onboarding)
+    pub first_run: bool,
+    /// What loading changed in the file: migrations applied and unknown keys dropped
+    pub upgrade_notes: Vec<String>,
+    /// Why the file on disk could not be used; it is left untouched and defaults apply
+    pub load_error: Option<String>,
+}
+
+/// Result of reading the config file.
+struct Loaded {
+    config: Config,
+    notes: Vec<String>,
+    error: Option<String>,
 }
 
 impl ConfigManager {
     pub fn new() -> Self {
-        let (config_path, mut config) = Self::load_or_default();
+        let (config_path, loaded) = Self::load_or_default();
+        let Loaded { mut config, notes, error } = loaded;
+        let new_file = !config_path.exists();
         // Auto-generate client_id if missing
         if config.analytics.client_id.is_empty() {
             config.analytics.client_id = Self::generate_client_id();
@@ -154,9 +470,30 @@ impl ConfigManager {
         // Always save after loading: existing values are preserved by serde,
         // and any new fields added in a version upgrade get written with their
         // defaults — so the on-disk config stays complete after every update.
-        let mgr = ConfigManager { config_path: config_path.clone(), config: config.clone() };
-        let _ = mgr.save();
-        Self { config_path, config }
+        // A file we could not read is never overwritten with defaults. A first run saves the
+        // defaults (and its client id) too; a marker keeps onboarding pending until it's done,
+        // so a cancelled or non-interactive first run is offered the setup again.
+        let mgr = ConfigManager {
+            config_path,
+            config,
+            first_run: error.is_none() && (new_file || Self::onboarding_marker().exists()),
+            upgrade_notes: notes,
+            load_error: error,
+        };
+        if mgr.load_error.is_none() && mgr.save().is_ok() && new_file {
+            let marker = Self::onboarding_marker();
+            if let Some(parent) = marker.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            let _ = fs::write(&marker, "");
+        }
+        mgr
+    }
+
+    /// The config as it is on disk, without saving it or generating a client id.
+    /// Used before argument parsing, where `new()` would mark the first run as done.
+    pub fn peek() -> Config {
+        Self::load_or_default().1.config
     }
 
     fn generate_client_id() -> String {
@@ -170,24 +507,80 @@ impl ConfigManager {
         hex::encode(&result[..8])
     }
 
-    fn load_or_default() -> (PathBuf, Config) {
+    fn load_or_default() -> (PathBuf, Loaded) {
         let config_dir = if let Some(proj_dirs) = ProjectDirs::from("", "volantic", "genesis") {
             proj_dirs.config_dir().to_path_buf()
         } else {
             dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")).join(".config").join("volantic-genesis")
         };
         let config_path = config_dir.join("config.toml");
-        if config_path.exists() {
-            if let Ok(content) = fs::read_to_string(&config_path) {
-                if let Ok(config) = toml::from_str(&content) {
-                    return (config_path, config);
-                }
+        let loaded = if config_path.exists() {
+            match fs::read_to_string(&config_path).map_err(anyhow::Error::from).and_then(|c| Self::parse(&c)) {
+                Ok(loaded) => loaded,
+                Err(e) => Loaded {
+                    config: Config { config_version: CONFIG_VERSION, ..Config::default() },
+                    notes: Vec::new(),
+                    error: Some(format!("{:#}", e)),
+                },
+            }
+        } else {
+            Loaded { config: Config { config_version: CONFIG_VERSION, ..Config::default() }, notes: Vec::new(), error: None }
+        };
+        (config_path, loaded)
+    }
+
+    /// Parse a config file, bringing older schemas up to `CONFIG_VERSION`.
+    fn parse(content: &str) -> Result<Loaded> {
+        let mut raw: toml::Table = toml::from_str(content).context("Invalid TOML")?;
+        let version = raw.get("config_version").and_then(toml::Value::as_integer).unwrap_or(0);
+        let version = u32::try_from(version).context("Invalid config_version")?;
+        if version > CONFIG_VERSION {
+            return Err(anyhow::anyhow!(
+                "written by a newer vg (config version {}, this vg knows {}) — update vg", version, CONFIG_VERSION,
+            ));
+        }
+
+        let mut notes = Vec::new();
+        for migration in MIGRATIONS.iter().filter(|m| m.to > version) {
+            notes.extend((migration.apply)(&mut raw));
+        }
+        if version >= 2 {
+            notes.extend(move_hook_scripts(&mut raw));
+        }
+        raw.insert("config_version".into(), toml::Value::Integer(CONFIG_VERSION.into()));
+        let config: Config = toml::Value::Table(raw.clone()).try_into()?;
+
+        // Keys serde skipped would vanish on the next save; say so instead of dropping them silently
+        if let Ok(kept) = toml::Table::try_from(&config) {
+            Self::dropped_keys(&raw, &kept, "", &mut notes);
+        }
+        if version < CONFIG_VERSION {
+            notes.insert(0, format!("Upgraded from config version {} to {}", version, CONFIG_VERSION));
+        }
+        Ok(Loaded { config, notes, error: None })
+    }
+
+    fn dropped_keys(raw: &toml::Table, kept: &toml::Table, prefix: &str, notes: &mut Vec<String>) {
+        for (key, value) in raw {
+            let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+            match (value, kept.get(key)) {
+                (_, None) => notes.push(format!("Removed unknown key {}", path)),
+                (toml::Value::Table(raw), Some(toml::Value::Table(kept))) => Self::dropped_keys(raw, kept, &path, notes),
+                _ => {}
             }
         }
-        (config_path, Config::default())
+    }
+
+    /// Fails while the file on disk couldn't be read, so defaults never replace it.
+    pub fn ensure_writable(&self) -> Result<()> {
+        match &self.load_error {
+            Some(e) => Err(anyhow::anyhow!("{} could not be read ({}) — fix it with vg config edit first", self.config_path.display(), e)),
+            None => Ok(()),
+        }
     }
 
     pub fn save(&self) -> Result<()> {
+        self.ensure_writable()?;
         if let Some(parent) = self.config_path.parent() {
             fs::create_dir_all(parent).context("Failed to create config directory")?;
         }
@@ -200,6 +593,46 @@ impl ConfigManager {
         &self.config_path
     }
 
+    /// Plaintext fallback for secrets when no OS keyring is available.
+    fn secrets_path(&self) -> PathBuf {
+        self.config_path.with_file_name("secrets.toml")
+    }
+
+    /// Read an API key or token. An env var named `NAME` (upper-cased) overrides stored values.
+    pub fn get_secret(&self, name: &str) -> Option<String> {
+        crate::secrets::get(name, &self.config.secrets.backend, &self.secrets_path())
+    }
+
+    pub fn set_secret(&self, name: &str, value: &str) -> Result<crate::secrets::Store> {
+        crate::secrets::set(name, value, &self.config.secrets.backend, &self.secrets_path())
+    }
+
+    pub fn delete_secret(&self, name: &str) -> Result<bool> {
+        crate::secrets::delete(name, &self.config.secrets.backend, &self.secrets_path())
+    }
+
+    /// Directory for vg's own data files (search index, jump history, snippets, …).
+    /// Present while first-run onboarding hasn't been completed.
+    fn onboarding_marker() -> PathBuf {
+        Self::data_dir().join("onboarding_pending")
+    }
+
+    /// Onboarding is done (or a restored backup made it unnecessary): stop offering it.
+    pub fn finish_onboarding(&mut self) {
+        let _ = fs::remove_file(Self::onboarding_marker());
+        self.first_run = false;
+    }
+
+    pub fn data_dir() -> PathBuf {
+        if let Some(proj) = ProjectDirs::from("", "volantic", "genesis") {
+            proj.data_dir().to_path_buf()
+        } else {
+            dirs::home_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join(".local").join("share").join("volantic-genesis")
+        }
+    }
+
     /// Path to the auto-index timestamp file.
     pub fn auto_index_stamp_path() -> PathBuf {
         let base = if let Some(proj) = ProjectDirs::from("", "volantic", "genesis") {
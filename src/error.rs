@@ -0,0 +1,110 @@
+// src/error.rs
+// Errors vg reports to the user, each with a stable code and a hint on what to do next.
+// Commands keep returning `anyhow::Result`; the cases below are raised where they happen
+// (`Err(Error::IndexMissing.into())`) and `report` finds them again anywhere in the chain.
+// Errors from other crates are classified by kind, so timeouts or a missing network get
+// a code and a hint too.
+
+use colored::Colorize;
+use serde::Serialize;
+use std::io;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No search index yet")]
+    IndexMissing,
+    #[error("{bin} not found ({used_by})")]
+    ToolMissing { bin: String, used_by: String, package: String },
+    #[error("Cannot prompt (not a terminal, or --non-interactive)")]
+    NotATerminal {
+        /// What to pass instead of answering the prompt
+        instead: String,
+    },
+}
+
+impl Error {
+    pub fn not_a_terminal(instead: &str) -> Self {
+        Error::NotATerminal { instead: instead.to_string() }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Error::IndexMissing => "index-missing",
+            Error::ToolMissing { .. } => "tool-missing",
+            Error::NotATerminal { .. } => "not-a-terminal",
+        }
+    }
+
+    fn hint(&self) -> String {
+        match self {
+            Error::IndexMissing => "Run vg index first".to_string(),
+            Error::ToolMissing { package, .. } => format!("Install it with: vg install {}", package),
+            Error::NotATerminal { instead } => format!("Run it in a terminal, or {}", instead),
+        }
+    }
+}
+
+/// An error as shown to the user.
+#[derive(Serialize)]
+pub struct Report {
+    /// Stable across releases; safe to match on in scripts and to quote in bug reports
+    pub code: &'static str,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+impl Report {
+    fn exit_code(&self) -> i32 {
+        match self.code {
+            "timeout" => crate::exit::TIMEOUT,
+            "interrupted" => crate::exit::INTERRUPTED,
+            _ => crate::exit::ERROR,
+        }
+    }
+}
+
+/// Classify `err` by the first cause vg knows about.
+pub fn describe(err: &anyhow::Error) -> Report {
+    let message = format!("{:#}", err);
+    for cause in err.chain() {
+        if let Some(e) = cause.downcast_ref::<Error>() {
+            return Report { code: e.code(), message, hint: Some(e.hint()) };
+        }
+        if let Some(e) = cause.downcast_ref::<inquire::InquireError>() {
+            if matches!(e, inquire::InquireError::NotTTY) {
+                return Report { code: "not-a-terminal", message, hint: Some("Run it in a terminal, or pass the answers as flags (see --help)".to_string()) };
+            }
+        }
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            let hint = if e.is_timeout() { "The server did not answer in time — try again later" } else { "Check your network connection" };
+            return Report { code: "network", message, hint: Some(hint.to_string()) };
+        }
+        if let Some(e) = cause.downcast_ref::<io::Error>() {
+            let (code, hint) = match e.kind() {
+                io::ErrorKind::TimedOut => ("timeout", None),
+                io::ErrorKind::Interrupted => ("interrupted", None),
+                io::ErrorKind::PermissionDenied => ("permission-denied", Some("Check the file's owner and mode, or run the command with sudo")),
+                io::ErrorKind::NotFound => ("not-found", None),
+                _ => continue,
+            };
+            return Report { code, message, hint: hint.map(str::to_string) };
+        }
+    }
+    Report { code: "error", message, hint: None }
+}
+
+/// Print `err` to stderr, as a JSON object when `json` is set; returns the exit code for it.
+pub fn report(err: &anyhow::Error, json: bool) -> i32 {
+    let report = describe(err);
+    if json {
+        let value = serde_json::json!({ "error": report });
+        eprintln!("{}", serde_json::to_string_pretty(&value).unwrap_or_default());
+        return report.exit_code();
+    }
+    eprintln!("  {} {}", "✗".truecolor(239, 68, 68).bold(), report.message.truecolor(239, 68, 68));
+    if let Some(hint) = &report.hint {
+        eprintln!("  {} {}", "·".truecolor(71, 85, 105), hint.truecolor(71, 85, 105));
+    }
+    eprintln!("  {} {}", "·".truecolor(71, 85, 105), format!("error code: {}", report.code).truecolor(71, 85, 105));
+    report.exit_code()
+}
@@ -0,0 +1,40 @@
+// src/exit.rs
+// Exit codes, so vg can be used in shell conditionals.
+// Commands report how things went with `set`; the worst code set during a run is what vg
+// exits with. Errors map to codes in `error::report`.
+
+use std::sync::atomic::{AtomicI32, Ordering};
+
+/// Success: something was found, nothing needs attention
+pub const OK: i32 = 0;
+/// The command worked and the answer is "no": nothing found, or warnings reported
+pub const NEGATIVE: i32 = 1;
+/// vg could not do what was asked: an error, a missing index or tool, or a usage error
+pub const ERROR: i32 = 2;
+/// A check found critical problems (`vg health`)
+pub const CRITICAL: i32 = 3;
+/// An external program ran past its timeout
+pub const TIMEOUT: i32 = 124;
+/// Stopped with Ctrl-C (128 + SIGINT)
+pub const INTERRUPTED: i32 = 130;
+
+/// Shown under `vg --help` and the commands with their own codes.
+pub const CONTRACT: &str = "\
+Exit codes:
+  0    success — found, healthy, nothing to report
+  1    nothing found (search, grep) or warnings (health, doctor, self check, perms audit, notify test)
+  2    error — including a missing index or tool, and command-line usage errors
+  3    critical problems (health)
+  124  an external program timed out
+  130  interrupted with Ctrl-C";
+
+static CODE: AtomicI32 = AtomicI32::new(OK);
+
+/// Raise the exit code for this run to `code`; a lower code never replaces a higher one.
+pub fn set(code: i32) {
+    CODE.fetch_max(code, Ordering::SeqCst);
+}
+
+pub fn code() -> i32 {
+    CODE.load(Ordering::SeqCst)
+}
@@ -0,0 +1,286 @@
+// src/history.rs
+// Undo journal for commands that change many files at once (replace, rename-batch, tidy).
+use crate::config::ConfigManager;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Older operations (and their backups) are dropped past this.
+const MAX_OPERATIONS: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Change {
+    /// Content rewritten in place; `backup` holds the original bytes, `sha256` what we wrote
+    Edit { path: PathBuf, backup: PathBuf, sha256: String },
+    Rename { from: PathBuf, to: PathBuf },
+    /// File deleted; its bytes were moved to `backup`
+    RemoveFile { path: PathBuf, backup: PathBuf },
+    RemoveDir { path: PathBuf },
+    RemoveLink { path: PathBuf, target: PathBuf },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operation {
+    pub id: String,
+    pub command: String,
+    pub summary: String,
+    pub timestamp: DateTime<Utc>,
+    pub changes: Vec<Change>,
+    /// Stopped with Ctrl-C; `changes` holds what was done until then
+    #[serde(default)]
+    pub interrupted: bool,
+    /// Interrupted and not yet pointed out at the start of a later run
+    #[serde(default)]
+    pub unnoticed: bool,
+}
+
+fn history_dir() -> PathBuf {
+    ConfigManager::data_dir().join("history")
+}
+
+fn index_path() -> PathBuf {
+    history_dir().join("index.json")
+}
+
+pub fn load() -> Result<Vec<Operation>> {
+    let path = index_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn save(ops: &[Operation]) -> Result<()> {
+    std::fs::create_dir_all(history_dir())?;
+    std::fs::write(index_path(), serde_json::to_string_pretty(ops)?)?;
+    Ok(())
+}
+
+pub fn sha256(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Collects the changes of one command run; nothing is journaled until `finish`.
+/// While it lives Ctrl-C is deferred: the next change fails instead, and dropping the
+/// recorder journals the changes made so far so `vg undo` can revert them.
+pub struct Recorder {
+    op: Operation,
+    dir: PathBuf,
+    _defer: crate::process::Running,
+}
+
+impl Recorder {
+    pub fn new(command: &str, summary: &str) -> Self {
+        let now = Utc::now();
+        let id = now.format("%Y%m%d-%H%M%S%3f").to_string();
+        let dir = history_dir().join(&id);
+        Self {
+            op: Operation {
+                id, command: command.to_string(), summary: summary.to_string(), timestamp: now, changes: Vec::new(),
+                interrupted: false, unnoticed: false,
+            },
+            dir,
+            _defer: crate::process::Running::start(),
+        }
+    }
+
+    fn check(&self) -> Result<()> {
+        if crate::process::interrupted() {
+            return Err(anyhow!("Interrupted after {} changes — vg undo reverts them", self.op.changes.len()));
+        }
+        Ok(())
+    }
+
+    /// Back up `path`, then overwrite it with `content`.
+    pub fn write(&mut self, path: &Path, content: &[u8]) -> Result<()> {
+        self.check()?;
+        std::fs::create_dir_all(&self.dir)?;
+        let backup = self.dir.join(format!("{}.bak", self.op.changes.len()));
+        std::fs::copy(path, &backup).with_context(|| format!("Failed to back up {}", path.display()))?;
+        std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+        self.op.changes.push(Change::Edit {
+            path: path.canonicalize().unwrap_or_else(|_| path.to_path_buf()),
+            backup,
+            sha256: sha256(content),
+        });
+        Ok(())
+    }
+
+    pub fn rename(&mut self, from: &Path, to: &Path) -> Result<()> {
+        self.check()?;
+        std::fs::rename(from, to).with_context(|| format!("Failed to rename {} → {}", from.display(), to.display()))?;
+        let abs = |p: &Path| std::path::absolute(p).unwrap_or_else(|_| p.to_path_buf());
+        self.op.changes.push(Change::Rename { from: abs(from), to: abs(to) });
+        Ok(())
+    }
+
+    /// Delete a file, keeping its bytes in the journal.
+    pub fn remove_file(&mut self, path: &Path) -> Result<()> {
+        self.check()?;
+        std::fs::create_dir_all(&self.dir)?;
+        let backup = self.dir.join(format!("{}.bak", self.op.changes.len()));
+        // Renaming is free on the same filesystem; otherwise copy, then delete
+        if std::fs::rename(path, &backup).is_err() {
+            std::fs::copy(path, &backup).with_context(|| format!("Failed to back up {}", path.display()))?;
+            std::fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+        let abs = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        self.op.changes.push(Change::RemoveFile { path: abs, backup });
+        Ok(())
+    }
+
+    /// Delete an empty directory.
+    pub fn remove_dir(&mut self, path: &Path) -> Result<()> {
+        self.check()?;
+        std::fs::remove_dir(path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        let abs = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        self.op.changes.push(Change::RemoveDir { path: abs });
+        Ok(())
+    }
+
+    /// Delete a symlink, remembering where it pointed.
+    pub fn remove_link(&mut self, path: &Path) -> Result<()> {
+        self.check()?;
+        let target = std::fs::read_link(path).with_context(|| format!("Failed to read link {}", path.display()))?;
+        std::fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        let abs = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        self.op.changes.push(Change::RemoveLink { path: abs, target });
+        Ok(())
+    }
+
+    /// Append the operation to the journal; returns its id.
+    pub fn finish(mut self) -> Result<Option<String>> {
+        self.journal()
+    }
+
+    /// Mark the operation as stopped early, so the next run points it out.
+    fn mark_stopped(&mut self) {
+        if !self.op.interrupted {
+            self.op.interrupted = true;
+            self.op.unnoticed = true;
+            self.op.summary = format!("{} (stopped after {} changes)", self.op.summary, self.op.changes.len());
+        }
+    }
+
+    fn journal(&mut self) -> Result<Option<String>> {
+        if self.op.changes.is_empty() {
+            return Ok(None);
+        }
+        if crate::process::interrupted() {
+            self.mark_stopped();
+        }
+        let mut ops = load()?;
+        let id = self.op.id.clone();
+        // Taking the changes leaves nothing for Drop to journal again
+        ops.push(Operation { changes: std::mem::take(&mut self.op.changes), ..self.op.clone() });
+        while ops.len() > MAX_OPERATIONS {
+            let old = ops.remove(0);
+            let _ = std::fs::remove_dir_all(history_dir().join(old.id));
+        }
+        save(&ops)?;
+        Ok(Some(id))
+    }
+}
+
+impl Drop for Recorder {
+    /// Dropped without `finish`: the command stopped early (Ctrl-C or an error) after changing files.
+    fn drop(&mut self) {
+        if self.op.changes.is_empty() {
+            return;
+        }
+        self.mark_stopped();
+        if let Err(e) = self.journal() {
+            eprintln!("Could not journal the partial {}: {}", self.op.command, e);
+        }
+    }
+}
+
+/// The newest operation that stopped early and hasn't been pointed out yet; marks it as noticed.
+pub fn take_unnoticed() -> Option<Operation> {
+    let mut ops = load().ok()?;
+    let op = ops.iter_mut().rev().find(|o| o.unnoticed)?;
+    op.unnoticed = false;
+    let found = op.clone();
+    save(&ops).ok()?;
+    Some(found)
+}
+
+/// Outcome of reverting one change.
+pub enum Reverted {
+    Done(String),
+    Skipped(String),
+}
+
+/// Remove an operation from the journal and revert it, newest change first.
+/// Files that were modified since are left alone and reported as skipped.
+pub fn undo(id: Option<&str>) -> Result<(Operation, Vec<Reverted>)> {
+    let mut ops = load()?;
+    let idx = match id {
+        Some(id) => ops.iter().position(|o| o.id == id).ok_or_else(|| anyhow!("No operation with id '{}'", id))?,
+        None => ops.len().checked_sub(1).ok_or_else(|| anyhow!("Nothing to undo"))?,
+    };
+    let op = ops.remove(idx);
+
+    let mut results = Vec::new();
+    for change in op.changes.iter().rev() {
+        results.push(match change {
+            Change::Edit { path, backup, sha256: written } => {
+                let current = std::fs::read(path).map(|b| sha256(&b)).unwrap_or_default();
+                if &current != written {
+                    Reverted::Skipped(format!("{} changed since — left as is", path.display()))
+                } else {
+                    std::fs::copy(backup, path).with_context(|| format!("Failed to restore {}", path.display()))?;
+                    Reverted::Done(format!("Restored {}", path.display()))
+                }
+            }
+            Change::Rename { from, to } => {
+                if !to.exists() || from.exists() {
+                    Reverted::Skipped(format!("{} → {} can't be reversed — one side was moved", to.display(), from.display()))
+                } else {
+                    std::fs::rename(to, from).with_context(|| format!("Failed to rename {} back", to.display()))?;
+                    Reverted::Done(format!("{} → {}", to.display(), from.display()))
+                }
+            }
+            Change::RemoveFile { path, backup } => {
+                if path.symlink_metadata().is_ok() {
+                    Reverted::Skipped(format!("{} exists again — left as is", path.display()))
+                } else {
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::copy(backup, path).with_context(|| format!("Failed to restore {}", path.display()))?;
+                    Reverted::Done(format!("Restored {}", path.display()))
+                }
+            }
+            Change::RemoveDir { path } => {
+                std::fs::create_dir_all(path).with_context(|| format!("Failed to recreate {}", path.display()))?;
+                Reverted::Done(format!("Recreated {}", path.display()))
+            }
+            Change::RemoveLink { path, target } => {
+                if path.symlink_metadata().is_ok() {
+                    Reverted::Skipped(format!("{} exists again — left as is", path.display()))
+                } else {
+                    restore_link(target, path)?;
+                    Reverted::Done(format!("Relinked {} → {}", path.display(), target.display()))
+                }
+            }
+        });
+    }
+
+    save(&ops)?;
+    let _ = std::fs::remove_dir_all(history_dir().join(&op.id));
+    Ok((op, results))
+}
+
+#[cfg(unix)]
+fn restore_link(target: &Path, path: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(target, path).with_context(|| format!("Failed to relink {}", path.display()))
+}
+
+#[cfg(windows)]
+fn restore_link(target: &Path, path: &Path) -> Result<()> {
+    std::os::windows::fs::symlink_file(target, path).with_context(|| format!("Failed to relink {}", path.display()))
+}
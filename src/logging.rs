@@ -0,0 +1,119 @@
+// src/logging.rs
+// Debug log for `vg --debug …`: the command, every process it spawned and how they exited.
+// Spawning goes through crate::process for time limits and Ctrl-C handling.
+use crate::config::ConfigManager;
+use crate::process::{Class, Running};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Older log files are deleted past this.
+const MAX_LOGS: usize = 20;
+
+pub fn logs_dir() -> PathBuf {
+    ConfigManager::data_dir().join("logs")
+}
+
+/// Start writing debug logs to a new file in the logs dir. Returns its path.
+pub fn init() -> Option<PathBuf> {
+    let dir = logs_dir();
+    std::fs::create_dir_all(&dir).ok()?;
+    prune(&dir);
+    let path = dir.join(format!("vg-{}-{}.log", chrono::Local::now().format("%Y%m%d-%H%M%S"), std::process::id()));
+    let file = std::fs::File::create(&path).ok()?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_max_level(tracing::Level::DEBUG)
+        .try_init()
+        .ok()?;
+    Some(path)
+}
+
+fn prune(dir: &Path) {
+    let mut logs: Vec<PathBuf> = std::fs::read_dir(dir).into_iter().flatten().flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "log"))
+        .collect();
+    // Names embed the timestamp, so lexical order is age order
+    logs.sort();
+    let excess = (logs.len() + 1).saturating_sub(MAX_LOGS);
+    for old in logs.into_iter().take(excess) {
+        let _ = std::fs::remove_file(old);
+    }
+}
+
+fn describe(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|a| a.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn record(cmd: &str, started: Instant, status: Result<&ExitStatus, &std::io::Error>) {
+    let ms = started.elapsed().as_millis() as u64;
+    match status {
+        Ok(s) => tracing::debug!(command = cmd, exit = ?s.code(), ms, "process finished"),
+        Err(e) => tracing::debug!(command = cmd, error = %e, ms, "process failed to start"),
+    }
+}
+
+/// `cmd.status()`, logged. Interactive, so there is no time limit; Ctrl-C becomes an error.
+pub fn status(cmd: &mut Command) -> std::io::Result<ExitStatus> {
+    let line = describe(cmd);
+    tracing::debug!(command = %line, "spawn");
+    let started = Instant::now();
+    let running = Running::start();
+    let res = cmd.status();
+    record(&line, started, res.as_ref());
+    running.check()?;
+    res
+}
+
+/// `cmd.output()` within the limit for `class`.
+fn capture(cmd: &mut Command, line: &str, class: Class) -> std::io::Result<Output> {
+    let running = Running::start();
+    let mut child = cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    // Read both pipes on the side so a chatty process can't block on a full one
+    fn drain(pipe: Option<impl Read + Send + 'static>) -> std::thread::JoinHandle<Vec<u8>> {
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            buf
+        })
+    }
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let status = crate::process::wait(&mut child, class, line)?;
+    running.check()?;
+    Ok(Output { status, stdout: stdout.join().unwrap_or_default(), stderr: stderr.join().unwrap_or_default() })
+}
+
+/// `cmd.output()` within `timeouts.query`, logged with the first line of stderr on failure.
+pub fn output(cmd: &mut Command) -> std::io::Result<Output> {
+    output_within(cmd, Class::Query)
+}
+
+/// Like `output`, within `timeouts.task`: longer work whose output vg still reads (e.g. a prune summary).
+pub fn task_output(cmd: &mut Command) -> std::io::Result<Output> {
+    output_within(cmd, Class::Task)
+}
+
+fn output_within(cmd: &mut Command, class: Class) -> std::io::Result<Output> {
+    let line = describe(cmd);
+    tracing::debug!(command = %line, "spawn");
+    let started = Instant::now();
+    let res = capture(cmd, &line, class);
+    record(&line, started, res.as_ref().map(|o| &o.status));
+    if let Ok(o) = &res {
+        if !o.status.success() {
+            let err = String::from_utf8_lossy(&o.stderr);
+            tracing::debug!(stderr = err.lines().next().unwrap_or(""), "process stderr");
+        }
+    }
+    res
+}
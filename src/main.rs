@@ -6,13 +6,34 @@ mod config;
 mod package_managers;
 mod commands;
 mod analytics;
+mod secrets;
+mod history;
+mod logging;
+mod aliases;
+mod tools;
+mod process;
+mod progress;
+mod walk;
+mod error;
+mod exit;
+mod notify;
 
 #[derive(Parser, Debug)]
 #[command(name = "vg")]
 #[command(author = "Volantic")]
 #[command(version = "3.8.3")]
 #[command(about = "Volantic Genesis — Fast, focused system CLI")]
+#[command(after_help = exit::CONTRACT)]
 struct Cli {
+    /// Write a detailed log (commands run, processes spawned, exit codes) to the data directory
+    #[arg(long, global = true)]
+    debug: bool,
+    /// Never prompt: use defaults or fail with what to pass instead (also when stdin isn't a terminal or CI is set)
+    #[arg(long, global = true)]
+    non_interactive: bool,
+    /// Run the command on another machine over SSH (user@server or a ~/.ssh/config alias); read-only commands only
+    #[arg(long, global = true, value_name = "HOST")]
+    host: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -21,20 +42,34 @@ struct Cli {
 enum Commands {
     /// Update all package managers
     Update {
+        #[command(subcommand)]
+        action: Option<UpdateAction>,
         #[arg(short, long)]
         yes: bool,
+        /// Also install firmware updates through fwupd (Linux)
+        #[arg(long)]
+        firmware: bool,
     },
     /// Search and install a package interactively
     Install {
         pkg: String,
         #[arg(short, long)]
         yes: bool,
+        /// Arch: search repos and AUR and pick any number of results (votes, versions, descriptions)
+        #[arg(long)]
+        pick: bool,
     },
     /// Uninstall a package
     Uninstall {
         pkg: String,
     },
+    /// Package maintenance across all package managers
+    Pkg {
+        #[command(subcommand)]
+        action: PkgAction,
+    },
     /// Lightning-fast file search (SQLite FTS5 + interactive TUI)
+    #[command(after_help = "Exit codes: 0 results found, 1 no results, 2 no index (run vg index) or another error")]
     Search {
         /// Search query (omit to launch interactive TUI)
         query: Option<String>,
@@ -44,6 +79,9 @@ enum Commands {
         /// Limit results to paths starting with this prefix
         #[arg(short = 'p', long)]
         path: Option<String>,
+        /// Filter by detected content type(s), comma-separated (e.g. image/*,application/pdf)
+        #[arg(short = 'm', long)]
+        mime: Option<String>,
         /// Maximum number of results
         #[arg(short = 'l', long)]
         limit: Option<usize>,
@@ -56,36 +94,73 @@ enum Commands {
         /// Search all indexed scopes including system files (default: user files only)
         #[arg(short = 'a', long)]
         all: bool,
+        /// Print results for scripts and launchers instead of the list
+        #[arg(short = 'f', long, value_enum)]
+        format: Option<commands::search::Format>,
     },
     /// Build or show file search index
     Index {
+        /// Show index size, stale entries, file types and recent builds
         #[arg(short, long)]
         info: bool,
+        /// Remove entries whose files no longer exist
+        #[arg(long)]
+        verify: bool,
         #[arg(short, long)]
         paths: Vec<String>,
+        /// Follow symlinks this run (see search.follow_links)
+        #[arg(long)]
+        follow_links: bool,
         /// Run silently as a background job (used internally by auto-index)
         #[arg(long, hide = true)]
         background: bool,
     },
     /// Daily greeting
     Greet,
+    /// Current weather and a 3-day forecast
+    Weather {
+        /// City (defaults to config weather.location)
+        city: Option<String>,
+    },
+    /// Home screen: system load, pending updates, today's reminders and tracked time, quick search
+    Dash,
     /// System health report
-    Health,
+    #[command(after_help = "Exit codes: 0 healthy, 1 warnings (pending updates or reboot, a disk over 90% full), 3 critical (failed units, a disk over 98% full), 2 error")]
+    Health {
+        /// Print a machine-readable snapshot instead (pending updates from the last background check)
+        #[arg(long)]
+        json: bool,
+    },
     /// System information
-    Info,
+    Info {
+        /// Print machine-readable JSON
+        #[arg(long, conflicts_with = "fetch")]
+        json: bool,
+        /// neofetch-style banner with the OS logo
+        #[arg(long)]
+        fetch: bool,
+    },
     /// Update Volantic Genesis itself
     #[command(name = "self-update")]
-    SelfUpdate,
+    SelfUpdate {
+        /// Release channel (overrides config self_update.channel)
+        #[arg(short = 'c', long, value_enum)]
+        channel: Option<commands::self_update::Channel>,
+    },
     /// Wait until a new release is available, then install it automatically
     #[command(name = "expect-update")]
     ExpectUpdate {
         /// Polling interval in seconds (overrides config expect_update.interval_secs)
         #[arg(short = 'i', long)]
         interval: Option<u64>,
+        /// Release channel (overrides config self_update.channel)
+        #[arg(short = 'c', long, value_enum)]
+        channel: Option<commands::self_update::Channel>,
     },
     /// View or change settings
     Config {
-        /// Action: list, get, set, edit
+        /// Action: list, get, set, edit ($EDITOR), path, set-secret, get-secret, unset-secret
+        /// (omit for the interactive editor)
         action: Option<String>,
         /// Config key (e.g. search.max_results)
         key: Option<String>,
@@ -94,12 +169,1003 @@ enum Commands {
     },
     /// Create a bootable Manjaro KDE USB stick with Ventoy
     Manjaro,
+    /// Generate UUIDs, passwords, passphrases and random tokens
+    Gen {
+        #[command(subcommand)]
+        kind: GenKind,
+        /// How many values to generate
+        #[arg(short = 'n', long, default_value_t = 1, global = true)]
+        count: usize,
+        /// Copy the result to the clipboard
+        #[arg(short, long, global = true)]
+        copy: bool,
+    },
+    /// Create, extract and list archives (zip, tar.*, 7z)
+    Archive {
+        #[command(subcommand)]
+        action: ArchiveAction,
+    },
+    /// List, control and inspect system services (systemd, launchd, Windows)
+    Service {
+        #[command(subcommand)]
+        action: ServiceAction,
+        /// Act on per-user services (systemd --user)
+        #[arg(long, global = true)]
+        user: bool,
+    },
+    /// Boot time breakdown and slowest startup units
+    Boot {
+        /// How many of the slowest units to show
+        #[arg(short = 'n', long, default_value_t = 10)]
+        top: usize,
+    },
+    /// Temperatures, fan speeds and battery health
+    Sensors {
+        /// Print machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// GPU model, driver, VRAM and current load
+    Gpu,
+    /// List processes with filters, sorting and JSON output
+    Ps {
+        /// Only processes whose name or command line contains this
+        #[arg(long)]
+        name: Option<String>,
+        /// Only processes owned by this user
+        #[arg(short, long)]
+        user: Option<String>,
+        /// Only processes listening on this port
+        #[arg(short, long)]
+        port: Option<u16>,
+        /// Sort order
+        #[arg(short, long, value_enum, default_value = "cpu")]
+        sort: commands::ps::SortKey,
+        /// Show at most this many rows
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+        /// Show parent/child hierarchy
+        #[arg(long, conflicts_with = "json")]
+        tree: bool,
+        /// Print machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show which process is listening on a port
+    Port {
+        port: u16,
+        /// Stop the process without asking
+        #[arg(short, long)]
+        kill: bool,
+        /// Use SIGKILL instead of SIGTERM
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Block devices, partitions, mounts and removable drives
+    Disks {
+        #[command(subcommand)]
+        action: Option<DisksAction>,
+    },
+    /// Hosts from ~/.ssh/config: list, fuzzy-connect, add and edit
+    #[command(args_conflicts_with_subcommands = true)]
+    Ssh {
+        #[command(subcommand)]
+        action: Option<SshAction>,
+        /// Host to connect to (fuzzy; omit to pick from a list)
+        query: Option<String>,
+    },
+    /// Jump to frequently used directories (`j proj` after `vg jump init`)
+    #[command(args_conflicts_with_subcommands = true)]
+    Jump {
+        #[command(subcommand)]
+        action: Option<JumpAction>,
+        /// Words to match against visited directories; prints the best match
+        query: Vec<String>,
+    },
+    /// Store, search and run command snippets with {{placeholders}}
+    Snip {
+        #[command(subcommand)]
+        action: Option<SnipAction>,
+    },
+    /// Send an HTTP request: vg http GET <url> [--json '{..}'] [-H name:value]
+    Http {
+        /// HTTP method, or @name to replay a saved request (no arguments lists saved requests)
+        method: Option<String>,
+        url: Option<String>,
+        /// Request header as name:value (repeatable)
+        #[arg(short = 'H', long = "header")]
+        headers: Vec<String>,
+        /// JSON request body; sets Content-Type: application/json
+        #[arg(long, conflicts_with = "data")]
+        json: Option<String>,
+        /// Raw request body
+        #[arg(short, long)]
+        data: Option<String>,
+        /// Save this request under a name for `vg http @name`
+        #[arg(long)]
+        save: Option<String>,
+        /// Show response headers
+        #[arg(short, long)]
+        include: bool,
+        /// Timeout in seconds
+        #[arg(long, default_value_t = 30)]
+        timeout: u64,
+    },
+    /// Search file contents (regex, respects .gitignore)
+    #[command(after_help = "Exit codes: 0 matches found, 1 no matches, 2 error")]
+    Grep {
+        pattern: String,
+        /// Directory or file to search (default: current directory)
+        path: Option<std::path::PathBuf>,
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// Treat the pattern as a literal string
+        #[arg(short = 'F', long)]
+        fixed: bool,
+        /// Match whole words only
+        #[arg(short, long)]
+        word: bool,
+        /// Include hidden files
+        #[arg(long)]
+        hidden: bool,
+        /// Only print the names of matching files
+        #[arg(short = 'l', long)]
+        files_with_matches: bool,
+        /// Stop after this many matches per file
+        #[arg(short, long)]
+        max_count: Option<usize>,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Find and replace across files with a per-file diff preview (undo with `vg undo`)
+    Replace {
+        pattern: String,
+        /// Replacement text; $1 / ${name} refer to capture groups unless --fixed
+        replacement: String,
+        /// Directory or file (default: current directory)
+        path: Option<std::path::PathBuf>,
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// Treat pattern and replacement as literal strings
+        #[arg(short = 'F', long)]
+        fixed: bool,
+        /// Include hidden files
+        #[arg(long)]
+        hidden: bool,
+        /// Apply every change without asking
+        #[arg(short, long)]
+        yes: bool,
+        /// Show the diff only
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Rename many files at once: regex captures, {n} counters, case changes, date prefixes
+    RenameBatch {
+        /// Files, or directories whose files are renamed (default: current directory)
+        #[arg(default_value = ".")]
+        paths: Vec<std::path::PathBuf>,
+        /// Only rename files whose name matches this regex; use $1 / ${name} in --to
+        #[arg(short = 'm', long = "match")]
+        pattern: Option<String>,
+        /// New name template: {stem} {ext} {date} {n} {n:3}
+        #[arg(short, long = "to")]
+        template: Option<String>,
+        /// Change the case of the name (extension is lowercased)
+        #[arg(short, long, value_enum)]
+        case: Option<commands::rename_batch::Case>,
+        /// Prefix YYYY-MM-DD from the photo's EXIF date, or the modification date
+        #[arg(long)]
+        date_prefix: bool,
+        /// First value for {n}
+        #[arg(long, default_value_t = 1)]
+        start: usize,
+        #[arg(long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Revert the last `vg replace`, `vg rename-batch`, `vg tidy`, `vg img optimize` or `vg pdf compress` run
+    Undo {
+        /// Operation id from `vg undo --list` (default: the newest)
+        id: Option<String>,
+        /// Show the undo history
+        #[arg(short, long)]
+        list: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Audit file permissions under home (or a path)
+    Perms {
+        #[command(subcommand)]
+        action: PermsAction,
+    },
+    /// Manage apps and user services that start at login
+    Startup {
+        #[command(subcommand)]
+        action: Option<StartupAction>,
+    },
+    /// Free disk space: package caches, journal, thumbnails, old temp files, container prune
+    Clean {
+        /// Show what would be freed without deleting anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Clean the default selection without asking
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Docker/Podman overview: containers, images, volumes and quick actions
+    Containers {
+        #[command(subcommand)]
+        action: Option<ContainersAction>,
+    },
+    /// Install per-language pre-commit hooks (format, lint, forbidden files)
+    Hooks {
+        #[command(subcommand)]
+        action: Option<HooksAction>,
+    },
+    /// Installed language runtimes with versions and end-of-life warnings
+    Toolchain {
+        #[command(subcommand)]
+        action: Option<ToolchainAction>,
+    },
+    /// Run a project task from Cargo.toml, package.json, a Makefile or a justfile
+    Run {
+        /// Task name (fuzzy); omit to pick from a list
+        task: Option<String>,
+        /// List the detected tasks
+        #[arg(short, long)]
+        list: bool,
+        /// Arguments passed on to the task
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Set up a new machine from a profile: packages, dotfiles, environment, index and greeting
+    Bootstrap {
+        /// Profile name in ~/.config/genesis/bootstrap/, or a path to a .toml file
+        profile: Option<String>,
+        /// Create the profile from an example
+        #[arg(long)]
+        init: bool,
+        /// Show the plan without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Run without asking
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Pending package updates per package manager and new vg releases
+    Updates {
+        /// Refresh the cache silently (used internally by the background check)
+        #[arg(long, hide = true)]
+        background: bool,
+    },
+    /// Remove empty directories, broken symlinks and empty temp files (undo with vg undo)
+    Tidy {
+        /// Directory to tidy (default: current directory)
+        #[arg(default_value = ".")]
+        path: std::path::PathBuf,
+        /// Skip paths matching this glob (repeatable), e.g. --exclude '*.part' --exclude build
+        #[arg(short, long)]
+        exclude: Vec<String>,
+        #[arg(long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Serve the search index as a local JSON API for launchers and editors
+    Serve {
+        /// Port on 127.0.0.1
+        #[arg(short, long, default_value_t = 7878)]
+        port: u16,
+        /// Listen on this Unix socket instead of TCP
+        #[arg(long)]
+        socket: Option<std::path::PathBuf>,
+    },
+    /// Measure vg's own performance
+    Benchmark {
+        #[command(subcommand)]
+        action: BenchmarkAction,
+    },
+    /// Image tools
+    Img {
+        #[command(subcommand)]
+        action: ImgAction,
+    },
+    /// PDF tools
+    Pdf {
+        #[command(subcommand)]
+        action: PdfAction,
+    },
+    /// Take a screenshot into Pictures/Screenshots and copy its path
+    Shot {
+        /// Select a region
+        #[arg(long, group = "shot_mode")]
+        region: bool,
+        /// Pick a window
+        #[arg(long, group = "shot_mode")]
+        window: bool,
+        /// Capture the whole screen (default)
+        #[arg(long, group = "shot_mode")]
+        full: bool,
+        /// Don't copy the path to the clipboard
+        #[arg(long)]
+        no_copy: bool,
+    },
+    /// Show a QR code for text or a URL, or decode one with `vg qr read image.png`
+    #[command(args_conflicts_with_subcommands = true)]
+    Qr {
+        #[command(subcommand)]
+        action: Option<QrAction>,
+        /// Text or URL to encode (default: stdin)
+        text: Option<String>,
+        /// Also save the code as a PNG
+        #[arg(long, value_name = "FILE")]
+        png: Option<std::path::PathBuf>,
+        /// Draw dark modules as blocks, for light terminal backgrounds
+        #[arg(long)]
+        invert: bool,
+    },
+    /// Desktop notification at a later time: vg remind "stand up" --in 45m
+    #[command(args_conflicts_with_subcommands = true)]
+    Remind {
+        #[command(subcommand)]
+        action: Option<RemindAction>,
+        /// What to remind you of
+        message: Option<String>,
+        /// After a duration, e.g. 45m, 1h30m, 90s
+        #[arg(long = "in", value_name = "DURATION", conflicts_with = "at")]
+        after: Option<String>,
+        /// At a time, e.g. 17:30 or "2026-10-17 09:00"
+        #[arg(long)]
+        at: Option<String>,
+    },
+    /// Compare machines in fleet.hosts, checked over SSH
+    #[command(after_help = "Exit codes: 0 all healthy, 1 warnings, 3 critical problems on a host, 2 a host could not be checked")]
+    Fleet {
+        #[command(subcommand)]
+        action: FleetAction,
+    },
+    /// Notification channels set up under [notify]
+    Notify {
+        #[command(subcommand)]
+        action: NotifyAction,
+    },
+    /// Track time against free-form labels
+    Track {
+        #[command(subcommand)]
+        action: TrackAction,
+    },
+    /// Back up or migrate all vg state (config, snippets, history, index) in one archive
+    Data {
+        #[command(subcommand)]
+        action: DataAction,
+    },
+    /// Share snippets and saved requests between machines ([sync] in config)
+    Sync {
+        #[command(subcommand)]
+        action: SyncAction,
+    },
+    /// Check vg's own setup: directories, config, search index and optional tools
+    #[command(name = "self")]
+    SelfCmd {
+        #[command(subcommand)]
+        action: SelfAction,
+    },
+    /// Rank and switch to the fastest distro mirrors (reflector, apt, dnf fastestmirror)
+    Mirrors {
+        /// Switch without asking
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Check the current project for missing tools, stale lockfiles and git hygiene
+    Doctor,
+    /// Everyday git helpers
+    Git {
+        #[command(subcommand)]
+        action: GitAction,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum GenKind {
+    /// UUID (v4 random, v7 time-ordered)
+    Uuid {
+        /// UUID version
+        #[arg(short = 'v', long, value_enum, default_value = "4")]
+        version: commands::gen::UuidVersion,
+    },
+    /// Random password
+    Password {
+        /// Number of characters
+        #[arg(short, long, default_value_t = 20)]
+        length: usize,
+        /// Leave out uppercase letters
+        #[arg(long)]
+        no_upper: bool,
+        /// Leave out digits
+        #[arg(long)]
+        no_digits: bool,
+        /// Leave out symbols
+        #[arg(long)]
+        no_symbols: bool,
+        /// Leave out look-alike characters (I l 1 O 0 o)
+        #[arg(long)]
+        no_ambiguous: bool,
+    },
+    /// Diceware-style passphrase
+    Passphrase {
+        /// Number of words
+        #[arg(short, long, default_value_t = 8)]
+        words: usize,
+        /// Word separator
+        #[arg(short, long, default_value = "-")]
+        separator: String,
+        /// Capitalize each word
+        #[arg(long)]
+        capitalize: bool,
+        /// Wordlist file (one word per line, or a diceware list)
+        #[arg(long)]
+        wordlist: Option<std::path::PathBuf>,
+    },
+    /// Random token
+    Token {
+        /// Number of random bytes
+        #[arg(short, long, default_value_t = 32)]
+        bytes: usize,
+        /// URL-safe base64 instead of hex
+        #[arg(long)]
+        base64: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ArchiveAction {
+    /// Pack files and folders; the format follows the output extension
+    Create {
+        /// Archive to write (.zip, .7z, .tar, .tar.gz, .tar.zst, .tar.xz, .tar.bz2)
+        output: std::path::PathBuf,
+        /// Files and folders to include
+        #[arg(required = true)]
+        inputs: Vec<std::path::PathBuf>,
+    },
+    /// Unpack an archive (into a folder named after it by default)
+    Extract {
+        archive: std::path::PathBuf,
+        /// Destination folder
+        dest: Option<std::path::PathBuf>,
+        /// Overwrite files that already exist in the destination
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Show what an archive contains
+    List {
+        archive: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ServiceAction {
+    /// All services, failed ones first
+    List {
+        /// Only show failed services
+        #[arg(long)]
+        failed: bool,
+    },
+    /// Detailed status (pick interactively when no name is given)
+    Status { name: Option<String> },
+    /// Start a service
+    Start { name: Option<String> },
+    /// Stop a service
+    Stop { name: Option<String> },
+    /// Restart a service
+    Restart { name: Option<String> },
+    /// Recent log output
+    Logs {
+        name: Option<String>,
+        /// Number of lines
+        #[arg(short = 'n', long, default_value_t = 50)]
+        lines: usize,
+        /// Keep streaming new lines
+        #[arg(short, long)]
+        follow: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DisksAction {
+    /// Unmount a removable drive and power it off
+    Eject {
+        /// Drive name, e.g. sdb or /dev/sdb
+        device: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SshAction {
+    /// All configured hosts
+    List {
+        /// Test whether each host accepts connections
+        #[arg(short, long)]
+        check: bool,
+    },
+    /// Add a host entry (optionally generating a key and running ssh-copy-id)
+    Add,
+    /// Change an existing host entry
+    Edit { alias: String },
+}
+
+#[derive(Subcommand, Debug)]
+enum JumpAction {
+    /// Print shell integration (hook + `j` function): eval "$(vg jump init bash)"
+    Init {
+        #[arg(value_parser = ["bash", "zsh", "fish"])]
+        shell: String,
+    },
+    /// Record a visit (called by the shell hook)
+    Add { dir: std::path::PathBuf },
+    /// Most frecent directories
+    List {
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Stop tracking a directory
+    Remove { dir: std::path::PathBuf },
+}
+
+#[derive(Subcommand, Debug)]
+enum SnipAction {
+    /// Save a snippet (prompts for the command if omitted)
+    Add {
+        name: String,
+        command: Option<String>,
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Comma-separated tags
+        #[arg(short, long, value_delimiter = ',')]
+        tags: Vec<String>,
+    },
+    /// List snippets
+    List {
+        #[arg(short, long)]
+        tag: Option<String>,
+    },
+    /// Fuzzy-search names, tags and descriptions
+    Search { query: String },
+    /// Run a snippet: vg snip run deploy --host=prod1
+    Run {
+        name: String,
+        /// Print the filled-in command without running it
+        #[arg(long)]
+        dry_run: bool,
+        /// Placeholder values as --key=value; anything else is appended
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Delete a snippet
+    Remove { name: String },
+}
+
+#[derive(Subcommand, Debug)]
+enum PermsAction {
+    /// Flag world-writable and setuid files, broken symlinks, foreign owners and exposed keys
+    Audit {
+        /// Directory to scan (default: home)
+        path: Option<std::path::PathBuf>,
+        /// Pick findings to repair
+        #[arg(long)]
+        fix: bool,
+        /// Write all findings to a file (.json for JSON, anything else for text)
+        #[arg(long)]
+        report: Option<std::path::PathBuf>,
+        #[arg(long, conflicts_with = "fix")]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum StartupAction {
+    /// Autostart entries, user services, login items and Run keys with their impact
+    List,
+    /// Start an entry at login again
+    Enable { name: Option<String> },
+    /// Stop an entry from starting at login
+    Disable { name: Option<String> },
+}
+
+#[derive(Subcommand, Debug)]
+enum ContainersAction {
+    /// Show a container's logs
+    Logs {
+        name: Option<String>,
+        #[arg(short, long)]
+        follow: bool,
+        #[arg(short = 'n', long, default_value_t = 100)]
+        tail: usize,
+    },
+    /// Open a shell (or run a command) in a running container
+    Exec {
+        name: Option<String>,
+        #[arg(trailing_var_arg = true)]
+        command: Vec<String>,
+    },
+    /// Stop a running container
+    Stop { name: Option<String> },
+    /// Remove stopped containers, unused images, volumes and build cache
+    Prune {
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum HooksAction {
+    /// Write .git/hooks/pre-commit for the detected languages
+    Install {
+        /// Replace a hook that wasn't written by vg (it is kept as a backup)
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove the vg hook and restore the previous one
+    Uninstall,
+}
+
+#[derive(Subcommand, Debug)]
+enum ToolchainAction {
+    /// Install or update a runtime through rustup, fnm/volta, pyenv/uv or the package manager
+    Update {
+        #[arg(value_enum)]
+        runtime: commands::toolchain::Runtime,
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+#[derive(Subcommand, Debug)]
+enum BenchmarkAction {
+    /// Index build time and query latency on synthetic trees (or the real index) vs a linear scan
+    Search {
+        /// Synthetic tree sizes in files, comma-separated
+        #[arg(long, value_delimiter = ',', default_value = "1000,10000")]
+        sizes: Vec<usize>,
+        /// Benchmark the current index instead of synthetic trees
+        #[arg(long)]
+        current: bool,
+        /// Queries to time with --current (repeatable)
+        #[arg(short, long = "query")]
+        queries: Vec<String>,
+        /// Warm repetitions per query
+        #[arg(long, default_value_t = 20)]
+        runs: usize,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ImgAction {
+    /// Recompress PNG/JPEG/WebP files with oxipng, pngquant, jpegoptim, cwebp or ImageMagick (undo with vg undo)
+    Optimize {
+        /// Image or directory (default: current directory)
+        #[arg(default_value = ".")]
+        path: std::path::PathBuf,
+        /// Allow quality loss for much smaller files
+        #[arg(long)]
+        lossy: bool,
+        /// Shrink images whose longer side exceeds this many pixels (e.g. 2560)
+        #[arg(long)]
+        max_size: Option<u32>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum PdfAction {
+    /// Combine PDFs into one, in the order given
+    Merge {
+        /// Input PDFs
+        #[arg(required = true, num_args = 2..)]
+        inputs: Vec<std::path::PathBuf>,
+        /// Output file
+        #[arg(short, long, default_value = "merged.pdf")]
+        output: std::path::PathBuf,
+    },
+    /// Write one PDF per page, or per range with --pages 1-3,4-
+    Split {
+        input: std::path::PathBuf,
+        /// Comma-separated pages or ranges; each becomes its own file
+        #[arg(short, long)]
+        pages: Option<String>,
+        /// Output directory (default: next to the input)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Print the text layer of a PDF
+    ExtractText {
+        input: std::path::PathBuf,
+        /// Only these pages, e.g. 1-3,7
+        #[arg(short, long)]
+        pages: Option<String>,
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Shrink a PDF with Ghostscript, or losslessly without it (undo with vg undo)
+    Compress {
+        input: std::path::PathBuf,
+        /// Write here instead of replacing the input
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+        /// Image resolution preset used with Ghostscript
+        #[arg(short, long, value_enum, default_value = "ebook")]
+        quality: commands::pdf::Quality,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum QrAction {
+    /// Decode QR codes and barcodes in an image (needs zbarimg)
+    Read {
+        image: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum RemindAction {
+    /// Show pending reminders
+    List,
+    /// Cancel a pending reminder
+    Cancel {
+        id: u32,
+    },
+    /// Deliver a reminder (used internally by the timer or background process)
+    #[command(hide = true)]
+    Fire {
+        id: u32,
+        #[arg(long)]
+        wait: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum FleetAction {
+    /// Health, pending updates and the fullest disk of every host, side by side
+    Status,
+}
+
+#[derive(Subcommand, Debug)]
+enum NotifyAction {
+    /// Send a test notification on every configured channel
+    Test,
+}
+
+#[derive(Subcommand, Debug)]
+enum TrackAction {
+    /// Start tracking (stops the running label first)
+    Start {
+        label: String,
+    },
+    /// Stop the running label
+    Stop,
+    /// What's running and today's total
+    Status,
+    /// Time per label today, or per day this week with --week
+    Report {
+        #[arg(long)]
+        week: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DataAction {
+    /// Write config and data to a .tar.gz (default: genesis-backup-<date>.tar.gz)
+    Export {
+        file: Option<std::path::PathBuf>,
+        /// Leave out the search index (rebuild it with vg index)
+        #[arg(long)]
+        no_index: bool,
+        /// Include the plaintext secrets store (keyring entries can't be exported)
+        #[arg(long)]
+        secrets: bool,
+    },
+    /// Restore an export; the current state is saved first
+    Import {
+        file: std::path::PathBuf,
+        /// Don't restore the search index
+        #[arg(long)]
+        no_index: bool,
+        #[arg(long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum SyncAction {
+    /// Merge remote changes, then upload this machine's
+    Push,
+    /// Merge remote changes into this machine (undo with vg undo)
+    Pull,
+    /// Show what push and pull would do
+    Status,
+}
+
+#[derive(Subcommand, Debug)]
+enum SelfAction {
+    /// Verify directories, config, index and the external tools commands rely on
+    Check,
+}
+
+#[derive(Subcommand, Debug)]
+enum PkgAction {
+    /// Remove dependencies no installed package needs any more
+    Autoremove {
+        /// Only list them with their sizes
+        #[arg(long)]
+        dry_run: bool,
+        /// Remove all of them without asking
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Which package installed a file (a path or a command name)
+    Owns {
+        path: String,
+    },
+    /// Files a package installed
+    Files {
+        package: String,
+    },
+    /// Packages installed and removed through vg
+    History {
+        /// Write the packages still installed to a file for `vg pkg replay`
+        #[arg(long)]
+        export: Option<std::path::PathBuf>,
+    },
+    /// Install the packages from an exported history (or another machine's packages.json)
+    Replay {
+        file: std::path::PathBuf,
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum UpdateAction {
+    /// Go back to an earlier package version (pacman, apt), undo a dnf transaction,
+    /// or restore a system snapshot
+    Rollback {
+        /// Package to downgrade; pick from recent upgrades when omitted
+        package: Option<String>,
+        /// Version to install instead of picking one
+        #[arg(long)]
+        to: Option<String>,
+        /// Restore a Timeshift or Snapper snapshot instead
+        #[arg(long)]
+        snapshot: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum GitAction {
+    /// Delete local branches already merged into the default branch and prune stale remote refs
+    Cleanup {
+        /// Only show what would be deleted
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Commit all changes as a WIP checkpoint
+    Wip {
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Undo the last WIP commit, keeping its changes
+    Unwip {
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Soft-reset the last commit, keeping its changes staged
+    Undo {
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// List the largest blobs in history
+    #[command(name = "big-files")]
+    BigFiles {
+        /// Number of blobs to show
+        #[arg(short = 'l', long, default_value_t = 10)]
+        limit: usize,
+    },
+}
+
+// Plain sync main: every network call uses reqwest::blocking, which panics inside a tokio runtime
+fn main() {
+    // Commands asked for JSON output get their errors as JSON too
+    let json = std::env::args().any(|a| a == "--json");
+    let aliases = config::ConfigManager::peek().aliases;
+    let args = match aliases::expand(std::env::args_os().collect(), &aliases) {
+        Ok(args) => args,
+        Err(e) => std::process::exit(error::report(&e, json)),
+    };
+    let remote_args = commands::remote::remote_args(&args);
+    let cli = Cli::parse_from(args);
+    if cli.non_interactive || std::env::var_os("CI").is_some() {
+        ui::set_non_interactive();
+    }
+    let debug_log = if cli.debug { logging::init() } else { None };
+    if debug_log.is_some() {
+        let args: Vec<String> = std::env::args().collect();
+        tracing::debug!(version = env!("CARGO_PKG_VERSION"), os = std::env::consts::OS, arch = std::env::consts::ARCH, "vg started");
+        tracing::debug!(args = ?args, cwd = ?std::env::current_dir().ok(), "command line");
+    }
+    let started = std::time::Instant::now();
+    let result = match &cli.host {
+        Some(host) if remote_supported(&cli.command) => commands::remote::run(host, &remote_args),
+        Some(_) => Err(anyhow::anyhow!("This command can't run with --host; remote runs are limited to read-only commands: {}", commands::remote::COMMANDS)),
+        None => run(cli),
+    };
+    if let Some(path) = debug_log {
+        match &result {
+            Ok(()) => tracing::debug!(ms = started.elapsed().as_millis() as u64, "finished"),
+            Err(e) => tracing::debug!(ms = started.elapsed().as_millis() as u64, error = format!("{:#}", e), "failed"),
+        }
+        eprintln!("Debug log: {}", path.display());
+    }
+    if process::interrupted() {
+        // 128 + SIGINT, what shells expect from a program stopped with Ctrl-C
+        ui::skip("Interrupted — stopped after the running program exited");
+        std::process::exit(exit::INTERRUPTED);
+    }
+    match result {
+        Err(e) => std::process::exit(error::report(&e, json)),
+        Ok(()) => std::process::exit(exit::code()),
+    }
+}
+
+/// Commands `--host` runs remotely: read-only ones, as a remote run can't prompt
+fn remote_supported(command: &Commands) -> bool {
+    matches!(command,
+        Commands::Health { .. }
+        | Commands::Info { .. }
+        | Commands::Disks { action: None }
+        | Commands::Sensors { .. }
+        | Commands::Gpu
+        | Commands::Ps { .. }
+        | Commands::Updates { background: false }
+        | Commands::Service { action: ServiceAction::List { .. } | ServiceAction::Status { .. } | ServiceAction::Logs { .. }, .. }
+    )
+}
+
+fn run(cli: Cli) -> Result<()> {
     let mut config_manager = config::ConfigManager::new();
+    process::init(&config_manager.config.timeouts);
+    if let Some(op) = history::take_unnoticed() {
+        ui::skip(&format!("vg {} stopped early last time after {} changes — vg undo {} reverts them", op.command, op.changes.len(), op.id));
+        println!();
+    }
+    if let Some(e) = &config_manager.load_error {
+        ui::fail(&format!("Ignoring {}: {}", config_manager.config_path().display(), e));
+        ui::skip("Using defaults for this run and leaving the file as it is (vg config edit)");
+    } else if !config_manager.upgrade_notes.is_empty() {
+        ui::section("Config updated");
+        for note in &config_manager.upgrade_notes {
+            ui::skip(note);
+        }
+        println!();
+    }
+
+    // First run: walk through setup before anything acts on the defaults
+    // (and not when restoring a backup, which brings its own config)
+    if config_manager.first_run && !matches!(&cli.command, Commands::Index { background: true, .. } | Commands::Data { action: DataAction::Import { .. } }) {
+        commands::onboarding::run(&mut config_manager);
+    }
 
     // Fire analytics ping in background (non-blocking, daily max)
     analytics::maybe_ping(&config_manager);
@@ -136,52 +1202,146 @@ async fn main() -> Result<()> {
         }
     }
 
+    // Background update count for greet; the check itself runs as `vg updates --background`
+    if !matches!(&cli.command, Commands::Updates { .. } | Commands::Index { background: true, .. }) {
+        commands::update_check::maybe_spawn(&config_manager);
+    }
+
     // Track command
     let cmd_name = match &cli.command {
         Commands::Update { .. } => "update",
         Commands::Install { .. } => "install",
         Commands::Uninstall { .. } => "uninstall",
+        Commands::Pkg { .. } => "pkg",
         Commands::Search { .. } => "search",
         Commands::Index { .. } => "index",
         Commands::Greet => "greet",
-        Commands::Health => "health",
-        Commands::Info => "info",
-        Commands::SelfUpdate => "self-update",
+        Commands::Weather { .. } => "weather",
+        Commands::Dash => "dash",
+        Commands::Health { .. } => "health",
+        Commands::Info { .. } => "info",
+        Commands::SelfUpdate { .. } => "self-update",
         Commands::ExpectUpdate { .. } => "expect-update",
         Commands::Config { .. } => "config",
         Commands::Manjaro => "manjaro",
+        Commands::Gen { .. } => "gen",
+        Commands::Archive { .. } => "archive",
+        Commands::Service { .. } => "service",
+        Commands::Boot { .. } => "boot",
+        Commands::Sensors { .. } => "sensors",
+        Commands::Gpu => "gpu",
+        Commands::Ps { .. } => "ps",
+        Commands::Port { .. } => "port",
+        Commands::Disks { .. } => "disks",
+        Commands::Ssh { .. } => "ssh",
+        Commands::Jump { .. } => "jump",
+        Commands::Snip { .. } => "snip",
+        Commands::Http { .. } => "http",
+        Commands::Grep { .. } => "grep",
+        Commands::Replace { .. } => "replace",
+        Commands::RenameBatch { .. } => "rename-batch",
+        Commands::Undo { .. } => "undo",
+        Commands::Perms { .. } => "perms",
+        Commands::Startup { .. } => "startup",
+        Commands::Clean { .. } => "clean",
+        Commands::Containers { .. } => "containers",
+        Commands::Hooks { .. } => "hooks",
+        Commands::Toolchain { .. } => "toolchain",
+        Commands::Run { .. } => "run",
+        Commands::Bootstrap { .. } => "bootstrap",
+        Commands::Updates { .. } => "updates",
+        Commands::Tidy { .. } => "tidy",
+        Commands::Serve { .. } => "serve",
+        Commands::Benchmark { .. } => "benchmark",
+        Commands::Img { .. } => "img",
+        Commands::Pdf { .. } => "pdf",
+        Commands::Shot { .. } => "shot",
+        Commands::Qr { .. } => "qr",
+        Commands::Remind { .. } => "remind",
+        Commands::Fleet { .. } => "fleet",
+        Commands::Notify { .. } => "notify",
+        Commands::Track { .. } => "track",
+        Commands::Data { .. } => "data",
+        Commands::Sync { .. } => "sync",
+        Commands::SelfCmd { .. } => "self",
+        Commands::Mirrors { .. } => "mirrors",
+        Commands::Doctor => "doctor",
+        Commands::Git { .. } => "git",
     };
     analytics::track_command(&config_manager, cmd_name);
 
-    match cli.command {
-        Commands::Update { yes } => {
-            commands::update::run(yes)?;
+    // User scripts around the command ([hooks.scripts] pre_<command> / post_<command>); background jobs skip them
+    let hooks = config_manager.config.hooks.clone();
+    let notify = config_manager.config.notify.clone();
+    let scripted = !matches!(&cli.command, Commands::Index { background: true, .. } | Commands::Updates { background: true });
+    let started_at = chrono::Utc::now();
+    if scripted {
+        commands::hooks::run_script(&hooks, "pre", cmd_name, None, started_at)?;
+    }
+    // A firing reminder notifies by itself
+    let notified = scripted && !matches!(&cli.command, Commands::Remind { action: Some(RemindAction::Fire { .. }), .. });
+    let result = dispatch(cli.command, config_manager);
+    if notified {
+        let took = (chrono::Utc::now() - started_at).to_std().unwrap_or_default();
+        notify::finished(&notify, cmd_name, took, result.as_ref().err());
+        notify::report(&notify, cmd_name, result.as_ref().err());
+    }
+    if scripted {
+        commands::hooks::run_script(&hooks, "post", cmd_name, Some(&result), started_at)?;
+    }
+    result
+}
+
+fn dispatch(command: Commands, mut config_manager: config::ConfigManager) -> Result<()> {
+    match command {
+        Commands::Update { action: Some(UpdateAction::Rollback { package, to, snapshot, yes }), .. } => {
+            commands::rollback::run(commands::rollback::RollbackParams { package, to, snapshot, yes })?;
+            commands::update_check::invalidate();
+        }
+        Commands::Update { action: None, yes, firmware } => {
+            let channel = commands::self_update::Channel::from_config(&config_manager.config.self_update.channel);
+            commands::update::run(yes, firmware, config_manager.config.system.update_mirrors, channel)?;
+            commands::update_check::invalidate();
+        }
+        Commands::Install { pkg, yes, pick: true } => {
+            commands::package::pick(&pkg, &config_manager.config.system.package_manager_priority, yes)?;
         }
-        Commands::Install { pkg, yes } => {
+        Commands::Install { pkg, yes, pick: false } => {
             commands::package::install(&pkg, yes)?;
         }
         Commands::Uninstall { pkg } => {
             commands::package::uninstall(&pkg)?;
         }
-        Commands::Search { query, ext, path, limit, interactive, verbose, all } => {
-            let use_tui = interactive || query.is_none();
+        Commands::Pkg { action } => match action {
+            PkgAction::Autoremove { dry_run, yes } => commands::pkg::autoremove(dry_run, yes)?,
+            PkgAction::Owns { path } => commands::pkg::owns(&path)?,
+            PkgAction::Files { package } => commands::pkg::files(&package)?,
+            PkgAction::History { export } => commands::pkg::history(export)?,
+            PkgAction::Replay { file, yes } => commands::pkg::replay(&file, yes)?,
+        },
+        Commands::Search { query, ext, path, mime, limit, interactive, verbose, all, format } => {
+            let use_tui = interactive || (query.is_none() && format.is_none());
             if use_tui {
                 let initial = query.as_deref().unwrap_or("");
                 commands::search_tui::run_interactive_with_query(&config_manager, initial)?;
             } else {
                 commands::search::search(commands::search::SearchParams {
-                    query: query.unwrap(),
+                    query: query.unwrap_or_default(),
                     ext,
                     path_filter: path,
+                    mime,
                     limit,
                     verbose,
                     all_scopes: all,
+                    format,
                 }, &config_manager)?;
             }
         }
-        Commands::Index { info, paths, background } => {
+        Commands::Index { info, verify, paths, follow_links, background } => {
             if info {
                 commands::search::info()?;
+            } else if verify {
+                commands::search::verify()?;
             } else {
                 let paths_to_index: Vec<std::path::PathBuf> = if paths.is_empty() {
                     config_manager.config.search.default_paths.iter()
@@ -190,6 +1350,9 @@ async fn main() -> Result<()> {
                 } else {
                     paths.iter().map(|p| std::path::PathBuf::from(p)).collect()
                 };
+                if follow_links {
+                    config_manager.config.search.follow_links = true;
+                }
                 // In background mode the parent already redirected stdio to null,
                 // so build_index output is invisible. Stamp on success.
                 commands::search::build_index(paths_to_index, &config_manager)?;
@@ -199,20 +1362,29 @@ async fn main() -> Result<()> {
             }
         }
         Commands::Greet => {
-            commands::greet::run();
+            commands::greet::run(&config_manager);
+        }
+        Commands::Weather { city } => {
+            commands::weather::run(city, &config_manager)?;
         }
-        Commands::Health => {
-            commands::health::run()?;
+        Commands::Dash => {
+            commands::dash::run(&config_manager)?;
         }
-        Commands::Info => {
-            commands::info::run();
+        Commands::Health { json } => {
+            commands::health::run(json)?;
         }
-        Commands::SelfUpdate => {
-            commands::self_update::run()?;
+        Commands::Info { json, fetch } => {
+            commands::info::run(json, fetch)?;
         }
-        Commands::ExpectUpdate { interval } => {
+        Commands::SelfUpdate { channel } => {
+            let channel = channel.unwrap_or_else(|| commands::self_update::Channel::from_config(&config_manager.config.self_update.channel));
+            commands::self_update::run(channel)?;
+            commands::update_check::invalidate();
+        }
+        Commands::ExpectUpdate { interval, channel } => {
             let secs = interval.unwrap_or(config_manager.config.expect_update.interval_secs);
-            commands::self_update::expect_update(secs)?;
+            let channel = channel.unwrap_or_else(|| commands::self_update::Channel::from_config(&config_manager.config.self_update.channel));
+            commands::self_update::expect_update(secs, channel)?;
         }
         Commands::Config { action, key, value } => {
             commands::config_cmd::run(action, key, value, &mut config_manager)?;
@@ -220,6 +1392,236 @@ async fn main() -> Result<()> {
         Commands::Manjaro => {
             commands::manjaro::run()?;
         }
+        Commands::Gen { kind, count, copy } => match kind {
+            GenKind::Uuid { version } => commands::gen::uuid(version, count, copy)?,
+            GenKind::Password { length, no_upper, no_digits, no_symbols, no_ambiguous } => {
+                commands::gen::password(length, no_upper, no_digits, no_symbols, no_ambiguous, count, copy)?
+            }
+            GenKind::Passphrase { words, separator, capitalize, wordlist } => {
+                commands::gen::passphrase(words, &separator, capitalize, wordlist.as_deref(), count, copy)?
+            }
+            GenKind::Token { bytes, base64 } => commands::gen::token(bytes, base64, count, copy)?,
+        },
+        Commands::Archive { action } => match action {
+            ArchiveAction::Create { output, inputs } => commands::archive::create(&output, &inputs)?,
+            ArchiveAction::Extract { archive, dest, force } => commands::archive::extract(&archive, dest, force)?,
+            ArchiveAction::List { archive } => commands::archive::list(&archive)?,
+        },
+        Commands::Service { action, user } => match action {
+            ServiceAction::List { failed } => commands::service::list(failed, user)?,
+            ServiceAction::Status { name } => commands::service::status(name, user)?,
+            ServiceAction::Start { name } => commands::service::control("start", name, user)?,
+            ServiceAction::Stop { name } => commands::service::control("stop", name, user)?,
+            ServiceAction::Restart { name } => commands::service::control("restart", name, user)?,
+            ServiceAction::Logs { name, lines, follow } => commands::service::logs(name, lines, follow, user)?,
+        },
+        Commands::Boot { top } => {
+            commands::boot::run(top)?;
+        }
+        Commands::Sensors { json } => {
+            commands::sensors::run(json)?;
+        }
+        Commands::Gpu => {
+            commands::gpu::run()?;
+        }
+        Commands::Ps { name, user, port, sort, limit, tree, json } => {
+            commands::ps::run(commands::ps::Filters { name, user, port }, sort, limit, tree, json)?;
+        }
+        Commands::Port { port, kill, force } => {
+            commands::port::run(port, kill, force)?;
+        }
+        Commands::Disks { action } => match action {
+            None => commands::disks::list()?,
+            Some(DisksAction::Eject { device }) => commands::disks::eject(&device)?,
+        },
+        Commands::Ssh { action, query } => match action {
+            None => commands::ssh::connect(query)?,
+            Some(SshAction::List { check }) => commands::ssh::list(check)?,
+            Some(SshAction::Add) => commands::ssh::add()?,
+            Some(SshAction::Edit { alias }) => commands::ssh::edit(&alias)?,
+        },
+        Commands::Jump { action, query } => match action {
+            None if query.is_empty() => commands::jump::list(20)?,
+            None => commands::jump::query(&query)?,
+            Some(JumpAction::Init { shell }) => commands::jump::init(&shell)?,
+            Some(JumpAction::Add { dir }) => commands::jump::add(&dir)?,
+            Some(JumpAction::List { limit }) => commands::jump::list(limit)?,
+            Some(JumpAction::Remove { dir }) => commands::jump::remove(&dir)?,
+        },
+        Commands::Snip { action } => match action {
+            None => commands::snip::list(None)?,
+            Some(SnipAction::Add { name, command, description, tags }) => commands::snip::add(&name, command, description, tags)?,
+            Some(SnipAction::List { tag }) => commands::snip::list(tag)?,
+            Some(SnipAction::Search { query }) => commands::snip::search(&query)?,
+            Some(SnipAction::Run { name, dry_run, args }) => commands::snip::run(&name, &args, dry_run)?,
+            Some(SnipAction::Remove { name }) => commands::snip::remove(&name)?,
+        },
+        Commands::Http { method, url, headers, json, data, save, include, timeout } => {
+            let mut req = match method.as_deref() {
+                None => {
+                    commands::http::list()?;
+                    return Ok(());
+                }
+                Some(m) if m.starts_with('@') => commands::http::saved(&m[1..])?,
+                Some(m) if url.is_none() && commands::http::METHODS.iter().any(|x| x.eq_ignore_ascii_case(m)) => {
+                    return Err(anyhow::anyhow!("Missing URL: vg http {} <url>", m.to_uppercase()));
+                }
+                // `vg http example.com` is a GET
+                Some(m) if url.is_none() => commands::http::Request { method: "GET".into(), url: m.to_string(), ..Default::default() },
+                Some(m) => commands::http::Request { method: m.to_string(), ..Default::default() },
+            };
+            if let Some(url) = url {
+                req.url = url;
+            }
+            req.headers.extend(headers);
+            if let Some(body) = json {
+                req.body = Some(body);
+                req.json = true;
+            } else if let Some(body) = data {
+                req.body = Some(body);
+                req.json = false;
+            }
+            if req.url.is_empty() {
+                return Err(anyhow::anyhow!("Missing URL: vg http {} <url>", req.method));
+            }
+            commands::http::run(req, save, include, timeout)?;
+        }
+        Commands::Grep { pattern, path, ignore_case, fixed, word, hidden, files_with_matches, max_count, json } => {
+            commands::grep::run(commands::grep::GrepParams {
+                pattern, path, ignore_case, fixed, word, hidden,
+                files_only: files_with_matches, max_count, json,
+            }, &config_manager)?;
+        }
+        Commands::Replace { pattern, replacement, path, ignore_case, fixed, hidden, yes, dry_run } => {
+            commands::replace::run(commands::replace::ReplaceParams {
+                pattern, replacement, path, ignore_case, fixed, hidden, yes, dry_run,
+            }, &config_manager)?;
+        }
+        Commands::RenameBatch { paths, pattern, template, case, date_prefix, start, dry_run, yes } => {
+            commands::rename_batch::run(commands::rename_batch::RenameParams {
+                paths, pattern, template, case, date_prefix, start, dry_run, yes,
+            })?;
+        }
+        Commands::Undo { id, list, yes } => {
+            if list {
+                commands::undo::list()?;
+            } else {
+                commands::undo::run(id, yes)?;
+            }
+        }
+        Commands::Perms { action } => match action {
+            PermsAction::Audit { path, fix, report, json } => commands::perms::audit(path, fix, report, json)?,
+        },
+        Commands::Startup { action } => match action {
+            None | Some(StartupAction::List) => commands::startup::list()?,
+            Some(StartupAction::Enable { name }) => commands::startup::set(name, true)?,
+            Some(StartupAction::Disable { name }) => commands::startup::set(name, false)?,
+        },
+        Commands::Clean { dry_run, yes } => commands::clean::run(dry_run, yes)?,
+        Commands::Containers { action } => match action {
+            None => commands::containers::list()?,
+            Some(ContainersAction::Logs { name, follow, tail }) => commands::containers::logs(name, follow, tail)?,
+            Some(ContainersAction::Exec { name, command }) => commands::containers::exec(name, command)?,
+            Some(ContainersAction::Stop { name }) => commands::containers::stop(name)?,
+            Some(ContainersAction::Prune { yes }) => commands::containers::prune(yes)?,
+        },
+        Commands::Hooks { action } => match action {
+            None => commands::hooks::status(&config_manager.config.hooks)?,
+            Some(HooksAction::Install { force }) => commands::hooks::install(&config_manager.config.hooks, force)?,
+            Some(HooksAction::Uninstall) => commands::hooks::uninstall()?,
+        },
+        Commands::Toolchain { action } => match action {
+            None => commands::toolchain::list()?,
+            Some(ToolchainAction::Update { runtime, yes }) => commands::toolchain::update(runtime, &config_manager.config.toolchain, yes)?,
+        },
+        Commands::Run { task, list, args } => {
+            commands::run::run(task, args, list)?;
+        }
+        Commands::Bootstrap { profile, init, dry_run, yes } => match profile {
+            None => commands::bootstrap::list(&config_manager)?,
+            Some(name) => commands::bootstrap::run(&mut config_manager, &name, init, dry_run, yes)?,
+        },
+        Commands::Updates { background } => {
+            commands::update_check::run(&config_manager, background)?;
+        }
+        Commands::Tidy { path, exclude, dry_run, yes } => {
+            commands::tidy::run(commands::tidy::TidyParams { path, exclude, dry_run, yes })?;
+        }
+        Commands::Serve { port, socket } => {
+            commands::serve::run(commands::serve::ServeParams { port, socket })?;
+        }
+        Commands::Benchmark { action } => match action {
+            BenchmarkAction::Search { sizes, current, queries, runs } => {
+                commands::benchmark::search(commands::benchmark::SearchBenchParams { sizes, current, queries, runs }, &config_manager)?;
+            }
+        },
+        Commands::Img { action } => match action {
+            ImgAction::Optimize { path, lossy, max_size } => {
+                commands::img::optimize_cmd(commands::img::OptimizeParams { path, lossy, max_size })?;
+            }
+        },
+        Commands::Pdf { action } => match action {
+            PdfAction::Merge { inputs, output } => commands::pdf::merge(inputs, output)?,
+            PdfAction::Split { input, pages, output } => commands::pdf::split(input, pages, output)?,
+            PdfAction::ExtractText { input, pages, output } => commands::pdf::extract_text(input, pages, output)?,
+            PdfAction::Compress { input, output, quality } => commands::pdf::compress(input, output, quality)?,
+        },
+        Commands::Shot { region, window, full: _, no_copy } => {
+            let mode = if region {
+                commands::shot::Mode::Region
+            } else if window {
+                commands::shot::Mode::Window
+            } else {
+                commands::shot::Mode::Full
+            };
+            commands::shot::run(mode, !no_copy)?;
+        }
+        Commands::Qr { action, text, png, invert } => match action {
+            Some(QrAction::Read { image }) => commands::qr::read(image)?,
+            None => commands::qr::generate(commands::qr::QrParams { text, png, invert })?,
+        },
+        Commands::Remind { action, message, after, at } => match action {
+            Some(RemindAction::List) => commands::remind::list()?,
+            Some(RemindAction::Cancel { id }) => commands::remind::cancel(id)?,
+            Some(RemindAction::Fire { id, wait }) => commands::remind::fire(id, wait, &config_manager.config.notify)?,
+            None => match message {
+                Some(message) => commands::remind::add(message, after, at)?,
+                None => commands::remind::list()?,
+            },
+        },
+        Commands::Fleet { action: FleetAction::Status } => commands::fleet::status(&config_manager.config.fleet.hosts)?,
+        Commands::Notify { action: NotifyAction::Test } => commands::notify::test(&config_manager.config.notify)?,
+        Commands::Track { action } => match action {
+            TrackAction::Start { label } => commands::track::start(label)?,
+            TrackAction::Stop => commands::track::stop()?,
+            TrackAction::Status => commands::track::status()?,
+            TrackAction::Report { week } => commands::track::report(week)?,
+        },
+        Commands::Data { action } => match action {
+            DataAction::Export { file, no_index, secrets } => commands::data::export(file, no_index, secrets, &config_manager)?,
+            DataAction::Import { file, no_index, dry_run, yes } => commands::data::import(file, no_index, dry_run, yes, &mut config_manager)?,
+        },
+        Commands::Sync { action } => match action {
+            SyncAction::Push => commands::sync::push(&config_manager)?,
+            SyncAction::Pull => commands::sync::pull(&config_manager)?,
+            SyncAction::Status => commands::sync::status(&config_manager)?,
+        },
+        Commands::SelfCmd { action: SelfAction::Check } => {
+            commands::self_check::run(&config_manager)?;
+        }
+        Commands::Mirrors { yes } => {
+            commands::mirrors::run(yes)?;
+        }
+        Commands::Doctor => {
+            commands::doctor::run()?;
+        }
+        Commands::Git { action } => match action {
+            GitAction::Cleanup { dry_run, yes } => commands::git::cleanup(dry_run, yes)?,
+            GitAction::Wip { dry_run, yes } => commands::git::wip(dry_run, yes)?,
+            GitAction::Unwip { dry_run, yes } => commands::git::unwip(dry_run, yes)?,
+            GitAction::Undo { dry_run, yes } => commands::git::undo(dry_run, yes)?,
+            GitAction::BigFiles { limit } => commands::git::big_files(limit)?,
+        },
     }
 
     Ok(())
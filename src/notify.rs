@@ -0,0 +1,167 @@
+// src/notify.rs
+// Notifications: desktop, terminal bell, ntfy push and webhooks, switched on in `[notify]`.
+// Reminders always notify. Other commands notify when they finish if they're listed in
+// `notify.commands` and ran for at least `notify.min_seconds`, so a long `vg update` can be
+// left running in another window. Commands listed in `notify.reports` also send what they
+// found (lines added with `note`) to ntfy and the webhook, for headless machines.
+
+use crate::config::NotifyConfig;
+use anyhow::{anyhow, Result};
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::Duration;
+use which::which;
+
+/// Commands that add notes for `report`; others would only ever send their status.
+pub const REPORTS: &[&str] = &["health", "update", "data"];
+
+static NOTES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Add a line to what this run reports when its command is in `notify.reports`.
+pub fn note(line: impl Into<String>) {
+    if let Ok(mut notes) = NOTES.lock() {
+        notes.push(line.into());
+    }
+}
+
+/// Show a desktop notification; false when no notifier is available.
+pub fn desktop(title: &str, body: &str) -> bool {
+    let mut cmd = if cfg!(target_os = "macos") {
+        let quote = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut c = Command::new("osascript");
+        c.arg("-e").arg(format!("display notification \"{}\" with title \"{}\"", quote(body), quote(title)));
+        c
+    } else if which("notify-send").is_ok() {
+        let mut c = Command::new("notify-send");
+        c.args(["--app-name", "vg", title, body]);
+        c
+    } else {
+        return false;
+    };
+    cmd.stdout(Stdio::null()).stderr(Stdio::null());
+    crate::logging::status(&mut cmd).is_ok_and(|s| s.success())
+}
+
+/// Ring the terminal bell; most terminals flash or mark the tab when it's in the background.
+pub fn bell() -> bool {
+    let mut err = std::io::stderr();
+    err.is_terminal() && err.write_all(b"\x07").and_then(|_| err.flush()).is_ok()
+}
+
+fn client() -> Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder().timeout(Duration::from_secs(10)).build()?)
+}
+
+/// Publish to an ntfy topic URL (`https://ntfy.sh/<topic>` or a self-hosted server).
+pub fn ntfy(url: &str, title: &str, body: &str, tags: &str) -> Result<()> {
+    client()?.post(url).header("Title", title).header("Tags", tags).body(body.to_string()).send()?
+        .error_for_status()?;
+    Ok(())
+}
+
+/// The values a webhook template can use, as `{name}`.
+pub struct Payload<'a> {
+    pub title: &'a str,
+    pub body: &'a str,
+    pub command: &'a str,
+    /// ok, warning, critical or failed
+    pub status: &'a str,
+}
+
+/// The body posted to `url` when `notify.template` is empty: what Slack and Discord expect, plain JSON otherwise.
+fn default_template(url: &str) -> &'static str {
+    if url.contains("hooks.slack.com") {
+        r#"{"text": "*{title}*\n{body}"}"#
+    } else if url.contains("discord.com/api/webhooks") || url.contains("discordapp.com/api/webhooks") {
+        r#"{"content": "**{title}**\n{body}"}"#
+    } else {
+        r#"{"title": "{title}", "body": "{body}", "host": "{host}", "command": "{command}", "status": "{status}"}"#
+    }
+}
+
+/// Fill in `template`; values are escaped for use inside JSON strings.
+pub fn render(template: &str, payload: &Payload) -> String {
+    let escape = |s: &str| {
+        let quoted = serde_json::to_string(s).unwrap_or_default();
+        quoted[1..quoted.len() - 1].to_string()
+    };
+    let host = sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string());
+    template
+        .replace("{title}", &escape(payload.title))
+        .replace("{body}", &escape(payload.body))
+        .replace("{host}", &escape(&host))
+        .replace("{command}", &escape(payload.command))
+        .replace("{status}", &escape(payload.status))
+}
+
+/// POST the rendered template to a webhook (Slack, Discord, or anything taking JSON).
+pub fn webhook(config: &NotifyConfig, payload: &Payload) -> Result<()> {
+    let template = if config.template.is_empty() { default_template(&config.webhook) } else { config.template.as_str() };
+    let body = render(template, payload);
+    serde_json::from_str::<serde_json::Value>(&body).map_err(|e| anyhow!("notify.template is not valid JSON once filled in: {}", e))?;
+    client()?.post(&config.webhook).header("Content-Type", "application/json").body(body).send()?
+        .error_for_status()?;
+    Ok(())
+}
+
+/// Notify on every channel turned on in `config`; false when none delivered.
+pub fn send(config: &NotifyConfig, title: &str, body: &str) -> bool {
+    let mut delivered = false;
+    if config.desktop {
+        delivered |= desktop(title, body);
+    }
+    if config.bell {
+        delivered |= bell();
+    }
+    if !config.ntfy.is_empty() {
+        delivered |= ntfy(&config.ntfy, title, body, "vg").inspect_err(|e| tracing::debug!(error = %e, "ntfy push failed")).is_ok();
+    }
+    delivered
+}
+
+/// Called once a command finishes: notifies when it's opted in and ran long enough.
+pub fn finished(config: &NotifyConfig, command: &str, took: Duration, error: Option<&anyhow::Error>) {
+    if !config.commands.iter().any(|c| c == command) || took.as_secs() < config.min_seconds {
+        return;
+    }
+    let secs = took.as_secs();
+    let took = if secs < 60 { format!("{} s", secs) } else { format!("{} min {} s", secs / 60, secs % 60) };
+    let (title, body) = match error {
+        None => (format!("vg {} finished", command), format!("Done after {}", took)),
+        Some(e) => (format!("vg {} failed", command), format!("{:#} (after {})", e, took)),
+    };
+    send(config, &title, &body);
+}
+
+/// Called once a command in `notify.reports` finishes: sends its status and notes to ntfy and the webhook.
+pub fn report(config: &NotifyConfig, command: &str, error: Option<&anyhow::Error>) {
+    if !config.reports.iter().any(|c| c == command) || (config.ntfy.is_empty() && config.webhook.is_empty()) {
+        return;
+    }
+    let status = match (error, crate::exit::code()) {
+        (Some(_), _) => "failed",
+        (None, crate::exit::OK) => "ok",
+        (None, crate::exit::CRITICAL) => "critical",
+        (None, _) => "warning",
+    };
+    let host = sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string());
+    let title = format!("vg {} on {}: {}", command, host, status);
+    let notes = NOTES.lock().map(|n| n.join("\n")).unwrap_or_default();
+    let body = match error {
+        Some(e) => format!("{:#}", e),
+        None if notes.is_empty() => "Nothing to report".to_string(),
+        None => notes,
+    };
+    if !config.ntfy.is_empty() {
+        let tags = if status == "ok" { "vg,white_check_mark" } else { "vg,warning" };
+        if let Err(e) = ntfy(&config.ntfy, &title, &body, tags) {
+            tracing::debug!(error = %e, "ntfy report failed");
+        }
+    }
+    if !config.webhook.is_empty() {
+        if let Err(e) = webhook(config, &Payload { title: &title, body: &body, command, status }) {
+            tracing::debug!(error = %e, "webhook report failed");
+        }
+    }
+}
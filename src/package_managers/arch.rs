@@ -1,5 +1,6 @@
-use super::{PackageManager, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
+use super::{PackageManager, PmOrphan, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
 use anyhow::Result;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 pub struct Pamac;
@@ -34,6 +35,7 @@ impl PackageManager for Pamac {
         };
         cmd.stdout(Stdio::piped()).stderr(Stdio::null());
 
+        let running = crate::process::Running::start();
         let mut child = cmd.spawn()?;
         if let Some(stdout) = child.stdout.take() {
             for line in BufReader::new(stdout).lines().flatten() {
@@ -43,6 +45,7 @@ impl PackageManager for Pamac {
             }
         }
         let status = child.wait()?;
+        running.check()?;
         if !status.success() {
             anyhow::bail!("pamac upgrade failed");
         }
@@ -51,11 +54,11 @@ impl PackageManager for Pamac {
 
     fn list_updates(&self) -> Vec<PmUpdate> {
         // pamac checkupdates: "name old_ver -> new_ver [repo]"
-        parse_qu_output(Command::new("pamac").args(["checkupdates"]).output().ok())
+        parse_qu_output(crate::logging::output(Command::new("pamac").args(["checkupdates"])).ok())
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("pamac").args(["search", query]).output()?;
+        let output = crate::logging::output(Command::new("pamac").args(["search", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         let mut results = Vec::new();
         for line in stdout.lines() {
@@ -100,11 +103,11 @@ impl PackageManager for Yay {
     }
 
     fn list_updates(&self) -> Vec<PmUpdate> {
-        parse_qu_output(Command::new("yay").args(["-Qu"]).output().ok())
+        parse_qu_output(crate::logging::output(Command::new("yay").args(["-Qu"])).ok())
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("yay").args(["-Ss", query]).output()?;
+        let output = crate::logging::output(Command::new("yay").args(["-Ss", query]))?;
         parse_pacman_search(&String::from_utf8_lossy(&output.stdout), "yay")
     }
 
@@ -117,6 +120,15 @@ impl PackageManager for Yay {
     fn uninstall(&self, pkg: &str) -> Result<()> {
         run_cmd(&["yay", "-Rns", pkg, "--noconfirm"], false)
     }
+
+    fn cache_dirs(&self) -> Vec<PathBuf> {
+        vec![dirs::cache_dir().unwrap_or_default().join("yay")]
+    }
+
+    fn clean_cache(&self) -> Result<()> {
+        // AUR build directories only; the pacman cache is cleaned by the Pacman entry
+        clear_dir(&dirs::cache_dir().unwrap_or_default().join("yay"))
+    }
 }
 
 impl PackageManager for Paru {
@@ -133,11 +145,11 @@ impl PackageManager for Paru {
     }
 
     fn list_updates(&self) -> Vec<PmUpdate> {
-        parse_qu_output(Command::new("paru").args(["-Qu"]).output().ok())
+        parse_qu_output(crate::logging::output(Command::new("paru").args(["-Qu"])).ok())
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("paru").args(["-Ss", query]).output()?;
+        let output = crate::logging::output(Command::new("paru").args(["-Ss", query]))?;
         parse_pacman_search(&String::from_utf8_lossy(&output.stdout), "paru")
     }
 
@@ -150,6 +162,14 @@ impl PackageManager for Paru {
     fn uninstall(&self, pkg: &str) -> Result<()> {
         run_cmd(&["paru", "-Rns", pkg, "--noconfirm"], false)
     }
+
+    fn cache_dirs(&self) -> Vec<PathBuf> {
+        vec![dirs::cache_dir().unwrap_or_default().join("paru")]
+    }
+
+    fn clean_cache(&self) -> Result<()> {
+        clear_dir(&dirs::cache_dir().unwrap_or_default().join("paru"))
+    }
 }
 
 impl PackageManager for Pacman {
@@ -167,11 +187,11 @@ impl PackageManager for Pacman {
     }
 
     fn list_updates(&self) -> Vec<PmUpdate> {
-        parse_qu_output(Command::new("pacman").args(["-Qu"]).output().ok())
+        parse_qu_output(crate::logging::output(Command::new("pacman").args(["-Qu"])).ok())
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("pacman").args(["-Ss", query]).output()?;
+        let output = crate::logging::output(Command::new("pacman").args(["-Ss", query]))?;
         parse_pacman_search(&String::from_utf8_lossy(&output.stdout), "pacman")
     }
 
@@ -184,6 +204,57 @@ impl PackageManager for Pacman {
     fn uninstall(&self, pkg: &str) -> Result<()> {
         run_cmd(&["pacman", "-Rns", pkg, "--noconfirm"], true)
     }
+
+    fn cache_dirs(&self) -> Vec<PathBuf> {
+        vec![PathBuf::from("/var/cache/pacman/pkg")]
+    }
+
+    fn clean_cache(&self) -> Result<()> {
+        // paccache keeps the last version so a downgrade stays possible
+        if is_available("paccache") {
+            run_cmd(&["paccache", "-rk1"], true)
+        } else {
+            run_cmd(&["pacman", "-Sc", "--noconfirm"], true)
+        }
+    }
+
+    // The AUR helpers and pamac share pacman's database, so only pacman reports orphans
+    fn orphans(&self) -> Vec<PmOrphan> {
+        let Ok(out) = crate::logging::output(Command::new("pacman").args(["-Qtdq"])) else { return vec![] };
+        let names: Vec<String> = String::from_utf8_lossy(&out.stdout).lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect();
+        if names.is_empty() {
+            return vec![];
+        }
+        // "Name            : foo" … "Installed Size  : 1.50 MiB", one block per package
+        let info = crate::logging::output(Command::new("pacman").env("LC_ALL", "C").arg("-Qi").args(&names)).ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+            .unwrap_or_default();
+        let mut sizes = std::collections::HashMap::new();
+        let mut current = String::new();
+        for line in info.lines() {
+            let Some((key, value)) = line.split_once(" : ") else { continue };
+            match key.trim() {
+                "Name" => current = value.trim().to_string(),
+                "Installed Size" => { sizes.insert(current.clone(), parse_pacman_size(value.trim())); }
+                _ => {}
+            }
+        }
+        names.into_iter().map(|n| { let size = sizes.get(&n).copied().flatten(); (n, size) }).collect()
+    }
+
+    fn remove_orphans(&self, pkgs: &[String]) -> Result<()> {
+        let mut args = vec!["pacman", "-Rns", "--noconfirm"];
+        args.extend(pkgs.iter().map(String::as_str));
+        run_cmd(&args, true)
+    }
+}
+
+/// "1.50 MiB" → bytes.
+fn parse_pacman_size(text: &str) -> Option<u64> {
+    let (num, unit) = text.split_once(' ')?;
+    let num: f64 = num.parse().ok()?;
+    let mult: u64 = match unit { "KiB" => 1 << 10, "MiB" => 1 << 20, "GiB" => 1 << 30, _ => 1 };
+    Some((num * mult as f64) as u64)
 }
 
 fn streaming_pacman_update(args: &[&str], sudo: bool, on_pkg_done: &mut dyn FnMut(&str)) -> Result<()> {
@@ -194,6 +265,7 @@ fn streaming_pacman_update(args: &[&str], sudo: bool, on_pkg_done: &mut dyn FnMu
     let mut cmd = Command::new(prog);
     if sudo { cmd.args(args); } else { cmd.args(rest); }
 
+    let running = crate::process::Running::start();
     let mut child = if is_available("stdbuf") {
         let mut c = Command::new("stdbuf");
         c.arg("-oL");
@@ -211,6 +283,7 @@ fn streaming_pacman_update(args: &[&str], sudo: bool, on_pkg_done: &mut dyn FnMu
         }
     }
     let status = child.wait()?;
+    running.check()?;
     // Exit code 1 from yay/paru typically means "nothing to do" — not a real error
     if !status.success() && status.code() != Some(1) {
         anyhow::bail!("Command failed: {:?}", args);
@@ -295,3 +368,17 @@ pub fn parse_pacman_search(output: &str, source: &str) -> Result<Vec<PmPackage>>
     }
     Ok(results)
 }
+
+/// Remove everything inside `dir`, keeping the directory itself.
+fn clear_dir(dir: &Path) -> Result<()> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Ok(()) };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            std::fs::remove_dir_all(&path)?;
+        } else {
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
-use super::{PackageManager, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
+use super::{PackageManager, PmOrphan, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
 use anyhow::Result;
+use std::path::PathBuf;
 use std::process::Command;
 
 pub struct Apt;
@@ -17,7 +18,7 @@ impl PackageManager for Apt {
 
     fn list_updates(&self) -> Vec<PmUpdate> {
         // Just query the already-cached index; the actual `apt update` runs during update()
-        let Ok(out) = Command::new("apt").args(["list", "--upgradable"]).output() else { return vec![] };
+        let Ok(out) = crate::logging::output(Command::new("apt").args(["list", "--upgradable"])) else { return vec![] };
         // Format: "name/release new_ver arch [upgradable from: old_ver]"
         String::from_utf8_lossy(&out.stdout)
             .lines()
@@ -33,7 +34,7 @@ impl PackageManager for Apt {
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("apt").args(["search", query]).output()?;
+        let output = crate::logging::output(Command::new("apt").args(["search", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         let mut results = Vec::new();
         for line in stdout.lines() {
@@ -63,4 +64,41 @@ impl PackageManager for Apt {
     fn uninstall(&self, pkg: &str) -> Result<()> {
         run_cmd(&["apt", "remove", "-y", pkg], true)
     }
+
+    fn cache_dirs(&self) -> Vec<PathBuf> {
+        vec![PathBuf::from("/var/cache/apt/archives")]
+    }
+
+    fn clean_cache(&self) -> Result<()> {
+        run_cmd(&["apt-get", "clean"], true)
+    }
+
+    fn orphans(&self) -> Vec<PmOrphan> {
+        // A simulated autoremove prints "Remv name [version]" per package it would remove
+        let Ok(out) = crate::logging::output(Command::new("apt-get").args(["autoremove", "--dry-run"])) else { return vec![] };
+        let names: Vec<String> = String::from_utf8_lossy(&out.stdout).lines()
+            .filter_map(|l| l.strip_prefix("Remv ")?.split_whitespace().next().map(str::to_string))
+            .collect();
+        if names.is_empty() {
+            return vec![];
+        }
+        // Installed-Size is in KiB
+        let sizes: std::collections::HashMap<String, u64> = crate::logging::output(
+            Command::new("dpkg-query").args(["-W", "-f=${Package} ${Installed-Size}\\n"]).args(&names),
+        ).ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).lines()
+                .filter_map(|l| {
+                    let (name, kib) = l.split_once(' ')?;
+                    Some((name.to_string(), kib.trim().parse::<u64>().ok()? * 1024))
+                })
+                .collect())
+            .unwrap_or_default();
+        names.into_iter().map(|n| { let size = sizes.get(n.split(':').next().unwrap_or(&n)).copied(); (n, size) }).collect()
+    }
+
+    fn remove_orphans(&self, pkgs: &[String]) -> Result<()> {
+        let mut args = vec!["apt-get", "remove", "-y"];
+        args.extend(pkgs.iter().map(String::as_str));
+        run_cmd(&args, true)
+    }
 }
@@ -1,5 +1,6 @@
-use super::{PackageManager, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
+use super::{PackageManager, PmOrphan, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
 use anyhow::Result;
+use std::path::PathBuf;
 use std::process::Command;
 
 pub struct Brew;
@@ -16,7 +17,7 @@ impl PackageManager for Brew {
 
     fn list_updates(&self) -> Vec<PmUpdate> {
         // Query cached state; `brew update` runs during update()
-        let Ok(out) = Command::new("brew").args(["outdated", "--json=v2"]).output() else { return vec![] };
+        let Ok(out) = crate::logging::output(Command::new("brew").args(["outdated", "--json=v2"])) else { return vec![] };
         let text = String::from_utf8_lossy(&out.stdout);
         let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) else { return vec![] };
         let mut updates = Vec::new();
@@ -38,7 +39,7 @@ impl PackageManager for Brew {
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("brew").args(["search", query]).output()?;
+        let output = crate::logging::output(Command::new("brew").args(["search", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         Ok(stdout.lines()
             .filter(|l| !l.trim().is_empty() && !l.contains("==>"))
@@ -58,4 +59,30 @@ impl PackageManager for Brew {
     fn uninstall(&self, pkg: &str) -> Result<()> {
         run_cmd(&["brew", "uninstall", pkg], false)
     }
+
+    fn cache_dirs(&self) -> Vec<PathBuf> {
+        crate::logging::output(Command::new("brew").arg("--cache")).ok()
+            .map(|o| PathBuf::from(String::from_utf8_lossy(&o.stdout).trim()))
+            .into_iter().collect()
+    }
+
+    fn clean_cache(&self) -> Result<()> {
+        run_cmd(&["brew", "cleanup", "--prune=all"], false)
+    }
+
+    fn orphans(&self) -> Vec<PmOrphan> {
+        // "==> Would autoremove 2 unneeded formulae:" followed by one name per line
+        let Ok(out) = crate::logging::output(Command::new("brew").args(["autoremove", "--dry-run"])) else { return vec![] };
+        String::from_utf8_lossy(&out.stdout).lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with("==>"))
+            .map(|l| (l.to_string(), None))
+            .collect()
+    }
+
+    fn remove_orphans(&self, pkgs: &[String]) -> Result<()> {
+        let mut args = vec!["brew", "uninstall"];
+        args.extend(pkgs.iter().map(String::as_str));
+        run_cmd(&args, false)
+    }
 }
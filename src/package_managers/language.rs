@@ -1,5 +1,6 @@
 use super::{PackageManager, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
 use anyhow::Result;
+use std::path::PathBuf;
 use std::process::Command;
 
 pub struct Cargo;
@@ -22,7 +23,7 @@ impl PackageManager for Cargo {
     fn list_updates(&self) -> Vec<PmUpdate> {
         if !is_available("cargo-install-update") { return vec![]; }
         // cargo install-update -l: "Package  Installed  Latest  Needs update"
-        let Ok(out) = Command::new("cargo").args(["install-update", "-l"]).output() else { return vec![] };
+        let Ok(out) = crate::logging::output(Command::new("cargo").args(["install-update", "-l"])) else { return vec![] };
         String::from_utf8_lossy(&out.stdout)
             .lines()
             .skip(2) // two header lines
@@ -39,7 +40,7 @@ impl PackageManager for Cargo {
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("cargo").args(["search", "--limit", "10", query]).output()?;
+        let output = crate::logging::output(Command::new("cargo").args(["search", "--limit", "10", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         let mut results = Vec::new();
         for line in stdout.lines() {
@@ -83,7 +84,7 @@ impl PackageManager for Npm {
 
     fn list_updates(&self) -> Vec<PmUpdate> {
         // npm outdated -g --json: {"pkg": {"current": "x", "latest": "y"}}
-        let Ok(out) = Command::new("npm").args(["outdated", "-g", "--json"]).output() else { return vec![] };
+        let Ok(out) = crate::logging::output(Command::new("npm").args(["outdated", "-g", "--json"])) else { return vec![] };
         let text = String::from_utf8_lossy(&out.stdout);
         let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) else { return vec![] };
         json.as_object()
@@ -102,7 +103,7 @@ impl PackageManager for Npm {
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("npm").args(["search", "--json", query]).output()?;
+        let output = crate::logging::output(Command::new("npm").args(["search", "--json", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         if let Ok(json) = serde_json::from_str::<serde_json::Value>(&stdout) {
             if let Some(arr) = json.as_array() {
@@ -126,6 +127,14 @@ impl PackageManager for Npm {
     fn uninstall(&self, pkg: &str) -> Result<()> {
         run_cmd(&["npm", "uninstall", "-g", pkg], false)
     }
+
+    fn cache_dirs(&self) -> Vec<PathBuf> {
+        vec![dirs::home_dir().unwrap_or_default().join(".npm").join("_cacache")]
+    }
+
+    fn clean_cache(&self) -> Result<()> {
+        run_cmd(&["npm", "cache", "clean", "--force"], false)
+    }
 }
 
 impl PackageManager for Pipx {
@@ -141,7 +150,7 @@ impl PackageManager for Pipx {
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
         // pipx has no search; use pip index
-        let output = Command::new("pip").args(["index", "versions", query]).output();
+        let output = crate::logging::output(Command::new("pip").args(["index", "versions", query]));
         if let Ok(o) = output {
             let stdout = String::from_utf8_lossy(&o.stdout);
             for line in stdout.lines() {
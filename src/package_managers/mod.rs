@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::path::PathBuf;
 use which::which;
 
 pub mod arch;
@@ -6,6 +7,7 @@ pub mod debian;
 pub mod universal;
 pub mod language;
 pub mod homebrew;
+pub mod windows;
 
 #[derive(Debug, Clone)]
 pub struct PmPackage {
@@ -18,6 +20,9 @@ pub struct PmPackage {
 /// A pending package update: (name, old_version, new_version).
 pub type PmUpdate = (String, String, String);
 
+/// A package installed as a dependency that nothing needs any more: (name, installed bytes if known).
+pub type PmOrphan = (String, Option<u64>);
+
 pub trait PackageManager: Send + Sync {
     fn id(&self) -> &str;
     fn display_name(&self) -> &str;
@@ -34,6 +39,16 @@ pub trait PackageManager: Send + Sync {
     fn update_streaming(&self, yes: bool, _on_pkg_done: &mut dyn FnMut(&str)) -> Result<()> {
         self.update(yes)
     }
+    /// Directories holding downloaded packages; used to estimate what `clean_cache` frees.
+    fn cache_dirs(&self) -> Vec<PathBuf> { vec![] }
+    /// Delete downloaded package files. Default: nothing to clean.
+    fn clean_cache(&self) -> Result<()> { Ok(()) }
+    /// Dependencies no installed package needs any more. Empty = unsupported or none.
+    fn orphans(&self) -> Vec<PmOrphan> { vec![] }
+    /// Remove packages returned by `orphans`. Default: one `uninstall` each.
+    fn remove_orphans(&self, pkgs: &[String]) -> Result<()> {
+        pkgs.iter().try_for_each(|p| self.uninstall(p))
+    }
 }
 
 pub fn get_all_managers() -> Vec<Box<dyn PackageManager>> {
@@ -49,6 +64,8 @@ pub fn get_all_managers() -> Vec<Box<dyn PackageManager>> {
         Box::new(language::Npm),
         Box::new(language::Pipx),
         Box::new(homebrew::Brew),
+        Box::new(windows::Winget),
+        Box::new(windows::Choco),
     ]
 }
 
@@ -74,40 +91,26 @@ pub fn run_cmd_quiet(args: &[&str], sudo: bool) -> Result<()> {
 /// Spawn `args` silently, show a spinner with `label` until it exits, then clear the line.
 pub fn run_with_spinner(args: &[&str], sudo: bool, label: &str) -> Result<()> {
     use std::process::{Command, Stdio};
-    use std::io::Write;
 
     let (prog, rest) = if sudo { ("sudo", args) } else { (args[0], &args[1..]) };
     let mut cmd = Command::new(prog);
     if sudo { cmd.args(args); } else { cmd.args(rest); }
     cmd.stdout(Stdio::null()).stderr(Stdio::null());
 
+    tracing::debug!(command = ?args, sudo, "spawn");
+    let started = std::time::Instant::now();
+    let running = crate::process::Running::start();
     let mut child = cmd.spawn()?;
-    let frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-    let mut i = 0usize;
-
-    loop {
-        match child.try_wait()? {
-            Some(status) => {
-                // Clear the spinner line
-                print!("\r\x1b[2K");
-                std::io::stdout().flush().ok();
-                if !status.success() {
-                    anyhow::bail!("Command failed: {:?}", args);
-                }
-                return Ok(());
-            }
-            None => {
-                print!(
-                    "\r  \x1b[38;2;96;165;250m{}\x1b[0m  \x1b[38;2;71;85;105m{}\x1b[0m",
-                    frames[i % frames.len()],
-                    label,
-                );
-                std::io::stdout().flush().ok();
-                i += 1;
-                std::thread::sleep(std::time::Duration::from_millis(80));
-            }
-        }
+    let pb = crate::progress::spinner(label);
+    let status = crate::process::wait(&mut child, crate::process::Class::Task, &args.join(" "));
+    pb.finish_and_clear();
+    let status = status?;
+    tracing::debug!(command = ?args, exit = ?status.code(), ms = started.elapsed().as_millis() as u64, "process finished");
+    running.check()?;
+    if !status.success() {
+        anyhow::bail!("Command failed: {:?}", args);
     }
+    Ok(())
 }
 
 fn run_cmd_impl(args: &[&str], sudo: bool, quiet: bool) -> Result<()> {
@@ -118,7 +121,7 @@ fn run_cmd_impl(args: &[&str], sudo: bool, quiet: bool) -> Result<()> {
     if quiet {
         cmd.stdout(Stdio::null()).stderr(Stdio::null());
     }
-    let status = cmd.status()?;
+    let status = crate::logging::status(&mut cmd)?;
     if !status.success() {
         anyhow::bail!("Command failed: {:?}", args);
     }
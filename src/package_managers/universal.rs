@@ -16,9 +16,8 @@ impl PackageManager for Flatpak {
 
     fn list_updates(&self) -> Vec<PmUpdate> {
         // flatpak remote-ls --updates: tab-separated application, installed-version, latest-version
-        let Ok(out) = Command::new("flatpak")
-            .args(["remote-ls", "--updates", "--columns=application,installed-version,version"])
-            .output() else { return vec![] };
+        let Ok(out) = crate::logging::output(Command::new("flatpak")
+            .args(["remote-ls", "--updates", "--columns=application,installed-version,version"])) else { return vec![] };
         String::from_utf8_lossy(&out.stdout)
             .lines()
             .filter_map(|line| {
@@ -36,7 +35,7 @@ impl PackageManager for Flatpak {
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("flatpak").args(["search", "--columns=application,name,version,description", query]).output()?;
+        let output = crate::logging::output(Command::new("flatpak").args(["search", "--columns=application,name,version,description", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         let mut results = Vec::new();
         for line in stdout.lines().skip(1) {
@@ -76,7 +75,7 @@ impl PackageManager for Snap {
 
     fn list_updates(&self) -> Vec<PmUpdate> {
         // snap refresh --list: "Name  Version  Rev  Size  Publisher  Notes"
-        let Ok(out) = Command::new("snap").args(["refresh", "--list"]).output() else { return vec![] };
+        let Ok(out) = crate::logging::output(Command::new("snap").args(["refresh", "--list"])) else { return vec![] };
         String::from_utf8_lossy(&out.stdout)
             .lines()
             .skip(1) // header row
@@ -95,7 +94,7 @@ impl PackageManager for Snap {
     }
 
     fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
-        let output = Command::new("snap").args(["find", query]).output()?;
+        let output = crate::logging::output(Command::new("snap").args(["find", query]))?;
         let stdout = String::from_utf8_lossy(&output.stdout);
         let mut results = Vec::new();
         for line in stdout.lines().skip(1) {
@@ -0,0 +1,141 @@
+use super::{PackageManager, PmPackage, PmUpdate, is_available, run_cmd, run_with_spinner};
+use anyhow::Result;
+use std::process::Command;
+
+/// Without these, winget stops at a license prompt the first time a source is used.
+const AGREEMENTS: [&str; 2] = ["--accept-source-agreements", "--disable-interactivity"];
+
+pub struct Winget;
+
+/// Rows of a winget table. winget has no machine-readable output for `upgrade` or `search`,
+/// so columns are cut at the header's word positions (the header is localized, the layout isn't).
+fn winget_table(text: &str) -> Vec<Vec<String>> {
+    // Progress spinners are redrawn with \r before the table
+    let lines: Vec<&str> = text.lines().map(|l| l.rsplit('\r').next().unwrap_or(l)).collect();
+    let Some(rule) = lines.iter().position(|l| l.len() > 10 && l.trim().chars().all(|c| c == '-')) else {
+        return Vec::new();
+    };
+    let Some(header) = rule.checked_sub(1).map(|i| lines[i]) else { return Vec::new() };
+    let header: Vec<char> = header.chars().collect();
+    let starts: Vec<usize> = (0..header.len())
+        .filter(|&i| header[i] != ' ' && (i == 0 || header[i - 1] == ' '))
+        .collect();
+
+    let mut rows = Vec::new();
+    // A blank line ends the table; a second table ("require explicit targeting") may follow
+    for line in lines[rule + 1..].iter().take_while(|l| !l.trim().is_empty()) {
+        let chars: Vec<char> = line.chars().collect();
+        let cell = |i: usize| {
+            let from = starts[i].min(chars.len());
+            let to = starts.get(i + 1).copied().unwrap_or(chars.len()).min(chars.len());
+            chars[from..to].iter().collect::<String>().trim().to_string()
+        };
+        let row: Vec<String> = (0..starts.len()).map(cell).collect();
+        // The "3 upgrades available." summary ends before the version column
+        if row.len() >= 3 && !row[1].is_empty() && !row[1].contains(' ') && !row[2].is_empty() {
+            rows.push(row);
+        }
+    }
+    rows
+}
+
+impl PackageManager for Winget {
+    fn id(&self) -> &str { "winget" }
+    fn display_name(&self) -> &str { "winget (Windows)" }
+    fn is_available(&self) -> bool { cfg!(windows) && is_available("winget") }
+
+    fn update(&self, _yes: bool) -> Result<()> {
+        let mut args = vec!["winget", "upgrade", "--all", "--accept-package-agreements"];
+        args.extend(AGREEMENTS);
+        run_with_spinner(&args, false, "Upgrading packages…")
+    }
+
+    fn list_updates(&self) -> Vec<PmUpdate> {
+        let Ok(out) = crate::logging::output(Command::new("winget").arg("upgrade").args(AGREEMENTS)) else { return vec![] };
+        // Columns: Name, Id, Version, Available, Source
+        winget_table(&String::from_utf8_lossy(&out.stdout))
+            .into_iter()
+            .filter(|row| row.len() >= 4 && !row[3].is_empty())
+            .map(|row| (row[1].clone(), row[2].clone(), row[3].clone()))
+            .collect()
+    }
+
+    fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
+        let output = crate::logging::output(Command::new("winget").args(["search", query]).args(AGREEMENTS))?;
+        // Columns: Name, Id, Version, [Match,] Source — installs go by Id
+        Ok(winget_table(&String::from_utf8_lossy(&output.stdout))
+            .into_iter()
+            .map(|row| PmPackage {
+                name: row[1].clone(),
+                version: Some(row[2].clone()),
+                description: Some(row[0].clone()),
+                source: "winget".to_string(),
+            })
+            .collect())
+    }
+
+    fn install(&self, pkg: &str, _yes: bool) -> Result<()> {
+        let mut args = vec!["winget", "install", "--id", pkg, "--exact", "--accept-package-agreements"];
+        args.extend(AGREEMENTS);
+        run_cmd(&args, false)
+    }
+
+    fn uninstall(&self, pkg: &str) -> Result<()> {
+        let mut args = vec!["winget", "uninstall", "--id", pkg, "--exact"];
+        args.extend(AGREEMENTS);
+        run_cmd(&args, false)
+    }
+}
+
+pub struct Choco;
+
+/// `choco … -r` prints one `field|field|…` record per line.
+fn choco_records(text: &str) -> impl Iterator<Item = Vec<&str>> {
+    text.lines().map(|l| l.trim().split('|').collect::<Vec<_>>()).filter(|f| f.len() >= 2)
+}
+
+impl PackageManager for Choco {
+    fn id(&self) -> &str { "choco" }
+    fn display_name(&self) -> &str { "Chocolatey" }
+    fn is_available(&self) -> bool { cfg!(windows) && is_available("choco") }
+
+    fn update(&self, yes: bool) -> Result<()> {
+        if yes {
+            run_with_spinner(&["choco", "upgrade", "all", "-y"], false, "Upgrading packages…")
+        } else {
+            run_cmd(&["choco", "upgrade", "all"], false)
+        }
+    }
+
+    fn list_updates(&self) -> Vec<PmUpdate> {
+        let Ok(out) = crate::logging::output(Command::new("choco").args(["outdated", "-r"])) else { return vec![] };
+        // Format: "name|current|available|pinned"
+        choco_records(&String::from_utf8_lossy(&out.stdout))
+            .filter(|f| f.len() >= 3 && f.get(3) != Some(&"true"))
+            .map(|f| (f[0].to_string(), f[1].to_string(), f[2].to_string()))
+            .collect()
+    }
+
+    fn search(&self, query: &str) -> Result<Vec<PmPackage>> {
+        let output = crate::logging::output(Command::new("choco").args(["search", query, "-r"]))?;
+        // Format: "name|version"
+        Ok(choco_records(&String::from_utf8_lossy(&output.stdout))
+            .map(|f| PmPackage {
+                name: f[0].to_string(),
+                version: Some(f[1].to_string()),
+                description: None,
+                source: "choco".to_string(),
+            })
+            .collect())
+    }
+
+    fn install(&self, pkg: &str, yes: bool) -> Result<()> {
+        let mut args = vec!["choco", "install", pkg];
+        if yes { args.push("-y"); }
+        run_cmd(&args, false)
+    }
+
+    fn uninstall(&self, pkg: &str) -> Result<()> {
+        run_cmd(&["choco", "uninstall", pkg], false)
+    }
+}
@@ -0,0 +1,143 @@
+// src/process.rs
+// Timeouts and Ctrl-C handling for external processes.
+// While a child process runs, Ctrl-C reaches it through the terminal and vg itself keeps going:
+// the child exits, the spawn wrapper reports the interruption as an error, and the command
+// unwinds through its cleanup instead of dying halfway through writing a file. Outside a child,
+// Ctrl-C ends vg immediately as before.
+
+use crate::config::TimeoutsConfig;
+use std::io;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static TIMEOUTS: OnceLock<TimeoutsConfig> = OnceLock::new();
+
+/// How long a kind of process may run before it is killed.
+#[derive(Clone, Copy)]
+pub enum Class {
+    /// Output captured for vg to read: update lists, searches, probes
+    Query,
+    /// Non-interactive work behind a spinner: package upgrades, cache refreshes
+    Task,
+}
+
+impl Class {
+    fn limit(self) -> Option<Duration> {
+        let config = TIMEOUTS.get_or_init(TimeoutsConfig::default);
+        let secs = match self {
+            Class::Query => config.query,
+            Class::Task => config.task,
+        };
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Class::Query => "timeouts.query",
+            Class::Task => "timeouts.task",
+        }
+    }
+}
+
+#[cfg(unix)]
+extern "C" fn on_sigint(_: libc::c_int) {
+    if RUNNING.load(Ordering::SeqCst) > 0 {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+    } else {
+        // Nothing to wait for: behave like the default handler
+        unsafe {
+            libc::signal(libc::SIGINT, libc::SIG_DFL);
+            libc::raise(libc::SIGINT);
+        }
+    }
+}
+
+/// Install the Ctrl-C handler and the configured limits. Called once at startup.
+pub fn init(timeouts: &TimeoutsConfig) {
+    let _ = TIMEOUTS.set(timeouts.clone());
+    #[cfg(unix)]
+    unsafe {
+        libc::signal(libc::SIGINT, on_sigint as *const () as libc::sighandler_t);
+    }
+}
+
+/// Whether Ctrl-C was pressed while it was deferred.
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Defers Ctrl-C while alive: a child process is running, or files are being changed
+/// under an undo record (`history::Recorder`).
+pub struct Running(());
+
+impl Running {
+    pub fn start() -> Self {
+        RUNNING.fetch_add(1, Ordering::SeqCst);
+        Running(())
+    }
+
+    /// The error to return once the child has exited, if Ctrl-C was pressed meanwhile.
+    pub fn check(&self) -> io::Result<()> {
+        if interrupted() {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Interrupted"));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Wait for `child` within the limit for `class`, killing it when the limit passes.
+pub fn wait(child: &mut Child, class: Class, what: &str) -> io::Result<ExitStatus> {
+    let deadline = class.limit().map(|limit| (Instant::now() + limit, limit));
+    // Short pauses first: most queries finish within a few milliseconds
+    let mut pause = Duration::from_millis(1);
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if let Some((at, limit)) = deadline {
+            if Instant::now() >= at {
+                // A child running under sudo belongs to root and can't be killed from here
+                let outcome = if child.kill().is_ok() {
+                    let _ = child.wait();
+                    "was stopped".to_string()
+                } else {
+                    format!("is still running as PID {}", child.id())
+                };
+                tracing::debug!(command = what, secs = limit.as_secs(), outcome = %outcome, "timed out");
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("{} did not finish within {} s and {} (raise {} in the config)", what, limit.as_secs(), outcome, class.key()),
+                ));
+            }
+        }
+        std::thread::sleep(pause);
+        pause = (pause * 2).min(Duration::from_millis(80));
+    }
+}
+
+/// Runs `cleanup` if it is dropped after Ctrl-C, i.e. while a command unwinds from an interruption.
+pub struct OnInterrupt<F: FnOnce()>(Option<F>);
+
+pub fn on_interrupt<F: FnOnce()>(cleanup: F) -> OnInterrupt<F> {
+    OnInterrupt(Some(cleanup))
+}
+
+impl<F: FnOnce()> Drop for OnInterrupt<F> {
+    fn drop(&mut self) {
+        if interrupted() {
+            if let Some(cleanup) = self.0.take() {
+                cleanup();
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// src/progress.rs
+// Progress bars and spinners for long operations, in the colours of the rest of vg's output.
+// They draw only when stdout is a terminal, so piped and background runs stay clean.
+// Callers `inc` them as work completes and `finish_and_clear` them before printing results.
+
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use std::time::Duration;
+
+/// Braille spinner; the last frame is shown once finished.
+const FRAMES: &str = "⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏ ";
+const TICK: Duration = Duration::from_millis(80);
+
+fn new(len: Option<u64>, template: &str, label: &str) -> ProgressBar {
+    let pb = ProgressBar::with_draw_target(len, ProgressDrawTarget::stdout());
+    // Templates are fixed strings; a typo shows up on the first run
+    let style = ProgressStyle::with_template(template).expect("valid progress template")
+        .progress_chars("█░")
+        .tick_chars(FRAMES);
+    pb.set_style(style);
+    pb.set_message(label.to_string());
+    pb
+}
+
+/// Work of known size: bar, percentage, count and time left.
+pub fn bar(total: u64, label: &str) -> ProgressBar {
+    new(Some(total), "  {bar:30.75/240}  {percent:>3}%  {msg:.240}  {human_pos}/{human_len} · {eta} left", label)
+}
+
+/// A download or copy of known size.
+pub fn bytes(total: u64, label: &str) -> ProgressBar {
+    new(Some(total), "  {bar:30.75/240}  {percent:>3}%  {msg:.240}  {bytes}/{total_bytes} · {binary_bytes_per_sec} · {eta} left", label)
+}
+
+/// Work of unknown size: a running count of `unit` and the rate.
+pub fn counter(label: &str, unit: &str) -> ProgressBar {
+    let pb = new(None, &format!("  {{spinner:.75}}  {{msg:.240}}  {{human_pos}} {} ({{per_sec}})", unit), label);
+    pb.enable_steady_tick(TICK);
+    pb
+}
+
+/// Waiting on something that reports no progress at all.
+pub fn spinner(label: &str) -> ProgressBar {
+    let pb = new(None, "  {spinner:.75}  {msg:.240}  {elapsed:.240}", label);
+    pb.enable_steady_tick(TICK);
+    pb
+}
@@ -0,0 +1,173 @@
+// src/secrets.rs
+// API keys and tokens, kept out of config.toml.
+// Secrets live in the OS keyring when one is reachable (`secret-tool` on Linux, the
+// Keychain on macOS, Credential Manager on Windows) and in a 0600 `secrets.toml` next to
+// the config otherwise.
+// An environment variable named after the secret (upper-cased) always wins.
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+#[cfg(unix)]
+use std::io::Write;
+use std::path::Path;
+#[cfg(not(any(target_os = "macos", windows)))]
+use std::process::{Command, Stdio};
+use which::which;
+
+const SERVICE: &str = "volantic-genesis";
+
+/// Where a secret ended up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Store {
+    Keyring,
+    File,
+}
+
+impl Store {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Store::Keyring => "OS keyring",
+            Store::File => "secrets file",
+        }
+    }
+}
+
+/// True when `backend` (from `secrets.backend`) allows the keyring and a keyring is available.
+fn keyring_enabled(backend: &str) -> bool {
+    if backend == "file" {
+        return false;
+    }
+    cfg!(any(target_os = "macos", windows)) || which("secret-tool").is_ok()
+}
+
+#[cfg(any(target_os = "macos", windows))]
+fn keyring_get(name: &str) -> Option<String> {
+    keyring::Entry::new(SERVICE, name).ok()?.get_password().ok().filter(|v| !v.is_empty())
+}
+
+#[cfg(any(target_os = "macos", windows))]
+fn keyring_set(name: &str, value: &str) -> Result<()> {
+    keyring::Entry::new(SERVICE, name)
+        .and_then(|e| e.set_password(value))
+        .map_err(|e| anyhow!("keyring rejected the secret: {}", e))
+}
+
+#[cfg(any(target_os = "macos", windows))]
+fn keyring_delete(name: &str) {
+    if let Ok(entry) = keyring::Entry::new(SERVICE, name) {
+        let _ = entry.delete_credential();
+    }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn keyring_get(name: &str) -> Option<String> {
+    let out = Command::new("secret-tool")
+        .args(["lookup", "service", SERVICE, "key", name])
+        .output().ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    let value = String::from_utf8_lossy(&out.stdout).trim_end_matches('\n').to_string();
+    if value.is_empty() { None } else { Some(value) }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn keyring_set(name: &str, value: &str) -> Result<()> {
+    // secret-tool reads the secret from stdin so it never shows up in `ps`
+    let mut child = Command::new("secret-tool")
+        .args(["store", "--label", &format!("vg {}", name), "service", SERVICE, "key", name])
+        .stdin(Stdio::piped()).stdout(Stdio::null()).stderr(Stdio::null())
+        .spawn()?;
+    child.stdin.take().context("Failed to open secret-tool stdin")?.write_all(value.as_bytes())?;
+    if !child.wait()?.success() {
+        return Err(anyhow!("keyring rejected the secret (is a keyring daemon running?)"));
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn keyring_delete(name: &str) {
+    let _ = Command::new("secret-tool")
+        .args(["clear", "service", SERVICE, "key", name])
+        .stdout(Stdio::null()).stderr(Stdio::null())
+        .status();
+}
+
+fn read_file(path: &Path) -> BTreeMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|c| toml::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn write_file(path: &Path, secrets: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    write_private(path, toml::to_string_pretty(secrets)?.as_bytes()).context("Failed to write secrets file")
+}
+
+/// Write a file only its owner can read (0600 on Unix), also when it already existed.
+pub fn write_private(path: &Path, content: &[u8]) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        let mut f = std::fs::OpenOptions::new()
+            .write(true).create(true).truncate(true).mode(0o600)
+            .open(path)?;
+        // mode() only applies to a new file; tighten one created looser by hand or an older vg
+        f.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        f.write_all(content)?;
+    }
+    #[cfg(not(unix))]
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Look up `name`: environment variable first, then keyring, then the secrets file.
+pub fn get(name: &str, backend: &str, file: &Path) -> Option<String> {
+    if let Ok(v) = std::env::var(name.to_uppercase()) {
+        if !v.is_empty() {
+            return Some(v);
+        }
+    }
+    if keyring_enabled(backend) {
+        if let Some(v) = keyring_get(name) {
+            return Some(v);
+        }
+    }
+    read_file(file).get(name).cloned()
+}
+
+/// Store `name`, preferring the keyring and falling back to the secrets file.
+pub fn set(name: &str, value: &str, backend: &str, file: &Path) -> Result<Store> {
+    if keyring_enabled(backend) && keyring_set(name, value).is_ok() {
+        // Don't leave an older plaintext copy shadowed behind the keyring entry
+        let mut secrets = read_file(file);
+        if secrets.remove(name).is_some() {
+            write_file(file, &secrets)?;
+        }
+        return Ok(Store::Keyring);
+    }
+    if backend == "keyring" {
+        return Err(anyhow!("No usable OS keyring found and secrets.backend = \"keyring\""));
+    }
+    let mut secrets = read_file(file);
+    secrets.insert(name.to_string(), value.to_string());
+    write_file(file, &secrets)?;
+    Ok(Store::File)
+}
+
+/// Remove `name` from every store. Returns false if it wasn't stored anywhere.
+pub fn delete(name: &str, backend: &str, file: &Path) -> Result<bool> {
+    let mut found = false;
+    if keyring_enabled(backend) && keyring_get(name).is_some() {
+        keyring_delete(name);
+        found = true;
+    }
+    let mut secrets = read_file(file);
+    if secrets.remove(name).is_some() {
+        write_file(file, &secrets)?;
+        found = true;
+    }
+    Ok(found)
+}
@@ -0,0 +1,134 @@
+// src/tools.rs
+// External programs vg commands shell out to, and the packages that provide them.
+// Commands call `require` instead of checking `which` themselves: a missing tool is
+// offered for installation through the system package manager when vg runs interactively,
+// and otherwise reported with the package name for this platform.
+
+use crate::package_managers::get_available_managers;
+use crate::ui;
+use anyhow::{anyhow, Result};
+use inquire::Confirm;
+use std::io::IsTerminal;
+use which::which;
+
+pub struct Tool {
+    /// Executables that provide it; any one will do
+    pub bins: &'static [&'static str],
+    /// Commands that need it
+    pub used_by: &'static str,
+    /// Package names per package manager family; empty where it isn't packaged there
+    /// (or, on macOS, where the system already ships an equivalent)
+    arch: &'static str,
+    debian: &'static str,
+    brew: &'static str,
+    cargo: &'static str,
+}
+
+const fn tool(
+    bins: &'static [&'static str],
+    used_by: &'static str,
+    [arch, debian, brew]: [&'static str; 3],
+) -> Tool {
+    Tool { bins, used_by, arch, debian, brew, cargo: "" }
+}
+
+pub const TOOLS: &[Tool] = &[
+    tool(&["git"], "vg git, vg hooks, vg sync", ["git", "git", "git"]),
+    tool(&["rclone"], "vg sync (rclone backend)", ["rclone", "rclone", "rclone"]),
+    tool(&["docker", "podman"], "vg containers", ["docker", "docker.io", "docker"]),
+    tool(&["journalctl"], "vg service logs, vg clean", ["systemd", "systemd", ""]),
+    tool(&["systemd-analyze"], "vg boot", ["systemd", "systemd", ""]),
+    tool(&["fwupdmgr"], "vg update --firmware", ["fwupd", "fwupd", ""]),
+    tool(&["reflector", "rate-mirrors"], "vg mirrors (Arch)", ["reflector", "", ""]),
+    tool(&["udisksctl"], "vg disks", ["udisks2", "udisks2", ""]),
+    tool(&["notify-send"], "vg remind", ["libnotify", "libnotify-bin", ""]),
+    tool(&["secret-tool"], "keyring secrets", ["libsecret", "libsecret-tools", ""]),
+    tool(&["gnome-screenshot", "spectacle", "grim", "scrot", "maim"], "vg shot", ["scrot", "scrot", ""]),
+    tool(&["wl-copy", "xclip", "xsel"], "clipboard copies", ["wl-clipboard", "wl-clipboard", ""]),
+    tool(&["zbarimg"], "vg qr read", ["zbar", "zbar-tools", "zbar"]),
+    tool(&["qpdf"], "vg pdf (files lopdf can't read)", ["qpdf", "qpdf", "qpdf"]),
+    tool(&["pdftotext"], "vg pdf text", ["poppler", "poppler-utils", "poppler"]),
+    tool(&["gs"], "vg pdf compress", ["ghostscript", "ghostscript", "ghostscript"]),
+    tool(&["magick", "convert"], "vg img", ["imagemagick", "imagemagick", "imagemagick"]),
+    tool(&["cwebp"], "vg img (WebP)", ["libwebp", "webp", "webp"]),
+    tool(&["tar"], "vg archive (.tar*)", ["tar", "tar", ""]),
+    tool(&["zip"], "vg archive (creating .zip)", ["zip", "zip", ""]),
+    tool(&["unzip"], "vg archive (.zip)", ["unzip", "unzip", ""]),
+    tool(&["7z", "7zz", "7za"], "vg archive (.7z)", ["7zip", "p7zip-full", "sevenzip"]),
+    tool(&["rsync"], "vg manjaro", ["rsync", "rsync", "rsync"]),
+    tool(&["ssh-keygen"], "vg ssh", ["openssh", "openssh-client", ""]),
+    tool(&["ssh"], "vg --host", ["openssh", "openssh-client", ""]),
+    Tool { cargo: "cargo-update", ..tool(&["cargo-install-update"], "vg update (cargo binaries)", ["", "", ""]) },
+];
+
+impl Tool {
+    /// The first of `bins` on PATH.
+    pub fn installed(&self) -> Option<&'static str> {
+        self.bins.iter().copied().find(|b| which(b).is_ok())
+    }
+
+    /// Package name for a package manager id (see `PackageManager::id`).
+    fn package_for(&self, manager: &str) -> &'static str {
+        match manager {
+            "pamac" | "yay" | "paru" | "pacman" => self.arch,
+            "apt" => self.debian,
+            "brew" => self.brew,
+            "cargo" => self.cargo,
+            _ => "",
+        }
+    }
+
+    /// Package to mention in messages: the one for an available manager, else the bin itself.
+    pub fn package(&self) -> &'static str {
+        get_available_managers().iter()
+            .map(|m| self.package_for(m.id()))
+            .find(|p| !p.is_empty())
+            .unwrap_or(self.bins[0])
+    }
+
+    /// Whether this system packages it: Linux-only tools don't apply on macOS, Arch-only ones not on Ubuntu.
+    pub fn applies(&self) -> bool {
+        if cfg!(windows) {
+            // No package names for winget/choco here; list the cross-platform tools
+            return !self.brew.is_empty();
+        }
+        get_available_managers().iter().any(|m| !self.package_for(m.id()).is_empty())
+    }
+}
+
+/// Registry entry for an executable name.
+pub fn find(bin: &str) -> Option<&'static Tool> {
+    TOOLS.iter().find(|t| t.bins.contains(&bin))
+}
+
+/// The installed executable for `bin` (or one of its alternatives). When none is installed,
+/// offer to install it in an interactive session, otherwise fail with an install hint.
+pub fn require(bin: &str) -> Result<&'static str> {
+    let tool = find(bin).ok_or_else(|| anyhow!("{} is not in the tool registry", bin))?;
+    if let Some(found) = tool.installed() {
+        return Ok(found);
+    }
+
+    let hint = || anyhow::Error::from(crate::error::Error::ToolMissing {
+        bin: tool.bins[0].to_string(),
+        used_by: tool.used_by.to_string(),
+        package: tool.package().to_string(),
+    });
+    if !crate::ui::interactive() || !std::io::stdout().is_terminal() {
+        return Err(hint());
+    }
+    let plan = get_available_managers().into_iter()
+        .find_map(|m| Some((tool.package_for(m.id()), m)).filter(|(p, _)| !p.is_empty()));
+    let Some((package, manager)) = plan else { return Err(hint()) };
+
+    let prompt = format!("{} is needed for {}. Install {} with {}?", tool.bins[0], tool.used_by, package, manager.display_name());
+    if !Confirm::new(&prompt).with_default(true).prompt()? {
+        return Err(hint());
+    }
+    manager.install(package, false)?;
+    crate::commands::pkg::record("install", manager.id(), package);
+    let found = tool.installed().ok_or_else(|| anyhow!("{} installed, but {} is still not on PATH", package, tool.bins[0]))?;
+    ui::success(&format!("Installed {}", package));
+    println!();
+    Ok(found)
+}
@@ -87,3 +87,30 @@ pub fn info_line(label: &str, value: &str) {
         rgb(TEXT_MAIN.0, TEXT_MAIN.1, TEXT_MAIN.2, value)
     );
 }
+
+static NON_INTERACTIVE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Never prompt for the rest of the run (`--non-interactive`, or `CI` set in the environment).
+pub fn set_non_interactive() {
+    NON_INTERACTIVE.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Whether vg may prompt: someone is at the terminal and prompts weren't turned off.
+pub fn interactive() -> bool {
+    use std::io::IsTerminal;
+    !NON_INTERACTIVE.load(std::sync::atomic::Ordering::SeqCst) && std::io::stdin().is_terminal()
+}
+
+/// Fails with a not-a-terminal error saying what to pass `instead` when vg may not prompt.
+pub fn ensure_interactive(instead: &str) -> anyhow::Result<()> {
+    if interactive() {
+        return Ok(());
+    }
+    Err(crate::error::Error::not_a_terminal(instead).into())
+}
+
+/// Ask before acting on `found`, the closest match for `query`; without a terminal only exact names are accepted.
+pub fn confirm_match(query: &str, found: &str) -> anyhow::Result<bool> {
+    ensure_interactive(&format!("use the exact name ({} is only the closest match for '{}')", found, query))?;
+    Ok(inquire::Confirm::new(&format!("Nothing is called '{}' — use {}?", query, found)).with_default(false).prompt()?)
+}
@@ -0,0 +1,102 @@
+// src/walk.rs
+// Parallel directory walking shared by index, tidy, grep, perms and the other tree walkers.
+// Directories are read on all cores through the `ignore` crate's parallel walker. Callers pass
+// a closure that runs on the worker threads for each entry — so per-file `stat`s and checks are
+// parallel too — and get back what it kept plus the entries that couldn't be read.
+
+use ignore::{DirEntry, WalkBuilder, WalkState};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+/// What to walk into. The default walks everything below the root, without following links.
+#[derive(Default, Clone)]
+pub struct Options {
+    /// Honour .gitignore, .ignore and the global git excludes, inside a git checkout or not
+    pub ignore_files: bool,
+    /// Skip dot-files and dot-directories
+    pub skip_hidden: bool,
+    /// Follow symlinks; link loops are reported as errors instead of being walked forever
+    pub follow_links: bool,
+    /// Stay on the root's filesystem
+    pub same_file_system: bool,
+    pub max_depth: Option<usize>,
+    /// Directory names never entered, wherever they appear (".git")
+    pub skip_names: Vec<String>,
+    /// Paths never entered, together with everything below them ("/proc")
+    pub skip_paths: Vec<PathBuf>,
+}
+
+/// The result of a walk.
+pub struct Walked<T> {
+    /// What the closure kept, in no particular order
+    pub items: Vec<T>,
+    /// Entries that couldn't be read: permission denied, vanished mid-walk, link loops
+    pub errors: Vec<ignore::Error>,
+}
+
+/// Walk `root` in parallel, keeping what `keep` returns for each entry (the root included, at depth 0).
+pub fn walk<T, F>(root: &Path, options: &Options, keep: F) -> Walked<T>
+where
+    T: Send,
+    F: Fn(&DirEntry) -> Option<T> + Sync,
+{
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .standard_filters(false)
+        .git_ignore(options.ignore_files)
+        .git_global(options.ignore_files)
+        .ignore(options.ignore_files)
+        .require_git(false)
+        .hidden(options.skip_hidden)
+        .follow_links(options.follow_links)
+        .same_file_system(options.same_file_system)
+        .max_depth(options.max_depth);
+    if !options.skip_names.is_empty() || !options.skip_paths.is_empty() {
+        let names = options.skip_names.clone();
+        let paths = options.skip_paths.clone();
+        builder.filter_entry(move |e| {
+            let skipped_name = e.file_type().is_some_and(|t| t.is_dir()) && names.iter().any(|n| e.file_name() == n.as_str());
+            !skipped_name && !paths.iter().any(|p| e.path().starts_with(p))
+        });
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let keep = &keep;
+    std::thread::scope(|s| {
+        // Drain while walking so kept items don't pile up in the channel
+        let collector = s.spawn(move || {
+            let mut walked = Walked { items: Vec::new(), errors: Vec::new() };
+            for result in rx {
+                match result {
+                    Ok(item) => walked.items.push(item),
+                    Err(e) => walked.errors.push(e),
+                }
+            }
+            walked
+        });
+        builder.build_parallel().run(|| {
+            let tx = tx.clone();
+            Box::new(move |entry| {
+                let sent = match entry {
+                    Ok(entry) => match keep(&entry) {
+                        Some(item) => tx.send(Ok(item)),
+                        None => Ok(()),
+                    },
+                    Err(e) => tx.send(Err(e)),
+                };
+                if sent.is_err() { WalkState::Quit } else { WalkState::Continue }
+            })
+        });
+        drop(tx);
+        let walked = collector.join().unwrap_or_else(|_| Walked { items: Vec::new(), errors: Vec::new() });
+        if !walked.errors.is_empty() {
+            tracing::debug!(root = %root.display(), errors = walked.errors.len(), "unreadable entries skipped");
+        }
+        walked
+    })
+}
+
+/// Regular files below `root`.
+pub fn files(root: &Path, options: &Options) -> Vec<PathBuf> {
+    walk(root, options, |e| e.file_type().is_some_and(|t| t.is_file()).then(|| e.path().to_path_buf())).items
+}